@@ -13,6 +13,9 @@
 //! - **Request/Response**: JSON serialization and deserialization
 //! - **Rate Limiting**: Built-in rate limit detection
 //! - **Timeout Handling**: Configurable request timeouts
+//! - **Undo Journal**: Records prior state of tasks and comments before changes
+//! - **Dry Run**: Prints mutating requests instead of sending them
+//! - **Protected Locations**: Refuses deletes in protected lists and spaces
 //!
 //! ## Usage
 //!
@@ -39,17 +42,25 @@
 
 use crate::config::Config;
 use crate::error::ClickUpError;
+use crate::journal::{Journal, JournalOperation};
 use crate::models::*;
 use crate::rate_limiter::RateLimiter;
 use colored::Colorize;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
-use reqwest::Client;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Timeout for attachment uploads and downloads, which can be much larger
+/// than regular API responses
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(600);
+
 /// ClickUp API client for making authenticated requests
 ///
 /// This struct provides a high-level interface for interacting with the ClickUp API.
@@ -65,6 +76,10 @@ pub struct ClickUpApi {
     config: Config,
     /// Rate limiter for managing API request limits
     rate_limiter: RateLimiter,
+    /// Journal recording prior state for mutating requests
+    journal: Journal,
+    /// Number of mutating requests printed instead of sent in dry-run mode
+    dry_run_count: Arc<AtomicUsize>,
 }
 
 impl ClickUpApi {
@@ -102,18 +117,211 @@ impl ClickUpApi {
         // Create rate limiter with configuration
         let rate_limiter = RateLimiter::new(config.rate_limit.clone());
 
+        // Journal mutating requests so they can be undone later. Nothing is
+        // changed in dry-run mode, so there is nothing to record.
+        let journal = if config.dry_run {
+            Journal::disabled()
+        } else {
+            Journal::default_location().unwrap_or_else(|e| {
+                warn!("Undo journal unavailable: {e}");
+                Journal::disabled()
+            })
+        };
+
         Ok(Self {
             client,
             config,
             rate_limiter,
+            journal,
+            dry_run_count: Arc::default(),
+        })
+    }
+
+    /// Returns a copy of this client that does not record journal entries
+    ///
+    /// This is used when reverting journal entries so that the revert itself
+    /// does not show up as a new change to undo.
+    pub fn without_journal(&self) -> Self {
+        Self {
+            journal: Journal::disabled(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the configuration used by this client
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Prints a mutating request instead of sending it when dry-run mode is enabled
+    ///
+    /// GET requests are always sent so that commands can still look up the
+    /// entities they would change. Intercepted requests are counted and
+    /// answered with a stand-in response, so commands that send many
+    /// requests carry on with the rest.
+    ///
+    /// # Returns
+    ///
+    /// Returns the stand-in response to hand back in place of the real one,
+    /// or `None` if the request should be sent. The response is a
+    /// `ClickUpError::DryRun` if no stand-in can be built for `T`.
+    fn intercept_dry_run<T>(
+        &self,
+        method: &reqwest::Method,
+        endpoint: &str,
+        body: Option<&Value>,
+        query_params: Option<&Vec<(String, String)>>,
+    ) -> Option<Result<T, ClickUpError>>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        if !self.config.dry_run || *method == reqwest::Method::GET {
+            return None;
+        }
+        let mut target = endpoint.to_string();
+        if let Some(params) = query_params {
+            let query_string: String = params
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            target = format!("{target}?{query_string}");
+        }
+        println!("{}", format!("[DRY RUN] {method} {target}").yellow());
+        if let Some(body) = body {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(body).unwrap_or_else(|_| body.to_string())
+            );
+        }
+        let sequence = self.dry_run_count.fetch_add(1, Ordering::Relaxed) + 1;
+        let response = Self::dry_run_response(endpoint, body, sequence);
+        Some(
+            serde_json::from_value(response)
+                .map_err(|_| ClickUpError::DryRun(format!("{method} {target} was not sent"))),
+        )
+    }
+
+    /// Builds the stand-in response for a request that was not sent
+    ///
+    /// Bulk commands create and update tasks, so the response is shaped like
+    /// a task: it keeps the task ID from the endpoint, or gets a placeholder
+    /// ID, and takes its name, description and status from the request.
+    /// Responses for other entities are read from the fields they share.
+    fn dry_run_response(endpoint: &str, body: Option<&Value>, sequence: usize) -> Value {
+        let mut segments = endpoint.trim_start_matches('/').split('/');
+        let (kind, entity_id) = (segments.next(), segments.next());
+        let id = match (kind, entity_id) {
+            (Some("task"), Some(task_id)) => task_id.to_string(),
+            _ => format!("dry-run-{sequence}"),
+        };
+        let list_id = match (kind, entity_id) {
+            (Some("list"), Some(list_id)) => list_id,
+            _ => "",
+        };
+        let field = |name: &str| {
+            body.and_then(|body| body.get(name))
+                .filter(|value| !value.is_null())
+                .cloned()
+        };
+        serde_json::json!({
+            "id": id,
+            "name": field("name"),
+            "text_content": "",
+            "description": field("description").unwrap_or_else(|| Value::from("")),
+            "status": {
+                "id": "",
+                "status": field("status").unwrap_or_else(|| Value::from("")),
+                "color": "",
+                "orderindex": 0,
+                "type": "open"
+            },
+            "orderindex": "0",
+            "date_created": "0",
+            "date_updated": "0",
+            "creator": {"id": 0, "username": "", "color": ""},
+            "assignees": [],
+            "watchers": [],
+            "checklists": [],
+            "tags": [],
+            "custom_fields": [],
+            "team_id": "",
+            "list": {"id": list_id, "access": true},
+            "space": {"id": ""},
+            "url": ""
         })
     }
 
+    /// Fetches the current state of a task for the journal
+    ///
+    /// Failures are logged and result in `None`, so a missing snapshot never
+    /// blocks the change itself.
+    async fn snapshot_task(&self, task_id: &str) -> Option<Value> {
+        if !self.journal.is_enabled() {
+            return None;
+        }
+        match self.get_task(task_id).await {
+            Ok(task) => serde_json::to_value(task).ok(),
+            Err(e) => {
+                warn!("Could not capture task {task_id} for undo journal: {e}");
+                None
+            }
+        }
+    }
+
+    /// Appends an entry to the journal, logging failures instead of returning them
+    ///
+    /// The API change has already happened when this is called, so a journal
+    /// write failure is reported but does not fail the command.
+    fn record_journal(
+        &self,
+        operation: JournalOperation,
+        entity_id: &str,
+        previous: Option<Value>,
+        parent_id: Option<String>,
+    ) {
+        if let Err(e) = self.journal.record(operation, entity_id, previous, parent_id) {
+            warn!("Failed to write undo journal entry: {e}");
+        }
+    }
+
+    /// Appends an entry for an entity that belongs to a task, list or view,
+    /// logging failures instead of returning them
+    fn record_journal_with_parent(
+        &self,
+        operation: JournalOperation,
+        entity_id: &str,
+        previous: Option<Value>,
+        parent: Option<(CommentTarget, String)>,
+    ) {
+        if let Err(e) = self
+            .journal
+            .record_with_parent(operation, entity_id, previous, parent)
+        {
+            warn!("Failed to write undo journal entry: {e}");
+        }
+    }
+
+    /// Builds the full URL for an endpoint
+    ///
+    /// Endpoints are relative to the v2 base URL. The few operations that
+    /// only exist in API v3 use endpoints starting with `/v3`, which are sent
+    /// to the matching v3 base URL (`.../api/v2` becomes `.../api/v3`).
+    fn endpoint_url(&self, endpoint: &str) -> String {
+        let base = self.config.api_base_url.trim_end_matches('/');
+        match endpoint.strip_prefix("/v3/") {
+            Some(path) => format!("{}/v3/{path}", base.strip_suffix("/v2").unwrap_or(base)),
+            None => format!("{base}{endpoint}"),
+        }
+    }
+
     /// Creates the authorization header for API requests
     ///
     /// This function retrieves the API token from configuration and formats it
     /// appropriately for HTTP authorization headers. ClickUp API v2 supports both
-    /// personal tokens and OAuth tokens, with different header formats.
+    /// personal tokens and OAuth tokens, with different header formats. Tokens
+    /// from `auth login --oauth` are recorded as `TokenType::OAuth` and always
+    /// use the `Bearer` scheme.
     ///
     /// # Returns
     ///
@@ -130,7 +338,7 @@ impl ClickUpApi {
         // ClickUp API v2 supports both personal tokens and OAuth tokens
         // Personal tokens (starting with 'pk_') are used directly
         // OAuth tokens and other tokens use 'Bearer' prefix
-        let auth_value = if token.starts_with("pk_") {
+        let auth_value = if self.config.token_type.is_personal() && token.starts_with("pk_") {
             // Personal token - use directly without Bearer
             token.to_string()
         } else {
@@ -142,6 +350,51 @@ impl ClickUpApi {
             .map_err(|e| ClickUpError::AuthError(format!("Invalid auth header: {e}")))
     }
 
+    /// Converts an unsuccessful response into a `ClickUpError`
+    ///
+    /// ClickUp's `{"err": ..., "ECODE": ...}` error bodies are included in the
+    /// message when present. Rate limiting (429) is retried by the callers and
+    /// is reported here as `RateLimitError` only if it reaches this point.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The response status code
+    /// * `response_text` - The response body
+    fn error_for_status(status: StatusCode, response_text: &str) -> ClickUpError {
+        let error_msg = if !response_text.is_empty() {
+            // Try to parse ClickUp-specific error format
+            if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(response_text) {
+                if let (Some(err_msg), Some(ecode)) = (
+                    error_json.get("err").and_then(|v| v.as_str()),
+                    error_json.get("ECODE").and_then(|v| v.as_str()),
+                ) {
+                    format!("ClickUp Error {ecode}: {err_msg}")
+                } else {
+                    response_text.to_string()
+                }
+            } else {
+                response_text.to_string()
+            }
+        } else {
+            format!(
+                "HTTP {}: {}",
+                status.as_u16(),
+                status.canonical_reason().unwrap_or("Unknown")
+            )
+        };
+        match status.as_u16() {
+            400 => ClickUpError::ValidationError(format!("Bad request: {error_msg}")),
+            401 => ClickUpError::AuthError("Invalid API token".to_string()),
+            403 => ClickUpError::PermissionError("Insufficient permissions".to_string()),
+            404 => ClickUpError::NotFoundError("Resource not found".to_string()),
+            409 => ClickUpError::ApiError(format!("Conflict: {error_msg}")),
+            422 => ClickUpError::ValidationError(format!("Validation error: {error_msg}")),
+            429 => ClickUpError::RateLimitError,
+            500..=599 => ClickUpError::ApiError(format!("Server error: {error_msg}")),
+            _ => ClickUpError::ApiError(error_msg),
+        }
+    }
+
     /// Makes an HTTP request to the ClickUp API
     ///
     /// This is the core function that handles all API communication. It automatically
@@ -178,12 +431,17 @@ impl ClickUpApi {
         T: for<'de> serde::Deserialize<'de> + Send + 'static,
     {
         Box::pin(async move {
+            if let Some(response) =
+                self.intercept_dry_run(&method, endpoint, body.as_ref(), query_params.as_ref())
+            {
+                return response;
+            }
             // Reset retry count for new request
             self.rate_limiter.reset_retry_count().await?;
             // Wait if we're approaching rate limits
             self.rate_limiter.wait_if_needed().await?;
             // Construct the full URL
-            let mut url = format!("{}{}", self.config.api_base_url, endpoint);
+            let mut url = self.endpoint_url(endpoint);
             // Add query parameters if provided
             if let Some(ref params) = query_params {
                 let query_string: String = params
@@ -221,63 +479,18 @@ impl ClickUpApi {
             debug!("Response status: {status}, body: {response_text}");
             // Handle error responses
             if !status.is_success() {
-                let error_msg = if !response_text.is_empty() {
-                    // Try to parse ClickUp-specific error format
-                    if let Ok(error_json) =
-                        serde_json::from_str::<serde_json::Value>(&response_text)
-                    {
-                        if let (Some(err_msg), Some(ecode)) = (
-                            error_json.get("err").and_then(|v| v.as_str()),
-                            error_json.get("ECODE").and_then(|v| v.as_str()),
-                        ) {
-                            format!("ClickUp Error {ecode}: {err_msg}")
-                        } else {
-                            response_text.clone()
-                        }
-                    } else {
-                        response_text.clone()
-                    }
-                } else {
-                    format!(
-                        "HTTP {}: {}",
-                        status.as_u16(),
-                        status.canonical_reason().unwrap_or("Unknown")
-                    )
-                };
-                return match status.as_u16() {
-                    400 => Err(ClickUpError::ValidationError(format!(
-                        "Bad request: {error_msg}"
-                    ))),
-                    401 => Err(ClickUpError::AuthError("Invalid API token".to_string())),
-                    403 => Err(ClickUpError::PermissionError(
-                        "Insufficient permissions".to_string(),
-                    )),
-                    404 => Err(ClickUpError::NotFoundError(
-                        "Resource not found".to_string(),
-                    )),
-                    409 => Err(ClickUpError::ApiError(format!("Conflict: {error_msg}"))),
-                    422 => Err(ClickUpError::ValidationError(format!(
-                        "Validation error: {error_msg}"
-                    ))),
-                    429 => {
-                        // Handle rate limiting with retry logic
-                        match self
-                            .rate_limiter
-                            .handle_rate_limit(retry_after_seconds)
-                            .await
-                        {
-                            Ok(()) => {
-                                // Retry the request after waiting
-                                info!("Retrying request after rate limit wait");
-                                self.make_request(method, endpoint, body, query_params)
-                                    .await
-                            }
-                            Err(e) => Err(e),
-                        }
-                    }
-                    500..=599 => Err(ClickUpError::ApiError(format!("Server error: {error_msg}"))),
-                    _ => Err(ClickUpError::ApiError(error_msg)),
-                };
+                if status.as_u16() == 429 {
+                    // Handle rate limiting with retry logic
+                    self.rate_limiter
+                        .handle_rate_limit(retry_after_seconds)
+                        .await?;
+                    // Retry the request after waiting
+                    info!("Retrying request after rate limit wait");
+                    return self
+                        .make_request(method, endpoint, body, query_params)
+                        .await;
+                }
+                return Err(Self::error_for_status(status, &response_text));
             }
             // Parse the response JSON
             serde_json::from_str(&response_text).map_err(|e| {
@@ -316,9 +529,18 @@ impl ClickUpApi {
         query_params: Option<Vec<(String, String)>>,
     ) -> Pin<Box<dyn Future<Output = Result<String, ClickUpError>> + Send + 'a>> {
         Box::pin(async move {
+            // Commands only check that a raw response arrived
+            if let Some(response) = self.intercept_dry_run::<Value>(
+                &method,
+                endpoint,
+                body.as_ref(),
+                query_params.as_ref(),
+            ) {
+                return response.map(|_| String::new());
+            }
             self.rate_limiter.reset_retry_count().await?;
             self.rate_limiter.wait_if_needed().await?;
-            let mut url = format!("{}{}", self.config.api_base_url, endpoint);
+            let mut url = self.endpoint_url(endpoint);
             if let Some(ref params) = query_params {
                 let query_string: String = params
                     .iter()
@@ -350,60 +572,16 @@ impl ClickUpApi {
             })?;
             debug!("Response status: {status}, body: {response_text}");
             if !status.is_success() {
-                let error_msg = if !response_text.is_empty() {
-                    if let Ok(error_json) =
-                        serde_json::from_str::<serde_json::Value>(&response_text)
-                    {
-                        if let (Some(err_msg), Some(ecode)) = (
-                            error_json.get("err").and_then(|v| v.as_str()),
-                            error_json.get("ECODE").and_then(|v| v.as_str()),
-                        ) {
-                            format!("ClickUp Error {ecode}: {err_msg}")
-                        } else {
-                            response_text.clone()
-                        }
-                    } else {
-                        response_text.clone()
-                    }
-                } else {
-                    format!(
-                        "HTTP {}: {}",
-                        status.as_u16(),
-                        status.canonical_reason().unwrap_or("Unknown")
-                    )
-                };
-                return match status.as_u16() {
-                    400 => Err(ClickUpError::ValidationError(format!(
-                        "Bad request: {error_msg}"
-                    ))),
-                    401 => Err(ClickUpError::AuthError("Invalid API token".to_string())),
-                    403 => Err(ClickUpError::PermissionError(
-                        "Insufficient permissions".to_string(),
-                    )),
-                    404 => Err(ClickUpError::NotFoundError(
-                        "Resource not found".to_string(),
-                    )),
-                    409 => Err(ClickUpError::ApiError(format!("Conflict: {error_msg}"))),
-                    422 => Err(ClickUpError::ValidationError(format!(
-                        "Validation error: {error_msg}"
-                    ))),
-                    429 => {
-                        match self
-                            .rate_limiter
-                            .handle_rate_limit(retry_after_seconds)
-                            .await
-                        {
-                            Ok(()) => {
-                                info!("Retrying request after rate limit wait");
-                                self.make_request_raw(method, endpoint, body, query_params)
-                                    .await
-                            }
-                            Err(e) => Err(e),
-                        }
-                    }
-                    500..=599 => Err(ClickUpError::ApiError(format!("Server error: {error_msg}"))),
-                    _ => Err(ClickUpError::ApiError(error_msg)),
-                };
+                if status.as_u16() == 429 {
+                    self.rate_limiter
+                        .handle_rate_limit(retry_after_seconds)
+                        .await?;
+                    info!("Retrying request after rate limit wait");
+                    return self
+                        .make_request_raw(method, endpoint, body, query_params)
+                        .await;
+                }
+                return Err(Self::error_for_status(status, &response_text));
             }
             Ok(response_text)
         })
@@ -498,6 +676,47 @@ impl ClickUpApi {
             .await
     }
 
+    /// Retrieves a specific list by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the list to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns the `List`, including the statuses its tasks can have.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_list(&self, list_id: &str) -> Result<List, ClickUpError> {
+        let endpoint = format!("/list/{list_id}");
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Retrieves the custom fields accessible from a list
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the list
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CustomFieldsResponse` with the fields tasks in the list can have.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_list_custom_fields(
+        &self,
+        list_id: &str,
+    ) -> Result<CustomFieldsResponse, ClickUpError> {
+        let endpoint = format!("/list/{list_id}/field");
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
     // Folder endpoints
 
     /// Retrieves all folders within a specific space
@@ -554,6 +773,35 @@ impl ClickUpApi {
     ///
     /// This function can return authentication, permission, or network errors.
     pub async fn get_tasks(&self, list_id: &str) -> Result<TasksResponse, ClickUpError> {
+        self.fetch_list_tasks(list_id, false).await
+    }
+
+    /// Retrieves all tasks within a specific list, including closed tasks
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the list to get tasks from
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TasksResponse` containing open and closed tasks.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_tasks_including_closed(
+        &self,
+        list_id: &str,
+    ) -> Result<TasksResponse, ClickUpError> {
+        self.fetch_list_tasks(list_id, true).await
+    }
+
+    /// Fetches every page of tasks in a list
+    async fn fetch_list_tasks(
+        &self,
+        list_id: &str,
+        include_closed: bool,
+    ) -> Result<TasksResponse, ClickUpError> {
         let mut all_tasks = Vec::new();
         let mut page = 0;
         let limit = 100; // ClickUp API default limit
@@ -563,6 +811,7 @@ impl ClickUpApi {
                 ("page".to_string(), page.to_string()),
                 ("limit".to_string(), limit.to_string()),
                 ("subtasks".to_string(), "true".to_string()),
+                ("include_closed".to_string(), include_closed.to_string()),
             ];
 
             let endpoint = format!("/list/{list_id}/task");
@@ -584,6 +833,75 @@ impl ClickUpApi {
         Ok(TasksResponse { tasks: all_tasks })
     }
 
+    /// Retrieves one page of tasks across a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to search
+    /// * `filter` - The lists and spaces to restrict the search to
+    /// * `page` - The page to retrieve, starting at 0
+    ///
+    /// # Returns
+    ///
+    /// Returns a `FilteredTasksResponse` containing up to 100 tasks.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_filtered_team_tasks(
+        &self,
+        workspace_id: &str,
+        filter: &TeamTaskFilter,
+        page: u32,
+    ) -> Result<FilteredTasksResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/task");
+        self.make_request(
+            reqwest::Method::GET,
+            &endpoint,
+            None,
+            Some(filter.query_params(page)),
+        )
+        .await
+    }
+
+    /// Retrieves every task across a workspace that matches a filter
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to search
+    /// * `filter` - The lists and spaces to restrict the search to
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TasksResponse` containing the tasks of all pages.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_all_filtered_team_tasks(
+        &self,
+        workspace_id: &str,
+        filter: &TeamTaskFilter,
+    ) -> Result<TasksResponse, ClickUpError> {
+        let mut tasks = Vec::new();
+        let mut page = 0;
+        let limit = 100; // Fixed page size of the endpoint
+
+        loop {
+            let response = self
+                .get_filtered_team_tasks(workspace_id, filter, page)
+                .await?;
+            let tasks_count = response.tasks.len();
+            tasks.extend(response.tasks);
+            if response.last_page || tasks_count < limit {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(TasksResponse { tasks })
+    }
+
     /// Retrieves tasks within a specific list filtered by tag
     ///
     /// Note: This performs client-side filtering since the ClickUp API may not support
@@ -912,6 +1230,26 @@ impl ClickUpApi {
             .await
     }
 
+    /// Retrieves a task together with its subtasks
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Task` with `subtasks` filled in.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_task_with_subtasks(&self, task_id: &str) -> Result<Task, ClickUpError> {
+        let endpoint = format!("/task/{task_id}");
+        let query_params = vec![("include_subtasks".to_string(), "true".to_string())];
+        self.make_request(reqwest::Method::GET, &endpoint, None, Some(query_params))
+            .await
+    }
+
     /// Creates a new task in a specific list
     ///
     /// # Arguments
@@ -935,8 +1273,11 @@ impl ClickUpApi {
         let body = serde_json::to_value(task_data).map_err(|e| {
             ClickUpError::SerializationError(format!("Failed to serialize task data: {e}"))
         })?;
-        self.make_request(reqwest::Method::POST, &endpoint, Some(body), None)
-            .await
+        let task: Task = self
+            .make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        self.record_journal(JournalOperation::TaskCreate, &task.id, None, None);
+        Ok(task)
     }
 
     /// Updates an existing task
@@ -962,105 +1303,1605 @@ impl ClickUpApi {
         let body = serde_json::to_value(task_data).map_err(|e| {
             ClickUpError::SerializationError(format!("Failed to serialize task data: {e}"))
         })?;
-        self.make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
-            .await
+        let previous = self.snapshot_task(task_id).await;
+        let task = self
+            .make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await?;
+        self.record_journal(JournalOperation::TaskUpdate, task_id, previous, None);
+        Ok(task)
     }
 
-    /// Deletes a task
+    /// Adds and removes assignees of a task
     ///
     /// # Arguments
     ///
-    /// * `task_id` - The ID of the task to delete
+    /// * `task_id` - The ID of the task
+    /// * `changes` - The user IDs to assign and unassign
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on successful deletion.
+    /// Returns the updated `Task`.
     ///
     /// # Errors
     ///
-    /// This function can return authentication, permission, or network errors.
-    pub async fn delete_task(&self, task_id: &str) -> Result<(), ClickUpError> {
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn update_task_assignees(
+        &self,
+        task_id: &str,
+        changes: AssigneeChanges,
+    ) -> Result<Task, ClickUpError> {
         let endpoint = format!("/task/{task_id}");
-        // DELETE operations may return empty responses, so we use make_request_raw
-        // and handle the response manually
-        let response_text = self
-            .make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+        let body = serde_json::to_value(TaskAssigneesRequest { assignees: changes }).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize assignees: {e}"))
+        })?;
+        let previous = self.snapshot_task(task_id).await;
+        let task = self
+            .make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
             .await?;
-
-        // For DELETE operations, empty response or 204 status indicates success
-        if response_text.trim().is_empty() {
-            Ok(())
-        } else {
-            // If there's a response body, it might be an error message
-            // Try to parse it as JSON to get a proper error
-            if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(&response_text) {
-                if let (Some(err_msg), Some(ecode)) = (
-                    error_json.get("err").and_then(|v| v.as_str()),
-                    error_json.get("ECODE").and_then(|v| v.as_str()),
-                ) {
-                    Err(ClickUpError::ApiError(format!(
-                        "ClickUp Error {ecode}: {err_msg}"
-                    )))
-                } else {
-                    Err(ClickUpError::ApiError(format!(
-                        "Delete failed: {response_text}"
-                    )))
-                }
-            } else {
-                // If it's not JSON, treat as generic error
-                Err(ClickUpError::ApiError(format!(
-                    "Delete failed: {response_text}"
-                )))
-            }
-        }
+        self.record_journal(JournalOperation::TaskUpdate, task_id, previous, None);
+        Ok(task)
     }
 
-    // Comment endpoints
-
-    /// Retrieves all comments for a specific task
+    /// Adds a tag to a task
     ///
     /// # Arguments
     ///
-    /// * `task_id` - The ID of the task to get comments from
+    /// * `task_id` - The ID of the task
+    /// * `tag` - The name of the tag, which must exist in the task's space
     ///
     /// # Returns
     ///
-    /// Returns a `CommentsResponse` containing a list of comments.
+    /// Returns `Ok(())` when the tag was added.
     ///
     /// # Errors
     ///
     /// This function can return authentication, permission, or network errors.
-    pub async fn get_comments(&self, task_id: &str) -> Result<CommentsResponse, ClickUpError> {
-        let endpoint = format!("/task/{task_id}/comment");
-        self.make_request(reqwest::Method::GET, &endpoint, None, None)
-            .await
+    pub async fn add_task_tag(&self, task_id: &str, tag: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/tag/{tag}");
+        let previous = self.snapshot_task(task_id).await;
+        self.make_request_raw(reqwest::Method::POST, &endpoint, None, None)
+            .await?;
+        self.record_journal(JournalOperation::TaskUpdate, task_id, previous, None);
+        Ok(())
     }
 
-    /// Creates a new comment on a task
+    /// Removes a tag from a task
     ///
     /// # Arguments
     ///
-    /// * `task_id` - The ID of the task to comment on
-    /// * `comment_data` - The comment data to create
+    /// * `task_id` - The ID of the task
+    /// * `tag` - The name of the tag
     ///
     /// # Returns
     ///
-    /// Returns the created `Comment` with its assigned ID and metadata.
+    /// Returns `Ok(())` when the tag was removed.
     ///
     /// # Errors
     ///
-    /// This function can return authentication, permission, validation, or network errors.
-    pub async fn create_comment(
+    /// This function can return authentication, permission, or network errors.
+    pub async fn remove_task_tag(&self, task_id: &str, tag: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/tag/{tag}");
+        let previous = self.snapshot_task(task_id).await;
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        self.record_journal(JournalOperation::TaskUpdate, task_id, previous, None);
+        Ok(())
+    }
+
+    /// Sets the value of a custom field on a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task
+    /// * `field_id` - The ID of the custom field
+    /// * `value` - The new value, in the format the field type expects
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the value was set.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn set_custom_field_value(
+        &self,
+        task_id: &str,
+        field_id: &str,
+        value: Value,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/field/{field_id}");
+        let body = serde_json::json!({ "value": value });
+        let previous = self.snapshot_task(task_id).await;
+        self.make_request_raw(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        self.record_journal(JournalOperation::TaskUpdate, task_id, previous, None);
+        Ok(())
+    }
+
+    /// Clears the value of a custom field on a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task
+    /// * `field_id` - The ID of the custom field
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the value was cleared.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn remove_custom_field_value(
+        &self,
+        task_id: &str,
+        field_id: &str,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/field/{field_id}");
+        let previous = self.snapshot_task(task_id).await;
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        self.record_journal(JournalOperation::TaskUpdate, task_id, previous, None);
+        Ok(())
+    }
+
+    /// Deletes a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    /// A `ClickUpError::PermissionError` is returned without sending the delete
+    /// if the task is in a protected list or space.
+    pub async fn delete_task(&self, task_id: &str) -> Result<(), ClickUpError> {
+        // The task is only fetched up front when it has to be checked
+        if self.config.has_protected_locations() {
+            let task = self.get_task(task_id).await?;
+            return self.delete_fetched_task(&task).await;
+        }
+        let previous = self.snapshot_task(task_id).await;
+        self.send_task_delete(task_id, previous).await
+    }
+
+    /// Deletes a task the caller has already fetched
+    ///
+    /// The task is checked against protected locations and journaled as
+    /// given, so it is not fetched again.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    /// A `ClickUpError::PermissionError` is returned without sending the delete
+    /// if the task is in a protected list or space.
+    pub async fn delete_fetched_task(&self, task: &Task) -> Result<(), ClickUpError> {
+        self.config
+            .ensure_deletable(&task.list.id, &task.space.id)?;
+        let previous = if self.journal.is_enabled() {
+            serde_json::to_value(task).ok()
+        } else {
+            None
+        };
+        self.send_task_delete(&task.id, previous).await
+    }
+
+    /// Sends a task delete and journals the task's prior state
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to delete
+    /// * `previous` - The task as it was before the delete, for the journal
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    async fn send_task_delete(
+        &self,
+        task_id: &str,
+        previous: Option<Value>,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}");
+        // DELETE operations may return empty responses, so we use make_request_raw
+        // and handle the response manually
+        let response_text = self
+            .make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+
+        // For DELETE operations, empty response or 204 status indicates success
+        if response_text.trim().is_empty() {
+            self.record_journal(JournalOperation::TaskDelete, task_id, previous, None);
+            Ok(())
+        } else {
+            // If there's a response body, it might be an error message
+            // Try to parse it as JSON to get a proper error
+            if let Ok(error_json) = serde_json::from_str::<serde_json::Value>(&response_text) {
+                if let (Some(err_msg), Some(ecode)) = (
+                    error_json.get("err").and_then(|v| v.as_str()),
+                    error_json.get("ECODE").and_then(|v| v.as_str()),
+                ) {
+                    Err(ClickUpError::ApiError(format!(
+                        "ClickUp Error {ecode}: {err_msg}"
+                    )))
+                } else {
+                    Err(ClickUpError::ApiError(format!(
+                        "Delete failed: {response_text}"
+                    )))
+                }
+            } else {
+                // If it's not JSON, treat as generic error
+                Err(ClickUpError::ApiError(format!(
+                    "Delete failed: {response_text}"
+                )))
+            }
+        }
+    }
+
+    /// Moves a task to a new home list
+    ///
+    /// API v2 has no way to change a task's home list, so this uses the v3
+    /// endpoint. The task keeps its ID; statuses that don't exist in the new
+    /// list are left for the caller to map.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace the task belongs to
+    /// * `task_id` - The ID of the task to move
+    /// * `list_id` - The ID of the new home list
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the task was moved.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn move_task(
+        &self,
+        workspace_id: &str,
+        task_id: &str,
+        list_id: &str,
+    ) -> Result<(), ClickUpError> {
+        let previous = self.snapshot_task(task_id).await;
+        let endpoint = format!("/v3/workspaces/{workspace_id}/tasks/{task_id}/home_list/{list_id}");
+        self.make_request_raw(reqwest::Method::PUT, &endpoint, None, None)
+            .await?;
+        let source_list_id = previous
+            .as_ref()
+            .and_then(|task| task["list"]["id"].as_str())
+            .map(str::to_string);
+        if let Err(e) = self.journal.record_move(task_id, previous, source_list_id) {
+            warn!("Failed to write undo journal entry: {e}");
+        }
+        Ok(())
+    }
+
+    /// Adds a task to an additional list
+    ///
+    /// Requires the Tasks in Multiple Lists ClickApp. The task's home list is
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the list to add the task to
+    /// * `task_id` - The ID of the task
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the task was added.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn add_task_to_list(&self, list_id: &str, task_id: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/list/{list_id}/task/{task_id}");
+        self.make_request_raw(reqwest::Method::POST, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Removes a task from an additional list
+    ///
+    /// A task can't be removed from its home list this way.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the additional list
+    /// * `task_id` - The ID of the task
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the task was removed.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn remove_task_from_list(
+        &self,
+        list_id: &str,
+        task_id: &str,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/list/{list_id}/task/{task_id}");
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds a dependency to a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to add the dependency to
+    /// * `dependency` - The task it waits on (`depends_on`) or blocks (`dependency_of`)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the dependency was added.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn add_dependency(
+        &self,
+        task_id: &str,
+        dependency: &DependencyRequest,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/dependency");
+        let body = serde_json::to_value(dependency).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize dependency: {e}"))
+        })?;
+        // POST returns an empty object on success
+        self.make_request_raw(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Removes a dependency from a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to remove the dependency from
+    /// * `dependency` - The task it waits on (`depends_on`) or blocks (`dependency_of`)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the dependency was removed.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn remove_dependency(
+        &self,
+        task_id: &str,
+        dependency: &DependencyRequest,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/dependency");
+        let mut query_params = Vec::new();
+        if let Some(depends_on) = &dependency.depends_on {
+            query_params.push(("depends_on".to_string(), depends_on.clone()));
+        }
+        if let Some(dependency_of) = &dependency.dependency_of {
+            query_params.push(("dependency_of".to_string(), dependency_of.clone()));
+        }
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, Some(query_params))
+            .await?;
+        Ok(())
+    }
+
+    /// Links two tasks
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to link from
+    /// * `links_to` - The ID of the task to link to
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the link was added.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn add_task_link(&self, task_id: &str, links_to: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/link/{links_to}");
+        self.make_request_raw(reqwest::Method::POST, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Removes the link between two tasks
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to unlink from
+    /// * `links_to` - The ID of the linked task
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the link was removed.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn remove_task_link(
+        &self,
+        task_id: &str,
+        links_to: &str,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/task/{task_id}/link/{links_to}");
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    // Checklist endpoints
+
+    /// Creates a checklist on a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to add the checklist to
+    /// * `request` - The checklist name
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `TaskChecklist`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_checklist(
+        &self,
+        task_id: &str,
+        request: CreateChecklistRequest,
+    ) -> Result<TaskChecklist, ClickUpError> {
+        let endpoint = format!("/task/{task_id}/checklist");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize checklist: {e}"))
+        })?;
+        let response: ChecklistResponse = self
+            .make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.checklist)
+    }
+
+    /// Renames or reorders a checklist
+    ///
+    /// # Arguments
+    ///
+    /// * `checklist_id` - The ID of the checklist to change
+    /// * `request` - The new name and/or position
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` when the checklist was changed.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn edit_checklist(
+        &self,
+        checklist_id: &str,
+        request: EditChecklistRequest,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/checklist/{checklist_id}");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize checklist: {e}"))
+        })?;
+        // PUT returns an empty object on success
+        self.make_request_raw(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a checklist and all its items
+    ///
+    /// # Arguments
+    ///
+    /// * `checklist_id` - The ID of the checklist to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn delete_checklist(&self, checklist_id: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/checklist/{checklist_id}");
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds an item to a checklist
+    ///
+    /// # Arguments
+    ///
+    /// * `checklist_id` - The ID of the checklist to add the item to
+    /// * `request` - The item name and assignee
+    ///
+    /// # Returns
+    ///
+    /// Returns the checklist including the new item.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_checklist_item(
+        &self,
+        checklist_id: &str,
+        request: CreateChecklistItemRequest,
+    ) -> Result<TaskChecklist, ClickUpError> {
+        let endpoint = format!("/checklist/{checklist_id}/checklist_item");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize checklist item: {e}"))
+        })?;
+        let response: ChecklistResponse = self
+            .make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.checklist)
+    }
+
+    /// Changes a checklist item
+    ///
+    /// # Arguments
+    ///
+    /// * `checklist_id` - The ID of the checklist containing the item
+    /// * `item_id` - The ID of the item to change
+    /// * `request` - The fields to change
+    ///
+    /// # Returns
+    ///
+    /// Returns the checklist including the changed item.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn edit_checklist_item(
+        &self,
+        checklist_id: &str,
+        item_id: &str,
+        request: EditChecklistItemRequest,
+    ) -> Result<TaskChecklist, ClickUpError> {
+        let endpoint = format!("/checklist/{checklist_id}/checklist_item/{item_id}");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize checklist item: {e}"))
+        })?;
+        let response: ChecklistResponse = self
+            .make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.checklist)
+    }
+
+    /// Deletes a checklist item
+    ///
+    /// # Arguments
+    ///
+    /// * `checklist_id` - The ID of the checklist containing the item
+    /// * `item_id` - The ID of the item to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn delete_checklist_item(
+        &self,
+        checklist_id: &str,
+        item_id: &str,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/checklist/{checklist_id}/checklist_item/{item_id}");
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    // Attachment endpoints
+
+    /// Uploads a file as an attachment to a task
+    ///
+    /// The file is sent as `multipart/form-data` with the same authentication
+    /// and rate limiting as other requests. In dry-run mode the upload is
+    /// printed instead of sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to attach the file to
+    /// * `file_name` - The file name to show in ClickUp
+    /// * `data` - The file contents
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Attachment`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn upload_attachment(
+        &self,
+        task_id: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<Attachment, ClickUpError> {
+        let endpoint = format!("/task/{task_id}/attachment");
+        let summary = serde_json::json!({ "attachment": file_name, "bytes": data.len() });
+        if let Some(response) =
+            self.intercept_dry_run(&reqwest::Method::POST, &endpoint, Some(&summary), None)
+        {
+            return response;
+        }
+        self.rate_limiter.reset_retry_count().await?;
+        let url = self.endpoint_url(&endpoint);
+        loop {
+            self.rate_limiter.wait_if_needed().await?;
+            // The form is consumed by each attempt, so it is rebuilt for retries
+            let form = Form::new().part(
+                "attachment",
+                Part::bytes(data.clone()).file_name(file_name.to_string()),
+            );
+            debug!("Uploading {file_name} to: {url}");
+            let response = self
+                .client
+                .post(&url)
+                .header(AUTHORIZATION, self.get_auth_header()?)
+                .multipart(form)
+                .timeout(TRANSFER_TIMEOUT)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!("Upload failed: {e}");
+                    ClickUpError::from(e)
+                })?;
+            let retry_after_seconds = response
+                .headers()
+                .get("Retry-After")
+                .and_then(|h| h.to_str().ok())
+                .and_then(|s| s.parse::<u64>().ok());
+            let status = response.status();
+            let response_text = response.text().await.map_err(|e| {
+                ClickUpError::NetworkError(format!("Failed to read response: {e}"))
+            })?;
+            debug!("Response status: {status}, body: {response_text}");
+            if status.as_u16() == 429 {
+                self.rate_limiter
+                    .handle_rate_limit(retry_after_seconds)
+                    .await?;
+                info!("Retrying upload after rate limit wait");
+                continue;
+            }
+            if !status.is_success() {
+                return Err(Self::error_for_status(status, &response_text));
+            }
+            return serde_json::from_str(&response_text).map_err(|e| {
+                ClickUpError::DeserializationError(format!("Failed to parse response: {e}"))
+            });
+        }
+    }
+
+    /// Downloads the contents of an attachment
+    ///
+    /// Attachment URLs usually point at ClickUp's file storage rather than
+    /// the API, so the request is not rate limited, and the API token is only
+    /// sent when the URL is on the configured API host.
+    ///
+    /// # Arguments
+    ///
+    /// * `attachment` - The attachment to download
+    ///
+    /// # Returns
+    ///
+    /// Returns the file contents.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if the attachment has no URL
+    /// - Permission, not found, or network errors from the download
+    pub async fn download_attachment(&self, attachment: &Attachment) -> Result<Vec<u8>, ClickUpError> {
+        if attachment.url.is_empty() {
+            return Err(ClickUpError::ValidationError(format!(
+                "Attachment {} has no download URL",
+                attachment.id
+            )));
+        }
+        let mut request = self.client.get(&attachment.url).timeout(TRANSFER_TIMEOUT);
+        if attachment.url.starts_with(&self.config.api_base_url) {
+            request = request.header(AUTHORIZATION, self.get_auth_header()?);
+        }
+        debug!("Downloading attachment from: {}", attachment.url);
+        let response = request.send().await.map_err(|e| {
+            error!("Download failed: {e}");
+            ClickUpError::from(e)
+        })?;
+        let status = response.status();
+        if !status.is_success() {
+            let response_text = response.text().await.unwrap_or_default();
+            return Err(Self::error_for_status(status, &response_text));
+        }
+        let bytes = response.bytes().await.map_err(|e| {
+            ClickUpError::NetworkError(format!("Failed to read attachment: {e}"))
+        })?;
+        Ok(bytes.to_vec())
+    }
+
+    // Comment endpoints
+
+    /// Retrieves all comments for a specific task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to get comments from
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CommentsResponse` containing a list of comments.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_comments(&self, task_id: &str) -> Result<CommentsResponse, ClickUpError> {
+        self.get_target_comments(CommentTarget::Task, task_id).await
+    }
+
+    /// Retrieves the comments on a task, list or chat view
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The kind of item the comments belong to
+    /// * `target_id` - The ID of the task, list or view
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CommentsResponse` containing a list of comments.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_target_comments(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+    ) -> Result<CommentsResponse, ClickUpError> {
+        let endpoint = format!("/{}/{target_id}/comment", target.endpoint_segment());
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Creates a new comment on a task
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task to comment on
+    /// * `comment_data` - The comment data to create
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Comment` with its assigned ID and metadata.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_comment(
+        &self,
+        task_id: &str,
+        comment_data: CreateCommentRequest,
+    ) -> Result<(), ClickUpError> {
+        self.create_target_comment(CommentTarget::Task, task_id, comment_data)
+            .await?;
+        Ok(())
+    }
+
+    /// Creates a new comment on a task, list or chat view
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The kind of item to comment on
+    /// * `target_id` - The ID of the task, list or view
+    /// * `comment_data` - The comment data to create
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the new comment.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_target_comment(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+        comment_data: CreateCommentRequest,
+    ) -> Result<CreatedComment, ClickUpError> {
+        let endpoint = format!("/{}/{target_id}/comment", target.endpoint_segment());
+        let body = serde_json::to_value(comment_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize comment data: {e}"))
+        })?;
+        self.make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await
+    }
+
+    // Webhook endpoints
+
+    /// Retrieves the webhooks registered in a workspace
+    ///
+    /// Only webhooks created with the current token (or OAuth app) are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to get webhooks from
+    ///
+    /// # Returns
+    ///
+    /// Returns a `WebhooksResponse` containing a list of webhooks.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_webhooks(&self, workspace_id: &str) -> Result<WebhooksResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/webhook");
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Creates a webhook in a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to create the webhook in
+    /// * `webhook_data` - The endpoint, events and optional location filter
+    ///
+    /// # Returns
+    ///
+    /// Returns a `WebhookResponse` containing the new webhook and its secret.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_webhook(
+        &self,
+        workspace_id: &str,
+        webhook_data: CreateWebhookRequest,
+    ) -> Result<WebhookResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/webhook");
+        let body = serde_json::to_value(webhook_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize webhook data: {e}"))
+        })?;
+        self.make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await
+    }
+
+    /// Updates a webhook
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_id` - The ID of the webhook to update
+    /// * `webhook_data` - The fields to update
+    ///
+    /// # Returns
+    ///
+    /// Returns a `WebhookResponse` containing the updated webhook.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn update_webhook(
+        &self,
+        webhook_id: &str,
+        webhook_data: UpdateWebhookRequest,
+    ) -> Result<WebhookResponse, ClickUpError> {
+        let endpoint = format!("/webhook/{webhook_id}");
+        let body = serde_json::to_value(webhook_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize webhook data: {e}"))
+        })?;
+        self.make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await
+    }
+
+    /// Deletes a webhook
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_id` - The ID of the webhook to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn delete_webhook(&self, webhook_id: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/webhook/{webhook_id}");
+        // DELETE returns an empty object on success
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    // Goal endpoints
+
+    /// Retrieves the goals in a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to get goals from
+    /// * `include_completed` - Whether to include completed goals
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GoalsResponse` containing a list of goals without their key results.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_goals(
+        &self,
+        workspace_id: &str,
+        include_completed: bool,
+    ) -> Result<GoalsResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/goal");
+        let query_params = vec![(
+            "include_completed".to_string(),
+            include_completed.to_string(),
+        )];
+        self.make_request(reqwest::Method::GET, &endpoint, None, Some(query_params))
+            .await
+    }
+
+    /// Retrieves a goal with its key results
+    ///
+    /// # Arguments
+    ///
+    /// * `goal_id` - The ID of the goal to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Goal`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_goal(&self, goal_id: &str) -> Result<Goal, ClickUpError> {
+        let endpoint = format!("/goal/{goal_id}");
+        let response: GoalResponse = self
+            .make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await?;
+        Ok(response.goal)
+    }
+
+    /// Creates a goal in a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to create the goal in
+    /// * `goal_data` - The goal name, owners and optional details
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Goal`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_goal(
+        &self,
+        workspace_id: &str,
+        goal_data: CreateGoalRequest,
+    ) -> Result<Goal, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/goal");
+        let body = serde_json::to_value(goal_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize goal data: {e}"))
+        })?;
+        let response: GoalResponse = self
+            .make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.goal)
+    }
+
+    /// Updates a goal
+    ///
+    /// # Arguments
+    ///
+    /// * `goal_id` - The ID of the goal to update
+    /// * `goal_data` - The fields to update
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Goal`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn update_goal(
+        &self,
+        goal_id: &str,
+        goal_data: UpdateGoalRequest,
+    ) -> Result<Goal, ClickUpError> {
+        let endpoint = format!("/goal/{goal_id}");
+        let body = serde_json::to_value(goal_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize goal data: {e}"))
+        })?;
+        let response: GoalResponse = self
+            .make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.goal)
+    }
+
+    /// Deletes a goal and its key results
+    ///
+    /// # Arguments
+    ///
+    /// * `goal_id` - The ID of the goal to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn delete_goal(&self, goal_id: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/goal/{goal_id}");
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds a key result to a goal
+    ///
+    /// # Arguments
+    ///
+    /// * `goal_id` - The ID of the goal to add the key result to
+    /// * `key_result_data` - The key result name, type and target
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `KeyResult`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn create_key_result(
+        &self,
+        goal_id: &str,
+        key_result_data: CreateKeyResultRequest,
+    ) -> Result<KeyResult, ClickUpError> {
+        let endpoint = format!("/goal/{goal_id}/key_result");
+        let body = serde_json::to_value(key_result_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize key result data: {e}"))
+        })?;
+        let response: KeyResultResponse = self
+            .make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.key_result)
+    }
+
+    /// Records progress on a key result
+    ///
+    /// # Arguments
+    ///
+    /// * `key_result_id` - The ID of the key result to update
+    /// * `key_result_data` - The new current value and an optional note
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `KeyResult`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn update_key_result(
+        &self,
+        key_result_id: &str,
+        key_result_data: UpdateKeyResultRequest,
+    ) -> Result<KeyResult, ClickUpError> {
+        let endpoint = format!("/key_result/{key_result_id}");
+        let body = serde_json::to_value(key_result_data).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize key result data: {e}"))
+        })?;
+        let response: KeyResultResponse = self
+            .make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await?;
+        Ok(response.key_result)
+    }
+
+    /// Deletes a key result
+    ///
+    /// # Arguments
+    ///
+    /// * `key_result_id` - The ID of the key result to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful deletion.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn delete_key_result(&self, key_result_id: &str) -> Result<(), ClickUpError> {
+        let endpoint = format!("/key_result/{key_result_id}");
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    // View endpoints
+
+    /// Retrieves the views of a workspace, space, folder or list
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of location to get views from
+    /// * `parent_id` - The ID of the workspace, space, folder or list
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ViewsResponse` containing the views and, except for
+    /// workspaces, the built-in views of the location.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_views(
+        &self,
+        kind: ViewParentKind,
+        parent_id: &str,
+    ) -> Result<ViewsResponse, ClickUpError> {
+        let endpoint = format!("/{}/{parent_id}/view", kind.endpoint_segment());
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Retrieves a view with its filters, sorting and grouping
+    ///
+    /// # Arguments
+    ///
+    /// * `view_id` - The ID of the view to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns the `View`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_view(&self, view_id: &str) -> Result<View, ClickUpError> {
+        let endpoint = format!("/view/{view_id}");
+        let response: ViewResponse = self
+            .make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await?;
+        Ok(response.view)
+    }
+
+    /// Retrieves one page of the tasks a view resolves to
+    ///
+    /// ClickUp applies the view's filters and sorting and returns up to 30
+    /// tasks per page.
+    ///
+    /// # Arguments
+    ///
+    /// * `view_id` - The ID of the view
+    /// * `page` - The page to retrieve, starting at 0
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ViewTasksResponse` containing the tasks and whether more pages follow.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_view_tasks(
+        &self,
+        view_id: &str,
+        page: u32,
+    ) -> Result<ViewTasksResponse, ClickUpError> {
+        let endpoint = format!("/view/{view_id}/task");
+        let query_params = vec![("page".to_string(), page.to_string())];
+        self.make_request(reqwest::Method::GET, &endpoint, None, Some(query_params))
+            .await
+    }
+
+    /// Retrieves every page of the tasks a view resolves to
+    ///
+    /// # Arguments
+    ///
+    /// * `view_id` - The ID of the view
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TasksResponse` containing the tasks of all pages in view order.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_all_view_tasks(&self, view_id: &str) -> Result<TasksResponse, ClickUpError> {
+        let mut all_tasks = Vec::new();
+        let mut page = 0;
+
+        loop {
+            let response = self.get_view_tasks(view_id, page).await?;
+            let tasks_count = response.tasks.len();
+            all_tasks.extend(response.tasks);
+
+            // An empty page also ends the loop in case `last_page` is never set
+            if response.last_page || tasks_count == 0 {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(TasksResponse { tasks: all_tasks })
+    }
+
+    // Template endpoints
+
+    /// Retrieves one page of the task templates in a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to get templates from
+    /// * `page` - The page to retrieve, starting at 0
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TaskTemplatesResponse` containing the templates on the page.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_task_templates(
+        &self,
+        workspace_id: &str,
+        page: u32,
+    ) -> Result<TaskTemplatesResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/taskTemplate");
+        let query_params = vec![("page".to_string(), page.to_string())];
+        self.make_request(reqwest::Method::GET, &endpoint, None, Some(query_params))
+            .await
+    }
+
+    /// Retrieves every task template in a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace to get templates from
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TaskTemplatesResponse` containing the templates of all pages.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_all_task_templates(
+        &self,
+        workspace_id: &str,
+    ) -> Result<TaskTemplatesResponse, ClickUpError> {
+        let mut templates = Vec::new();
+        let mut page = 0;
+
+        // The page size isn't documented, so stop at the first empty page
+        loop {
+            let response = self.get_task_templates(workspace_id, page).await?;
+            if response.templates.is_empty() {
+                break;
+            }
+            templates.extend(response.templates);
+            page += 1;
+        }
+
+        Ok(TaskTemplatesResponse { templates })
+    }
+
+    /// Creates a task in a list from a task template
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The ID of the list to create the task in
+    /// * `template_id` - The ID of the task template
+    /// * `request` - The name of the new task
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the created task.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn create_task_from_template(
+        &self,
+        list_id: &str,
+        template_id: &str,
+        request: CreateTaskFromTemplateRequest,
+    ) -> Result<String, ClickUpError> {
+        let endpoint = format!("/list/{list_id}/taskTemplate/{template_id}");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize template task data: {e}"))
+        })?;
+        let response: TemplateTaskResponse = self
+            .make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await?;
+        self.record_journal(JournalOperation::TaskCreate, &response.id, None, None);
+        Ok(response.id)
+    }
+
+    /// Overrides fields of a task created from a template
+    ///
+    /// The change is not journaled separately; undoing the task's creation
+    /// removes it along with the overrides.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The ID of the task
+    /// * `request` - The fields to override
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Task`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn apply_template_overrides(
         &self,
         task_id: &str,
-        comment_data: CreateCommentRequest,
+        request: TemplateOverridesRequest,
+    ) -> Result<Task, ClickUpError> {
+        let endpoint = format!("/task/{task_id}");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize task overrides: {e}"))
+        })?;
+        self.make_request(reqwest::Method::PUT, &endpoint, Some(body), None)
+            .await
+    }
+
+    // Member endpoints
+
+    /// Retrieves the tasks, lists and folders shared with the current user
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    ///
+    /// # Returns
+    ///
+    /// Returns a `SharedHierarchyResponse` containing the shared items.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn get_shared_hierarchy(
+        &self,
+        workspace_id: &str,
+    ) -> Result<SharedHierarchyResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/shared");
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Invites a user to a workspace as a member
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    /// * `request` - The email address and role of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TeamResponse` containing the workspace and its members.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn invite_user(
+        &self,
+        workspace_id: &str,
+        request: InviteUserRequest,
+    ) -> Result<TeamResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/user");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize invite data: {e}"))
+        })?;
+        self.make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await
+    }
+
+    /// Retrieves a workspace member
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    /// * `user_id` - The ID of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns a `MemberResponse` containing the member.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_workspace_user(
+        &self,
+        workspace_id: &str,
+        user_id: i64,
+    ) -> Result<MemberResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/user/{user_id}");
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Removes a member from a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    /// * `user_id` - The ID of the user to remove
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful removal.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn remove_workspace_user(
+        &self,
+        workspace_id: &str,
+        user_id: i64,
     ) -> Result<(), ClickUpError> {
-        let endpoint = format!("/task/{task_id}/comment");
-        let body = serde_json::to_value(comment_data).map_err(|e| {
-            ClickUpError::SerializationError(format!("Failed to serialize comment data: {e}"))
+        let endpoint = format!("/team/{workspace_id}/user/{user_id}");
+        // DELETE returns the updated workspace, which isn't needed
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Invites a guest to a workspace
+    ///
+    /// Guests only see the tasks, lists and folders shared with them.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    /// * `request` - The email address and permissions of the guest
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TeamResponse` containing the workspace and its members.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn invite_guest(
+        &self,
+        workspace_id: &str,
+        request: InviteGuestRequest,
+    ) -> Result<TeamResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/guest");
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize guest data: {e}"))
         })?;
-        let _response_text = self
-            .make_request_raw(reqwest::Method::POST, &endpoint, Some(body), None)
+        self.make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await
+    }
+
+    /// Retrieves a workspace guest
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    /// * `guest_id` - The ID of the guest
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GuestResponse` containing the guest and the items shared with them.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn get_guest(
+        &self,
+        workspace_id: &str,
+        guest_id: i64,
+    ) -> Result<GuestResponse, ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/guest/{guest_id}");
+        self.make_request(reqwest::Method::GET, &endpoint, None, None)
+            .await
+    }
+
+    /// Removes a guest from a workspace
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The ID of the workspace
+    /// * `guest_id` - The ID of the guest to remove
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful removal.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, not found, or network errors.
+    pub async fn remove_guest(&self, workspace_id: &str, guest_id: i64) -> Result<(), ClickUpError> {
+        let endpoint = format!("/team/{workspace_id}/guest/{guest_id}");
+        // DELETE returns the updated workspace, which isn't needed
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Gives a guest access to a task, list or folder
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Whether the item is a task, list or folder
+    /// * `item_id` - The ID of the item
+    /// * `guest_id` - The ID of the guest
+    /// * `request` - The permission level to grant
+    ///
+    /// # Returns
+    ///
+    /// Returns a `GuestResponse` containing the guest.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, validation, or network errors.
+    pub async fn add_guest_to_item(
+        &self,
+        kind: SharedItemKind,
+        item_id: &str,
+        guest_id: i64,
+        request: GuestPermissionRequest,
+    ) -> Result<GuestResponse, ClickUpError> {
+        let endpoint = format!("/{}/{item_id}/guest/{guest_id}", kind.endpoint_segment());
+        let body = serde_json::to_value(request).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize guest permission: {e}"))
+        })?;
+        self.make_request(reqwest::Method::POST, &endpoint, Some(body), None)
+            .await
+    }
+
+    /// Removes a guest's access to a task, list or folder
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Whether the item is a task, list or folder
+    /// * `item_id` - The ID of the item
+    /// * `guest_id` - The ID of the guest
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn remove_guest_from_item(
+        &self,
+        kind: SharedItemKind,
+        item_id: &str,
+        guest_id: i64,
+    ) -> Result<(), ClickUpError> {
+        let endpoint = format!("/{}/{item_id}/guest/{guest_id}", kind.endpoint_segment());
+        self.make_request_raw(reqwest::Method::DELETE, &endpoint, None, None)
             .await?;
         Ok(())
     }
@@ -1086,40 +2927,113 @@ impl ClickUpApi {
             .await
     }
 
-    /// Updates a comment on a task
+    /// Updates a comment
+    ///
+    /// The API has no way to fetch a single comment, so the caller passes the
+    /// comment it found in its task, list or view thread. That comment is
+    /// journaled as given, so it is not searched for again.
     ///
     /// # Arguments
     ///
-    /// * `comment_id` - The ID of the comment to update
+    /// * `target` - The kind of item the comment belongs to
+    /// * `target_id` - The ID of the task, list or view
+    /// * `comment` - The comment as it is before the update
     /// * `comment_data` - The updated comment data
     ///
     /// # Returns
     ///
-    /// Returns the updated `Comment` with the new information.
+    /// Returns `Ok(())` on successful update.
     ///
     /// # Errors
     ///
     /// This function can return authentication, permission, validation, or network errors.
     pub async fn update_comment(
         &self,
-        comment_id: &str,
+        target: CommentTarget,
+        target_id: &str,
+        comment: &Comment,
         comment_data: CreateCommentRequest,
     ) -> Result<(), ClickUpError> {
-        let endpoint = format!("/comment/{comment_id}");
         let body = serde_json::to_value(comment_data).map_err(|e| {
             ClickUpError::SerializationError(format!("Failed to serialize comment data: {e}"))
         })?;
+        self.put_comment(target, target_id, comment, body).await
+    }
+
+    /// Marks a comment as resolved or reopens it
+    ///
+    /// Only the resolved state is sent, so the comment's text and assignee are
+    /// left as they are. The change is journaled as a comment update, using
+    /// the comment the caller passes.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The kind of item the comment belongs to
+    /// * `target_id` - The ID of the task, list or view
+    /// * `comment` - The comment as it is before the change
+    /// * `resolved` - Whether the comment is resolved
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors.
+    pub async fn set_comment_resolved(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+        comment: &Comment,
+        resolved: bool,
+    ) -> Result<(), ClickUpError> {
+        let body = serde_json::to_value(ResolveCommentRequest { resolved }).map_err(|e| {
+            ClickUpError::SerializationError(format!("Failed to serialize comment data: {e}"))
+        })?;
+        self.put_comment(target, target_id, comment, body).await
+    }
+
+    /// Sends a comment update and journals the comment's prior state
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The kind of item the comment belongs to
+    /// * `target_id` - The ID of the task, list or view
+    /// * `comment` - The comment as it is before the update, for the journal
+    /// * `body` - The request body
+    async fn put_comment(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+        comment: &Comment,
+        body: Value,
+    ) -> Result<(), ClickUpError> {
+        let previous = if self.journal.is_enabled() {
+            serde_json::to_value(comment).ok()
+        } else {
+            None
+        };
+        let endpoint = format!("/comment/{}", comment.id);
         let _response_text = self
             .make_request_raw(reqwest::Method::PUT, &endpoint, Some(body), None)
             .await?;
+        self.record_journal_with_parent(
+            JournalOperation::CommentUpdate,
+            &comment.id,
+            previous,
+            Some((target, target_id.to_string())),
+        );
         Ok(())
     }
 
     /// Deletes a comment
     ///
+    /// The API has no way to fetch a single comment, so the caller passes the
+    /// comment it found in its task, list or view thread. That item is checked
+    /// against protected locations and the comment is journaled as given, so
+    /// it is not searched for again.
+    ///
     /// # Arguments
     ///
-    /// * `comment_id` - The ID of the comment to delete
+    /// * `target` - The kind of item the comment belongs to
+    /// * `target_id` - The ID of the task, list or view
+    /// * `comment` - The comment to delete
     ///
     /// # Returns
     ///
@@ -1128,7 +3042,24 @@ impl ClickUpApi {
     /// # Errors
     ///
     /// This function can return authentication, permission, or network errors.
-    pub async fn delete_comment(&self, comment_id: &str) -> Result<(), ClickUpError> {
+    /// A `ClickUpError::PermissionError` is returned without sending the delete
+    /// if the comment's task, list or view is in a protected list or space.
+    pub async fn delete_comment(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+        comment: &Comment,
+    ) -> Result<(), ClickUpError> {
+        if self.config.has_protected_locations() {
+            self.ensure_comment_target_deletable(target, target_id)
+                .await?;
+        }
+        let previous = if self.journal.is_enabled() {
+            serde_json::to_value(comment).ok()
+        } else {
+            None
+        };
+        let comment_id = comment.id.as_str();
         let endpoint = format!("/comment/{comment_id}");
         // DELETE operations may return empty responses, so we use make_request_raw
         // and handle the response manually
@@ -1138,6 +3069,12 @@ impl ClickUpApi {
 
         // For DELETE operations, empty response or 204 status indicates success
         if response_text.trim().is_empty() || response_text.trim() == "{}" {
+            self.record_journal_with_parent(
+                JournalOperation::CommentDelete,
+                comment_id,
+                previous,
+                Some((target, target_id.to_string())),
+            );
             Ok(())
         } else {
             // If there's a response body, it might be an error message
@@ -1185,6 +3122,46 @@ impl ClickUpApi {
         Ok((request_count, retry_count))
     }
 
+    /// Refuses comment deletes on a task, list or view in a protected location
+    ///
+    /// A view is checked against the list or space it belongs to; views on
+    /// folders and workspaces are never protected.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::PermissionError` if the item is in a protected list or space
+    /// - Authentication, permission, or network errors from looking the item up
+    async fn ensure_comment_target_deletable(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+    ) -> Result<(), ClickUpError> {
+        match target {
+            CommentTarget::Task => {
+                let task = self.get_task(target_id).await?;
+                self.config.ensure_deletable(&task.list.id, &task.space.id)
+            }
+            CommentTarget::List => {
+                let list = self.get_list(target_id).await?;
+                self.config.ensure_deletable(&list.id, &list.space.id)
+            }
+            CommentTarget::View => {
+                let Some(parent) = self.get_view(target_id).await?.parent else {
+                    return Ok(());
+                };
+                match parent.kind() {
+                    Some(ViewParentKind::List) => {
+                        let list = self.get_list(&parent.id).await?;
+                        self.config.ensure_deletable(&list.id, &list.space.id)
+                    }
+                    Some(ViewParentKind::Space) => self.config.ensure_deletable("", &parent.id),
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+
     /// Retrieves a specific comment by its ID
     ///
     /// This method uses an efficient search strategy to find a comment by ID.
@@ -1203,6 +3180,29 @@ impl ClickUpApi {
     ///
     /// This function can return authentication, permission, or network errors.
     pub async fn get_comment(&self, comment_id: &str) -> Result<Comment, ClickUpError> {
+        self.find_comment(comment_id)
+            .await
+            .map(|(_task_id, comment)| comment)
+    }
+
+    /// Finds a comment by its ID along with the ID of the task it belongs to
+    ///
+    /// The ClickUp API has no endpoint for fetching a single comment, so this
+    /// searches the comments of tasks across all accessible workspaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The ID of the comment to find
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(task_id, comment)`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return authentication, permission, or network errors,
+    /// or `ClickUpError::NotFoundError` if the comment doesn't exist.
+    pub async fn find_comment(&self, comment_id: &str) -> Result<(String, Comment), ClickUpError> {
         // First, try to get the user's workspaces to understand the scope
         let workspaces = self.get_workspaces().await?;
         
@@ -1232,12 +3232,12 @@ impl ClickUpApi {
                             
                             // Look for the specific comment
                             if let Some(comment) = comments.comments.into_iter().find(|c| c.id == comment_id) {
-                                return Ok(Some(comment));
+                                return Ok(Some((task.id.clone(), comment)));
                             }
                         }
                     }
                 }
-                Ok::<Option<Comment>, ClickUpError>(None)
+                Ok::<Option<(String, Comment)>, ClickUpError>(None)
             };
             
             search_futures.push(future);
@@ -1249,11 +3249,11 @@ impl ClickUpApi {
         // Find the first successful result
         for result in results {
             match result {
-                Ok(Some(comment)) => return Ok(comment),
+                Ok(Some(found)) => return Ok(found),
                 Ok(None) => continue,
                 Err(e) => {
                     // Log the error but continue searching other workspaces
-                    eprintln!("Warning: Error searching workspace: {}", e);
+                    warn!("Error searching workspace: {e}");
                     continue;
                 }
             }
@@ -0,0 +1,95 @@
+//! # Space Backups
+//!
+//! This module defines the JSON file written by `spaces backup`, so that
+//! commands can read a backup back instead of calling the API.
+//!
+//! ## Format
+//!
+//! A backup holds the space, its folders and lists, every task in those
+//! lists (including closed tasks) and, if requested when it was made, the
+//! comments of each task. `metadata` records when the backup was made and
+//! what it includes; backups made by older versions hold open tasks only.
+
+use crate::error::ClickUpError;
+use crate::models::{Comment, Folder, List, Space, Task};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Backup data structure for a complete space backup
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpaceBackup {
+    /// Backup metadata
+    pub metadata: BackupMetadata,
+    /// Space information
+    pub space: Option<Space>,
+    /// Folders in the space
+    #[serde(default)]
+    pub folders: Vec<Folder>,
+    /// Lists in the space (including those in folders)
+    #[serde(default)]
+    pub lists: Vec<List>,
+    /// Tasks in all lists
+    #[serde(default)]
+    pub tasks: Vec<Task>,
+    /// Comments for tasks (if included)
+    #[serde(default)]
+    pub comments: Vec<TaskComments>,
+}
+
+/// Backup metadata
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct BackupMetadata {
+    /// Backup creation timestamp
+    pub created_at: String,
+    /// Space ID that was backed up
+    pub space_id: String,
+    /// Whether comments were included
+    pub include_comments: bool,
+    /// Whether attachment files were downloaded
+    pub include_attachments: bool,
+    /// Whether closed tasks were included
+    pub include_closed: bool,
+    /// Total number of folders
+    pub folder_count: usize,
+    /// Total number of lists
+    pub list_count: usize,
+    /// Total number of tasks
+    pub task_count: usize,
+    /// Total number of comments
+    pub comment_count: usize,
+    /// Total number of attachment files downloaded
+    pub attachment_count: usize,
+}
+
+/// Task comments structure
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskComments {
+    /// Task ID
+    pub task_id: String,
+    /// Task name
+    pub task_name: String,
+    /// Comments for this task
+    pub comments: Vec<Comment>,
+}
+
+impl SpaceBackup {
+    /// Reads a backup file written by `spaces backup`
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the file cannot be read
+    /// - `ClickUpError::DeserializationError` if the file is not a space backup;
+    ///   the message names the file
+    pub fn load(path: &Path) -> Result<Self, ClickUpError> {
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            ClickUpError::DeserializationError(format!(
+                "Invalid backup file {}: {e}",
+                path.display()
+            ))
+        })
+    }
+}
@@ -0,0 +1,175 @@
+//! # Attachment Commands
+//!
+//! This module implements `tasks attach` and `tasks attachments download`,
+//! which upload files to a task and save its attachments to a directory.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::DisplayUtils;
+use crate::error::ClickUpError;
+use crate::models::Attachment;
+use colored::*;
+use std::path::{Path, PathBuf};
+
+/// Upload files as attachments to a task
+///
+/// Every file is checked before the first upload, so a mistyped path does
+/// not leave the task with only some of the files. An upload that fails is
+/// reported and the remaining files are still uploaded.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to attach the files to
+/// * `files` - Paths of the files to upload
+///
+/// # Returns
+///
+/// Returns `Ok(())` when every file was uploaded.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a path is not a readable file
+/// - `ClickUpError::ApiError` if any upload failed
+pub async fn attach_files(
+    api: &ClickUpApi,
+    task_id: &str,
+    files: &[PathBuf],
+) -> Result<(), ClickUpError> {
+    for path in files {
+        if !path.is_file() {
+            return Err(ClickUpError::ValidationError(format!(
+                "{} is not a file",
+                path.display()
+            )));
+        }
+    }
+
+    let mut failed = 0;
+    for path in files {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        let data = tokio::fs::read(path).await?;
+        let size = format_size(data.len() as i64);
+        match api.upload_attachment(task_id, &file_name, data).await {
+            Ok(attachment) => {
+                println!(
+                    "{}",
+                    format!("✓ Attached {file_name} ({size}) as {}", attachment.id).green()
+                );
+            }
+            Err(e) => {
+                failed += 1;
+                println!("{}", format!("✗ Failed to attach {}: {e}", path.display()).red());
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(ClickUpError::ApiError(format!(
+            "{failed} of {} file(s) could not be attached",
+            files.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Download a task's attachments into a directory
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to download attachments from
+/// * `out` - Directory to save the files in
+/// * `only` - Attachment ID or file name to download instead of all attachments
+///
+/// # Returns
+///
+/// Returns `Ok(())` when every selected attachment was saved.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the task or the selected attachment doesn't exist
+/// - `ClickUpError::IoError` if the directory cannot be created
+/// - `ClickUpError::ApiError` if any download failed
+pub async fn download_attachments(
+    api: &ClickUpApi,
+    task_id: &str,
+    out: &Path,
+    only: Option<&str>,
+) -> Result<(), ClickUpError> {
+    let task = api.get_task(task_id).await?;
+    if task.attachments.is_empty() {
+        DisplayUtils::display_empty_message("attachments");
+        return Ok(());
+    }
+
+    // Names are chosen from all attachments so a single download gets the
+    // same name as it would in a full download
+    let names = Attachment::unique_file_names(&task.attachments);
+    let selected: Vec<(&Attachment, &String)> = task
+        .attachments
+        .iter()
+        .zip(&names)
+        .filter(|(attachment, name)| match only {
+            Some(reference) => {
+                attachment.id == reference || name.eq_ignore_ascii_case(reference.trim())
+            }
+            None => true,
+        })
+        .collect();
+    if selected.is_empty() {
+        return Err(ClickUpError::NotFoundError(format!(
+            "No attachment '{}' on task {task_id}",
+            only.unwrap_or_default()
+        )));
+    }
+
+    std::fs::create_dir_all(out)?;
+    let mut failed = 0;
+    for (attachment, name) in &selected {
+        let path = out.join(name);
+        let result = match api.download_attachment(attachment).await {
+            Ok(data) => std::fs::write(&path, &data).map(|_| data.len()).map_err(ClickUpError::from),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(size) => {
+                println!(
+                    "{}",
+                    format!("✓ Saved {} ({})", path.display(), format_size(size as i64)).green()
+                );
+            }
+            Err(e) => {
+                failed += 1;
+                println!("{}", format!("✗ Failed to download {name}: {e}").red());
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(ClickUpError::ApiError(format!(
+            "{failed} of {} attachment(s) could not be downloaded",
+            selected.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Formats a byte count for display, e.g. `1.5 MB`
+pub fn format_size(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
@@ -7,24 +7,50 @@
 //! ## Commands
 //!
 //! - **Set**: Configure API token (interactive or command-line)
+//! - **Login**: Log in through a ClickUp OAuth app (`--oauth`)
 //! - **Test**: Verify current authentication with ClickUp API
 //! - **Status**: Display current authentication status
 //! - **Clear**: Remove stored API token
 //! - **RateLimit**: Configure rate limiting settings
+//! - **Profiles**: List, switch between, and delete named profiles
+//! - **Migrate**: Move a plaintext token from `config.toml` to secure storage
+//!
+//! ## Profiles
+//!
+//! All commands act on the profile selected with the global `--profile` flag
+//! (or `CLICKUP_PROFILE`, or the active profile). `auth set --profile <name>`
+//! creates a new profile, and `auth rate-limit --profile <name>` sets
+//! profile-specific rate limits.
 //!
 //! ## Security
 //!
-//! API tokens are stored securely in the user's configuration directory and
-//! are masked when displayed to prevent accidental exposure.
+//! API tokens are stored in the OS keyring (Secret Service on Linux), or in a
+//! passphrase-encrypted file when no keyring is available. Plaintext storage in
+//! `config.toml` is only used when requested with `--storage config`. Tokens are
+//! masked when displayed to prevent accidental exposure.
+//!
+//! ## OAuth
+//!
+//! `auth login --oauth` runs the OAuth2 authorization-code flow against a
+//! ClickUp OAuth app, so users don't need to create or share personal tokens.
+//! The resulting access token is stored like a personal token and sent with
+//! the `Bearer` scheme.
 
 use crate::api::ClickUpApi;
-use crate::config::Config;
+use crate::commands::utils::{DisplayUtils, PromptUtils, TableBuilder, TableHeaders};
+use crate::config::{Config, ProfileConfig, TokenType, DEFAULT_PROFILE};
 use crate::error::ClickUpError;
+use crate::oauth::{self, OAuthClient, RedirectListener, DEFAULT_REDIRECT_PORT};
+use crate::token_store::{TokenStorage, TokenStore};
 
 use clap::Subcommand;
 use colored::*;
 
 use std::io::{self, Write};
+use std::time::Duration;
+
+/// How long `auth login --oauth` waits for the user to approve the app
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(300);
 
 /// Authentication command variants
 ///
@@ -44,6 +70,46 @@ pub enum AuthCommands {
         /// enter the token interactively when prompted.
         #[arg(short, long)]
         token: Option<String>,
+
+        /// Where to store the token (default: OS keyring, falling back to an
+        /// encrypted file)
+        #[arg(long, value_enum)]
+        storage: Option<TokenStorage>,
+    },
+    /// Log in with your ClickUp account
+    ///
+    /// With `--oauth`, this command opens ClickUp's authorization page in your
+    /// browser, receives the redirect on a local port, and exchanges the code
+    /// for an access token. The OAuth app is taken from the options, the
+    /// `CLICKUP_OAUTH_CLIENT_ID`/`CLICKUP_OAUTH_CLIENT_SECRET` environment
+    /// variables, or the `[oauth]` configuration table.
+    Login {
+        /// Use the OAuth2 authorization-code flow
+        #[arg(long)]
+        oauth: bool,
+
+        /// OAuth app client ID
+        #[arg(long)]
+        client_id: Option<String>,
+
+        /// OAuth app client secret
+        #[arg(long)]
+        client_secret: Option<String>,
+
+        /// Local port for the redirect listener (default: 4320)
+        ///
+        /// Must match the redirect URL registered for the OAuth app.
+        #[arg(long)]
+        port: Option<u16>,
+
+        /// Print the authorization URL instead of opening a browser
+        #[arg(long)]
+        no_browser: bool,
+
+        /// Where to store the access token (default: OS keyring, falling back
+        /// to an encrypted file)
+        #[arg(long, value_enum)]
+        storage: Option<TokenStorage>,
     },
     /// Test your current authentication
     ///
@@ -86,6 +152,42 @@ pub enum AuthCommands {
         #[arg(long)]
         show: bool,
     },
+    /// Move a plaintext token from config.toml to secure storage
+    ///
+    /// This command moves an existing token out of `config.toml` into the OS
+    /// keyring, or into an encrypted file if no keyring is available.
+    Migrate {
+        /// Where to move the token (default: OS keyring, falling back to an
+        /// encrypted file)
+        #[arg(long, value_enum)]
+        storage: Option<TokenStorage>,
+    },
+    /// Manage named profiles
+    ///
+    /// Profiles hold separate tokens, workspaces and rate limits, for example
+    /// for a company workspace and client workspaces.
+    Profiles {
+        /// The profile subcommand to execute
+        #[command(subcommand)]
+        command: ProfileCommands,
+    },
+}
+
+/// Profile command variants
+#[derive(Subcommand)]
+pub enum ProfileCommands {
+    /// List all configured profiles
+    List,
+    /// Make a profile the default for future commands
+    Use {
+        /// Profile name (`default` for the top-level settings)
+        name: String,
+    },
+    /// Delete a named profile
+    Delete {
+        /// Profile name
+        name: String,
+    },
 }
 
 /// Execute authentication commands
@@ -111,8 +213,26 @@ pub enum AuthCommands {
 /// - Rate limiting configuration errors
 pub async fn execute(command: AuthCommands, config: &mut Config) -> Result<(), ClickUpError> {
     match command {
-        AuthCommands::Set { token } => {
-            set_token(token, config).await?;
+        AuthCommands::Set { token, storage } => {
+            set_token(token, storage, config).await?;
+        }
+        AuthCommands::Login {
+            oauth,
+            client_id,
+            client_secret,
+            port,
+            no_browser,
+            storage,
+        } => {
+            if !oauth {
+                return Err(ClickUpError::ValidationError(
+                    "Use 'auth login --oauth', or 'auth set' for a personal API token".to_string(),
+                ));
+            }
+            login_oauth(config, client_id, client_secret, port, no_browser, storage).await?;
+        }
+        AuthCommands::Migrate { storage } => {
+            migrate_token(config, storage)?;
         }
         AuthCommands::Test => {
             test_auth(config).await?;
@@ -139,7 +259,174 @@ pub async fn execute(command: AuthCommands, config: &mut Config) -> Result<(), C
                 show,
             )?;
         }
+        AuthCommands::Profiles { command } => match command {
+            ProfileCommands::List => list_profiles(config),
+            ProfileCommands::Use { name } => use_profile(config, &name)?,
+            ProfileCommands::Delete { name } => delete_profile(config, &name)?,
+        },
+    }
+    Ok(())
+}
+
+/// Mask a token for display, showing only the first and last 4 characters
+fn mask_token(token: &str) -> String {
+    if token.len() > 8 {
+        format!("{}...{}", &token[..4], &token[token.len() - 4..])
+    } else {
+        "***".to_string()
+    }
+}
+
+/// List all configured profiles
+///
+/// This function displays the default profile and every named profile with
+/// a masked token, default workspace and list, and requests-per-minute limit.
+/// The profile in effect for this command is marked as active.
+///
+/// # Arguments
+///
+/// * `config` - Reference to the application configuration
+fn list_profiles(config: &Config) {
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::name(),
+        "Token",
+        "Workspace",
+        "Default List",
+        "Requests/min",
+        "Storage",
+        "Active",
+    ]);
+
+    // The top-level settings are only in the main fields when no profile is applied
+    let default_settings = if config.profile.is_some() {
+        config.base_settings.clone()
+    } else {
+        ProfileConfig {
+            api_token: config.api_token.clone(),
+            workspace_id: config.workspace_id.clone(),
+            default_list_id: config.default_list_id.clone(),
+            rate_limit: Some(config.rate_limit.clone()),
+            token_storage: config.token_storage,
+            token_type: config.token_type,
+        }
+    };
+    let default_rpm = default_settings
+        .rate_limit
+        .as_ref()
+        .map(|r| r.requests_per_minute)
+        .unwrap_or_default();
+
+    let rows = std::iter::once((DEFAULT_PROFILE, &default_settings))
+        .chain(config.profiles.iter().map(|(name, p)| (name.as_str(), p)));
+    for (name, settings) in rows {
+        let rpm = settings
+            .rate_limit
+            .as_ref()
+            .map_or(default_rpm, |r| r.requests_per_minute);
+        table_builder.add_row(vec![
+            name.to_string(),
+            settings
+                .api_token
+                .as_deref()
+                .map_or_else(|| "-".to_string(), mask_token),
+            settings.workspace_id.clone().unwrap_or_else(|| "-".to_string()),
+            settings
+                .default_list_id
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+            rpm.to_string(),
+            settings.token_storage.to_string(),
+            if name == config.active_profile_name() { "Yes" } else { "" }.to_string(),
+        ]);
+    }
+
+    table_builder.print();
+    if config.profiles.is_empty() {
+        println!();
+        DisplayUtils::display_empty_message("named profiles");
+        println!("Use 'clickup-cli auth set --profile <name>' to create one");
+    }
+}
+
+/// Make a profile the default for future commands
+///
+/// # Arguments
+///
+/// * `config` - Mutable reference to the application configuration
+/// * `name` - The profile to activate, or `default` for the top-level settings
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the profile doesn't exist
+/// - `ClickUpError::ConfigError` if the configuration cannot be saved
+fn use_profile(config: &mut Config, name: &str) -> Result<(), ClickUpError> {
+    if name == DEFAULT_PROFILE {
+        config.active_profile = None;
+    } else if config.profiles.contains_key(name) {
+        config.active_profile = Some(name.to_string());
+    } else {
+        return Err(ClickUpError::NotFoundError(format!(
+            "Profile '{name}' not found"
+        )));
     }
+
+    config.save()?;
+    println!(
+        "{}",
+        format!("✓ Profile '{name}' is now the active profile").green()
+    );
+    Ok(())
+}
+
+/// Delete a named profile after confirmation
+///
+/// # Arguments
+///
+/// * `config` - Mutable reference to the application configuration
+/// * `name` - The profile to delete
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if `name` is the default profile
+/// - `ClickUpError::NotFoundError` if the profile doesn't exist
+/// - `ClickUpError::ConfigError` if the configuration cannot be saved
+fn delete_profile(config: &mut Config, name: &str) -> Result<(), ClickUpError> {
+    if name == DEFAULT_PROFILE {
+        return Err(ClickUpError::ValidationError(
+            "The default profile cannot be deleted".to_string(),
+        ));
+    }
+    if !config.profiles.contains_key(name) {
+        return Err(ClickUpError::NotFoundError(format!(
+            "Profile '{name}' not found"
+        )));
+    }
+    if !PromptUtils::confirm_delete(config, &format!("profile '{name}'"))? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    let storage = config.profiles[name].token_storage;
+    config.remove_profile(name)?;
+    config.save()?;
+    if let Err(e) = TokenStore::for_profile(name).and_then(|store| store.delete(storage)) {
+        println!(
+            "{}",
+            format!("⚠ Could not remove the profile's token from the {storage}: {e}").yellow()
+        );
+    }
+    println!("{}", format!("✓ Profile '{name}' deleted").green());
     Ok(())
 }
 
@@ -244,12 +531,14 @@ fn configure_rate_limit(
 /// Set the API token in configuration
 ///
 /// This function handles setting the API token either from command-line arguments
-/// or through interactive input. It validates the token and saves it to the
-/// configuration file. For security, interactive input is recommended.
+/// or through interactive input. It validates the token and stores it in the
+/// OS keyring (or the encrypted file fallback) unless another storage is given.
+/// For security, interactive input is recommended.
 ///
 /// # Arguments
 ///
 /// * `token` - Optional token from command-line arguments
+/// * `storage` - Optional storage location for the token
 /// * `config` - Mutable reference to the application configuration
 ///
 /// # Returns
@@ -260,9 +549,13 @@ fn configure_rate_limit(
 ///
 /// This function can return:
 /// - `ClickUpError::ValidationError` if the token is empty
-/// - `ClickUpError::ConfigError` if the configuration cannot be saved
+/// - `ClickUpError::ConfigError` if the configuration or token cannot be saved
 /// - `ClickUpError::IoError` if interactive input fails
-async fn set_token(token: Option<String>, config: &mut Config) -> Result<(), ClickUpError> {
+async fn set_token(
+    token: Option<String>,
+    storage: Option<TokenStorage>,
+    config: &mut Config,
+) -> Result<(), ClickUpError> {
     // Get token from argument or prompt user
     let token = match token {
         Some(t) => t,
@@ -287,13 +580,149 @@ async fn set_token(token: Option<String>, config: &mut Config) -> Result<(), Cli
         ));
     }
 
-    // Save token to configuration
-    config.set_api_token(token)?;
-    println!("{}", "✓ API token saved successfully!".green());
+    // Save token to its storage and record the location in the configuration
+    config.token_type = TokenType::Personal;
+    let storage = config.store_api_token(token, storage)?;
+    match &config.profile {
+        Some(name) => println!(
+            "{}",
+            format!("✓ API token saved to profile '{name}'!").green()
+        ),
+        None => println!("{}", "✓ API token saved successfully!".green()),
+    }
+    println!("Storage: {storage}");
 
     Ok(())
 }
 
+/// Log in through the OAuth2 authorization-code flow
+///
+/// This function binds the redirect listener, sends the user to the
+/// authorization page, waits for the redirect, and exchanges the code for an
+/// access token. The token is stored like `auth set` would store it and
+/// marked as an OAuth token so it is sent with the `Bearer` scheme. A client
+/// ID given as an option is saved to the `[oauth]` table for the next login,
+/// and a client secret given as an option is stored like the token. A
+/// plaintext secret from the `[oauth]` table is moved out of `config.toml`.
+///
+/// # Arguments
+///
+/// * `config` - Mutable reference to the application configuration
+/// * `client_id` - Optional OAuth app client ID
+/// * `client_secret` - Optional OAuth app client secret
+/// * `port` - Optional redirect listener port
+/// * `no_browser` - Whether to skip opening a browser
+/// * `storage` - Optional storage location for the token
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful login, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ConfigError` if the OAuth app is not configured
+/// - `ClickUpError::IoError` if the redirect port cannot be bound
+/// - `ClickUpError::AuthError` if authorization is denied, times out, or the
+///   code exchange fails
+async fn login_oauth(
+    config: &mut Config,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    port: Option<u16>,
+    no_browser: bool,
+    storage: Option<TokenStorage>,
+) -> Result<(), ClickUpError> {
+    let remember_app = client_id.is_some() || client_secret.is_some();
+    let client = OAuthClient::from_config(config, client_id, client_secret)?;
+    let port = port
+        .or(config.oauth.redirect_port)
+        .unwrap_or(DEFAULT_REDIRECT_PORT);
+    let listener = RedirectListener::bind(port).await?;
+    let state = oauth::generate_state();
+    let url = client.authorization_url(&listener.redirect_uri(), &state);
+
+    println!("Open this URL to authorize the ClickUp CLI:\n\n  {url}\n");
+    if !no_browser && !oauth::open_browser(&url) {
+        println!("{}", "Could not open a browser; open the URL manually.".yellow());
+    }
+    println!("Waiting for authorization on {}...", listener.redirect_uri());
+
+    let code = listener.wait_for_code(&state, LOGIN_TIMEOUT).await?;
+    let token = client.exchange_code(&code).await?;
+
+    if remember_app {
+        config.oauth.client_id = Some(client.client_id().to_string());
+    }
+    let secret_to_store = if remember_app {
+        Some(client.client_secret().to_string())
+    } else {
+        config.oauth.client_secret.clone()
+    };
+    if let Some(secret) = secret_to_store {
+        config.store_oauth_client_secret(secret, storage)?;
+    }
+    config.token_type = TokenType::OAuth;
+    let storage = config.store_api_token(token, storage)?;
+    match &config.profile {
+        Some(name) => println!(
+            "{}",
+            format!("✓ Logged in with OAuth to profile '{name}'!").green()
+        ),
+        None => println!("{}", "✓ Logged in with OAuth!".green()),
+    }
+    println!("Storage: {storage}");
+
+    Ok(())
+}
+
+/// Move a plaintext token out of the configuration file
+///
+/// This function migrates a token stored as `api_token` in `config.toml` to
+/// the OS keyring, or to the encrypted file if no keyring is available. The
+/// plaintext token is removed from the configuration file once it has been
+/// stored.
+///
+/// # Arguments
+///
+/// * `config` - Mutable reference to the application configuration
+/// * `storage` - Optional target storage; defaults to the keyring with fallback
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful migration, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if `--storage config` is requested
+/// - `ClickUpError::ConfigError` if the keyring or passphrase is unavailable
+fn migrate_token(config: &mut Config, storage: Option<TokenStorage>) -> Result<(), ClickUpError> {
+    if storage == Some(TokenStorage::Config) {
+        return Err(ClickUpError::ValidationError(
+            "Choose keyring or encrypted-file as the migration target".to_string(),
+        ));
+    }
+    if !config.token_storage.is_config() {
+        println!(
+            "{}",
+            format!("✓ Token is already stored in the {}", config.token_storage).green()
+        );
+        return Ok(());
+    }
+    let Some(token) = config.api_token.clone() else {
+        println!("{}", "No plaintext API token to migrate".yellow());
+        return Ok(());
+    };
+
+    let storage = config.store_api_token(token, storage)?;
+    println!(
+        "{}",
+        format!("✓ API token moved from config.toml to the {storage}").green()
+    );
+    Ok(())
+}
+
 /// Test the current authentication
 ///
 /// This function verifies that the current API token is valid by making a
@@ -360,16 +789,21 @@ async fn test_auth(config: &Config) -> Result<(), ClickUpError> {
 /// This function can return:
 /// - `ClickUpError::ConfigError` if the configuration cannot be read
 fn show_status(config: &Config) -> Result<(), ClickUpError> {
+    println!("Profile: {}", config.active_profile_name());
     if config.is_authenticated() {
         println!("{}", "✓ API token is configured".green());
         if let Some(token) = &config.api_token {
             // Mask the token for security (show first 4 and last 4 characters)
-            let masked_token = if token.len() > 8 {
-                format!("{}...{}", &token[..4], &token[token.len() - 4..])
-            } else {
-                "***".to_string()
-            };
-            println!("Token: {masked_token}");
+            println!("Token: {}", mask_token(token));
+        }
+        println!("Type: {}", config.token_type);
+        println!("Storage: {}", config.token_storage);
+        if config.token_storage.is_config() {
+            println!(
+                "{}",
+                "⚠ The token is stored in plaintext. Run 'clickup-cli auth migrate' to move it to the OS keyring."
+                    .yellow()
+            );
         }
     } else {
         println!("{}", "✗ No API token configured".red());
@@ -381,8 +815,8 @@ fn show_status(config: &Config) -> Result<(), ClickUpError> {
 
 /// Clear the stored API token
 ///
-/// This function removes the API token from the configuration, effectively
-/// logging the user out of the CLI.
+/// This function removes the API token from the configuration and from the
+/// keyring or encrypted file, effectively logging the user out of the CLI.
 ///
 /// # Arguments
 ///
@@ -397,8 +831,7 @@ fn show_status(config: &Config) -> Result<(), ClickUpError> {
 /// This function can return:
 /// - `ClickUpError::ConfigError` if the configuration cannot be saved
 fn clear_token(config: &mut Config) -> Result<(), ClickUpError> {
-    config.api_token = None;
-    config.save()?;
+    config.clear_api_token()?;
     println!("{}", "✓ API token cleared".green());
     Ok(())
 }
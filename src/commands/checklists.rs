@@ -0,0 +1,826 @@
+//! # Checklist Commands
+//!
+//! This module handles checklist operations on tasks for the ClickUp CLI,
+//! available as `clickup-cli tasks checklist`.
+//!
+//! ## Commands
+//!
+//! - **List**: Show a task's checklists with numbered items
+//! - **Create**: Create a checklist, optionally from a Markdown task list on stdin
+//! - **Rename**: Rename a checklist
+//! - **Delete**: Remove a checklist and all its items
+//! - **Item**: Add, resolve, unresolve, assign, and delete checklist items
+//!
+//! ## References
+//!
+//! Checklists and items are referred to by ID, by their 1-based position as
+//! shown by `tasks checklist list`, or by name (case-insensitive). When a task
+//! has a single checklist, `--checklist` can be left out.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::{ApiUtils, CommandExecutor, PromptUtils};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::{
+    CreateChecklistItemRequest, CreateChecklistRequest, EditChecklistItemRequest,
+    EditChecklistRequest, Task, TaskChecklist, TaskChecklistItem,
+};
+use clap::Subcommand;
+use colored::*;
+
+/// Checklist command variants
+#[derive(Subcommand)]
+pub enum ChecklistCommands {
+    /// List a task's checklists with numbered items
+    List {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+    },
+    /// Create a checklist on a task
+    ///
+    /// With --from-markdown, items are read from a Markdown task list on
+    /// stdin. Checked items (`- [x]`) are created resolved, and a leading
+    /// `# Heading` is used as the name when --name is not given.
+    Create {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist name
+        #[arg(short, long, required_unless_present = "from_markdown")]
+        name: Option<String>,
+        /// Read items from a Markdown task list on stdin
+        #[arg(long)]
+        from_markdown: bool,
+    },
+    /// Rename a checklist
+    Rename {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+        /// New checklist name
+        #[arg(short, long)]
+        name: String,
+    },
+    /// Delete a checklist and all its items
+    Delete {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+    },
+    /// Manage checklist items
+    Item {
+        /// The checklist item subcommand to execute
+        #[command(subcommand)]
+        command: ChecklistItemCommands,
+    },
+}
+
+/// Checklist item command variants
+#[derive(Subcommand)]
+pub enum ChecklistItemCommands {
+    /// Add an item to a checklist
+    Add {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+        /// Item name
+        #[arg(short, long)]
+        name: String,
+        /// User ID to assign the item to
+        #[arg(short, long)]
+        assignee: Option<i64>,
+    },
+    /// Mark an item as resolved
+    Resolve {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+        /// Item ID, number, or name
+        #[arg(short, long)]
+        item: String,
+    },
+    /// Mark an item as not resolved
+    Unresolve {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+        /// Item ID, number, or name
+        #[arg(short, long)]
+        item: String,
+    },
+    /// Assign an item to a user, or remove its assignee
+    Assign {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+        /// Item ID, number, or name
+        #[arg(short, long)]
+        item: String,
+        /// User ID to assign the item to
+        #[arg(short, long, required_unless_present = "unassign")]
+        assignee: Option<i64>,
+        /// Remove the current assignee
+        #[arg(long, conflicts_with = "assignee")]
+        unassign: bool,
+    },
+    /// Delete an item
+    Delete {
+        /// Task ID
+        #[arg(short, long)]
+        task_id: String,
+        /// Checklist ID, number, or name
+        #[arg(short, long)]
+        checklist: Option<String>,
+        /// Item ID, number, or name
+        #[arg(short, long)]
+        item: String,
+    },
+}
+
+/// A checklist parsed from a Markdown task list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownChecklist {
+    /// Text of the first `#` heading, if any
+    pub title: Option<String>,
+    /// Items in order, with whether each is checked
+    pub items: Vec<(String, bool)>,
+}
+
+impl CommandExecutor for ChecklistCommands {
+    type Commands = ChecklistCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            ChecklistCommands::List { task_id } => {
+                list_checklists(api, &task_id).await?;
+            }
+            ChecklistCommands::Create {
+                task_id,
+                name,
+                from_markdown,
+            } => {
+                let markdown = if from_markdown {
+                    Some(parse_markdown_checklist(&std::io::read_to_string(
+                        std::io::stdin(),
+                    )?))
+                } else {
+                    None
+                };
+                create_checklist(api, &task_id, name, markdown).await?;
+            }
+            ChecklistCommands::Rename {
+                task_id,
+                checklist,
+                name,
+            } => {
+                rename_checklist(api, &task_id, checklist.as_deref(), name).await?;
+            }
+            ChecklistCommands::Delete { task_id, checklist } => {
+                delete_checklist(api, &task_id, checklist.as_deref()).await?;
+            }
+            ChecklistCommands::Item { command } => match command {
+                ChecklistItemCommands::Add {
+                    task_id,
+                    checklist,
+                    name,
+                    assignee,
+                } => {
+                    add_item(api, &task_id, checklist.as_deref(), name, assignee).await?;
+                }
+                ChecklistItemCommands::Resolve {
+                    task_id,
+                    checklist,
+                    item,
+                } => {
+                    let request = EditChecklistItemRequest {
+                        resolved: Some(true),
+                        ..Default::default()
+                    };
+                    edit_item(api, &task_id, checklist.as_deref(), &item, request, "Resolved")
+                        .await?;
+                }
+                ChecklistItemCommands::Unresolve {
+                    task_id,
+                    checklist,
+                    item,
+                } => {
+                    let request = EditChecklistItemRequest {
+                        resolved: Some(false),
+                        ..Default::default()
+                    };
+                    edit_item(api, &task_id, checklist.as_deref(), &item, request, "Unresolved")
+                        .await?;
+                }
+                ChecklistItemCommands::Assign {
+                    task_id,
+                    checklist,
+                    item,
+                    assignee,
+                    unassign,
+                } => {
+                    let (assignee, action) = if unassign {
+                        (None, "Unassigned".to_string())
+                    } else {
+                        let assignee = assignee.ok_or_else(|| {
+                            ClickUpError::ValidationError(
+                                "Specify --assignee or --unassign".to_string(),
+                            )
+                        })?;
+                        (Some(assignee), format!("Assigned user {assignee} to"))
+                    };
+                    let request = EditChecklistItemRequest {
+                        assignee: Some(assignee),
+                        ..Default::default()
+                    };
+                    edit_item(api, &task_id, checklist.as_deref(), &item, request, &action)
+                        .await?;
+                }
+                ChecklistItemCommands::Delete {
+                    task_id,
+                    checklist,
+                    item,
+                } => {
+                    delete_item(api, &task_id, checklist.as_deref(), &item).await?;
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Execute checklist commands
+///
+/// # Arguments
+///
+/// * `command` - The checklist command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Validation errors for ambiguous or missing references
+/// - Not found errors for missing tasks, checklists or items
+#[allow(dead_code)]
+pub async fn execute(command: ChecklistCommands, config: &Config) -> Result<(), ClickUpError> {
+    ChecklistCommands::execute(command, config).await
+}
+
+/// Finds a checklist by ID, 1-based position, or name
+///
+/// # Arguments
+///
+/// * `checklists` - The task's checklists
+/// * `reference` - The reference, or `None` to use the task's only checklist
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the reference is missing and the task
+///   doesn't have exactly one checklist, or if a name matches several checklists
+/// - `ClickUpError::NotFoundError` if nothing matches
+pub fn find_checklist<'a>(
+    checklists: &'a [TaskChecklist],
+    reference: Option<&str>,
+) -> Result<&'a TaskChecklist, ClickUpError> {
+    match (reference, checklists) {
+        (Some(reference), _) => find_by_reference(
+            checklists,
+            reference,
+            "Checklist",
+            |checklist| &checklist.id,
+            |checklist| checklist.name.as_deref(),
+        ),
+        (None, [only]) => Ok(only),
+        (None, []) => Err(ClickUpError::NotFoundError(
+            "Task has no checklists".to_string(),
+        )),
+        (None, _) => Err(ClickUpError::ValidationError(format!(
+            "Task has {} checklists; choose one with --checklist",
+            checklists.len()
+        ))),
+    }
+}
+
+/// Finds a checklist item by ID, 1-based position, or name
+///
+/// # Arguments
+///
+/// * `checklist` - The checklist containing the item
+/// * `reference` - The item reference
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a name matches several items
+/// - `ClickUpError::NotFoundError` if nothing matches
+pub fn find_item<'a>(
+    checklist: &'a TaskChecklist,
+    reference: &str,
+) -> Result<&'a TaskChecklistItem, ClickUpError> {
+    find_by_reference(
+        checklist.items(),
+        reference,
+        "Checklist item",
+        |item| &item.id,
+        |item| item.name.as_deref(),
+    )
+}
+
+/// Resolves a reference against IDs first, then positions, then names
+fn find_by_reference<'a, T>(
+    entries: &'a [T],
+    reference: &str,
+    kind: &str,
+    id: impl Fn(&T) -> &str,
+    name: impl Fn(&T) -> Option<&str>,
+) -> Result<&'a T, ClickUpError> {
+    let reference = reference.trim();
+    if let Some(entry) = entries.iter().find(|entry| id(entry) == reference) {
+        return Ok(entry);
+    }
+    if let Ok(position) = reference.parse::<usize>() {
+        return position
+            .checked_sub(1)
+            .and_then(|index| entries.get(index))
+            .ok_or_else(|| {
+                ClickUpError::NotFoundError(format!(
+                    "{kind} {position} not found (there are {})",
+                    entries.len()
+                ))
+            });
+    }
+
+    let matches: Vec<&T> = entries
+        .iter()
+        .filter(|entry| name(entry).is_some_and(|name| name.eq_ignore_ascii_case(reference)))
+        .collect();
+    match matches.as_slice() {
+        [entry] => Ok(entry),
+        [] => Err(ClickUpError::NotFoundError(format!(
+            "{kind} '{reference}' not found"
+        ))),
+        _ => Err(ClickUpError::ValidationError(format!(
+            "{} {kind}s are named '{reference}'; use the number or ID instead",
+            matches.len()
+        ))),
+    }
+}
+
+/// Parses a Markdown task list into checklist items
+///
+/// Lines starting with `-`, `*` or `+` become items, with `[ ]` and `[x]`
+/// checkboxes marking whether they are resolved. Nested items are flattened,
+/// and other lines are ignored apart from the first `#` heading.
+///
+/// # Arguments
+///
+/// * `markdown` - The Markdown text
+pub fn parse_markdown_checklist(markdown: &str) -> MarkdownChecklist {
+    let mut checklist = MarkdownChecklist::default();
+    for line in markdown.lines() {
+        let line = line.trim();
+        if let Some(heading) = line.strip_prefix('#') {
+            if checklist.title.is_none() {
+                let heading = heading.trim_start_matches('#').trim();
+                if !heading.is_empty() {
+                    checklist.title = Some(heading.to_string());
+                }
+            }
+            continue;
+        }
+
+        let Some(text) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| line.strip_prefix(bullet))
+        else {
+            continue;
+        };
+        let text = text.trim_start();
+        let (text, resolved) = if let Some(rest) = text.strip_prefix("[ ]") {
+            (rest, false)
+        } else if let Some(rest) = text
+            .strip_prefix("[x]")
+            .or_else(|| text.strip_prefix("[X]"))
+        {
+            (rest, true)
+        } else {
+            (text, false)
+        };
+        let text = text.trim();
+        if !text.is_empty() {
+            checklist.items.push((text.to_string(), resolved));
+        }
+    }
+    checklist
+}
+
+/// Returns the display name of an item's assignee
+fn assignee_name(item: &TaskChecklistItem) -> Option<String> {
+    let assignee = item.assignee.as_ref()?;
+    assignee
+        .get("username")
+        .and_then(|username| username.as_str())
+        .map(str::to_string)
+        .or_else(|| assignee.get("id").map(|id| id.to_string()))
+}
+
+/// Prints a checklist with numbered items
+fn print_checklist(position: usize, checklist: &TaskChecklist) {
+    let items = checklist.items();
+    let resolved = items
+        .iter()
+        .filter(|item| item.resolved.unwrap_or(false))
+        .count();
+    println!(
+        "{}. {} ({}) - {resolved}/{} resolved",
+        position,
+        checklist.name.as_deref().unwrap_or("Unnamed").bold(),
+        checklist.id,
+        items.len()
+    );
+    for (index, item) in items.iter().enumerate() {
+        let mark = if item.resolved.unwrap_or(false) {
+            "[x]".green()
+        } else {
+            "[ ]".normal()
+        };
+        let mut line = format!(
+            "   {}. {mark} {}",
+            index + 1,
+            item.name.as_deref().unwrap_or("")
+        );
+        if let Some(assignee) = assignee_name(item) {
+            line.push_str(&format!(" (@{assignee})"));
+        }
+        println!("{line}");
+    }
+}
+
+/// List a task's checklists
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the task doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn list_checklists(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError> {
+    let task = api.get_task(task_id).await?;
+    if task.checklists.is_empty() {
+        println!("{}", "No checklists found".yellow());
+        return Ok(());
+    }
+    for (index, checklist) in task.checklists.iter().enumerate() {
+        print_checklist(index + 1, checklist);
+    }
+    Ok(())
+}
+
+/// Create a checklist, optionally with items
+///
+/// Items are added one at a time after the checklist is created; checked
+/// Markdown items are resolved right after they are added.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `name` - The checklist name, or `None` to use the Markdown heading
+/// * `markdown` - Items parsed from Markdown, if any
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if there is no name, or the Markdown has no items
+/// - `ClickUpError::NotFoundError` if the task doesn't exist
+/// - `ClickUpError::NetworkError` if an API request fails
+async fn create_checklist(
+    api: &ClickUpApi,
+    task_id: &str,
+    name: Option<String>,
+    markdown: Option<MarkdownChecklist>,
+) -> Result<(), ClickUpError> {
+    if markdown.as_ref().is_some_and(|markdown| markdown.items.is_empty()) {
+        return Err(ClickUpError::ValidationError(
+            "No list items found in the Markdown on stdin".to_string(),
+        ));
+    }
+    let markdown = markdown.unwrap_or_default();
+    let name = name.or(markdown.title).ok_or_else(|| {
+        ClickUpError::ValidationError(
+            "Specify --name or start the Markdown with a # heading".to_string(),
+        )
+    })?;
+
+    let mut checklist = api
+        .create_checklist(task_id, CreateChecklistRequest { name })
+        .await?;
+    println!(
+        "{}",
+        format!(
+            "✓ Created checklist '{}' ({})",
+            checklist.name.as_deref().unwrap_or(""),
+            checklist.id
+        )
+        .green()
+    );
+
+    let total = markdown.items.len();
+    for (name, resolved) in markdown.items {
+        let known: Vec<String> = checklist.items().iter().map(|i| i.id.clone()).collect();
+        checklist = api
+            .create_checklist_item(
+                &checklist.id,
+                CreateChecklistItemRequest {
+                    name,
+                    assignee: None,
+                },
+            )
+            .await?;
+        if resolved {
+            let added = checklist
+                .items()
+                .iter()
+                .find(|item| !known.contains(&item.id))
+                .map(|item| item.id.clone());
+            if let Some(item_id) = added {
+                checklist = api
+                    .edit_checklist_item(
+                        &checklist.id,
+                        &item_id,
+                        EditChecklistItemRequest {
+                            resolved: Some(true),
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+            }
+        }
+    }
+    if total > 0 {
+        println!("{}", format!("✓ Added {total} items").green());
+        print_checklist(1, &checklist);
+    }
+    Ok(())
+}
+
+/// Fetches a task and resolves one of its checklists
+async fn fetch_checklist(
+    api: &ClickUpApi,
+    task_id: &str,
+    reference: Option<&str>,
+) -> Result<(Task, TaskChecklist), ClickUpError> {
+    let task = api.get_task(task_id).await?;
+    let checklist = find_checklist(&task.checklists, reference)?.clone();
+    Ok((task, checklist))
+}
+
+/// Rename a checklist
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `reference` - The checklist reference
+/// * `name` - The new name
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the task or checklist doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn rename_checklist(
+    api: &ClickUpApi,
+    task_id: &str,
+    reference: Option<&str>,
+    name: String,
+) -> Result<(), ClickUpError> {
+    let (_, checklist) = fetch_checklist(api, task_id, reference).await?;
+    api.edit_checklist(
+        &checklist.id,
+        EditChecklistRequest {
+            name: Some(name.clone()),
+            position: None,
+        },
+    )
+    .await?;
+    println!(
+        "{}",
+        format!(
+            "✓ Renamed checklist '{}' to '{name}'",
+            checklist.name.as_deref().unwrap_or("")
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Delete a checklist
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `reference` - The checklist reference
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success or when cancelled, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::PermissionError` if the task is in a protected list or space
+/// - `ClickUpError::NotFoundError` if the task or checklist doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn delete_checklist(
+    api: &ClickUpApi,
+    task_id: &str,
+    reference: Option<&str>,
+) -> Result<(), ClickUpError> {
+    let (task, checklist) = fetch_checklist(api, task_id, reference).await?;
+    api.config()
+        .ensure_deletable(&task.list.id, &task.space.id)?;
+
+    let description = format!(
+        "checklist '{}' with {} items",
+        checklist.name.as_deref().unwrap_or("Unnamed"),
+        checklist.items().len()
+    );
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_checklist(&checklist.id).await?;
+    println!("{}", format!("✓ Deleted {description}").green());
+    Ok(())
+}
+
+/// Add an item to a checklist
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `reference` - The checklist reference
+/// * `name` - The item name
+/// * `assignee` - User ID to assign the item to
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the task or checklist doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn add_item(
+    api: &ClickUpApi,
+    task_id: &str,
+    reference: Option<&str>,
+    name: String,
+    assignee: Option<i64>,
+) -> Result<(), ClickUpError> {
+    let (_, checklist) = fetch_checklist(api, task_id, reference).await?;
+    let checklist = api
+        .create_checklist_item(&checklist.id, CreateChecklistItemRequest { name: name.clone(), assignee })
+        .await?;
+    println!(
+        "{}",
+        format!(
+            "✓ Added '{name}' to checklist '{}' as item {}",
+            checklist.name.as_deref().unwrap_or(""),
+            checklist.items().len()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Change a checklist item
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `checklist_reference` - The checklist reference
+/// * `item_reference` - The item reference
+/// * `request` - The fields to change
+/// * `action` - Past-tense description of the change for output
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a reference is ambiguous
+/// - `ClickUpError::NotFoundError` if the task, checklist or item doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn edit_item(
+    api: &ClickUpApi,
+    task_id: &str,
+    checklist_reference: Option<&str>,
+    item_reference: &str,
+    request: EditChecklistItemRequest,
+    action: &str,
+) -> Result<(), ClickUpError> {
+    let (_, checklist) = fetch_checklist(api, task_id, checklist_reference).await?;
+    let item = find_item(&checklist, item_reference)?;
+    api.edit_checklist_item(&checklist.id, &item.id, request)
+        .await?;
+    println!(
+        "{}",
+        format!("✓ {action} '{}'", item.name.as_deref().unwrap_or("")).green()
+    );
+    Ok(())
+}
+
+/// Delete a checklist item
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `checklist_reference` - The checklist reference
+/// * `item_reference` - The item reference
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success or when cancelled, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::PermissionError` if the task is in a protected list or space
+/// - `ClickUpError::NotFoundError` if the task, checklist or item doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn delete_item(
+    api: &ClickUpApi,
+    task_id: &str,
+    checklist_reference: Option<&str>,
+    item_reference: &str,
+) -> Result<(), ClickUpError> {
+    let (task, checklist) = fetch_checklist(api, task_id, checklist_reference).await?;
+    let item = find_item(&checklist, item_reference)?;
+    api.config()
+        .ensure_deletable(&task.list.id, &task.space.id)?;
+
+    let description = format!("checklist item '{}'", item.name.as_deref().unwrap_or(""));
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_checklist_item(&checklist.id, &item.id).await?;
+    println!("{}", format!("✓ Deleted {description}").green());
+    Ok(())
+}
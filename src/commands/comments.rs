@@ -1,28 +1,53 @@
 //! # Comment Commands
 //!
 //! This module handles all comment-related operations for the ClickUp CLI.
-//! It provides commands for listing, creating, updating, and deleting comments
-//! on tasks.
+//! It provides commands for listing, creating, updating, resolving and
+//! deleting comments on tasks, lists and chat views.
 //!
 //! ## Commands
 //!
-//! - **List**: Display all comments for a specific task
+//! - **List**: Display all comments for a task, list or view
 //! - **Show**: Show detailed information about a specific comment
-//! - **Create**: Add a new comment to a task
-//! - **Update**: Modify an existing comment
-//! - **Delete**: Remove a comment from a task
+//! - **Create**: Add a new comment to a task, list or view
+//! - **Update**: Modify an existing comment on a task, list or view
+//! - **Resolve**: Mark a comment as resolved
+//! - **Unresolve**: Reopen a resolved comment
+//! - **Inbox**: List unresolved comments assigned to you, grouped by task
+//! - **Delete**: Remove a comment from a task, list or view
 //!
 //! ## Features
 //!
 //! Comments support rich text formatting, user mentions, and can be assigned
-//! to specific team members. Comments are displayed in a formatted table
-//! for easy reading.
+//! to specific team members:
+//!
+//! - `--markdown` sends bold, italic, code, links, lists and headings as
+//!   ClickUp rich text
+//! - `@username` becomes a mention when it names a workspace member
+//! - `--assignee` takes a user ID, a username or an email address
+//!
+//! Usernames are looked up in the configured workspace unless
+//! `--workspace-id` is given. Rich text is shown with terminal formatting.
+//!
+//! `inbox` checks the comments of every task in a workspace or space, which
+//! takes one request per task.
+//!
+//! With `--offline`, `list` reads task comments from a space backup made
+//! with `--include-comments`; other comment commands are refused.
 
 use crate::api::ClickUpApi;
+use crate::backup::SpaceBackup;
 use crate::config::Config;
+use crate::data_source::DataSource;
+use crate::dependency_graph::format_due_date;
 use crate::error::ClickUpError;
-use crate::models::CreateCommentRequest;
-use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, TableBuilder, TableHeaders};
+use crate::models::{
+    Comment, CommentTarget, CreateCommentRequest, Task, TeamTaskFilter, Workspace,
+};
+use crate::commands::users::{find_member_by_email, find_member_by_username};
+use crate::commands::utils::{
+    ApiUtils, CommandExecutor, DisplayUtils, ErrorUtils, PromptUtils, TableBuilder, TableHeaders,
+};
+use crate::rich_text;
 use clap::Subcommand;
 use colored::*;
 
@@ -32,11 +57,17 @@ use colored::*;
 /// associated parameters and help text.
 #[derive(Subcommand)]
 pub enum CommentCommands {
-    /// List all comments for a task
+    /// List all comments for a task, list or view
     List {
         /// Task ID
-        #[arg(short, long)]
-        task_id: String,
+        #[arg(short, long, conflicts_with_all = ["list_id", "view_id"])]
+        task_id: Option<String>,
+        /// List ID, for the list's comment thread
+        #[arg(long, conflicts_with = "view_id")]
+        list_id: Option<String>,
+        /// Chat view ID
+        #[arg(long)]
+        view_id: Option<String>,
     },
     /// Show details of a specific comment
     Show {
@@ -44,41 +75,128 @@ pub enum CommentCommands {
         #[arg(short, long)]
         id: String,
     },
-    /// Create a new comment on a task
+    /// Create a new comment on a task, list or view
     Create {
         /// Task ID
-        #[arg(short = 'i', long)]
-        task_id: String,
-        /// Comment text
+        #[arg(short = 'i', long, conflicts_with_all = ["list_id", "view_id"])]
+        task_id: Option<String>,
+        /// List ID, for the list's comment thread
+        #[arg(long, conflicts_with = "view_id")]
+        list_id: Option<String>,
+        /// Chat view ID
+        #[arg(long)]
+        view_id: Option<String>,
+        /// Comment text; `@username` mentions a workspace member
         #[arg(short = 't', long)]
         text: String,
-        /// Assignee ID (optional)
+        /// Assignee user ID, username or email (optional)
         #[arg(short, long)]
-        assignee: Option<i64>,
+        assignee: Option<String>,
         /// Notify all team members
         #[arg(short, long)]
         notify_all: Option<bool>,
+        /// Format the text as Markdown
+        #[arg(short, long)]
+        markdown: bool,
+        /// Workspace to look up usernames in (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
     },
     /// Update an existing comment
+    ///
+    /// Comments on lists and views are only found when their list or view
+    /// is given; otherwise the comments of every task are searched.
     Update {
         /// Comment ID
         #[arg(short, long)]
         id: String,
+        /// Task the comment is on
+        #[arg(long, conflicts_with_all = ["list_id", "view_id"])]
+        task_id: Option<String>,
+        /// List whose comment thread the comment is in
+        #[arg(long, conflicts_with = "view_id")]
+        list_id: Option<String>,
+        /// Chat view the comment is in
+        #[arg(long)]
+        view_id: Option<String>,
         /// New comment text
         #[arg(short = 't', long)]
         text: String,
-        /// Assignee ID (optional)
+        /// Assignee user ID, username or email (optional)
         #[arg(short, long)]
-        assignee: Option<i64>,
+        assignee: Option<String>,
         /// Notify all team members
         #[arg(short, long)]
         notify_all: Option<bool>,
+        /// Workspace to look up the assignee in (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+    },
+    /// Mark a comment as resolved
+    ///
+    /// Comments on lists and views are only found when their list or view
+    /// is given; otherwise the comments of every task are searched.
+    Resolve {
+        /// Comment ID
+        #[arg(short, long)]
+        id: String,
+        /// Task the comment is on
+        #[arg(long, conflicts_with_all = ["list_id", "view_id"])]
+        task_id: Option<String>,
+        /// List whose comment thread the comment is in
+        #[arg(long, conflicts_with = "view_id")]
+        list_id: Option<String>,
+        /// Chat view the comment is in
+        #[arg(long)]
+        view_id: Option<String>,
+    },
+    /// Reopen a resolved comment
+    ///
+    /// Comments on lists and views are only found when their list or view
+    /// is given; otherwise the comments of every task are searched.
+    Unresolve {
+        /// Comment ID
+        #[arg(short, long)]
+        id: String,
+        /// Task the comment is on
+        #[arg(long, conflicts_with_all = ["list_id", "view_id"])]
+        task_id: Option<String>,
+        /// List whose comment thread the comment is in
+        #[arg(long, conflicts_with = "view_id")]
+        list_id: Option<String>,
+        /// Chat view the comment is in
+        #[arg(long)]
+        view_id: Option<String>,
+    },
+    /// List unresolved comments assigned to you, grouped by task
+    Inbox {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Only check tasks in this space
+        #[arg(long)]
+        space_id: Option<String>,
+        /// Also check closed tasks
+        #[arg(long)]
+        include_closed: bool,
     },
     /// Delete a comment
+    ///
+    /// Comments on lists and views are only found when their list or view
+    /// is given; otherwise the comments of every task are searched.
     Delete {
         /// Comment ID
         #[arg(short, long)]
         id: String,
+        /// Task the comment is on
+        #[arg(long, conflicts_with_all = ["list_id", "view_id"])]
+        task_id: Option<String>,
+        /// List whose comment thread the comment is in
+        #[arg(long, conflicts_with = "view_id")]
+        list_id: Option<String>,
+        /// Chat view the comment is in
+        #[arg(long)]
+        view_id: Option<String>,
     },
 }
 
@@ -86,36 +204,95 @@ impl CommandExecutor for CommentCommands {
     type Commands = CommentCommands;
     
     async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        if let Some(path) = &config.offline {
+            let backup = SpaceBackup::load(path)?;
+            return handle_offline_command(command, &backup).await;
+        }
         let api = ApiUtils::create_client(config)?;
         Self::handle_command(command, &api).await
     }
     
     async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
         match command {
-            CommentCommands::List { task_id } => {
-                list_comments(api, &task_id).await?;
+            CommentCommands::List {
+                task_id,
+                list_id,
+                view_id,
+            } => {
+                let (target, target_id) = comment_target(task_id, list_id, view_id)?;
+                list_comments(api, target, &target_id).await?;
             }
             CommentCommands::Show { id } => {
                 show_comment(api, &id).await?;
             }
             CommentCommands::Create {
                 task_id,
+                list_id,
+                view_id,
                 text,
                 assignee,
                 notify_all,
+                markdown,
+                workspace_id,
             } => {
-                create_comment(api, &task_id, text, assignee, notify_all).await?;
+                let (target, target_id) = comment_target(task_id, list_id, view_id)?;
+                create_comment(
+                    api,
+                    (target, &target_id),
+                    text,
+                    assignee,
+                    notify_all,
+                    markdown,
+                    workspace_id,
+                )
+                .await?;
             }
             CommentCommands::Update {
                 id,
+                task_id,
+                list_id,
+                view_id,
                 text,
                 assignee,
                 notify_all,
+                workspace_id,
+            } => {
+                let target = optional_comment_target(task_id, list_id, view_id);
+                update_comment(api, &id, target, text, assignee, notify_all, workspace_id).await?;
+            }
+            CommentCommands::Resolve {
+                id,
+                task_id,
+                list_id,
+                view_id,
+            } => {
+                let target = optional_comment_target(task_id, list_id, view_id);
+                set_resolved(api, &id, target, true).await?;
+            }
+            CommentCommands::Unresolve {
+                id,
+                task_id,
+                list_id,
+                view_id,
             } => {
-                update_comment(api, &id, text, assignee, notify_all).await?;
+                let target = optional_comment_target(task_id, list_id, view_id);
+                set_resolved(api, &id, target, false).await?;
             }
-            CommentCommands::Delete { id } => {
-                delete_comment(api, &id).await?;
+            CommentCommands::Inbox {
+                workspace_id,
+                space_id,
+                include_closed,
+            } => {
+                show_inbox(api, workspace_id, space_id, include_closed).await?;
+            }
+            CommentCommands::Delete {
+                id,
+                task_id,
+                list_id,
+                view_id,
+            } => {
+                let target = optional_comment_target(task_id, list_id, view_id);
+                delete_comment(api, &id, target).await?;
             }
         }
         Ok(())
@@ -147,15 +324,42 @@ pub async fn execute(command: CommentCommands, config: &Config) -> Result<(), Cl
     CommentCommands::execute(command, config).await
 }
 
-/// List all comments for a task
+/// Handle a comment command from a backup given with `--offline`
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` for commands that need ClickUp, for list
+///   and view comments, or if the backup was made without comments
+/// - `ClickUpError::NotFoundError` if the task isn't in the backup
+async fn handle_offline_command(
+    command: CommentCommands,
+    backup: &SpaceBackup,
+) -> Result<(), ClickUpError> {
+    match command {
+        CommentCommands::List {
+            task_id,
+            list_id,
+            view_id,
+        } => {
+            let (target, target_id) = comment_target(task_id, list_id, view_id)?;
+            list_comments(backup, target, &target_id).await
+        }
+        _ => Err(ErrorUtils::offline_error()),
+    }
+}
+
+/// List all comments for a task, list or view
 ///
-/// This function retrieves and displays all comments for a specific task
-/// in a formatted table. Long comments are truncated for display.
+/// This function retrieves and displays all comments for a specific task,
+/// list or chat view in a formatted table. Rich text is laid out with its bullets, numbers,
+/// link targets and mentions, and long lines are truncated for display.
 ///
 /// # Arguments
 ///
-/// * `api` - Reference to the ClickUp API client
-/// * `task_id` - The ID of the task to list comments for
+/// * `source` - Where to read from: the ClickUp API or a backup
+/// * `target` - The kind of item to list comments for
+/// * `target_id` - The ID of the task, list or view
 ///
 /// # Returns
 ///
@@ -165,9 +369,13 @@ pub async fn execute(command: CommentCommands, config: &Config) -> Result<(), Cl
 ///
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
-/// - `ClickUpError::NotFoundError` if the task doesn't exist
-async fn list_comments(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError> {
-    let comments = api.get_comments(task_id).await?;
+/// - `ClickUpError::NotFoundError` if the task, list or view doesn't exist
+async fn list_comments(
+    source: &impl DataSource,
+    target: CommentTarget,
+    target_id: &str,
+) -> Result<(), ClickUpError> {
+    let comments = source.get_target_comments(target, target_id).await?;
 
     if comments.comments.is_empty() {
         DisplayUtils::display_empty_message("comments");
@@ -184,17 +392,11 @@ async fn list_comments(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpErr
     ]);
 
     for comment in &comments.comments {
-        let comment_text = if comment.comment_text.len() > 50 {
-            format!("{}...", &comment.comment_text[..50])
-        } else {
-            comment.comment_text.clone()
-        };
-
         table_builder.add_row(vec![
             comment.id.clone(),
             comment.user.username.clone(),
-            comment_text,
-            comment.date.clone(),
+            comment_preview(comment),
+            comment_date(comment),
             if comment.resolved { "Yes" } else { "No" }.to_string(),
         ]);
     }
@@ -203,6 +405,47 @@ async fn list_comments(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpErr
     Ok(())
 }
 
+/// Returns a comment's text laid out for a table cell, with long lines
+/// truncated
+fn comment_preview(comment: &Comment) -> String {
+    let text = if comment.comment.is_empty() {
+        comment.comment_text.clone()
+    } else {
+        rich_text::plain_text(&comment.comment)
+    };
+    text.lines()
+        .map(|line| {
+            if line.chars().count() > 50 {
+                format!("{}...", line.chars().take(50).collect::<String>())
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns a comment's creation date as `YYYY-MM-DD`, or as given if it is
+/// not a timestamp
+fn comment_date(comment: &Comment) -> String {
+    comment
+        .date
+        .parse::<i64>()
+        .ok()
+        .and_then(format_due_date)
+        .unwrap_or_else(|| comment.date.clone())
+}
+
+/// Returns the username, or failing that the ID, of a comment's assignee
+fn assignee_name(comment: &Comment) -> Option<String> {
+    let assignee = comment.assignee.as_ref()?;
+    assignee
+        .get("username")
+        .and_then(|name| name.as_str())
+        .map(str::to_string)
+        .or_else(|| comment.assignee_id().map(|id| id.to_string()))
+}
+
 /// Show detailed information about a specific comment
 ///
 /// This function uses an efficient search strategy to find a comment by ID
@@ -233,10 +476,18 @@ async fn show_comment(api: &ClickUpApi, comment_id: &str) -> Result<(), ClickUpE
     println!("Created: {}", comment.date);
     println!("Updated: {}", comment.date_updated.as_deref().unwrap_or(&comment.date));
     println!("Resolved: {}", if comment.resolved { "Yes" } else { "No" });
-    println!("Text: {}", comment.comment_text);
+    let text = rich_text::render_comment(&comment);
+    if text.contains('\n') {
+        println!("Text:");
+        for line in text.lines() {
+            println!("  {line}");
+        }
+    } else {
+        println!("Text: {text}");
+    }
 
-    if let Some(assignee) = &comment.assignee {
-        println!("Assignee: {assignee:?}");
+    if let Some(assignee) = assignee_name(&comment) {
+        println!("Assignee: {assignee}");
     }
 
     if let Some(assignee_by) = &comment.assignee_by {
@@ -267,9 +518,12 @@ async fn show_comment(api: &ClickUpApi, comment_id: &str) -> Result<(), ClickUpE
 ///
 /// * `api` - Reference to the ClickUp API client
 /// * `comment_id` - The ID of the comment to update
+/// * `target` - The task, list or view the comment is in; without one, the
+///   comments of every task are searched
 /// * `text` - The new comment text
-/// * `assignee` - Optional assignee ID to mention
+/// * `assignee` - Optional assignee user ID, username or email
 /// * `notify_all` - Optional flag to notify all team members
+/// * `workspace_id` - Workspace to look up the assignee in
 ///
 /// # Returns
 ///
@@ -280,35 +534,192 @@ async fn show_comment(api: &ClickUpApi, comment_id: &str) -> Result<(), ClickUpE
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the comment doesn't exist
-/// - `ClickUpError::ValidationError` if the text is empty
+/// - `ClickUpError::ValidationError` if the text is empty, or no workspace is
+///   available to look up an assignee's username
+/// - `ClickUpError::NotFoundError` if no member has the assignee's username
 async fn update_comment(
     api: &ClickUpApi,
     comment_id: &str,
+    target: Option<(CommentTarget, String)>,
     text: String,
-    assignee: Option<i64>,
+    assignee: Option<String>,
     notify_all: Option<bool>,
+    workspace_id: Option<String>,
 ) -> Result<(), ClickUpError> {
+    validate_text(&text)?;
+    let workspace = if assignee.as_deref().is_some_and(needs_lookup) {
+        Some(fetch_workspace(api, workspace_id).await?)
+    } else {
+        None
+    };
+    let assignee = assignee
+        .map(|assignee| resolve_assignee(&assignee, workspace.as_ref()))
+        .transpose()?;
+
     let comment_data = CreateCommentRequest {
         comment_text: text,
+        comment: None,
         assignee,
         notify_all,
+        resolved: None,
     };
 
-    api.update_comment(comment_id, comment_data).await?;
+    let (target, target_id, comment) = find_comment(api, comment_id, target).await?;
+    api.update_comment(target, &target_id, &comment, comment_data)
+        .await?;
 
     println!("✓ Comment updated successfully!");
     Ok(())
 }
 
+/// Resolve or reopen a comment
+///
+/// Only the comment's resolved state changes; its text and assignee are kept.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `comment_id` - The ID of the comment to change
+/// * `target` - The task, list or view the comment is in; without one, the
+///   comments of every task are searched
+/// * `resolved` - Whether to resolve (`true`) or reopen (`false`) the comment
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the comment doesn't exist
+async fn set_resolved(
+    api: &ClickUpApi,
+    comment_id: &str,
+    target: Option<(CommentTarget, String)>,
+    resolved: bool,
+) -> Result<(), ClickUpError> {
+    let (target, target_id, comment) = find_comment(api, comment_id, target).await?;
+    api.set_comment_resolved(target, &target_id, &comment, resolved)
+        .await?;
+
+    let message = if resolved {
+        format!("✓ Comment {comment_id} resolved")
+    } else {
+        format!("✓ Comment {comment_id} reopened")
+    };
+    println!("{}", message.green());
+    Ok(())
+}
+
+/// List the unresolved comments assigned to the current user
+///
+/// This function checks the comments of every task in a workspace, or in one
+/// of its spaces, and prints the unresolved comments assigned to the
+/// authenticated user, grouped by task with the task's link.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Workspace to check (defaults to the configured workspace)
+/// * `space_id` - Only check tasks in this space
+/// * `include_closed` - Whether to also check closed tasks
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace is given or configured
+/// - `ClickUpError::NetworkError` if an API request fails
+async fn show_inbox(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    space_id: Option<String>,
+    include_closed: bool,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let user_id = api.get_user().await?.user.id;
+    let filter = TeamTaskFilter {
+        list_ids: Vec::new(),
+        space_ids: space_id.into_iter().collect(),
+        include_closed,
+    };
+    let tasks = api
+        .get_all_filtered_team_tasks(&workspace_id, &filter)
+        .await?
+        .tasks;
+
+    let task_count = tasks.len();
+    let mut inbox: Vec<(Task, Vec<Comment>)> = Vec::new();
+    for task in tasks {
+        let comments = inbox_comments(api.get_comments(&task.id).await?.comments, user_id);
+        if !comments.is_empty() {
+            inbox.push((task, comments));
+        }
+    }
+
+    if inbox.is_empty() {
+        DisplayUtils::display_empty_message("unresolved comments assigned to you");
+    }
+    for (task, comments) in &inbox {
+        DisplayUtils::display_section_header(&format!(
+            "{} ({})",
+            task.name.as_deref().unwrap_or("Untitled task"),
+            task.id
+        ));
+        println!("{}", task.url.blue());
+
+        let mut table_builder = TableBuilder::new();
+        table_builder.add_header(vec![
+            TableHeaders::id(),
+            TableHeaders::user(),
+            TableHeaders::comment(),
+            TableHeaders::created(),
+        ]);
+        for comment in comments {
+            table_builder.add_row(vec![
+                comment.id.clone(),
+                comment.user.username.clone(),
+                comment_preview(comment),
+                comment_date(comment),
+            ]);
+        }
+        table_builder.print();
+    }
+
+    println!("\n{}", "Summary:".bold());
+    println!("Tasks checked: {task_count}");
+    println!("Tasks with assigned comments: {}", inbox.len());
+    println!(
+        "Unresolved comments: {}",
+        inbox.iter().map(|(_, comments)| comments.len()).sum::<usize>()
+    );
+    Ok(())
+}
+
+/// Keeps the unresolved comments assigned to a user, in their original order
+pub fn inbox_comments(comments: Vec<Comment>, user_id: i64) -> Vec<Comment> {
+    comments
+        .into_iter()
+        .filter(|comment| !comment.resolved && comment.assignee_id() == Some(user_id))
+        .collect()
+}
+
 /// Delete a comment
 ///
-/// This function permanently removes a comment from a task.
-/// This action cannot be undone.
+/// This function permanently removes a comment from a task, list or view
+/// after asking for confirmation with the comment's text, unless `--yes` was
+/// given. Comments in protected lists or spaces are never deleted.
 ///
 /// # Arguments
 ///
 /// * `api` - Reference to the ClickUp API client
 /// * `comment_id` - The ID of the comment to delete
+/// * `target` - The task, list or view the comment is in; without one, the
+///   comments of every task are searched
 ///
 /// # Returns
 ///
@@ -319,8 +730,26 @@ async fn update_comment(
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the comment doesn't exist
-async fn delete_comment(api: &ClickUpApi, comment_id: &str) -> Result<(), ClickUpError> {
-    api.delete_comment(comment_id).await?;
+/// - `ClickUpError::PermissionError` if the comment is in a protected list or space
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn delete_comment(
+    api: &ClickUpApi,
+    comment_id: &str,
+    target: Option<(CommentTarget, String)>,
+) -> Result<(), ClickUpError> {
+    let (target, target_id, comment) = find_comment(api, comment_id, target).await?;
+
+    let mut preview: String = comment.comment_text.chars().take(50).collect();
+    if comment.comment_text.chars().count() > 50 {
+        preview.push_str("...");
+    }
+    let description = format!("comment '{preview}' ({comment_id})");
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_comment(target, &target_id, &comment).await?;
 
     println!("{}", "✓ Comment deleted successfully!".green());
     println!("Deleted comment ID: {comment_id}");
@@ -328,18 +757,69 @@ async fn delete_comment(api: &ClickUpApi, comment_id: &str) -> Result<(), ClickU
     Ok(())
 }
 
-/// Create a new comment on a task
+/// Find a comment in the thread of the task, list or view it is on
 ///
-/// This function creates a new comment on the specified task with
-/// optional assignee mention and team notification.
+/// The API has no way to fetch a single comment, so without a target the
+/// comments of every task are searched.
 ///
 /// # Arguments
 ///
 /// * `api` - Reference to the ClickUp API client
-/// * `task_id` - The ID of the task to comment on
+/// * `comment_id` - The ID of the comment to find
+/// * `target` - The task, list or view the comment is in
+///
+/// # Returns
+///
+/// Returns the kind and ID of the comment's task, list or view, and the comment.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the comment doesn't exist
+async fn find_comment(
+    api: &ClickUpApi,
+    comment_id: &str,
+    target: Option<(CommentTarget, String)>,
+) -> Result<(CommentTarget, String, Comment), ClickUpError> {
+    match target {
+        Some((target, target_id)) => {
+            let comment = api
+                .get_target_comments(target, &target_id)
+                .await?
+                .comments
+                .into_iter()
+                .find(|comment| comment.id == comment_id)
+                .ok_or_else(|| {
+                    ClickUpError::NotFoundError(format!(
+                        "Comment {comment_id} not found on {} {target_id}",
+                        target.endpoint_segment()
+                    ))
+                })?;
+            Ok((target, target_id, comment))
+        }
+        None => {
+            let (task_id, comment) = api.find_comment(comment_id).await?;
+            Ok((CommentTarget::Task, task_id, comment))
+        }
+    }
+}
+
+/// Create a new comment on a task, list or view
+///
+/// This function creates a new comment on the specified item with
+/// optional assignee and team notification. Markdown text and resolved
+/// `@username` mentions are sent as rich text; other text is sent as is.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `target` - The kind and ID of the task, list or view to comment on
 /// * `text` - The comment text content
-/// * `assignee` - Optional assignee ID to mention in the comment
+/// * `assignee` - Optional assignee user ID, username or email
 /// * `notify_all` - Optional flag to notify all team members
+/// * `markdown` - Whether to format the text as Markdown
+/// * `workspace_id` - Workspace to look up usernames in
 ///
 /// # Returns
 ///
@@ -349,23 +829,181 @@ async fn delete_comment(api: &ClickUpApi, comment_id: &str) -> Result<(), ClickU
 ///
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
-/// - `ClickUpError::NotFoundError` if the task doesn't exist
-/// - `ClickUpError::ValidationError` if the text is empty
+/// - `ClickUpError::NotFoundError` if the task, list or view doesn't exist, or
+///   no member has the assignee's username
+/// - `ClickUpError::ValidationError` if the text is empty, a username matches
+///   more than one member, or no workspace is available to look up an
+///   assignee's username
 async fn create_comment(
     api: &ClickUpApi,
-    task_id: &str,
+    (target, target_id): (CommentTarget, &str),
     text: String,
-    assignee: Option<i64>,
+    assignee: Option<String>,
     notify_all: Option<bool>,
+    markdown: bool,
+    workspace_id: Option<String>,
 ) -> Result<(), ClickUpError> {
-    let comment_data = CreateCommentRequest {
-        comment_text: text,
-        assignee,
-        notify_all,
+    validate_text(&text)?;
+    let blocks = if markdown {
+        rich_text::markdown_to_blocks(&text)
+    } else {
+        rich_text::text_blocks(&text)
+    };
+
+    // Members are only fetched when a username has to be looked up
+    let workspace = if assignee.as_deref().is_some_and(needs_lookup) {
+        Some(fetch_workspace(api, workspace_id).await?)
+    } else if rich_text::mention_names(&blocks).is_empty() {
+        None
+    } else {
+        match ApiUtils::resolve_workspace_id(api, workspace_id) {
+            Ok(workspace_id) => Some(api.get_workspace(&workspace_id).await?),
+            Err(_) => {
+                println!(
+                    "{}",
+                    "Warning: no workspace configured; @mentions are sent as text".yellow()
+                );
+                None
+            }
+        }
+    };
+    let assignee = assignee
+        .map(|assignee| resolve_assignee(&assignee, workspace.as_ref()))
+        .transpose()?;
+    let blocks = match &workspace {
+        Some(workspace) => rich_text::link_mentions(blocks, |name| {
+            Ok(find_member_by_username(workspace, name)?.and_then(|user| user.id))
+        })?,
+        None => blocks,
+    };
+
+    let mentions = rich_text::mention_count(&blocks);
+    let comment_data = if markdown || mentions > 0 {
+        CreateCommentRequest {
+            comment_text: String::new(),
+            comment: Some(blocks),
+            assignee,
+            notify_all,
+            resolved: None,
+        }
+    } else {
+        CreateCommentRequest {
+            comment_text: text,
+            comment: None,
+            assignee,
+            notify_all,
+            resolved: None,
+        }
     };
 
-    api.create_comment(task_id, comment_data).await?;
+    api.create_target_comment(target, target_id, comment_data)
+        .await?;
 
     println!("✓ Comment created successfully!");
+    if mentions > 0 {
+        println!("Mentions: {mentions}");
+    }
     Ok(())
 }
+
+/// Returns the item given by `--task-id`, `--list-id` or `--view-id`
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no item is given
+pub fn comment_target(
+    task_id: Option<String>,
+    list_id: Option<String>,
+    view_id: Option<String>,
+) -> Result<(CommentTarget, String), ClickUpError> {
+    optional_comment_target(task_id, list_id, view_id).ok_or_else(|| {
+        ClickUpError::ValidationError(
+            "No item given. Use --task-id, --list-id or --view-id".to_string(),
+        )
+    })
+}
+
+/// Returns the item given by `--task-id`, `--list-id` or `--view-id`, if any
+fn optional_comment_target(
+    task_id: Option<String>,
+    list_id: Option<String>,
+    view_id: Option<String>,
+) -> Option<(CommentTarget, String)> {
+    task_id
+        .map(|id| (CommentTarget::Task, id))
+        .or_else(|| list_id.map(|id| (CommentTarget::List, id)))
+        .or_else(|| view_id.map(|id| (CommentTarget::View, id)))
+}
+
+/// Checks that comment text is not empty
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the text is empty or only whitespace
+fn validate_text(text: &str) -> Result<(), ClickUpError> {
+    if text.trim().is_empty() {
+        return Err(ClickUpError::ValidationError(
+            "Comment text cannot be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns whether an assignee is given by name rather than by user ID
+fn needs_lookup(assignee: &str) -> bool {
+    assignee.trim().parse::<i64>().is_err()
+}
+
+/// Fetches the workspace whose members' usernames are looked up in
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace is given or configured
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn fetch_workspace(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+) -> Result<Workspace, ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    api.get_workspace(&workspace_id).await
+}
+
+/// Resolves an assignee given as a user ID, a username or an email address
+///
+/// A leading `@` is ignored, and usernames match as in
+/// `find_member_by_username`.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a username is given without a
+///   workspace, or matches more than one member
+/// - `ClickUpError::NotFoundError` if no member has the username or email
+pub fn resolve_assignee(
+    assignee: &str,
+    workspace: Option<&Workspace>,
+) -> Result<i64, ClickUpError> {
+    let assignee = assignee.trim();
+    if let Ok(id) = assignee.parse::<i64>() {
+        return Ok(id);
+    }
+    let workspace = workspace.ok_or_else(|| {
+        ClickUpError::ValidationError(format!(
+            "A workspace is needed to look up the assignee '{assignee}'"
+        ))
+    })?;
+    let name = assignee.trim_start_matches('@');
+    let member = match find_member_by_username(workspace, name)? {
+        Some(member) => Some(member),
+        None => find_member_by_email(workspace, name),
+    };
+    member.and_then(|member| member.id).ok_or_else(|| {
+        ClickUpError::NotFoundError(format!(
+            "No member of workspace {} has the username or email '{assignee}'",
+            workspace.id
+        ))
+    })
+}
@@ -0,0 +1,802 @@
+//! # Goal Commands
+//!
+//! This module handles goals and their key results (targets) for the
+//! ClickUp CLI.
+//!
+//! ## Commands
+//!
+//! - **List**: Display the goals in a workspace with their progress
+//! - **Show**: Show a goal's details and key results
+//! - **Create**: Create a goal
+//! - **Update**: Change a goal's name, description, due date, color or owners
+//! - **Delete**: Remove a goal and its key results
+//! - **KeyResult**: Add, update and delete key results
+//!
+//! ## Key Results
+//!
+//! Key results measure progress as a number, an amount of money, a
+//! percentage, a done/not done flag, or automatically from linked tasks and
+//! lists. `goals key-result update` records a new current value with an
+//! optional note, so weekly check-ins can be scripted.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::{
+    ApiUtils, CommandExecutor, DisplayUtils, PromptUtils, TableBuilder, TableHeaders,
+};
+use crate::config::Config;
+use crate::dependency_graph::format_due_date;
+use crate::error::ClickUpError;
+use crate::models::{
+    CreateGoalRequest, CreateKeyResultRequest, Goal, GoalOwner, KeyResult, KeyResultType,
+    UpdateGoalRequest, UpdateKeyResultRequest,
+};
+use clap::Subcommand;
+use colored::*;
+
+/// Width of the progress bars in `goals show`
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Goal command variants
+///
+/// This enum defines all available goal subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum GoalCommands {
+    /// List goals in a workspace
+    List {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Include completed goals
+        #[arg(long)]
+        include_completed: bool,
+    },
+    /// Show a goal's progress and key results
+    Show {
+        /// Goal ID
+        #[arg(short, long)]
+        id: String,
+    },
+    /// Create a goal
+    Create {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Goal name
+        #[arg(short, long)]
+        name: String,
+        /// Goal description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Due date (Unix timestamp in milliseconds)
+        #[arg(long)]
+        due_date: Option<i64>,
+        /// User ID of an owner (repeat for several owners)
+        #[arg(long = "owner")]
+        owners: Vec<i64>,
+        /// Goal color, e.g. #32a852
+        #[arg(long)]
+        color: Option<String>,
+    },
+    /// Update a goal
+    Update {
+        /// Goal ID
+        #[arg(short, long)]
+        id: String,
+        /// New name
+        #[arg(short, long)]
+        name: Option<String>,
+        /// New description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// New due date (Unix timestamp in milliseconds)
+        #[arg(long)]
+        due_date: Option<i64>,
+        /// New color
+        #[arg(long)]
+        color: Option<String>,
+        /// User ID to add as an owner (repeatable)
+        #[arg(long = "add-owner")]
+        add_owners: Vec<i64>,
+        /// User ID to remove as an owner (repeatable)
+        #[arg(long = "remove-owner")]
+        remove_owners: Vec<i64>,
+    },
+    /// Delete a goal and its key results
+    Delete {
+        /// Goal ID
+        #[arg(short, long)]
+        id: String,
+    },
+    /// Manage the key results of a goal
+    KeyResult {
+        /// The key result subcommand to execute
+        #[command(subcommand)]
+        command: KeyResultCommands,
+    },
+}
+
+/// Key result command variants
+#[derive(Subcommand)]
+pub enum KeyResultCommands {
+    /// Add a key result to a goal
+    ///
+    /// Number and currency key results need --target. Percentage key results
+    /// default to 0-100, boolean key results are done or not done, and
+    /// automatic key results track the tasks and lists given.
+    Add {
+        /// Goal ID
+        #[arg(short, long)]
+        goal_id: String,
+        /// Key result name
+        #[arg(short, long)]
+        name: String,
+        /// How progress is measured: number, currency, boolean, percentage or automatic
+        #[arg(short = 't', long = "type", value_parser = parse_key_result_type)]
+        kind: KeyResultType,
+        /// Starting value (default 0)
+        #[arg(long, allow_negative_numbers = true)]
+        start: Option<f64>,
+        /// Target value
+        #[arg(long, allow_negative_numbers = true)]
+        target: Option<f64>,
+        /// Unit shown next to values, e.g. USD or signups
+        #[arg(short, long)]
+        unit: Option<String>,
+        /// User ID of an owner (repeatable)
+        #[arg(long = "owner")]
+        owners: Vec<i64>,
+        /// Task tracked by an automatic key result (repeatable)
+        #[arg(long = "task-id")]
+        task_ids: Vec<String>,
+        /// List tracked by an automatic key result (repeatable)
+        #[arg(long = "list-id")]
+        list_ids: Vec<String>,
+    },
+    /// Record progress on a key result
+    ///
+    /// The value is a number (a trailing % is allowed), or done/true/yes and
+    /// not-done/false/no for boolean key results.
+    Update {
+        /// Key result ID
+        #[arg(short, long)]
+        id: String,
+        /// New current value
+        #[arg(short, long, allow_hyphen_values = true)]
+        current: String,
+        /// Check-in note
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// Delete a key result
+    Delete {
+        /// Key result ID
+        #[arg(short, long)]
+        id: String,
+    },
+}
+
+impl CommandExecutor for GoalCommands {
+    type Commands = GoalCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            GoalCommands::List {
+                workspace_id,
+                include_completed,
+            } => {
+                list_goals(api, workspace_id, include_completed).await?;
+            }
+            GoalCommands::Show { id } => {
+                show_goal(api, &id).await?;
+            }
+            GoalCommands::Create {
+                workspace_id,
+                name,
+                description,
+                due_date,
+                owners,
+                color,
+            } => {
+                let request = CreateGoalRequest {
+                    name,
+                    due_date,
+                    description: description.unwrap_or_default(),
+                    multiple_owners: owners.len() > 1,
+                    owners,
+                    color,
+                };
+                create_goal(api, workspace_id, request).await?;
+            }
+            GoalCommands::Update {
+                id,
+                name,
+                description,
+                due_date,
+                color,
+                add_owners,
+                remove_owners,
+            } => {
+                let request = UpdateGoalRequest {
+                    name,
+                    due_date,
+                    description,
+                    color,
+                    add_owners,
+                    rem_owners: remove_owners,
+                };
+                update_goal(api, &id, request).await?;
+            }
+            GoalCommands::Delete { id } => {
+                delete_goal(api, &id).await?;
+            }
+            GoalCommands::KeyResult { command } => match command {
+                KeyResultCommands::Add {
+                    goal_id,
+                    name,
+                    kind,
+                    start,
+                    target,
+                    unit,
+                    owners,
+                    task_ids,
+                    list_ids,
+                } => {
+                    let (steps_start, steps_end) = key_result_range(kind, start, target)?;
+                    if kind == KeyResultType::Automatic
+                        && task_ids.is_empty()
+                        && list_ids.is_empty()
+                    {
+                        return Err(ClickUpError::ValidationError(
+                            "Automatic key results need at least one --task-id or --list-id"
+                                .to_string(),
+                        ));
+                    }
+                    if kind != KeyResultType::Automatic
+                        && !(task_ids.is_empty() && list_ids.is_empty())
+                    {
+                        return Err(ClickUpError::ValidationError(
+                            "--task-id and --list-id are only used by automatic key results"
+                                .to_string(),
+                        ));
+                    }
+                    let request = CreateKeyResultRequest {
+                        name,
+                        owners,
+                        type_: kind,
+                        steps_start,
+                        steps_end,
+                        unit,
+                        task_ids,
+                        list_ids,
+                    };
+                    add_key_result(api, &goal_id, request).await?;
+                }
+                KeyResultCommands::Update { id, current, note } => {
+                    let request = UpdateKeyResultRequest {
+                        steps_current: parse_progress_value(&current)?,
+                        note,
+                    };
+                    update_key_result(api, &id, request).await?;
+                }
+                KeyResultCommands::Delete { id } => {
+                    delete_key_result(api, &id).await?;
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Execute goal commands
+///
+/// This function routes goal commands to their appropriate handlers.
+///
+/// # Arguments
+///
+/// * `command` - The goal command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Validation errors for invalid key result values or ranges
+/// - Not found errors for missing goals or key results
+pub async fn execute(command: GoalCommands, config: &Config) -> Result<(), ClickUpError> {
+    GoalCommands::execute(command, config).await
+}
+
+/// Parses a key result type name for `--type`
+fn parse_key_result_type(name: &str) -> Result<KeyResultType, String> {
+    KeyResultType::from_name(name).ok_or_else(|| {
+        format!(
+            "unknown key result type '{name}'. Known types: {}",
+            KeyResultType::ALL.map(|kind| kind.as_str()).join(", ")
+        )
+    })
+}
+
+/// Returns the start and target values for a new key result
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a number or currency key result has no
+///   target, a boolean key result is given a range, or the target equals the start
+pub fn key_result_range(
+    kind: KeyResultType,
+    start: Option<f64>,
+    target: Option<f64>,
+) -> Result<(f64, f64), ClickUpError> {
+    let (start, target) = match kind {
+        KeyResultType::Boolean => {
+            if start.is_some() || target.is_some() {
+                return Err(ClickUpError::ValidationError(
+                    "Boolean key results are done or not done and take no --start or --target"
+                        .to_string(),
+                ));
+            }
+            (0.0, 1.0)
+        }
+        KeyResultType::Percentage | KeyResultType::Automatic => {
+            (start.unwrap_or(0.0), target.unwrap_or(100.0))
+        }
+        KeyResultType::Number | KeyResultType::Currency => {
+            let target = target.ok_or_else(|| {
+                ClickUpError::ValidationError(format!(
+                    "{} key results need a --target",
+                    kind.as_str()
+                ))
+            })?;
+            (start.unwrap_or(0.0), target)
+        }
+    };
+    if start == target {
+        return Err(ClickUpError::ValidationError(
+            "The target must differ from the start value".to_string(),
+        ));
+    }
+    Ok((start, target))
+}
+
+/// Parses a progress value for `goals key-result update`
+///
+/// Accepts numbers with an optional trailing `%`, and done/true/yes/complete
+/// or not-done/false/no/incomplete for boolean key results.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the value is not a number or a known word
+pub fn parse_progress_value(value: &str) -> Result<f64, ClickUpError> {
+    let value = value.trim();
+    match value.to_lowercase().as_str() {
+        "done" | "true" | "yes" | "complete" => return Ok(1.0),
+        "not-done" | "false" | "no" | "incomplete" => return Ok(0.0),
+        _ => {}
+    }
+    value
+        .strip_suffix('%')
+        .unwrap_or(value)
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+        .ok_or_else(|| {
+            ClickUpError::ValidationError(format!(
+                "Invalid progress value '{value}'. Use a number, or done/not-done"
+            ))
+        })
+}
+
+/// Formats a progress percentage with a bar, e.g. `[#####-----]  50%`
+pub fn progress_bar(percent: f64, width: usize) -> String {
+    let percent = percent.clamp(0.0, 100.0);
+    let filled = ((percent / 100.0) * width as f64).round() as usize;
+    format!(
+        "[{}{}] {:>3.0}%",
+        "#".repeat(filled),
+        "-".repeat(width - filled),
+        percent
+    )
+}
+
+/// Formats a key result value with its unit
+fn format_value(key_result: &KeyResult, value: Option<f64>) -> String {
+    let Some(value) = value else {
+        return "-".to_string();
+    };
+    let number = if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    };
+    match key_result.kind() {
+        Some(KeyResultType::Boolean) => if value >= 1.0 { "done" } else { "not done" }.to_string(),
+        Some(KeyResultType::Percentage) => format!("{number}%"),
+        _ => match key_result.unit.as_deref().filter(|unit| !unit.is_empty()) {
+            Some(unit) => format!("{number} {unit}"),
+            None => number,
+        },
+    }
+}
+
+/// Formats a due date given as a Unix timestamp in milliseconds
+fn format_due(due_date: Option<&str>) -> String {
+    due_date
+        .and_then(|due| due.parse::<i64>().ok())
+        .and_then(format_due_date)
+        .unwrap_or_else(|| "None".to_string())
+}
+
+/// Joins owner names for display
+fn format_owners(owners: &[GoalOwner]) -> String {
+    owners
+        .iter()
+        .map(|owner| {
+            owner
+                .username
+                .clone()
+                .or_else(|| owner.email.clone())
+                .unwrap_or_else(|| owner.id.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// List the goals in a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `include_completed` - Whether to include completed goals
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn list_goals(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    include_completed: bool,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let response = api.get_goals(&workspace_id, include_completed).await?;
+
+    if response.goals.is_empty() {
+        DisplayUtils::display_empty_message("goals");
+        return Ok(());
+    }
+
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::name(),
+        "Progress",
+        TableHeaders::due_date(),
+        "Key Results",
+        "Owners",
+    ]);
+    for goal in &response.goals {
+        table_builder.add_row(vec![
+            goal.id.clone(),
+            goal.name.clone(),
+            goal.progress()
+                .map(|percent| format!("{percent:.0}%"))
+                .unwrap_or_else(|| "-".to_string()),
+            format_due(goal.due_date.as_deref()),
+            goal.key_result_count.unwrap_or(0).to_string(),
+            format_owners(&goal.owners),
+        ]);
+    }
+    table_builder.print();
+    Ok(())
+}
+
+/// Show a goal's details, progress and key results
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `goal_id` - The ID of the goal to show
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the goal doesn't exist
+async fn show_goal(api: &ClickUpApi, goal_id: &str) -> Result<(), ClickUpError> {
+    let goal = api.get_goal(goal_id).await?;
+    print_goal(&goal);
+    Ok(())
+}
+
+/// Prints a goal's details and key results
+fn print_goal(goal: &Goal) {
+    DisplayUtils::display_details_header("Goal");
+    println!("ID: {}", goal.id);
+    println!("Name: {}", goal.name);
+    if let Some(progress) = goal.progress() {
+        println!("Progress: {}", progress_bar(progress, PROGRESS_BAR_WIDTH));
+    }
+    println!("Due Date: {}", format_due(goal.due_date.as_deref()));
+    if !goal.owners.is_empty() {
+        println!("Owners: {}", format_owners(&goal.owners));
+    }
+    if let Some(description) = goal.description.as_deref().filter(|d| !d.is_empty()) {
+        println!("Description: {description}");
+    }
+    if goal.archived == Some(true) {
+        println!("{}", "Archived".yellow());
+    }
+
+    if goal.key_results.is_empty() {
+        println!("\n{}", "No key results".yellow());
+        return;
+    }
+    println!("\n{}", "Key Results:".bold());
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::name(),
+        "Type",
+        "Current",
+        "Target",
+        "Progress",
+    ]);
+    for key_result in &goal.key_results {
+        let automatic = key_result.kind() == Some(KeyResultType::Automatic);
+        table_builder.add_row(vec![
+            key_result.id.clone(),
+            key_result.name.clone(),
+            key_result.type_.clone(),
+            if automatic {
+                format!(
+                    "{} tasks, {} lists",
+                    key_result.task_ids.len(),
+                    key_result.list_ids.len()
+                )
+            } else {
+                format_value(key_result, key_result.steps_current)
+            },
+            if automatic {
+                "-".to_string()
+            } else {
+                format_value(key_result, key_result.steps_end)
+            },
+            key_result
+                .progress()
+                .map(|percent| progress_bar(percent, PROGRESS_BAR_WIDTH / 2))
+                .unwrap_or_else(|| "-".to_string()),
+        ]);
+    }
+    table_builder.print();
+}
+
+/// Create a goal
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `request` - The goal to create
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful creation, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn create_goal(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    request: CreateGoalRequest,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let goal = api.create_goal(&workspace_id, request).await?;
+
+    println!("{}", "✓ Goal created successfully!".green());
+    println!("ID: {}", goal.id);
+    println!("Name: {}", goal.name);
+    Ok(())
+}
+
+/// Update a goal
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `goal_id` - The ID of the goal to update
+/// * `request` - The fields to update
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful update, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if nothing is updated
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the goal doesn't exist
+async fn update_goal(
+    api: &ClickUpApi,
+    goal_id: &str,
+    request: UpdateGoalRequest,
+) -> Result<(), ClickUpError> {
+    if request.name.is_none()
+        && request.description.is_none()
+        && request.due_date.is_none()
+        && request.color.is_none()
+        && request.add_owners.is_empty()
+        && request.rem_owners.is_empty()
+    {
+        return Err(ClickUpError::ValidationError(
+            "Nothing to update. Use --name, --description, --due-date, --color, --add-owner or --remove-owner"
+                .to_string(),
+        ));
+    }
+
+    let goal = api.update_goal(goal_id, request).await?;
+
+    println!("{}", "✓ Goal updated successfully!".green());
+    println!("ID: {}", goal.id);
+    println!("Name: {}", goal.name);
+    Ok(())
+}
+
+/// Delete a goal
+///
+/// This function removes a goal and its key results after asking for
+/// confirmation, unless `--yes` was given.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `goal_id` - The ID of the goal to delete
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful deletion, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the goal doesn't exist
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn delete_goal(api: &ClickUpApi, goal_id: &str) -> Result<(), ClickUpError> {
+    let description = format!("goal {goal_id} and its key results");
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_goal(goal_id).await?;
+
+    println!("{}", "✓ Goal deleted successfully!".green());
+    println!("Deleted goal ID: {goal_id}");
+    Ok(())
+}
+
+/// Add a key result to a goal
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `goal_id` - The ID of the goal to add the key result to
+/// * `request` - The key result to create
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful creation, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the goal doesn't exist
+async fn add_key_result(
+    api: &ClickUpApi,
+    goal_id: &str,
+    request: CreateKeyResultRequest,
+) -> Result<(), ClickUpError> {
+    let key_result = api.create_key_result(goal_id, request).await?;
+
+    println!("{}", "✓ Key result added successfully!".green());
+    println!("ID: {}", key_result.id);
+    println!("Name: {}", key_result.name);
+    Ok(())
+}
+
+/// Record progress on a key result
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `key_result_id` - The ID of the key result to update
+/// * `request` - The new current value and note
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful update, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the key result doesn't exist
+async fn update_key_result(
+    api: &ClickUpApi,
+    key_result_id: &str,
+    request: UpdateKeyResultRequest,
+) -> Result<(), ClickUpError> {
+    let key_result = api.update_key_result(key_result_id, request).await?;
+
+    println!("{}", "✓ Key result updated successfully!".green());
+    println!("Name: {}", key_result.name);
+    println!(
+        "Current: {} of {}",
+        format_value(&key_result, key_result.steps_current),
+        format_value(&key_result, key_result.steps_end)
+    );
+    if let Some(progress) = key_result.progress() {
+        println!("Progress: {}", progress_bar(progress, PROGRESS_BAR_WIDTH));
+    }
+    Ok(())
+}
+
+/// Delete a key result
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `key_result_id` - The ID of the key result to delete
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful deletion, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the key result doesn't exist
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn delete_key_result(api: &ClickUpApi, key_result_id: &str) -> Result<(), ClickUpError> {
+    let description = format!("key result {key_result_id}");
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_key_result(key_result_id).await?;
+
+    println!("{}", "✓ Key result deleted successfully!".green());
+    println!("Deleted key result ID: {key_result_id}");
+    Ok(())
+}
@@ -0,0 +1,452 @@
+//! # Guest Commands
+//!
+//! This module manages workspace guests for the ClickUp CLI. Guests only see
+//! the tasks, lists and folders shared with them, which suits contractors and
+//! clients.
+//!
+//! ## Commands
+//!
+//! - **Add**: Invite a guest to a workspace by email
+//! - **Show**: Show a guest's details and the items shared with them
+//! - **Remove**: Remove a guest from a workspace
+//! - **Grant**: Give a guest access to a task, list or folder
+//! - **Revoke**: Remove a guest's access to a task, list or folder
+//!
+//! ## Permission Levels
+//!
+//! Access is granted as `read`, `comment`, `edit` or `create`. `create`
+//! lets the guest add tasks, so it only applies to lists and folders.
+
+use crate::api::ClickUpApi;
+use crate::commands::users::{find_member_by_email, print_member_details};
+use crate::commands::utils::{ApiUtils, CommandExecutor, PromptUtils};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::{GuestPermissionRequest, InviteGuestRequest, SharedItemKind};
+use clap::{Subcommand, ValueEnum};
+use colored::*;
+
+/// Guest command variants
+///
+/// This enum defines all available guest subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum GuestCommands {
+    /// Invite a guest to a workspace
+    Add {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Email address to invite
+        #[arg(short, long)]
+        email: String,
+        /// Allow the guest to create and edit tags
+        #[arg(long)]
+        can_edit_tags: bool,
+        /// Allow the guest to see time tracked on tasks
+        #[arg(long)]
+        can_see_time_spent: bool,
+        /// Allow the guest to see time estimates
+        #[arg(long)]
+        can_see_time_estimated: bool,
+        /// Allow the guest to create views
+        #[arg(long)]
+        can_create_views: bool,
+        /// Custom role ID to give the guest
+        #[arg(long)]
+        custom_role_id: Option<i64>,
+    },
+    /// Show a guest and the items shared with them
+    Show {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Guest ID
+        #[arg(short, long)]
+        id: i64,
+    },
+    /// Remove a guest from a workspace
+    Remove {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Guest ID
+        #[arg(short, long)]
+        id: i64,
+    },
+    /// Give a guest access to a task, list or folder
+    Grant {
+        /// Guest ID
+        #[arg(short, long)]
+        id: i64,
+        /// Task to share
+        #[arg(long, conflicts_with_all = ["list_id", "folder_id"])]
+        task_id: Option<String>,
+        /// List to share
+        #[arg(long, conflicts_with = "folder_id")]
+        list_id: Option<String>,
+        /// Folder to share
+        #[arg(long)]
+        folder_id: Option<String>,
+        /// Permission level
+        #[arg(short, long, value_enum, default_value_t = GuestPermission::Read)]
+        permission: GuestPermission,
+    },
+    /// Remove a guest's access to a task, list or folder
+    Revoke {
+        /// Guest ID
+        #[arg(short, long)]
+        id: i64,
+        /// Task to stop sharing
+        #[arg(long, conflicts_with_all = ["list_id", "folder_id"])]
+        task_id: Option<String>,
+        /// List to stop sharing
+        #[arg(long, conflicts_with = "folder_id")]
+        list_id: Option<String>,
+        /// Folder to stop sharing
+        #[arg(long)]
+        folder_id: Option<String>,
+    },
+}
+
+/// Permission levels for `guests grant`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GuestPermission {
+    /// View only
+    Read,
+    /// View and comment
+    Comment,
+    /// View, comment and edit
+    Edit,
+    /// Edit and create tasks (lists and folders only)
+    Create,
+}
+
+impl GuestPermission {
+    /// Returns the permission level as sent to the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GuestPermission::Read => "read",
+            GuestPermission::Comment => "comment",
+            GuestPermission::Edit => "edit",
+            GuestPermission::Create => "create",
+        }
+    }
+}
+
+impl CommandExecutor for GuestCommands {
+    type Commands = GuestCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            GuestCommands::Add {
+                workspace_id,
+                email,
+                can_edit_tags,
+                can_see_time_spent,
+                can_see_time_estimated,
+                can_create_views,
+                custom_role_id,
+            } => {
+                let request = InviteGuestRequest {
+                    email,
+                    can_edit_tags,
+                    can_see_time_spent,
+                    can_see_time_estimated,
+                    can_create_views,
+                    custom_role_id,
+                };
+                add_guest(api, workspace_id, request).await?;
+            }
+            GuestCommands::Show { workspace_id, id } => {
+                show_guest(api, workspace_id, id).await?;
+            }
+            GuestCommands::Remove { workspace_id, id } => {
+                remove_guest(api, workspace_id, id).await?;
+            }
+            GuestCommands::Grant {
+                id,
+                task_id,
+                list_id,
+                folder_id,
+                permission,
+            } => {
+                let (kind, item_id) = shared_item(task_id, list_id, folder_id)?;
+                grant_access(api, id, kind, &item_id, permission).await?;
+            }
+            GuestCommands::Revoke {
+                id,
+                task_id,
+                list_id,
+                folder_id,
+            } => {
+                let (kind, item_id) = shared_item(task_id, list_id, folder_id)?;
+                revoke_access(api, id, kind, &item_id).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute guest commands
+///
+/// This function routes guest commands to their appropriate handlers.
+///
+/// # Arguments
+///
+/// * `command` - The guest command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Permission errors if the workspace plan doesn't allow guests
+/// - Validation errors for a missing item or an invalid permission level
+pub async fn execute(command: GuestCommands, config: &Config) -> Result<(), ClickUpError> {
+    GuestCommands::execute(command, config).await
+}
+
+/// Returns the item given by `--task-id`, `--list-id` or `--folder-id`
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no item is given
+pub fn shared_item(
+    task_id: Option<String>,
+    list_id: Option<String>,
+    folder_id: Option<String>,
+) -> Result<(SharedItemKind, String), ClickUpError> {
+    task_id
+        .map(|id| (SharedItemKind::Task, id))
+        .or_else(|| list_id.map(|id| (SharedItemKind::List, id)))
+        .or_else(|| folder_id.map(|id| (SharedItemKind::Folder, id)))
+        .ok_or_else(|| {
+            ClickUpError::ValidationError(
+                "No item given. Use --task-id, --list-id or --folder-id".to_string(),
+            )
+        })
+}
+
+/// Checks that a permission level can be granted on an item
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if `create` is granted on a task
+pub fn validate_permission(
+    kind: SharedItemKind,
+    permission: GuestPermission,
+) -> Result<(), ClickUpError> {
+    if kind == SharedItemKind::Task && permission == GuestPermission::Create {
+        return Err(ClickUpError::ValidationError(
+            "The create permission only applies to lists and folders".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Invite a guest to a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `request` - The email address and permissions of the guest
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::PermissionError` if the workspace plan doesn't allow guests
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn add_guest(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    request: InviteGuestRequest,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let email = request.email.clone();
+    let response = api.invite_guest(&workspace_id, request).await?;
+
+    println!("{}", format!("✓ Invited guest {email}!").green());
+    if let Some(id) = find_member_by_email(&response.team, &email).and_then(|user| user.id) {
+        println!("Guest ID: {id}");
+        println!("Share items with: clickup-cli guests grant --id {id} --list-id <list-id>");
+    }
+    Ok(())
+}
+
+/// Show a guest and the items shared with them
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `guest_id` - The ID of the guest
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NotFoundError` if the guest doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn show_guest(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    guest_id: i64,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let response = api.get_guest(&workspace_id, guest_id).await?;
+    print_member_details("Guest", &response.guest);
+    Ok(())
+}
+
+/// Remove a guest from a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `guest_id` - The ID of the guest to remove
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful removal, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NotFoundError` if the guest doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn remove_guest(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    guest_id: i64,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let description = format!("guest {guest_id} from workspace {workspace_id}");
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.remove_guest(&workspace_id, guest_id).await?;
+
+    println!("{}", "✓ Guest removed from workspace!".green());
+    println!("Removed guest ID: {guest_id}");
+    Ok(())
+}
+
+/// Give a guest access to a task, list or folder
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `guest_id` - The ID of the guest
+/// * `kind` - Whether the item is a task, list or folder
+/// * `item_id` - The ID of the item
+/// * `permission` - The permission level to grant
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the permission doesn't apply to the item
+/// - `ClickUpError::NotFoundError` if the guest or item doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn grant_access(
+    api: &ClickUpApi,
+    guest_id: i64,
+    kind: SharedItemKind,
+    item_id: &str,
+    permission: GuestPermission,
+) -> Result<(), ClickUpError> {
+    validate_permission(kind, permission)?;
+    let request = GuestPermissionRequest {
+        permission_level: permission.as_str().to_string(),
+    };
+    api.add_guest_to_item(kind, item_id, guest_id, request)
+        .await?;
+
+    println!(
+        "{}",
+        format!(
+            "✓ Gave guest {guest_id} {} access to {} {item_id}",
+            permission.as_str(),
+            kind.endpoint_segment()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Remove a guest's access to a task, list or folder
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `guest_id` - The ID of the guest
+/// * `kind` - Whether the item is a task, list or folder
+/// * `item_id` - The ID of the item
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn revoke_access(
+    api: &ClickUpApi,
+    guest_id: i64,
+    kind: SharedItemKind,
+    item_id: &str,
+) -> Result<(), ClickUpError> {
+    let description = format!(
+        "access of guest {guest_id} to {} {item_id}",
+        kind.endpoint_segment()
+    );
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.remove_guest_from_item(kind, item_id, guest_id).await?;
+
+    println!(
+        "{}",
+        format!(
+            "✓ Revoked guest {guest_id}'s access to {} {item_id}",
+            kind.endpoint_segment()
+        )
+        .green()
+    );
+    Ok(())
+}
@@ -13,9 +13,13 @@
 //!
 //! Lists are displayed in a formatted table showing key information
 //! including task counts, folder organization, and content descriptions.
+//!
+//! With `--offline`, `list` reads a space backup instead of ClickUp.
 
 use crate::api::ClickUpApi;
+use crate::backup::SpaceBackup;
 use crate::config::Config;
+use crate::data_source::DataSource;
 use crate::error::ClickUpError;
 use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, ErrorUtils, TableBuilder, TableHeaders};
 use clap::Subcommand;
@@ -44,6 +48,10 @@ impl CommandExecutor for ListCommands {
     type Commands = ListCommands;
     
     async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        if let Some(path) = &config.offline {
+            let backup = SpaceBackup::load(path)?;
+            return handle_offline_command(command, &backup).await;
+        }
         let api = ApiUtils::create_client(config)?;
         Self::handle_command(command, &api).await
     }
@@ -86,6 +94,23 @@ pub async fn execute(command: ListCommands, config: &Config) -> Result<(), Click
     ListCommands::execute(command, config).await
 }
 
+/// Handle a list command from a backup given with `--offline`
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` for commands that need ClickUp
+/// - `ClickUpError::NotFoundError` if the space isn't the one in the backup
+async fn handle_offline_command(
+    command: ListCommands,
+    backup: &SpaceBackup,
+) -> Result<(), ClickUpError> {
+    match command {
+        ListCommands::List { space_id } => list_lists(backup, &space_id).await,
+        ListCommands::Show { .. } => Err(ErrorUtils::offline_error()),
+    }
+}
+
 /// List all lists in a space
 ///
 /// This function retrieves and displays all lists for a specific space
@@ -94,7 +119,7 @@ pub async fn execute(command: ListCommands, config: &Config) -> Result<(), Click
 ///
 /// # Arguments
 ///
-/// * `api` - Reference to the ClickUp API client
+/// * `source` - Where to read from: the ClickUp API or a backup
 /// * `space_id` - The ID of the space to list lists for
 ///
 /// # Returns
@@ -106,8 +131,8 @@ pub async fn execute(command: ListCommands, config: &Config) -> Result<(), Click
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the space doesn't exist
-async fn list_lists(api: &ClickUpApi, space_id: &str) -> Result<(), ClickUpError> {
-    let lists = api.get_lists(space_id).await?;
+async fn list_lists(source: &impl DataSource, space_id: &str) -> Result<(), ClickUpError> {
+    let lists = source.get_lists(space_id).await?;
 
     if lists.lists.is_empty() {
         DisplayUtils::display_empty_message("lists");
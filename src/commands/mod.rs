@@ -13,7 +13,23 @@
 //! - **Spaces**: Space management and navigation
 //! - **Lists**: List operations and organization
 //! - **Tasks**: Task creation, updates, and management
+//! - **Task Deps**: Task dependencies, links and the dependency graph (used by `tasks`)
+//! - **Task Export**: Writing the tasks of a list or space to CSV, JSON or Markdown (used by `tasks`)
+//! - **Task Import**: Creating tasks from CSV, JSON or Markdown files (used by `tasks`)
+//! - **Task Move**: Moving, duplicating and multi-list membership of tasks (used by `tasks`)
+//! - **Task Watch**: Polling a list and printing task changes (used by `tasks`)
+//! - **Checklists**: Checklists and checklist items on tasks
+//! - **Attachments**: Uploading and downloading task attachments (used by `tasks`)
 //! - **Comments**: Comment system operations
+//! - **Undo**: Reverting changes recorded in the undo journal
+//! - **Webhooks**: Webhook management and a local webhook receiver
+//! - **Goals**: Goals and their key results
+//! - **Views**: Saved views and the tasks they resolve to
+//! - **Templates**: ClickUp task templates and local YAML templates
+//! - **Users**: Inviting, showing and removing workspace members
+//! - **Guests**: Workspace guests and their access to tasks, lists and folders
+//! - **Search**: Full-text search across tasks and comments
+//! - **Reports**: Workload and burndown reports
 //!
 //! ## Standardized Command Architecture
 //!
@@ -225,11 +241,27 @@
 //! user experience with formatted tables, proper error handling, and clear
 //! output formatting.
 
+pub mod attachments;
 pub mod auth;
+pub mod checklists;
 pub mod comments;
+pub mod goals;
+pub mod guests;
 pub mod lists;
+pub mod reports;
+pub mod search;
 pub mod spaces;
+pub mod task_deps;
+pub mod task_export;
+pub mod task_import;
+pub mod task_move;
+pub mod task_watch;
 pub mod tasks;
 pub mod teams;
+pub mod templates;
+pub mod undo;
+pub mod users;
+pub mod views;
+pub mod webhooks;
 pub mod workspaces;
 pub mod utils;
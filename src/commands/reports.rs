@@ -0,0 +1,286 @@
+//! # Report Commands
+//!
+//! This module implements sprint and workload reports over the tasks of a
+//! space or list.
+//!
+//! ## Commands
+//!
+//! - **Workload**: Open tasks, time estimates, points and overdue tasks of a
+//!   space per assignee, status or list
+//! - **Burndown**: Tasks remaining in a list at the end of each day
+//!
+//! ## Features
+//!
+//! Reports are printed as a table, as JSON or as an ASCII bar chart. Tasks
+//! are fetched page by page from every list in the space, or from the list,
+//! as described in the `reports` module.
+
+use crate::api::ClickUpApi;
+use crate::commands::spaces::fetch_folders_and_lists;
+use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, TableBuilder};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::Task;
+use crate::reports::{self, BurndownDay, GroupBy, ReportFormat, WorkloadRow};
+use crate::task_tree;
+use chrono::{NaiveDate, Utc};
+use clap::Subcommand;
+use colored::*;
+use serde_json::json;
+use std::collections::HashSet;
+
+/// Most days a burndown report covers
+const MAX_BURNDOWN_DAYS: i64 = 366;
+
+/// Report command variants
+///
+/// This enum defines all available report subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum ReportCommands {
+    /// Open work in a space per assignee, status or list
+    Workload {
+        /// Space ID
+        #[arg(short, long)]
+        space_id: String,
+        /// How to group open tasks
+        #[arg(long, value_enum, default_value_t = GroupBy::Assignee)]
+        by: GroupBy,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = ReportFormat::Table)]
+        format: ReportFormat,
+    },
+    /// Tasks remaining in a list at the end of each day
+    Burndown {
+        /// List ID
+        #[arg(short, long)]
+        list_id: String,
+        /// First day (YYYY-MM-DD)
+        #[arg(long)]
+        from: NaiveDate,
+        /// Last day (YYYY-MM-DD), at most 366 days after the first
+        #[arg(long)]
+        to: NaiveDate,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = ReportFormat::Table)]
+        format: ReportFormat,
+    },
+}
+
+impl CommandExecutor for ReportCommands {
+    type Commands = ReportCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            ReportCommands::Workload {
+                space_id,
+                by,
+                format,
+            } => {
+                workload_report(api, &space_id, by, format).await?;
+            }
+            ReportCommands::Burndown {
+                list_id,
+                from,
+                to,
+                format,
+            } => {
+                burndown_report(api, &list_id, from, to, format).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute report commands
+///
+/// # Arguments
+///
+/// * `command` - The report command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Validation errors for invalid date ranges
+/// - Not found errors for missing spaces or lists
+pub async fn execute(command: ReportCommands, config: &Config) -> Result<(), ClickUpError> {
+    ReportCommands::execute(command, config).await
+}
+
+/// Report the open work in a space
+///
+/// Open tasks are fetched from every list in the space, including lists in
+/// folders. Tasks in several lists are counted once.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `space_id` - The ID of the space to report on
+/// * `by` - How to group open tasks
+/// * `format` - How to print the report
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if an API request fails
+/// - `ClickUpError::NotFoundError` if the space doesn't exist
+async fn workload_report(
+    api: &ClickUpApi,
+    space_id: &str,
+    by: GroupBy,
+    format: ReportFormat,
+) -> Result<(), ClickUpError> {
+    let (_, lists) = fetch_folders_and_lists(api, space_id).await?;
+    let mut seen = HashSet::new();
+    let mut tasks: Vec<Task> = Vec::new();
+    for list in &lists {
+        for task in api.get_tasks(&list.id).await?.tasks {
+            if seen.insert(task.id.clone()) {
+                tasks.push(task);
+            }
+        }
+    }
+
+    let now = Utc::now().timestamp_millis();
+    let rows = reports::workload(&tasks, by, now);
+    let total = reports::workload_total(&tasks, now);
+
+    match format {
+        ReportFormat::Json => {
+            let report = json!({
+                "space_id": space_id,
+                "by": by,
+                "groups": rows,
+                "total": total,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        _ if rows.is_empty() => DisplayUtils::display_empty_message("open tasks"),
+        ReportFormat::Chart => print!("{}", reports::workload_chart(&rows)),
+        ReportFormat::Table => print_workload_table(by, &rows, &total),
+    }
+    Ok(())
+}
+
+/// Prints workload rows as a table followed by a total row
+fn print_workload_table(by: GroupBy, rows: &[WorkloadRow], total: &WorkloadRow) {
+    let group = match by {
+        GroupBy::Assignee => "Assignee",
+        GroupBy::Status => "Status",
+        GroupBy::List => "List",
+    };
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![group, "Open Tasks", "Estimate", "Points", "Overdue"]);
+    for row in rows.iter().chain(std::iter::once(total)) {
+        table_builder.add_row(vec![
+            row.group.clone(),
+            row.tasks.to_string(),
+            task_tree::format_estimate(row.time_estimate),
+            reports::format_points(row.points),
+            row.overdue.to_string(),
+        ]);
+    }
+    table_builder.print();
+}
+
+/// Report the tasks remaining in a list at the end of each day
+///
+/// Closed tasks are fetched too, so that the days before they were closed
+/// count them as remaining.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `list_id` - The ID of the list to report on
+/// * `from` - The first day
+/// * `to` - The last day
+/// * `format` - How to print the report
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if `to` is before `from`, or the range
+///   is longer than a year
+/// - `ClickUpError::NetworkError` if an API request fails
+/// - `ClickUpError::NotFoundError` if the list doesn't exist
+async fn burndown_report(
+    api: &ClickUpApi,
+    list_id: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+    format: ReportFormat,
+) -> Result<(), ClickUpError> {
+    if to < from {
+        return Err(ClickUpError::ValidationError(format!(
+            "--to ({to}) is before --from ({from})"
+        )));
+    }
+    let day_count = (to - from).num_days() + 1;
+    if day_count > MAX_BURNDOWN_DAYS {
+        return Err(ClickUpError::ValidationError(format!(
+            "The report covers {day_count} days; use a range of at most {MAX_BURNDOWN_DAYS} days"
+        )));
+    }
+
+    let tasks = api.get_tasks_including_closed(list_id).await?.tasks;
+    let days = reports::burndown(&tasks, from, to);
+
+    match format {
+        ReportFormat::Json => {
+            let report = json!({
+                "list_id": list_id,
+                "from": from,
+                "to": to,
+                "days": days,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        ReportFormat::Chart => print!("{}", reports::burndown_chart(&days)),
+        ReportFormat::Table => print_burndown_table(&days),
+    }
+
+    if format != ReportFormat::Json {
+        if let (Some(first), Some(last)) = (days.first(), days.last()) {
+            println!("\n{}", "Summary:".bold());
+            println!("Remaining on {}: {}", first.date, first.remaining);
+            println!("Remaining on {}: {}", last.date, last.remaining);
+        }
+    }
+    Ok(())
+}
+
+/// Prints burndown days as a table of remaining tasks, points, estimates and the ideal burn
+fn print_burndown_table(days: &[BurndownDay]) {
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec!["Date", "Remaining", "Points", "Estimate", "Ideal"]);
+    for day in days {
+        table_builder.add_row(vec![
+            day.date.to_string(),
+            day.remaining.to_string(),
+            reports::format_points(day.remaining_points),
+            task_tree::format_estimate(day.remaining_estimate),
+            format!("{:.1}", day.ideal),
+        ]);
+    }
+    table_builder.print();
+}
@@ -0,0 +1,247 @@
+//! # Search Command
+//!
+//! This module implements `search`, which finds text in task names, task
+//! descriptions and, optionally, comments.
+//!
+//! ## Usage
+//!
+//! - `search "<text>"` searches every task in the configured workspace
+//! - `--list-id` or `--space-id` narrows the search to a list or space
+//! - `--regex` treats the text as a regular expression, `--ignore-case`
+//!   ignores case
+//! - `--comments` also searches comments, which takes one request per task
+//! - `--from-backup <file>` searches a `spaces backup` file instead of ClickUp,
+//!   as does the global `--offline <file>`
+//!
+//! Tasks are fetched with the workspace's filtered task endpoint. A list is
+//! fetched directly when no workspace is given or configured.
+//!
+//! Results are ranked as described in the `search` module and printed with
+//! the matching text highlighted.
+
+use crate::api::ClickUpApi;
+use crate::backup::SpaceBackup;
+use crate::commands::utils::{ApiUtils, DisplayUtils};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::{Comment, Task, TeamTaskFilter};
+use crate::search::{self, Matcher, SearchOptions, SearchResult};
+use clap::Args;
+use colored::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the search command
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Text to search for
+    pub text: String,
+    /// Workspace to search (defaults to the configured workspace)
+    #[arg(short, long, conflicts_with = "from_backup")]
+    pub workspace_id: Option<String>,
+    /// Only search tasks in this list
+    #[arg(long, conflicts_with = "space_id")]
+    pub list_id: Option<String>,
+    /// Only search tasks in this space
+    #[arg(long)]
+    pub space_id: Option<String>,
+    /// Also search comments (one extra request per task)
+    #[arg(long)]
+    pub comments: bool,
+    /// Treat the text as a regular expression
+    #[arg(short = 'E', long)]
+    pub regex: bool,
+    /// Ignore case when matching
+    #[arg(short, long)]
+    pub ignore_case: bool,
+    /// Also search closed tasks
+    #[arg(long)]
+    pub include_closed: bool,
+    /// Maximum number of results to show
+    #[arg(short = 'n', long, default_value_t = 20)]
+    pub limit: usize,
+    /// Search a file written by `spaces backup` instead of ClickUp
+    #[arg(long, value_name = "FILE")]
+    pub from_backup: Option<PathBuf>,
+}
+
+/// Execute the search command
+///
+/// # Arguments
+///
+/// * `args` - The search command arguments
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the text is empty or an invalid regex,
+///   or no workspace is available for a space search
+/// - `ClickUpError::IoError` or `ClickUpError::DeserializationError` if the
+///   backup file cannot be read
+/// - `ClickUpError::NetworkError` if an API request fails
+pub async fn execute(args: SearchArgs, config: &Config) -> Result<(), ClickUpError> {
+    let matcher = Matcher::new(
+        &args.text,
+        &SearchOptions {
+            regex: args.regex,
+            ignore_case: args.ignore_case,
+        },
+    )?;
+
+    let backup = args.from_backup.as_deref().or(config.offline.as_deref());
+    let documents = match backup {
+        Some(path) => load_backup_documents(path, &args)?,
+        None => {
+            let api = ApiUtils::create_client(config)?;
+            fetch_documents(&api, &args).await?
+        }
+    };
+
+    let mut results: Vec<SearchResult> = documents
+        .iter()
+        .filter_map(|(task, comments)| matcher.search_task(task, comments))
+        .collect();
+    search::rank(&mut results);
+
+    if results.is_empty() {
+        DisplayUtils::display_empty_message("matching tasks");
+    } else {
+        print_results(&results[..results.len().min(args.limit)]);
+    }
+
+    println!("\n{}", "Summary:".bold());
+    println!("Tasks searched: {}", documents.len());
+    println!("Matching tasks: {}", results.len());
+    if results.len() > args.limit {
+        println!("Showing the top {}; use --limit to see more", args.limit);
+    }
+    Ok(())
+}
+
+/// Returns whether a task is closed
+fn is_closed(task: &Task) -> bool {
+    task.status.type_ == "closed" || task.date_closed.is_some()
+}
+
+/// Reads the tasks and comments to search from a backup file
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::IoError` if the file cannot be read
+/// - `ClickUpError::DeserializationError` if the file is not a space backup
+fn load_backup_documents(
+    path: &Path,
+    args: &SearchArgs,
+) -> Result<Vec<(Task, Vec<Comment>)>, ClickUpError> {
+    let backup = SpaceBackup::load(path)?;
+    if args.comments && !backup.metadata.include_comments {
+        println!(
+            "{}",
+            "Warning: the backup was made without comments; only tasks are searched".yellow()
+        );
+    }
+
+    let mut comments_by_task: HashMap<String, Vec<Comment>> = backup
+        .comments
+        .into_iter()
+        .map(|entry| (entry.task_id, entry.comments))
+        .collect();
+    Ok(backup
+        .tasks
+        .into_iter()
+        .filter(|task| args.list_id.as_ref().is_none_or(|id| &task.list.id == id))
+        .filter(|task| args.space_id.as_ref().is_none_or(|id| &task.space.id == id))
+        .filter(|task| args.include_closed || !is_closed(task))
+        .map(|task| {
+            let comments = if args.comments {
+                comments_by_task.remove(&task.id).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            (task, comments)
+        })
+        .collect())
+}
+
+/// Fetches the tasks to search and, if requested, their comments
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace is available for a
+///   workspace or space search
+/// - `ClickUpError::NetworkError` if an API request fails
+async fn fetch_documents(
+    api: &ClickUpApi,
+    args: &SearchArgs,
+) -> Result<Vec<(Task, Vec<Comment>)>, ClickUpError> {
+    let workspace_id = args
+        .workspace_id
+        .clone()
+        .or_else(|| api.config().workspace_id.clone());
+
+    let tasks = match (&workspace_id, &args.list_id) {
+        // Without a workspace a list can still be fetched directly
+        (None, Some(list_id)) => {
+            let tasks = if args.include_closed {
+                api.get_tasks_including_closed(list_id).await?
+            } else {
+                api.get_tasks(list_id).await?
+            };
+            tasks.tasks
+        }
+        _ => {
+            let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+            let filter = TeamTaskFilter {
+                list_ids: args.list_id.iter().cloned().collect(),
+                space_ids: args.space_id.iter().cloned().collect(),
+                include_closed: args.include_closed,
+            };
+            api.get_all_filtered_team_tasks(&workspace_id, &filter)
+                .await?
+                .tasks
+        }
+    };
+
+    let mut documents = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let comments = if args.comments {
+            api.get_comments(&task.id).await?.comments
+        } else {
+            Vec::new()
+        };
+        documents.push((task, comments));
+    }
+    Ok(documents)
+}
+
+/// Prints ranked results with their highlighted snippets and URLs
+fn print_results(results: &[SearchResult]) {
+    for (rank, result) in results.iter().enumerate() {
+        let matches = if result.matches == 1 { "match" } else { "matches" };
+        println!(
+            "{}. {} {}",
+            rank + 1,
+            result.task_name.bold(),
+            format!(
+                "({}, score {}, {} {matches})",
+                result.task_id, result.score, result.matches
+            )
+            .dimmed()
+        );
+        println!(
+            "   {}: {}{}{}",
+            result.field.as_str(),
+            result.snippet.before,
+            result.snippet.matched.yellow().bold(),
+            result.snippet.after
+        );
+        println!("   {}", result.url.blue());
+    }
+}
@@ -10,17 +10,23 @@
 //! - **Show**: Show detailed information about a specific space
 //! - **ListFolders**: Display all folders in a space
 //! - **Backup**: Create a comprehensive backup of a space with all content
+//! - **Diff**: Compare two backups, or a backup with the space as it is now
 //!
 //! ## Features
 //!
 //! Spaces are displayed in formatted tables showing key information.
 //! The backup feature creates complete JSON backups including all nested
-//! content with optional comment inclusion.
+//! content with optional comment and attachment inclusion. Backups can be
+//! compared with `diff`, which lists added, removed and changed folders,
+//! lists and tasks as text or JSON.
 
 use crate::api::ClickUpApi;
 use crate::config::Config;
 use crate::error::ClickUpError;
-use crate::models::{Comment, Folder, List, Space, Task};
+use crate::backup::{BackupMetadata, SpaceBackup, TaskComments};
+use crate::models::{Attachment, Folder, List, Space};
+use crate::space_diff::{self, ChangeKind, ItemDiff, SpaceDiff};
+use crate::watch;
 use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, ErrorUtils, TableBuilder, TableHeaders};
 use chrono::Utc;
 use clap::Subcommand;
@@ -28,7 +34,10 @@ use colored::*;
 use serde_json;
 use std::fs;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// `--to` value that compares a backup with the space as it is now
+const LIVE: &str = "live";
 
 /// Space command variants
 ///
@@ -66,6 +75,24 @@ pub enum SpaceCommands {
         /// Include task comments in backup
         #[arg(long, default_value = "false")]
         include_comments: bool,
+        /// Download task attachments next to the backup file
+        #[arg(long, default_value = "false")]
+        include_attachments: bool,
+    },
+    /// Compare two backups, or a backup with the space as it is now
+    ///
+    /// Reports added, removed and changed folders, lists and tasks. A task
+    /// closed in between shows up as a status change.
+    Diff {
+        /// Earlier backup file written by `spaces backup`
+        #[arg(long, value_name = "FILE")]
+        from: PathBuf,
+        /// Later backup file, or `live` to fetch the space from ClickUp
+        #[arg(long, value_name = "FILE|live")]
+        to: String,
+        /// Print the differences as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -73,6 +100,14 @@ impl CommandExecutor for SpaceCommands {
     type Commands = SpaceCommands;
     
     async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        // Comparing two backup files needs no API client
+        if let SpaceCommands::Diff { from, to, json } = &command {
+            if to != LIVE {
+                let before = SpaceBackup::load(from)?;
+                let after = SpaceBackup::load(Path::new(to))?;
+                return print_space_diff(&space_diff::diff(&before, &after), *json);
+            }
+        }
         let api = ApiUtils::create_client(config)?;
         Self::handle_command(command, &api).await
     }
@@ -92,12 +127,20 @@ impl CommandExecutor for SpaceCommands {
                 space_id,
                 output_dir,
                 include_comments,
+                include_attachments,
             } => {
                 let space_id = match space_id {
                     Some(id) => id,
                     None => select_space_interactive(api).await?,
                 };
-                backup_space(api, &space_id, &output_dir, include_comments).await?;
+                let options = BackupOptions {
+                    include_comments,
+                    include_attachments,
+                };
+                backup_space(api, &space_id, &output_dir, options).await?;
+            }
+            SpaceCommands::Diff { from, to, json } => {
+                diff_space(api, &from, &to, json).await?;
             }
         }
         Ok(())
@@ -338,51 +381,12 @@ async fn list_folders(api: &ClickUpApi, space_id: &str) -> Result<(), ClickUpErr
     Ok(())
 }
 
-/// Backup data structure for a complete space backup
-#[derive(serde::Serialize)]
-struct SpaceBackup {
-    /// Backup metadata
-    metadata: BackupMetadata,
-    /// Space information
-    space: Option<Space>,
-    /// Folders in the space
-    folders: Vec<Folder>,
-    /// Lists in the space (including those in folders)
-    lists: Vec<List>,
-    /// Tasks in all lists
-    tasks: Vec<Task>,
-    /// Comments for tasks (if included)
-    comments: Vec<TaskComments>,
-}
-
-/// Backup metadata
-#[derive(serde::Serialize)]
-struct BackupMetadata {
-    /// Backup creation timestamp
-    created_at: String,
-    /// Space ID that was backed up
-    space_id: String,
-    /// Whether comments were included
+/// Optional content to include in a space backup
+struct BackupOptions {
+    /// Include task comments
     include_comments: bool,
-    /// Total number of folders
-    folder_count: usize,
-    /// Total number of lists
-    list_count: usize,
-    /// Total number of tasks
-    task_count: usize,
-    /// Total number of comments
-    comment_count: usize,
-}
-
-/// Task comments structure
-#[derive(serde::Serialize)]
-struct TaskComments {
-    /// Task ID
-    task_id: String,
-    /// Task name
-    task_name: String,
-    /// Comments for this task
-    comments: Vec<Comment>,
+    /// Download task attachments
+    include_attachments: bool,
 }
 
 /// Create a comprehensive backup of a space
@@ -391,12 +395,16 @@ struct TaskComments {
 /// lists, tasks, and optionally comments. The backup is saved as a JSON file
 /// with timestamped filename in the specified output directory.
 ///
+/// With attachments included, each task is fetched individually (list
+/// responses do not contain attachments) and its files are saved in a
+/// `<backup name>_attachments/<task id>/` directory next to the JSON file.
+///
 /// # Arguments
 ///
 /// * `api` - Reference to the ClickUp API client
 /// * `space_id` - The ID of the space to backup
 /// * `output_dir` - Directory to save the backup file
-/// * `include_comments` - Whether to include task comments in the backup
+/// * `options` - Whether to include comments and attachments
 ///
 /// # Returns
 ///
@@ -412,8 +420,12 @@ async fn backup_space(
     api: &ClickUpApi,
     space_id: &str,
     output_dir: &str,
-    include_comments: bool,
+    options: BackupOptions,
 ) -> Result<(), ClickUpError> {
+    let BackupOptions {
+        include_comments,
+        include_attachments,
+    } = options;
     println!("{}", "Starting space backup...".bold().green());
 
     // Create output directory if it doesn't exist
@@ -424,27 +436,19 @@ async fn backup_space(
         })?;
     }
 
+    // Generate file names with timestamp
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+    let backup_name = format!("clickup_space_backup_{space_id}_{timestamp}");
+    let attachments_path = output_path.join(format!("{backup_name}_attachments"));
+    let mut attachment_count = 0;
+
     // Get space information
     println!("Fetching space information...");
     let space = get_space_info(api, space_id).await?;
 
-    // Get folders
-    println!("Fetching folders...");
-    let folders = api.get_folders(space_id).await?;
-
-    // Get lists (both in space and in folders)
-    println!("Fetching lists...");
-    let mut all_lists = Vec::new();
-
-    // Get lists directly in the space
-    let space_lists = api.get_lists(space_id).await?;
-    all_lists.extend(space_lists.lists);
-
-    // Get lists in folders
-    for folder in &folders.folders {
-        let folder_lists = api.get_folder_lists(&folder.id).await?;
-        all_lists.extend(folder_lists.lists);
-    }
+    // Get folders and lists (both in space and in folders)
+    println!("Fetching folders and lists...");
+    let (folders, all_lists) = fetch_folders_and_lists(api, space_id).await?;
 
     // Get tasks from all lists
     println!("Fetching tasks...");
@@ -456,7 +460,26 @@ async fn backup_space(
             "  Fetching tasks from list: {}",
             list.name.as_deref().unwrap_or("Unknown")
         );
-        let tasks = api.get_tasks(&list.id).await?;
+        let mut tasks = api.get_tasks_including_closed(&list.id).await?;
+
+        // Fetch each task in full for its attachments and save the files
+        if include_attachments {
+            for task in &mut tasks.tasks {
+                match api.get_task(&task.id).await {
+                    Ok(full_task) => *task = full_task,
+                    Err(e) => {
+                        println!(
+                            "  Warning: Failed to fetch attachments for task {}: {}",
+                            task.id, e
+                        );
+                        continue;
+                    }
+                }
+                attachment_count +=
+                    save_task_attachments(api, &task.id, &task.attachments, &attachments_path)
+                        .await;
+            }
+        }
         all_tasks.extend(tasks.tasks.clone());
 
         // Get comments for each task if requested
@@ -492,22 +515,22 @@ async fn backup_space(
             created_at: Utc::now().to_rfc3339(),
             space_id: space_id.to_string(),
             include_comments,
-            folder_count: folders.folders.len(),
+            include_attachments,
+            include_closed: true,
+            folder_count: folders.len(),
             list_count: all_lists.len(),
             task_count: all_tasks.len(),
             comment_count: all_comments.len(),
+            attachment_count,
         },
         space: Some(space),
-        folders: folders.folders,
+        folders,
         lists: all_lists,
         tasks: all_tasks,
         comments: all_comments,
     };
 
-    // Generate filename with timestamp
-    let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-    let filename = format!("clickup_space_backup_{space_id}_{timestamp}.json");
-    let filepath = output_path.join(&filename);
+    let filepath = output_path.join(format!("{backup_name}.json"));
 
     // Write backup to file
     println!("Writing backup to file: {}", filepath.display());
@@ -527,14 +550,206 @@ async fn backup_space(
     if include_comments {
         println!("Comments: {}", backup.metadata.comment_count);
     }
+    if include_attachments {
+        println!("Attachments: {}", backup.metadata.attachment_count);
+        if attachment_count > 0 {
+            println!("Attachment files: {}", attachments_path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetches a space's folders and every list in it, including lists in folders
+///
+/// Used by backups, diffs and reports.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if an API request fails
+pub async fn fetch_folders_and_lists(
+    api: &ClickUpApi,
+    space_id: &str,
+) -> Result<(Vec<Folder>, Vec<List>), ClickUpError> {
+    let folders = api.get_folders(space_id).await?.folders;
+    let mut lists = api.get_lists(space_id).await?.lists;
+    for folder in &folders {
+        lists.extend(api.get_folder_lists(&folder.id).await?.lists);
+    }
+    Ok((folders, lists))
+}
+
+/// Compare a backup with another backup or with the space as it is now
+///
+/// With `--to live` the space of the earlier backup is fetched from ClickUp
+/// the same way `spaces backup` fetches it, without comments or attachments.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `from` - The earlier backup file
+/// * `to` - The later backup file, or `live`
+/// * `json` - Whether to print the differences as JSON
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful comparison, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::IoError` or `ClickUpError::DeserializationError` if a
+///   backup file cannot be read
+/// - `ClickUpError::NetworkError` if an API request fails
+/// - `ClickUpError::NotFoundError` if the space no longer exists
+async fn diff_space(
+    api: &ClickUpApi,
+    from: &Path,
+    to: &str,
+    json: bool,
+) -> Result<(), ClickUpError> {
+    let before = SpaceBackup::load(from)?;
+    let after = if to == LIVE {
+        let space_id = &before.metadata.space_id;
+        let space = get_space_info(api, space_id).await?;
+        let (folders, lists) = fetch_folders_and_lists(api, space_id).await?;
+        let mut tasks = Vec::new();
+        for list in &lists {
+            tasks.extend(api.get_tasks_including_closed(&list.id).await?.tasks);
+        }
+        SpaceBackup {
+            metadata: BackupMetadata {
+                created_at: Utc::now().to_rfc3339(),
+                space_id: space_id.clone(),
+                include_closed: true,
+                folder_count: folders.len(),
+                list_count: lists.len(),
+                task_count: tasks.len(),
+                ..Default::default()
+            },
+            space: Some(space),
+            folders,
+            lists,
+            tasks,
+            comments: Vec::new(),
+        }
+    } else {
+        SpaceBackup::load(Path::new(to))?
+    };
+
+    print_space_diff(&space_diff::diff(&before, &after), json)
+}
+
+/// Prints a space diff as JSON or as text grouped by folders, lists and tasks
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::JsonError` if the diff cannot be serialized
+fn print_space_diff(diff: &SpaceDiff, json: bool) -> Result<(), ClickUpError> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(diff)?);
+        return Ok(());
+    }
 
+    println!(
+        "{}",
+        format!("Space {}: {} → {}", diff.space_id, diff.from, diff.to).bold()
+    );
+    if diff.is_empty() {
+        DisplayUtils::display_empty_message("differences");
+        return Ok(());
+    }
+
+    for (title, items) in [
+        ("Folders:", &diff.folders),
+        ("Lists:", &diff.lists),
+        ("Tasks:", &diff.tasks),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        println!("\n{}", title.bold());
+        for item in items {
+            print_item_diff(item);
+        }
+    }
+
+    println!("\n{}", "Summary:".bold());
+    println!("Added: {}", diff.count(ChangeKind::Added));
+    println!("Removed: {}", diff.count(ChangeKind::Removed));
+    println!("Changed: {}", diff.count(ChangeKind::Changed));
     Ok(())
 }
 
+/// Prints one added, removed or changed item with its changed fields
+fn print_item_diff(item: &ItemDiff) {
+    let marker = match item.change {
+        ChangeKind::Added => "+".green(),
+        ChangeKind::Removed => "-".red(),
+        ChangeKind::Changed => "~".cyan(),
+    };
+    println!("  {marker} {} {}", item.id.dimmed(), item.name);
+    for change in &item.changes {
+        // Descriptions are too long to show side by side
+        if change.field == "description" {
+            println!("      description changed");
+            continue;
+        }
+        println!(
+            "      {}: {} → {}",
+            change.field,
+            watch::format_change_value(&change.field, &change.before),
+            watch::format_change_value(&change.field, &change.after)
+        );
+    }
+}
+
+/// Downloads a task's attachments into `<attachments_path>/<task id>/`
+///
+/// Failed downloads are reported as warnings so that one missing file does
+/// not abort the whole backup.
+///
+/// # Returns
+///
+/// Returns the number of files saved.
+async fn save_task_attachments(
+    api: &ClickUpApi,
+    task_id: &str,
+    attachments: &[Attachment],
+    attachments_path: &Path,
+) -> usize {
+    if attachments.is_empty() {
+        return 0;
+    }
+    let task_path = attachments_path.join(task_id);
+    if let Err(e) = fs::create_dir_all(&task_path) {
+        println!("  Warning: Failed to create {}: {}", task_path.display(), e);
+        return 0;
+    }
+
+    let mut saved = 0;
+    let names = Attachment::unique_file_names(attachments);
+    for (attachment, name) in attachments.iter().zip(names) {
+        let result = match api.download_attachment(attachment).await {
+            Ok(data) => fs::write(task_path.join(&name), data).map_err(ClickUpError::from),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => saved += 1,
+            Err(e) => println!(
+                "  Warning: Failed to download attachment {name} of task {task_id}: {e}"
+            ),
+        }
+    }
+    saved
+}
+
 /// Helper function to get space information
 ///
 /// This function searches for a space across all accessible workspaces
-/// and returns its complete information. Used internally by backup operations.
+/// and returns its complete information. Used by backups and exports.
 ///
 /// # Arguments
 ///
@@ -550,7 +765,7 @@ async fn backup_space(
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the space doesn't exist
-async fn get_space_info(api: &ClickUpApi, space_id: &str) -> Result<Space, ClickUpError> {
+pub async fn get_space_info(api: &ClickUpApi, space_id: &str) -> Result<Space, ClickUpError> {
     // Search through workspaces to find the space
     let workspaces = api.get_workspaces().await?;
 
@@ -0,0 +1,247 @@
+//! # Task Dependency and Link Commands
+//!
+//! This module implements `tasks deps` and `tasks link`. Dependencies mark a
+//! task as waiting on or blocking another; links relate two tasks without an
+//! order. `deps graph` renders a list's dependencies with the
+//! `dependency_graph` module and reports cycles and due date conflicts.
+
+use crate::api::ClickUpApi;
+use crate::commands::tasks::GraphFormat;
+use crate::dependency_graph::{self, DependencyGraph};
+use crate::error::ClickUpError;
+use crate::models::DependencyRelation;
+use colored::*;
+
+/// Builds the dependency relation from the `--waiting-on` and `--blocking` options
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if neither or both options are given
+pub fn dependency_relation(
+    waiting_on: Option<String>,
+    blocking: Option<String>,
+) -> Result<DependencyRelation, ClickUpError> {
+    match (waiting_on, blocking) {
+        (Some(id), None) => Ok(DependencyRelation::WaitingOn(id)),
+        (None, Some(id)) => Ok(DependencyRelation::Blocking(id)),
+        _ => Err(ClickUpError::ValidationError(
+            "Specify exactly one of --waiting-on or --blocking".to_string(),
+        )),
+    }
+}
+
+/// Describes a dependency relation for output
+fn describe_relation(task_id: &str, relation: &DependencyRelation) -> String {
+    match relation {
+        DependencyRelation::WaitingOn(other) => format!("{task_id} is waiting on {other}"),
+        DependencyRelation::Blocking(other) => format!("{task_id} is blocking {other}"),
+    }
+}
+
+/// Add a dependency between two tasks
+///
+/// After the dependency is added, this function warns if the waiting task is
+/// due before the task it now waits on.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to add the dependency to
+/// * `relation` - The task it waits on or blocks
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a task would depend on itself
+/// - `ClickUpError::NotFoundError` if either task doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+pub async fn add_dependency(
+    api: &ClickUpApi,
+    task_id: &str,
+    relation: DependencyRelation,
+) -> Result<(), ClickUpError> {
+    let (blocker_id, waiting_id) = match &relation {
+        DependencyRelation::WaitingOn(other) => (other.as_str(), task_id),
+        DependencyRelation::Blocking(other) => (task_id, other.as_str()),
+    };
+    if blocker_id == waiting_id {
+        return Err(ClickUpError::ValidationError(
+            "A task cannot depend on itself".to_string(),
+        ));
+    }
+    let description = describe_relation(task_id, &relation);
+    api.add_dependency(task_id, &relation.clone().into()).await?;
+    println!("{}", format!("✓ {description}").green());
+
+    // Only a warning: the dependency has already been added
+    let waiting = api.get_task(waiting_id).await?;
+    let blocker = api.get_task(blocker_id).await?;
+    let due = |task: &crate::models::Task| {
+        task.due_date
+            .as_deref()
+            .and_then(|due| due.parse::<i64>().ok())
+    };
+    if let (Some(due_date), Some(blocker_due_date)) = (due(&waiting), due(&blocker)) {
+        if due_date < blocker_due_date {
+            println!(
+                "{}",
+                format!(
+                    "⚠ {waiting_id} is due {} but waits on {blocker_id}, due {}",
+                    dependency_graph::format_due_date(due_date).unwrap_or_default(),
+                    dependency_graph::format_due_date(blocker_due_date).unwrap_or_default()
+                )
+                .yellow()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Remove a dependency between two tasks
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to remove the dependency from
+/// * `relation` - The task it waits on or blocks
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if either task doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+pub async fn remove_dependency(
+    api: &ClickUpApi,
+    task_id: &str,
+    relation: DependencyRelation,
+) -> Result<(), ClickUpError> {
+    let description = describe_relation(task_id, &relation);
+    api.remove_dependency(task_id, &relation.into()).await?;
+    println!("{}", format!("✓ Removed: {description}").green());
+    Ok(())
+}
+
+/// Output the dependency graph of a list
+///
+/// The graph is written to stdout so it can be piped to `dot` or pasted
+/// into Markdown. Cycles and tasks due before their blockers are reported
+/// on stderr.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `list_id` - The ID of the list to graph
+/// * `format` - The output format
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the list doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+pub async fn dependency_graph(
+    api: &ClickUpApi,
+    list_id: &str,
+    format: GraphFormat,
+) -> Result<(), ClickUpError> {
+    // Closed blockers still belong in the graph
+    let tasks = api.get_tasks_including_closed(list_id).await?;
+    let graph = DependencyGraph::from_tasks(&tasks.tasks);
+
+    match format {
+        GraphFormat::Dot => print!("{}", graph.to_dot()),
+        GraphFormat::Mermaid => print!("{}", graph.to_mermaid()),
+    }
+
+    if graph.edge_count() == 0 {
+        eprintln!("{}", "No dependencies found in this list".yellow());
+    }
+    for cycle in graph.cycles() {
+        eprintln!(
+            "{}",
+            format!("✗ Dependency cycle: {}", cycle.join(" ↔ ")).red()
+        );
+    }
+    for conflict in graph.due_conflicts() {
+        eprintln!(
+            "{}",
+            format!(
+                "⚠ {} is due {} but waits on {}, due {}",
+                conflict.task_id,
+                dependency_graph::format_due_date(conflict.due_date).unwrap_or_default(),
+                conflict.blocker_id,
+                dependency_graph::format_due_date(conflict.blocker_due_date).unwrap_or_default()
+            )
+            .yellow()
+        );
+    }
+    Ok(())
+}
+
+/// Link two tasks
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to link from
+/// * `links_to` - The ID of the task to link to
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a task would be linked to itself
+/// - `ClickUpError::NotFoundError` if either task doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+pub async fn link_tasks(api: &ClickUpApi, task_id: &str, links_to: &str) -> Result<(), ClickUpError> {
+    if task_id == links_to {
+        return Err(ClickUpError::ValidationError(
+            "A task cannot be linked to itself".to_string(),
+        ));
+    }
+    api.add_task_link(task_id, links_to).await?;
+    println!("{}", format!("✓ Linked {task_id} to {links_to}").green());
+    Ok(())
+}
+
+/// Remove the link between two tasks
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to unlink from
+/// * `links_to` - The ID of the linked task
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if either task doesn't exist
+/// - `ClickUpError::NetworkError` if the API request fails
+pub async fn unlink_tasks(
+    api: &ClickUpApi,
+    task_id: &str,
+    links_to: &str,
+) -> Result<(), ClickUpError> {
+    api.remove_task_link(task_id, links_to).await?;
+    println!("{}", format!("✓ Unlinked {task_id} from {links_to}").green());
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! # Task Export Command
+//!
+//! This module implements `tasks export`, which writes the tasks of a list or
+//! space as CSV, Markdown, iCalendar or HTML. Rendering lives in the `export`
+//! module; this module gathers the tasks, including those in a space's
+//! folders.
+
+use crate::api::ClickUpApi;
+use crate::commands::spaces::get_space_info;
+use crate::error::ClickUpError;
+use crate::export::{self, ExportFormat};
+use crate::models::Task;
+use colored::*;
+use std::path::PathBuf;
+
+/// Parameters for exporting tasks to a file
+pub struct ExportParams {
+    pub list_id: Option<String>,
+    pub space_id: Option<String>,
+    pub format: ExportFormat,
+    pub output: Option<PathBuf>,
+    pub include_closed: bool,
+}
+
+/// Export the tasks of a list or space
+///
+/// Tasks of a space are gathered from its lists, including those in
+/// folders. A task that is in several lists is exported once.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `params` - The list or space, format and output file
+///
+/// # Returns
+///
+/// Returns `Ok(())` once the export is written.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if an API request fails
+/// - `ClickUpError::NotFoundError` if the list or space doesn't exist
+/// - `ClickUpError::IoError` if the output file cannot be written
+pub async fn export_tasks(api: &ClickUpApi, params: ExportParams) -> Result<(), ClickUpError> {
+    let (title, lists) = match (&params.list_id, &params.space_id) {
+        (Some(list_id), _) => {
+            let list = api.get_list(list_id).await?;
+            (list.name.clone().unwrap_or_else(|| list_id.clone()), vec![list.id])
+        }
+        (None, Some(space_id)) => {
+            let space = get_space_info(api, space_id).await?;
+            let mut lists: Vec<String> = api
+                .get_lists(space_id)
+                .await?
+                .lists
+                .into_iter()
+                .map(|list| list.id)
+                .collect();
+            for folder in api.get_folders(space_id).await?.folders {
+                let folder_lists = api.get_folder_lists(&folder.id).await?;
+                lists.extend(folder_lists.lists.into_iter().map(|list| list.id));
+            }
+            (space.name.unwrap_or_else(|| space_id.clone()), lists)
+        }
+        (None, None) => {
+            return Err(ClickUpError::ValidationError(
+                "Give --list-id or --space-id".to_string(),
+            ))
+        }
+    };
+
+    let mut tasks: Vec<Task> = Vec::new();
+    for list_id in &lists {
+        let response = if params.include_closed {
+            api.get_tasks_including_closed(list_id).await?
+        } else {
+            api.get_tasks(list_id).await?
+        };
+        for task in response.tasks {
+            if !tasks.iter().any(|exported| exported.id == task.id) {
+                tasks.push(task);
+            }
+        }
+    }
+
+    let data = export::render(params.format, &tasks, &title, chrono::Utc::now())?;
+    match &params.output {
+        Some(path) => {
+            std::fs::write(path, data)?;
+            println!(
+                "{}",
+                format!("✓ Exported {} task(s) to {}", tasks.len(), path.display()).green()
+            );
+        }
+        None => print!("{data}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,243 @@
+//! # Task Import Command
+//!
+//! This module implements `tasks import`, which creates tasks in a list from
+//! a CSV, JSON or Markdown file. Parsing and row checks that don't need the
+//! API live in the `import` module; this module checks rows against the list
+//! and workspace, creates the tasks in parent-first order and writes the
+//! result file.
+
+use crate::api::ClickUpApi;
+use crate::commands::comments::resolve_assignee;
+use crate::commands::task_move;
+use crate::commands::utils::{ApiUtils, DisplayUtils};
+use crate::error::ClickUpError;
+use crate::import::{self, ImportFormat, ImportResult, ImportRow, RowError};
+use crate::models::CustomFieldValue;
+use colored::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Parameters for importing tasks from a file
+pub struct ImportParams {
+    pub list_id: String,
+    pub file: PathBuf,
+    pub format: Option<ImportFormat>,
+    pub mappings: Vec<String>,
+    pub done_status: String,
+    pub per_minute: Option<u32>,
+    pub results: Option<PathBuf>,
+    pub workspace_id: Option<String>,
+}
+
+/// Import tasks from a CSV, JSON or Markdown file
+///
+/// The whole file is read and checked first (see `check_import_rows`), so a
+/// bad row stops the import before any task exists. Tasks are then created
+/// one at a time, parents before their subtasks. A task whose parent row
+/// failed is skipped. The outcome of each row is written to the result file
+/// as soon as it is known.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `params` - The file, target list and import options
+///
+/// # Returns
+///
+/// Returns `Ok(())` when every task was created.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::IoError` if the file cannot be read or the result file written
+/// - `ClickUpError::ValidationError` if any row is invalid, listing every problem
+/// - `ClickUpError::ApiError` if any task could not be created
+pub async fn import_tasks(api: &ClickUpApi, params: ImportParams) -> Result<(), ClickUpError> {
+    let data = std::fs::read_to_string(&params.file)?;
+    let format = match params.format {
+        Some(format) => format,
+        None => ImportFormat::from_path(&params.file)?,
+    };
+    let mut rows = match format {
+        ImportFormat::Csv => import::parse_csv(&data, &import::parse_mapping(&params.mappings)?)?,
+        ImportFormat::Json => import::parse_json(&data)?,
+        ImportFormat::Markdown => import::parse_markdown(&data, &params.done_status),
+    };
+    if rows.is_empty() {
+        DisplayUtils::display_empty_message("tasks");
+        return Ok(());
+    }
+    let order = import::order_rows(&rows)?;
+    check_import_rows(api, &mut rows, &params.list_id, params.workspace_id).await?;
+
+    // Row references, so a parent can be told apart from an existing task ID
+    let references: HashMap<String, usize> = rows
+        .iter()
+        .filter_map(|row| Some((row.reference.clone()?, row.line)))
+        .collect();
+    let pause = params
+        .per_minute
+        .map(|per_minute| Duration::from_secs_f64(60.0 / f64::from(per_minute)));
+    let results_path = params.results.unwrap_or_else(|| {
+        let mut path = params.file.clone().into_os_string();
+        path.push(".results.json");
+        PathBuf::from(path)
+    });
+    // Placeholder IDs from a dry run must not end up in a result file
+    let results_file = (!api.config().dry_run).then_some(results_path.as_path());
+    let mut created: HashMap<String, String> = HashMap::new();
+    let mut results = Vec::with_capacity(rows.len());
+    for (position, index) in order.into_iter().enumerate() {
+        let row = &mut rows[index];
+        let mut result = ImportResult {
+            line: row.line,
+            reference: row.reference.clone(),
+            name: row.request.name.clone(),
+            id: None,
+            url: None,
+            error: None,
+        };
+        let parent = match row.parent.as_deref() {
+            Some(parent) if references.contains_key(parent) => match created.get(parent) {
+                Some(id) => Some(id.clone()),
+                None => {
+                    let message =
+                        format!("its parent on line {} was not imported", references[parent]);
+                    println!("{}", format!("✗ Skipped line {}: {message}", row.line).red());
+                    result.error = Some(message);
+                    results.push(result);
+                    if let Some(path) = results_file {
+                        import::write_results(path, &results)?;
+                    }
+                    continue;
+                }
+            },
+            parent => parent.map(str::to_string),
+        };
+
+        if let (Some(pause), true) = (pause, position > 0) {
+            tokio::time::sleep(pause).await;
+        }
+        let mut request = std::mem::take(&mut row.request);
+        request.parent = parent;
+        match api.create_task(&params.list_id, request).await {
+            Ok(task) => {
+                println!(
+                    "{}",
+                    format!("✓ Line {}: created {} ({})", row.line, result.name, task.id).green()
+                );
+                if let Some(reference) = &row.reference {
+                    created.insert(reference.clone(), task.id.clone());
+                }
+                result.id = Some(task.id);
+                result.url = Some(task.url);
+            }
+            Err(e) => {
+                println!("{}", format!("✗ Line {}: {e}", row.line).red());
+                result.error = Some(e.to_string());
+            }
+        }
+        results.push(result);
+        if let Some(path) = results_file {
+            import::write_results(path, &results)?;
+        }
+    }
+
+    let failed = results.iter().filter(|result| result.error.is_some()).count();
+    println!("\n{}", "Summary:".bold());
+    println!("Created: {}", results.len() - failed);
+    println!("Failed: {failed}");
+    if let Some(path) = results_file {
+        println!("Results: {}", path.display());
+    }
+    if failed > 0 {
+        return Err(ClickUpError::ApiError(format!(
+            "{failed} of {} task(s) could not be imported",
+            results.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Checks imported rows against the target list and workspace
+///
+/// Statuses must exist in the list (and take its spelling), assignees are
+/// looked up in the workspace unless given as user IDs, and custom field
+/// values are converted for the list's fields. Every row is checked before
+/// the problems are reported together.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` listing every invalid status, assignee
+///   and custom field value, or if assignees are given by name without a workspace
+/// - `ClickUpError::NetworkError` if an API request fails
+async fn check_import_rows(
+    api: &ClickUpApi,
+    rows: &mut [ImportRow],
+    list_id: &str,
+    workspace_id: Option<String>,
+) -> Result<(), ClickUpError> {
+    let statuses = task_move::list_statuses(api, list_id).await?;
+    let fields = if rows.iter().any(|row| !row.custom_fields.is_empty()) {
+        api.get_list_custom_fields(list_id).await?.fields
+    } else {
+        Vec::new()
+    };
+    let by_name = |assignee: &String| assignee.trim().parse::<i64>().is_err();
+    let workspace = if rows.iter().any(|row| row.assignees.iter().any(by_name)) {
+        let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+        Some(api.get_workspace(&workspace_id).await?)
+    } else {
+        None
+    };
+
+    let mut errors = Vec::new();
+    for row in rows.iter_mut() {
+        let line = row.line;
+        if let Some(status) = &row.request.status {
+            match statuses
+                .iter()
+                .find(|candidate| candidate.status.eq_ignore_ascii_case(status))
+            {
+                Some(found) => row.request.status = Some(found.status.clone()),
+                None if statuses.is_empty() => {}
+                None => errors.push(RowError {
+                    line,
+                    message: format!("the list has no status '{status}'"),
+                }),
+            }
+        }
+        for assignee in &row.assignees {
+            match resolve_assignee(assignee, workspace.as_ref()) {
+                Ok(id) => row.request.assignees.get_or_insert_with(Vec::new).push(id),
+                Err(e) => errors.push(RowError {
+                    line,
+                    message: e.to_string(),
+                }),
+            }
+        }
+        for (key, value) in &row.custom_fields {
+            let Some(field) = import::find_custom_field(&fields, key) else {
+                errors.push(RowError {
+                    line,
+                    message: format!("the list has no custom field '{key}'"),
+                });
+                continue;
+            };
+            match import::custom_field_value(field, value) {
+                Ok(value) => row
+                    .request
+                    .custom_fields
+                    .get_or_insert_with(Vec::new)
+                    .push(CustomFieldValue {
+                        id: field.id.clone(),
+                        value,
+                    }),
+                Err(message) => errors.push(RowError { line, message }),
+            }
+        }
+    }
+    import::check_errors(errors)
+}
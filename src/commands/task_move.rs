@@ -0,0 +1,427 @@
+//! # Task Move Commands
+//!
+//! This module implements the `tasks` subcommands that change where a task
+//! lives: moving it to another list, duplicating it, and adding it to or
+//! removing it from additional lists.
+//!
+//! ## Commands
+//!
+//! - **Move**: Move a task to another list, mapping its status
+//! - **Duplicate**: Copy a task, optionally with subtasks, checklists and comments
+//! - **AddToList**: Add a task to an additional list (Tasks in Multiple Lists)
+//! - **RemoveFromList**: Remove a task from an additional list
+//!
+//! ## Statuses
+//!
+//! A task keeps its status when the target list has one with the same name.
+//! Otherwise it gets the first status of the same type, or the list's first
+//! status (see `map_status`).
+
+use crate::api::ClickUpApi;
+use crate::error::ClickUpError;
+use crate::models::{
+    CreateChecklistItemRequest, CreateChecklistRequest, CreateCommentRequest, CreateTaskRequest,
+    EditChecklistItemRequest, SpaceStatus, Task, TaskChecklist, TaskStatus, UpdateTaskRequest,
+};
+use colored::*;
+
+/// What to copy along with a duplicated task
+pub struct DuplicateOptions {
+    /// Copy the task's subtasks, keeping their nesting
+    pub subtasks: bool,
+    /// Copy checklists and their items
+    pub checklists: bool,
+    /// Copy comments, oldest first
+    pub comments: bool,
+}
+
+/// Finds the status a task should get in a list with different statuses
+///
+/// A status with the same name (ignoring case) is preferred, then the first
+/// status of the same type (`open`, `custom`, `done` or `closed`), then the
+/// list's first status.
+///
+/// # Arguments
+///
+/// * `status` - The task's current status
+/// * `statuses` - The statuses of the list the task is going to
+///
+/// # Returns
+///
+/// Returns the matching status, or `None` if the list has no statuses.
+pub fn map_status<'a>(status: &TaskStatus, statuses: &'a [SpaceStatus]) -> Option<&'a SpaceStatus> {
+    statuses
+        .iter()
+        .find(|candidate| candidate.status.eq_ignore_ascii_case(&status.status))
+        .or_else(|| {
+            statuses
+                .iter()
+                .filter(|candidate| candidate.type_ == status.type_)
+                .min_by_key(|candidate| candidate.orderindex)
+        })
+        .or_else(|| statuses.iter().min_by_key(|candidate| candidate.orderindex))
+}
+
+/// Fetches the statuses of a list
+pub async fn list_statuses(api: &ClickUpApi, list_id: &str) -> Result<Vec<SpaceStatus>, ClickUpError> {
+    Ok(api.get_list(list_id).await?.statuses.unwrap_or_default())
+}
+
+/// Move a task to another list
+///
+/// The task is moved with its ID intact. Its status is then mapped to one
+/// of the new list's statuses (see `map_status`) if the list doesn't have
+/// a status with the same name.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to move
+/// * `list_id` - The ID of the list to move it to
+///
+/// # Returns
+///
+/// Returns `Ok(())` on a successful move, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the task or list doesn't exist
+pub async fn move_task(api: &ClickUpApi, task_id: &str, list_id: &str) -> Result<(), ClickUpError> {
+    let task = api.get_task(task_id).await?;
+    if task.list.id == list_id {
+        println!(
+            "{}",
+            format!("Task {task_id} is already in list {list_id}").yellow()
+        );
+        return Ok(());
+    }
+    let statuses = list_statuses(api, list_id).await?;
+
+    api.move_task(&task.team_id, task_id, list_id).await?;
+
+    println!("{}", "✓ Task moved successfully!".green());
+    println!("Task: {} ({task_id})", task.name.as_deref().unwrap_or("Unnamed"));
+    println!("From list: {}", task.list.id);
+    println!("To list: {list_id}");
+
+    if let Some(status) = map_status(&task.status, &statuses) {
+        if status.status != task.status.status {
+            let update = UpdateTaskRequest {
+                name: None,
+                description: None,
+                status: Some(status.status.clone()),
+                priority: None,
+                due_date: None,
+                due_date_time: None,
+                time_estimate: None,
+                assignees: None,
+                tags: None,
+                parent: None,
+                custom_fields: None,
+                start_date: None,
+                start_date_time: None,
+                points: None,
+                notify_all: None,
+            };
+            // Undoing the move restores the status, so this needs no entry of its own
+            api.without_journal().update_task(task_id, update).await?;
+            println!("Status: {} → {}", task.status.status, status.status);
+        }
+    }
+    Ok(())
+}
+
+/// Duplicate a task
+///
+/// The copy is created in `list_id` (or the task's own list) with the
+/// task's name, description, dates, priority, assignees and tags. Custom
+/// field values are only copied within the same list. Subtasks keep their
+/// nesting, and copied comments are posted by the current user.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task to duplicate
+/// * `list_id` - Optional list to create the copy in
+/// * `options` - Whether to copy subtasks, checklists and comments
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if an API request fails
+/// - `ClickUpError::NotFoundError` if the task or list doesn't exist
+pub async fn duplicate_task(
+    api: &ClickUpApi,
+    task_id: &str,
+    list_id: Option<&str>,
+    options: DuplicateOptions,
+) -> Result<(), ClickUpError> {
+    let source = if options.subtasks {
+        api.get_task_with_subtasks(task_id).await?
+    } else {
+        api.get_task(task_id).await?
+    };
+    let list_id = list_id.unwrap_or(&source.list.id).to_string();
+    let statuses = if list_id == source.list.id {
+        None
+    } else {
+        Some(list_statuses(api, &list_id).await?)
+    };
+
+    let copy = copy_task(api, &source, &list_id, None, statuses.as_deref()).await?;
+    println!("{}", "✓ Task duplicated successfully!".green());
+    println!("Task ID: {}", copy.id);
+    println!("Name: {}", copy.name.as_deref().unwrap_or("Unnamed"));
+    println!("List: {list_id}");
+    println!("URL: {}", copy.url.blue());
+
+    let mut copies = vec![(&source, copy.id.clone())];
+    if options.subtasks {
+        let mut subtasks: Vec<&Task> = source.subtasks.iter().flatten().collect();
+        subtasks.sort_by(|a, b| order_value(a).total_cmp(&order_value(b)));
+        // Subtasks of subtasks are attached to the copy of their parent once
+        // it exists, so keep going while each pass creates something
+        while !subtasks.is_empty() {
+            let pending = subtasks.len();
+            let mut remaining = Vec::new();
+            for subtask in subtasks {
+                let parent = match subtask.parent.as_deref() {
+                    None => Some(copy.id.clone()),
+                    Some(parent) => copies
+                        .iter()
+                        .find(|(original, _)| original.id == parent)
+                        .map(|(_, id)| id.clone()),
+                };
+                match parent {
+                    Some(parent) => {
+                        let created =
+                            copy_task(api, subtask, &list_id, Some(parent), statuses.as_deref())
+                                .await?;
+                        copies.push((subtask, created.id));
+                    }
+                    None => remaining.push(subtask),
+                }
+            }
+            if remaining.len() == pending {
+                // Parents outside the duplicated tree: attach to the top copy
+                for subtask in remaining.drain(..) {
+                    let created = copy_task(
+                        api,
+                        subtask,
+                        &list_id,
+                        Some(copy.id.clone()),
+                        statuses.as_deref(),
+                    )
+                    .await?;
+                    copies.push((subtask, created.id));
+                }
+            }
+            subtasks = remaining;
+        }
+    }
+
+    let mut checklist_count = 0;
+    let mut comment_count = 0;
+    for (original, copy_id) in &copies {
+        if options.checklists {
+            checklist_count += copy_checklists(api, &original.checklists, copy_id).await?;
+        }
+        if options.comments {
+            comment_count += copy_comments(api, &original.id, copy_id).await?;
+        }
+    }
+
+    println!("\n{}", "Summary:".bold());
+    if options.subtasks {
+        println!("Subtasks: {}", copies.len() - 1);
+    }
+    if options.checklists {
+        println!("Checklists: {checklist_count}");
+    }
+    if options.comments {
+        println!("Comments: {comment_count}");
+    }
+    Ok(())
+}
+
+/// Returns a task's position within its list as a number
+fn order_value(task: &Task) -> f64 {
+    task.orderindex.parse().unwrap_or_default()
+}
+
+/// Creates a copy of one task in a list
+///
+/// `statuses` is `None` when the copy stays in the task's own list, in which
+/// case the status and custom field values are kept as they are.
+async fn copy_task(
+    api: &ClickUpApi,
+    task: &Task,
+    list_id: &str,
+    parent: Option<String>,
+    statuses: Option<&[SpaceStatus]>,
+) -> Result<Task, ClickUpError> {
+    let mut request = CreateTaskRequest::from(task);
+    request.parent = parent;
+    if let Some(statuses) = statuses {
+        request.status = map_status(&task.status, statuses).map(|status| status.status.clone());
+        request.custom_fields = None;
+    }
+    api.create_task(list_id, request).await
+}
+
+/// Copies checklists and their items to another task
+///
+/// # Returns
+///
+/// Returns the number of checklists copied.
+async fn copy_checklists(
+    api: &ClickUpApi,
+    checklists: &[TaskChecklist],
+    task_id: &str,
+) -> Result<usize, ClickUpError> {
+    for checklist in checklists {
+        let mut created = api
+            .create_checklist(
+                task_id,
+                CreateChecklistRequest {
+                    name: checklist.name.clone().unwrap_or_default(),
+                },
+            )
+            .await?;
+        let mut items: Vec<_> = checklist.items().iter().collect();
+        items.sort_by_key(|item| item.orderindex);
+        for item in items {
+            let known: Vec<String> = created.items().iter().map(|i| i.id.clone()).collect();
+            created = api
+                .create_checklist_item(
+                    &created.id,
+                    CreateChecklistItemRequest {
+                        name: item.name.clone().unwrap_or_default(),
+                        assignee: None,
+                    },
+                )
+                .await?;
+            if item.resolved == Some(true) {
+                let added = created
+                    .items()
+                    .iter()
+                    .find(|added| !known.contains(&added.id))
+                    .map(|added| added.id.clone());
+                if let Some(item_id) = added {
+                    created = api
+                        .edit_checklist_item(
+                            &created.id,
+                            &item_id,
+                            EditChecklistItemRequest {
+                                resolved: Some(true),
+                                ..Default::default()
+                            },
+                        )
+                        .await?;
+                }
+            }
+        }
+    }
+    Ok(checklists.len())
+}
+
+/// Copies a task's comments to another task, oldest first
+///
+/// # Returns
+///
+/// Returns the number of comments copied.
+async fn copy_comments(api: &ClickUpApi, from: &str, to: &str) -> Result<usize, ClickUpError> {
+    let mut comments = api.get_comments(from).await?.comments;
+    comments.sort_by_key(|comment| comment.date.parse::<i64>().unwrap_or_default());
+    for comment in &comments {
+        let request = CreateCommentRequest {
+            comment_text: comment.comment_text.clone(),
+            comment: (!comment.comment.is_empty()).then(|| comment.comment.clone()),
+            assignee: None,
+            notify_all: None,
+            resolved: None,
+        };
+        api.create_comment(to, request).await?;
+    }
+    Ok(comments.len())
+}
+
+/// Add a task to an additional list
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `list_id` - The ID of the additional list
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the list is the task's home list
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::PermissionError` if Tasks in Multiple Lists is disabled
+pub async fn add_task_to_list(api: &ClickUpApi, task_id: &str, list_id: &str) -> Result<(), ClickUpError> {
+    ensure_not_home_list(api, task_id, list_id).await?;
+    api.add_task_to_list(list_id, task_id).await?;
+    println!(
+        "{}",
+        format!("✓ Added task {task_id} to list {list_id}").green()
+    );
+    Ok(())
+}
+
+/// Remove a task from an additional list
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `task_id` - The ID of the task
+/// * `list_id` - The ID of the additional list
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the list is the task's home list
+/// - `ClickUpError::NetworkError` if the API request fails
+pub async fn remove_task_from_list(
+    api: &ClickUpApi,
+    task_id: &str,
+    list_id: &str,
+) -> Result<(), ClickUpError> {
+    ensure_not_home_list(api, task_id, list_id).await?;
+    api.remove_task_from_list(list_id, task_id).await?;
+    println!(
+        "{}",
+        format!("✓ Removed task {task_id} from list {list_id}").green()
+    );
+    Ok(())
+}
+
+/// Refuses list membership changes that target the task's home list
+async fn ensure_not_home_list(
+    api: &ClickUpApi,
+    task_id: &str,
+    list_id: &str,
+) -> Result<(), ClickUpError> {
+    let task = api.get_task(task_id).await?;
+    if task.list.id == list_id {
+        return Err(ClickUpError::ValidationError(format!(
+            "List {list_id} is the home list of task {task_id}; use `tasks move` to change it"
+        )));
+    }
+    Ok(())
+}
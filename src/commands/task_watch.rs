@@ -0,0 +1,163 @@
+//! # Task Watch Command
+//!
+//! This module implements `tasks watch`, which polls a list and prints an
+//! event for every created, updated, closed or deleted task. Snapshots and
+//! diffing live in the `watch` module; this module runs the poll loop and
+//! prints events as text or NDJSON.
+
+use crate::api::ClickUpApi;
+use crate::error::ClickUpError;
+use crate::watch::{self, WatchEvent, WatchEventKind, WatchState};
+use colored::*;
+
+/// Options for watching a list
+pub struct WatchOptions {
+    pub interval: std::time::Duration,
+    pub json: bool,
+    pub once: bool,
+    pub reset: bool,
+}
+
+/// Watch a list for task changes
+///
+/// This function polls the list (including closed tasks) every interval,
+/// compares each poll with the previous snapshot and prints an event for
+/// every created, updated, closed or deleted task. Requests go through the
+/// client's rate limiter, so large lists with short intervals slow down
+/// rather than fail. Transient network and rate limit errors are reported
+/// and retried at the next poll.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `list_id` - The ID of the list to watch
+/// * `options` - Poll interval and output options
+///
+/// # Returns
+///
+/// Returns `Ok(())` when stopped with Ctrl-C or after a single `--once` poll.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if the list doesn't exist
+/// - `ClickUpError::IoError` if the snapshot cannot be read or written
+/// - `ClickUpError::JsonError` if the saved snapshot is corrupt
+pub async fn watch_tasks(
+    api: &ClickUpApi,
+    list_id: &str,
+    options: WatchOptions,
+) -> Result<(), ClickUpError> {
+    let path = WatchState::default_path(list_id)?;
+    if options.reset && path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    let mut previous = WatchState::load(&path)?;
+
+    // Keep stdout to one JSON object per line in JSON mode
+    let status = |line: String| {
+        if options.json {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+    if !options.once {
+        status(
+            format!(
+                "Watching list {list_id} every {}s",
+                options.interval.as_secs()
+            )
+            .green()
+            .to_string(),
+        );
+        status("Press Ctrl-C to stop".to_string());
+    }
+
+    let mut polls = 0usize;
+    let mut emitted = 0usize;
+    loop {
+        match api.get_tasks_including_closed(list_id).await {
+            Ok(response) => {
+                polls += 1;
+                let current = WatchState::from_tasks(list_id, &response.tasks);
+                match &previous {
+                    Some(previous) => {
+                        for event in watch::diff(previous, &current) {
+                            emitted += 1;
+                            print_watch_event(&event, options.json)?;
+                        }
+                    }
+                    None => status(format!(
+                        "Recorded a baseline of {} tasks",
+                        current.tasks.len()
+                    )),
+                }
+                current.save(&path)?;
+                previous = Some(current);
+            }
+            Err(e @ (ClickUpError::NetworkError(_) | ClickUpError::RateLimitError))
+                if !options.once =>
+            {
+                eprintln!("{}", format!("⚠ Poll failed, retrying next interval: {e}").yellow());
+            }
+            Err(e) => return Err(e),
+        }
+
+        if options.once {
+            break;
+        }
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(options.interval) => {}
+        }
+    }
+
+    if !options.once {
+        status(format!("\n{}", "Summary:".bold()));
+        status(format!("Polls: {polls}"));
+        status(format!("Events: {emitted}"));
+    }
+    Ok(())
+}
+
+/// Prints a watch event as a line of text or JSON
+fn print_watch_event(event: &WatchEvent, json: bool) -> Result<(), ClickUpError> {
+    if json {
+        let mut value = serde_json::to_value(event)?;
+        value["timestamp"] = chrono::Utc::now().to_rfc3339().into();
+        println!("{value}");
+        return Ok(());
+    }
+
+    let kind = match event.event {
+        WatchEventKind::Created => event.event.to_string().green(),
+        WatchEventKind::Updated => event.event.to_string().cyan(),
+        WatchEventKind::Closed => event.event.to_string().blue(),
+        WatchEventKind::Deleted => event.event.to_string().red(),
+    };
+    let changes: Vec<String> = event
+        .changes
+        .iter()
+        .map(|change| {
+            format!(
+                "{}: {} → {}",
+                change.field,
+                watch::format_change_value(&change.field, &change.before),
+                watch::format_change_value(&change.field, &change.after)
+            )
+        })
+        .collect();
+    let mut line = format!(
+        "[{}] {:<7} {} {}",
+        chrono::Local::now().format("%H:%M:%S"),
+        kind,
+        event.task_id,
+        event.name
+    );
+    if !changes.is_empty() {
+        line.push_str(&format!(" ({})", changes.join("; ")));
+    }
+    println!("{line}");
+    Ok(())
+}
@@ -6,14 +6,27 @@
 //!
 //! ## Commands
 //!
-//! - **List**: Display all tasks in a list
+//! - **List**: Display all tasks in a list, flat or as a subtask tree
 //! - **ListByTag**: Display tasks filtered by a specific tag
 //! - **SearchByTag**: Search for tasks with a tag across multiple spaces
 //! - **UpdateOverdueByTag**: Update overdue tasks with a tag to today's date
-//! - **Show**: Show detailed information about a specific task
-//! - **Create**: Create a new task with various parameters
+//! - **Show**: Show detailed information about a specific task and its subtasks
+//! - **Subtasks**: Show a task's subtasks at any depth with rollups
+//! - **Create**: Create a new task with various parameters, or from a template
 //! - **Update**: Update an existing task with new values
 //! - **Delete**: Remove a task permanently
+//! - **Import**: Create tasks from a CSV, JSON or Markdown file
+//! - **Export**: Write a list's or space's tasks as CSV, Markdown, iCalendar or HTML
+//! - **Move**: Move a task to another list, mapping its status
+//! - **Duplicate**: Copy a task, optionally with subtasks, checklists and comments
+//! - **AddToList**: Add a task to an additional list (Tasks in Multiple Lists)
+//! - **RemoveFromList**: Remove a task from an additional list
+//! - **Watch**: Poll a list and report created, updated, closed and deleted tasks
+//! - **Deps**: Add or remove dependencies and graph a list's dependencies
+//! - **Link**: Link and unlink related tasks
+//! - **Checklist**: Manage checklists and their items (see `checklists`)
+//! - **Attach**: Upload files as task attachments
+//! - **Attachments**: Download a task's attachments
 //!
 //! ## Features
 //!
@@ -21,15 +34,35 @@
 //! status, priority, due dates, and assignees. Advanced features include
 //! tag-based filtering, overdue task management with dry-run support, and
 //! comprehensive task details display.
+//!
+//! With `--offline`, `list`, `show` and `subtasks` read a space backup
+//! instead of ClickUp; other task commands are refused.
 
 use crate::api::ClickUpApi;
+use crate::backup::SpaceBackup;
+use crate::commands::attachments;
+use crate::commands::checklists::ChecklistCommands;
+use crate::commands::task_export::{self, ExportParams};
+use crate::commands::task_import::{self, ImportParams};
+use crate::commands::task_move::{self, DuplicateOptions};
+use crate::commands::task_deps;
+use crate::commands::task_watch::{self, WatchOptions};
+use crate::commands::templates::{self, TemplateOverrides};
 use crate::config::Config;
+use crate::data_source::DataSource;
 use crate::error::ClickUpError;
-use crate::models::{CreateTaskRequest, UpdateTaskRequest};
-use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, TableBuilder, TableHeaders};
+use crate::export::ExportFormat;
+use crate::import::ImportFormat;
+use crate::models::{CreateTaskRequest, DependencyRelation, Task, UpdateTaskRequest};
+use crate::task_tree::{self, TaskNode};
+use crate::watch;
+use crate::commands::utils::{
+    ApiUtils, CommandExecutor, DisplayUtils, ErrorUtils, PromptUtils, TableBuilder, TableHeaders,
+};
 use chrono::NaiveTime;
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 use colored::*;
+use std::path::PathBuf;
 
 /// Parameters for creating a task
 ///
@@ -43,6 +76,7 @@ struct CreateTaskParams {
     priority: Option<i64>,
     due_date: Option<i64>,
     time_estimate: Option<i64>,
+    assignees: Vec<i64>,
 }
 
 /// Parameters for updating a task
@@ -71,6 +105,10 @@ pub enum TaskCommands {
         /// List ID
         #[arg(short, long)]
         list_id: String,
+        /// Nest subtasks under their parents, with done/total counts and
+        /// summed time estimates (includes closed tasks)
+        #[arg(long)]
+        tree: bool,
     },
     /// List tasks in a list filtered by tag
     ListByTag {
@@ -105,7 +143,10 @@ pub enum TaskCommands {
         #[arg(short, long)]
         space_id: Option<String>,
         /// Dry run mode - show what would be updated without making changes
-        #[arg(short, long)]
+        ///
+        /// `-d` only lists the overdue tasks; the global `--dry-run` flag
+        /// prints each update request instead of sending it.
+        #[arg(short = 'd', id = "overdue_dry_run")]
         dry_run: bool,
     },
     /// Show details of a specific task
@@ -114,14 +155,22 @@ pub enum TaskCommands {
         #[arg(short, long)]
         id: String,
     },
+    /// Show a task's subtasks at any depth
+    ///
+    /// Subtasks are looked up in the task's list, including closed ones.
+    Subtasks {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+    },
     /// Create a new task
     Create {
         /// List ID
         #[arg(short, long)]
         list_id: String,
-        /// Task name
-        #[arg(short, long)]
-        name: String,
+        /// Task name (defaults to the template's when --from-template is given)
+        #[arg(short, long, required_unless_present = "from_template")]
+        name: Option<String>,
         /// Task description
         #[arg(long)]
         description: Option<String>,
@@ -137,6 +186,16 @@ pub enum TaskCommands {
         /// Time estimate (in milliseconds)
         #[arg(short, long)]
         time_estimate: Option<i64>,
+        /// User ID to assign (repeatable)
+        #[arg(long = "assignee")]
+        assignees: Vec<i64>,
+        /// Create the task from a local template (name or file name) or a
+        /// ClickUp task template (ID or name)
+        #[arg(long)]
+        from_template: Option<String>,
+        /// Workspace whose templates are searched (defaults to the configured workspace)
+        #[arg(short, long, requires = "from_template")]
+        workspace_id: Option<String>,
     },
     /// Update an existing task
     Update {
@@ -168,20 +227,285 @@ pub enum TaskCommands {
         #[arg(short, long)]
         id: String,
     },
+    /// Import tasks from a CSV, JSON or Markdown file
+    ///
+    /// Every row is checked against the list and workspace before any task
+    /// is created. Parent rows are created before their subtasks, and the
+    /// IDs of the new tasks are written to a result file. With --dry-run each
+    /// task is printed instead of created and no result file is written.
+    Import {
+        /// List to create the tasks in
+        #[arg(short, long)]
+        list_id: String,
+        /// File to import
+        #[arg(short, long)]
+        file: PathBuf,
+        /// File format (defaults to the file's extension)
+        #[arg(long, value_enum)]
+        format: Option<ImportFormat>,
+        /// Map a CSV column to a task field, e.g. "Summary=name" or
+        /// "Story Points=custom:Points" (repeatable)
+        #[arg(long = "map")]
+        mappings: Vec<String>,
+        /// Status given to checked items of a Markdown task list
+        #[arg(long, default_value = "complete")]
+        done_status: String,
+        /// Most tasks to create per minute (requests are always kept within
+        /// the configured rate limit)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        per_minute: Option<u32>,
+        /// File to write the IDs of the created tasks to (defaults to
+        /// <file>.results.json next to the imported file)
+        #[arg(short, long)]
+        results: Option<PathBuf>,
+        /// Workspace whose members assignees are looked up in (defaults to
+        /// the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+    },
+    /// Export the tasks of a list or space
+    ///
+    /// CSV has a column per custom field, Markdown and HTML are reports
+    /// grouped by status, and iCalendar has an event per due date.
+    Export {
+        /// List to export
+        #[arg(short, long, required_unless_present = "space_id", conflicts_with = "space_id")]
+        list_id: Option<String>,
+        /// Space to export (all lists, including those in folders)
+        #[arg(short, long)]
+        space_id: Option<String>,
+        /// Export format
+        #[arg(short, long, value_enum)]
+        format: ExportFormat,
+        /// File to write (defaults to standard output)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Include closed tasks
+        #[arg(long)]
+        include_closed: bool,
+    },
+    /// Move a task to another list
+    ///
+    /// The task keeps its ID. If the new list doesn't have the task's
+    /// status, the closest one is used: a status of the same type, or
+    /// else the list's first status.
+    Move {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// List to move the task to
+        #[arg(long)]
+        to_list: String,
+    },
+    /// Duplicate a task
+    Duplicate {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// List to create the copy in (defaults to the task's list)
+        #[arg(long)]
+        to_list: Option<String>,
+        /// Also copy the task's subtasks
+        #[arg(long)]
+        with_subtasks: bool,
+        /// Also copy checklists and their items
+        #[arg(long)]
+        with_checklists: bool,
+        /// Also copy comments (posted as you, oldest first)
+        #[arg(long)]
+        with_comments: bool,
+    },
+    /// Add a task to an additional list
+    ///
+    /// Requires the Tasks in Multiple Lists ClickApp. The task's home
+    /// list is unchanged.
+    AddToList {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Additional list ID
+        #[arg(short, long)]
+        list_id: String,
+    },
+    /// Remove a task from an additional list
+    RemoveFromList {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Additional list ID
+        #[arg(short, long)]
+        list_id: String,
+    },
+    /// Poll a list and print task changes as they happen
+    ///
+    /// The first poll of a list records a baseline without printing events.
+    /// The last snapshot is kept in the config directory, so a restarted
+    /// watch only reports changes made since it last polled.
+    Watch {
+        /// List ID
+        #[arg(short, long)]
+        list_id: String,
+        /// Time between polls (e.g. 30s, 5m, 1h)
+        #[arg(long, default_value = "60s")]
+        interval: String,
+        /// Print one JSON object per event (NDJSON)
+        #[arg(long)]
+        json: bool,
+        /// Poll once, print changes since the saved snapshot and exit
+        #[arg(long)]
+        once: bool,
+        /// Discard the saved snapshot and record a new baseline
+        #[arg(long)]
+        reset: bool,
+    },
+    /// Manage task dependencies
+    Deps {
+        /// The dependency subcommand to execute
+        #[command(subcommand)]
+        command: DepsCommands,
+    },
+    /// Manage links between related tasks
+    Link {
+        /// The link subcommand to execute
+        #[command(subcommand)]
+        command: LinkCommands,
+    },
+    /// Manage checklists and checklist items
+    Checklist {
+        /// The checklist subcommand to execute
+        #[command(subcommand)]
+        command: ChecklistCommands,
+    },
+    /// Upload files as attachments to a task
+    Attach {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// File to attach (repeat to attach several files)
+        #[arg(short, long = "file", required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Download task attachments
+    Attachments {
+        /// The attachment subcommand to execute
+        #[command(subcommand)]
+        command: AttachmentCommands,
+    },
+}
+
+/// Attachment command variants
+#[derive(Subcommand)]
+pub enum AttachmentCommands {
+    /// Download a task's attachments into a directory
+    ///
+    /// Files keep their ClickUp names. Attachments that share a name get
+    /// their attachment ID added, and existing files are overwritten.
+    Download {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Directory to save the files in (created if missing)
+        #[arg(short, long, default_value = ".")]
+        out: PathBuf,
+        /// Only download the attachment with this ID or file name
+        #[arg(short, long)]
+        attachment: Option<String>,
+    },
+}
+
+/// Dependency command variants
+#[derive(Subcommand)]
+pub enum DepsCommands {
+    /// Make a task wait on, or block, another task
+    Add {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Task that this task waits on
+        #[arg(long, conflicts_with = "blocking", required_unless_present = "blocking")]
+        waiting_on: Option<String>,
+        /// Task that this task blocks
+        #[arg(long)]
+        blocking: Option<String>,
+    },
+    /// Remove a dependency between two tasks
+    Remove {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Task that this task waits on
+        #[arg(long, conflicts_with = "blocking", required_unless_present = "blocking")]
+        waiting_on: Option<String>,
+        /// Task that this task blocks
+        #[arg(long)]
+        blocking: Option<String>,
+    },
+    /// Output the dependency graph of a list
+    ///
+    /// Edges point from the blocking task to the waiting task. Cycles and
+    /// tasks due before their blockers are highlighted and reported on stderr.
+    Graph {
+        /// List ID
+        #[arg(short, long)]
+        list_id: String,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+    },
+}
+
+/// Output formats for `tasks deps graph`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// Link command variants
+#[derive(Subcommand)]
+pub enum LinkCommands {
+    /// Link two tasks
+    Add {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Task to link to
+        #[arg(long)]
+        to: String,
+    },
+    /// Remove the link between two tasks
+    Remove {
+        /// Task ID
+        #[arg(short, long)]
+        id: String,
+        /// Linked task to unlink
+        #[arg(long)]
+        to: String,
+    },
 }
 
 impl CommandExecutor for TaskCommands {
     type Commands = TaskCommands;
     
     async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        if let Some(path) = &config.offline {
+            let backup = SpaceBackup::load(path)?;
+            return handle_offline_command(command, &backup).await;
+        }
         let api = ApiUtils::create_client(config)?;
         Self::handle_command(command, &api).await
     }
     
     async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
         match command {
-            TaskCommands::List { list_id } => {
-                list_tasks(api, &list_id).await?;
+            TaskCommands::List { list_id, tree } => {
+                if tree {
+                    list_task_tree(api, &list_id).await?;
+                } else {
+                    list_tasks(api, &list_id).await?;
+                }
             }
             TaskCommands::ListByTag { list_id, tag } => {
                 list_tasks_by_tag(api, &list_id, &tag).await?;
@@ -204,6 +528,9 @@ impl CommandExecutor for TaskCommands {
             TaskCommands::Show { id } => {
                 show_task(api, &id).await?;
             }
+            TaskCommands::Subtasks { id } => {
+                show_subtasks(api, &id).await?;
+            }
             TaskCommands::Create {
                 list_id,
                 name,
@@ -212,17 +539,46 @@ impl CommandExecutor for TaskCommands {
                 priority,
                 due_date,
                 time_estimate,
+                assignees,
+                from_template,
+                workspace_id,
             } => {
-                let params = CreateTaskParams {
-                    list_id: list_id.clone(),
-                    name,
-                    description,
-                    status,
-                    priority,
-                    due_date,
-                    time_estimate,
-                };
-                create_task(api, params).await?;
+                if let Some(reference) = from_template {
+                    let overrides = TemplateOverrides {
+                        name,
+                        description,
+                        status,
+                        priority,
+                        due_date,
+                        time_estimate,
+                        assignees,
+                    };
+                    templates::create_task_from_template(
+                        api,
+                        &list_id,
+                        &reference,
+                        workspace_id,
+                        overrides,
+                    )
+                    .await?;
+                } else {
+                    let name = name.ok_or_else(|| {
+                        ClickUpError::ValidationError(
+                            "Specify --name or --from-template".to_string(),
+                        )
+                    })?;
+                    let params = CreateTaskParams {
+                        list_id: list_id.clone(),
+                        name,
+                        description,
+                        status,
+                        priority,
+                        due_date,
+                        time_estimate,
+                        assignees,
+                    };
+                    create_task(api, params).await?;
+                }
             }
             TaskCommands::Update {
                 id,
@@ -247,6 +603,123 @@ impl CommandExecutor for TaskCommands {
             TaskCommands::Delete { id } => {
                 delete_task(api, &id).await?;
             }
+            TaskCommands::Import {
+                list_id,
+                file,
+                format,
+                mappings,
+                done_status,
+                per_minute,
+                results,
+                workspace_id,
+            } => {
+                let params = ImportParams {
+                    list_id,
+                    file,
+                    format,
+                    mappings,
+                    done_status,
+                    per_minute,
+                    results,
+                    workspace_id,
+                };
+                task_import::import_tasks(api, params).await?;
+            }
+            TaskCommands::Export {
+                list_id,
+                space_id,
+                format,
+                output,
+                include_closed,
+            } => {
+                let params = ExportParams {
+                    list_id,
+                    space_id,
+                    format,
+                    output,
+                    include_closed,
+                };
+                task_export::export_tasks(api, params).await?;
+            }
+            TaskCommands::Move { id, to_list } => {
+                task_move::move_task(api, &id, &to_list).await?;
+            }
+            TaskCommands::Duplicate {
+                id,
+                to_list,
+                with_subtasks,
+                with_checklists,
+                with_comments,
+            } => {
+                let options = DuplicateOptions {
+                    subtasks: with_subtasks,
+                    checklists: with_checklists,
+                    comments: with_comments,
+                };
+                task_move::duplicate_task(api, &id, to_list.as_deref(), options).await?;
+            }
+            TaskCommands::AddToList { id, list_id } => {
+                task_move::add_task_to_list(api, &id, &list_id).await?;
+            }
+            TaskCommands::RemoveFromList { id, list_id } => {
+                task_move::remove_task_from_list(api, &id, &list_id).await?;
+            }
+            TaskCommands::Watch {
+                list_id,
+                interval,
+                json,
+                once,
+                reset,
+            } => {
+                let options = WatchOptions {
+                    interval: watch::parse_interval(&interval)?,
+                    json,
+                    once,
+                    reset,
+                };
+                task_watch::watch_tasks(api, &list_id, options).await?;
+            }
+            TaskCommands::Deps { command } => match command {
+                DepsCommands::Add {
+                    id,
+                    waiting_on,
+                    blocking,
+                } => {
+                    let relation = task_deps::dependency_relation(waiting_on, blocking)?;
+                    task_deps::add_dependency(api, &id, relation).await?;
+                }
+                DepsCommands::Remove {
+                    id,
+                    waiting_on,
+                    blocking,
+                } => {
+                    let relation = task_deps::dependency_relation(waiting_on, blocking)?;
+                    task_deps::remove_dependency(api, &id, relation).await?;
+                }
+                DepsCommands::Graph { list_id, format } => {
+                    task_deps::dependency_graph(api, &list_id, format).await?;
+                }
+            },
+            TaskCommands::Link { command } => match command {
+                LinkCommands::Add { id, to } => {
+                    task_deps::link_tasks(api, &id, &to).await?;
+                }
+                LinkCommands::Remove { id, to } => {
+                    task_deps::unlink_tasks(api, &id, &to).await?;
+                }
+            },
+            TaskCommands::Checklist { command } => {
+                ChecklistCommands::handle_command(command, api).await?;
+            }
+            TaskCommands::Attach { id, files } => {
+                attachments::attach_files(api, &id, &files).await?;
+            }
+            TaskCommands::Attachments { command } => match command {
+                AttachmentCommands::Download { id, out, attachment } => {
+                    attachments::download_attachments(api, &id, &out, attachment.as_deref())
+                        .await?;
+                }
+            },
         }
         Ok(())
     }
@@ -278,6 +751,31 @@ pub async fn execute(command: TaskCommands, config: &Config) -> Result<(), Click
     TaskCommands::execute(command, config).await
 }
 
+/// Handle a task command from a backup given with `--offline`
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` for commands that need ClickUp
+/// - `ClickUpError::NotFoundError` if the list or task isn't in the backup
+async fn handle_offline_command(
+    command: TaskCommands,
+    backup: &SpaceBackup,
+) -> Result<(), ClickUpError> {
+    match command {
+        TaskCommands::List { list_id, tree } => {
+            if tree {
+                list_task_tree(backup, &list_id).await
+            } else {
+                list_tasks(backup, &list_id).await
+            }
+        }
+        TaskCommands::Show { id } => show_task(backup, &id).await,
+        TaskCommands::Subtasks { id } => show_subtasks(backup, &id).await,
+        _ => Err(ErrorUtils::offline_error()),
+    }
+}
+
 /// List all tasks in a list
 ///
 /// This function retrieves and displays all tasks for a specific list
@@ -286,7 +784,37 @@ pub async fn execute(command: TaskCommands, config: &Config) -> Result<(), Click
 ///
 /// # Arguments
 ///
-/// * `api` - Reference to the ClickUp API client
+/// * `source` - Where to read from: the ClickUp API or a backup
+/// * `list_id` - The ID of the list to list tasks for
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the list doesn't exist
+async fn list_tasks(source: &impl DataSource, list_id: &str) -> Result<(), ClickUpError> {
+    let tasks = source.get_tasks(list_id).await?;
+
+    if tasks.tasks.is_empty() {
+        DisplayUtils::display_empty_message("tasks");
+        return Ok(());
+    }
+
+    print_task_table(&tasks.tasks);
+    Ok(())
+}
+
+/// List the tasks in a list as a subtask tree
+///
+/// Closed tasks are included so that the rollups count finished subtasks.
+///
+/// # Arguments
+///
+/// * `source` - Where to read from: the ClickUp API or a backup
 /// * `list_id` - The ID of the list to list tasks for
 ///
 /// # Returns
@@ -298,14 +826,63 @@ pub async fn execute(command: TaskCommands, config: &Config) -> Result<(), Click
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the list doesn't exist
-async fn list_tasks(api: &ClickUpApi, list_id: &str) -> Result<(), ClickUpError> {
-    let tasks = api.get_tasks(list_id).await?;
+async fn list_task_tree(source: &impl DataSource, list_id: &str) -> Result<(), ClickUpError> {
+    let tasks = source.get_tasks_including_closed(list_id).await?;
 
     if tasks.tasks.is_empty() {
         DisplayUtils::display_empty_message("tasks");
         return Ok(());
     }
 
+    print_task_tree(&task_tree::build_tree(&tasks.tasks));
+    Ok(())
+}
+
+/// Prints task trees as a table with indented names and subtask rollups
+///
+/// The Subtasks column shows done/total subtasks at any depth, and the
+/// Estimate column the task's time estimate plus those of its subtasks.
+pub fn print_task_tree(nodes: &[TaskNode]) {
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::name(),
+        TableHeaders::status(),
+        "Subtasks",
+        "Estimate",
+    ]);
+
+    for (depth, node) in task_tree::flatten(nodes) {
+        let rollup = node.rollup();
+        let marker = if depth == 0 { "" } else { "└ " };
+        let subtasks = if rollup.total == 0 {
+            String::new()
+        } else {
+            format!("{}/{}", rollup.done, rollup.total)
+        };
+        let estimate = if rollup.time_estimate == 0 {
+            String::new()
+        } else {
+            task_tree::format_estimate(rollup.time_estimate)
+        };
+        table_builder.add_row(vec![
+            node.task.id.clone(),
+            format!(
+                "{}{marker}{}",
+                "  ".repeat(depth.saturating_sub(1)),
+                node.task.name.as_deref().unwrap_or("")
+            ),
+            node.task.status.status.clone(),
+            subtasks,
+            estimate,
+        ]);
+    }
+
+    table_builder.print();
+}
+
+/// Prints tasks as a table of ID, name, status, priority, due date and assignees
+pub fn print_task_table(tasks: &[Task]) {
     let mut table_builder = TableBuilder::new();
     table_builder.add_header(vec![
         TableHeaders::id(),
@@ -316,7 +893,7 @@ async fn list_tasks(api: &ClickUpApi, list_id: &str) -> Result<(), ClickUpError>
         "Assignees",
     ]);
 
-    for task in &tasks.tasks {
+    for task in tasks {
         let priority = task
             .priority
             .as_ref()
@@ -344,7 +921,6 @@ async fn list_tasks(api: &ClickUpApi, list_id: &str) -> Result<(), ClickUpError>
     }
 
     table_builder.print();
-    Ok(())
 }
 
 /// List tasks in a list filtered by tag
@@ -739,7 +1315,7 @@ async fn update_overdue_by_tag(
 ///
 /// # Arguments
 ///
-/// * `api` - Reference to the ClickUp API client
+/// * `source` - Where to read from: the ClickUp API or a backup
 /// * `task_id` - The ID of the task to show
 ///
 /// # Returns
@@ -751,8 +1327,8 @@ async fn update_overdue_by_tag(
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the task doesn't exist
-async fn show_task(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError> {
-    let task = api.get_task(task_id).await?;
+async fn show_task(source: &impl DataSource, task_id: &str) -> Result<(), ClickUpError> {
+    let task = source.get_task_with_subtasks(task_id).await?;
 
     println!("{}", "Task Details".bold());
     println!("ID: {}", task.id);
@@ -802,6 +1378,38 @@ async fn show_task(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError>
         }
     }
 
+    let relations: Vec<DependencyRelation> = task
+        .dependencies
+        .iter()
+        .filter_map(|dependency| dependency.relation_to(&task.id))
+        .collect();
+    let waiting_on: Vec<&str> = relations
+        .iter()
+        .filter_map(|relation| match relation {
+            DependencyRelation::WaitingOn(id) => Some(id.as_str()),
+            DependencyRelation::Blocking(_) => None,
+        })
+        .collect();
+    let blocking: Vec<&str> = relations
+        .iter()
+        .filter_map(|relation| match relation {
+            DependencyRelation::Blocking(id) => Some(id.as_str()),
+            DependencyRelation::WaitingOn(_) => None,
+        })
+        .collect();
+    if !waiting_on.is_empty() || !blocking.is_empty() || !task.linked_tasks.is_empty() {
+        println!("\n{}", "Dependencies:".bold());
+        for id in waiting_on {
+            println!("  - Waiting on {id}");
+        }
+        for id in blocking {
+            println!("  - Blocking {id}");
+        }
+        for link in &task.linked_tasks {
+            println!("  - Linked to {}", link.other_task(&task.id));
+        }
+    }
+
     if !task.checklists.is_empty() {
         println!("\n{}", "Checklists:".bold());
         for checklist in &task.checklists {
@@ -810,6 +1418,34 @@ async fn show_task(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError>
                 checklist.name.as_deref().unwrap_or(""),
                 checklist.resolved.unwrap_or(false)
             );
+            for item in checklist.items() {
+                let mark = if item.resolved.unwrap_or(false) { "x" } else { " " };
+                println!("        [{mark}] {}", item.name.as_deref().unwrap_or(""));
+            }
+        }
+    }
+
+    let subtasks = task.subtasks.as_deref().unwrap_or_default();
+    if !subtasks.is_empty() {
+        println!("\n{}", "Subtasks:".bold());
+        for (depth, node) in task_tree::flatten(&task_tree::build_tree(subtasks)) {
+            let mark = if task_tree::is_done(node.task) { "x" } else { " " };
+            println!(
+                "  {}[{mark}] {} ({}) - {}",
+                "  ".repeat(depth),
+                node.task.name.as_deref().unwrap_or(""),
+                node.task.id,
+                node.task.status.status
+            );
+        }
+    }
+
+    if !task.attachments.is_empty() {
+        println!("\n{}", "Attachments:".bold());
+        for attachment in &task.attachments {
+            let size = attachment.size.map(attachments::format_size).unwrap_or_default();
+            println!("  - {} {} ({})", attachment.file_name(), size.dimmed(), attachment.id);
+            println!("    {}", attachment.url);
         }
     }
 
@@ -818,6 +1454,55 @@ async fn show_task(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError>
     Ok(())
 }
 
+/// Show a task's subtasks at any depth
+///
+/// The task's list is fetched with subtasks and closed tasks, and the tree
+/// under the task is printed with rollups for each subtask.
+///
+/// # Arguments
+///
+/// * `source` - Where to read from: the ClickUp API or a backup
+/// * `task_id` - The ID of the parent task
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the task doesn't exist
+async fn show_subtasks(source: &impl DataSource, task_id: &str) -> Result<(), ClickUpError> {
+    let task = source.get_task(task_id).await?;
+    let mut tasks = source.get_tasks_including_closed(&task.list.id).await?.tasks;
+    if !tasks.iter().any(|listed| listed.id == task.id) {
+        tasks.push(task);
+    }
+
+    let tree = task_tree::build_tree(&tasks);
+    let Some(node) = tree.iter().find_map(|root| root.find(task_id)) else {
+        DisplayUtils::display_empty_message("subtasks");
+        return Ok(());
+    };
+    if node.children.is_empty() {
+        DisplayUtils::display_empty_message("subtasks");
+        return Ok(());
+    }
+
+    print_task_tree(std::slice::from_ref(node));
+
+    let rollup = node.rollup();
+    println!("\n{}", "Summary:".bold());
+    println!("Subtasks: {}", rollup.total);
+    println!("Done: {}/{}", rollup.done, rollup.total);
+    println!(
+        "Time estimate: {}",
+        task_tree::format_estimate(rollup.time_estimate)
+    );
+    Ok(())
+}
+
 /// Create a new task
 ///
 /// This function creates a new task with the specified parameters including
@@ -847,7 +1532,7 @@ async fn create_task(api: &ClickUpApi, params: CreateTaskParams) -> Result<(), C
         due_date: params.due_date,
         due_date_time: params.due_date.is_some().then_some(true),
         time_estimate: params.time_estimate,
-        assignees: None,
+        assignees: (!params.assignees.is_empty()).then_some(params.assignees),
         tags: None,
         parent: None,
         custom_fields: None,
@@ -920,8 +1605,9 @@ async fn update_task(api: &ClickUpApi, params: UpdateTaskParams) -> Result<(), C
 
 /// Delete a task
 ///
-/// This function permanently removes a task from ClickUp.
-/// This action cannot be undone.
+/// This function permanently removes a task from ClickUp after asking for
+/// confirmation with the task's name, unless `--yes` was given. Tasks in
+/// protected lists or spaces are never deleted.
 ///
 /// # Arguments
 ///
@@ -937,11 +1623,25 @@ async fn update_task(api: &ClickUpApi, params: UpdateTaskParams) -> Result<(), C
 /// This function can return:
 /// - `ClickUpError::NetworkError` if the API request fails
 /// - `ClickUpError::NotFoundError` if the task doesn't exist
+/// - `ClickUpError::PermissionError` if the task is in a protected list or space
+/// - `ClickUpError::IoError` if reading the confirmation fails
 async fn delete_task(api: &ClickUpApi, task_id: &str) -> Result<(), ClickUpError> {
-    api.delete_task(task_id).await?;
+    let task = api.get_task(task_id).await?;
+
+    let description = format!(
+        "task '{}' ({task_id})",
+        task.name.as_deref().unwrap_or("Unnamed")
+    );
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_fetched_task(&task).await?;
 
     println!("{}", "✓ Task deleted successfully!".green());
     println!("Deleted task ID: {task_id}");
 
     Ok(())
 }
+
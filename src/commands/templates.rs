@@ -0,0 +1,408 @@
+//! # Template Commands
+//!
+//! This module handles task templates for the ClickUp CLI, both those saved
+//! in ClickUp and local YAML templates in the config directory.
+//!
+//! ## Commands
+//!
+//! - **List**: Display the task templates of a workspace and the local templates
+//!
+//! ## Creating Tasks
+//!
+//! `tasks create --from-template` instantiates a template into a list. Local
+//! templates are matched by name or file name first; otherwise the workspace's
+//! ClickUp templates are matched by ID or name. The task name, description,
+//! status, priority, due date, time estimate and assignees given on the
+//! command line override the template.
+//!
+//! Local templates create the task, its checklists and its subtasks one
+//! request at a time. See the `template` module for the file format.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, TableBuilder, TableHeaders};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::{
+    AssigneeChanges, CreateChecklistItemRequest, CreateChecklistRequest,
+    CreateTaskFromTemplateRequest, CreateTaskRequest, TaskTemplate, TemplateOverridesRequest,
+};
+use crate::template::{self, LocalTemplate, TemplateChecklist};
+use clap::Subcommand;
+use colored::*;
+
+/// Template command variants
+///
+/// This enum defines all available template subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+    /// List ClickUp task templates and local templates
+    List {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Only list local templates
+        #[arg(long)]
+        local: bool,
+    },
+}
+
+/// Values given on the command line that override a template
+#[derive(Debug, Default, Clone)]
+pub struct TemplateOverrides {
+    /// Task name
+    pub name: Option<String>,
+    /// Task description
+    pub description: Option<String>,
+    /// Task status
+    pub status: Option<String>,
+    /// Task priority (1-4)
+    pub priority: Option<i64>,
+    /// Due date (Unix timestamp in milliseconds)
+    pub due_date: Option<i64>,
+    /// Time estimate (in milliseconds)
+    pub time_estimate: Option<i64>,
+    /// User IDs to assign
+    pub assignees: Vec<i64>,
+}
+
+impl CommandExecutor for TemplateCommands {
+    type Commands = TemplateCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            TemplateCommands::List {
+                workspace_id,
+                local,
+            } => {
+                list_templates(api, workspace_id, local).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute template commands
+///
+/// This function routes template commands to their appropriate handlers.
+///
+/// # Arguments
+///
+/// * `command` - The template command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Validation errors if no workspace is given or configured
+/// - Deserialization errors for invalid local templates
+pub async fn execute(command: TemplateCommands, config: &Config) -> Result<(), ClickUpError> {
+    TemplateCommands::execute(command, config).await
+}
+
+/// Builds the request for a task created from a local template
+///
+/// # Arguments
+///
+/// * `template` - The local template
+/// * `overrides` - Values from the command line that replace the template's
+pub fn local_task_request(
+    template: &LocalTemplate,
+    overrides: &TemplateOverrides,
+) -> CreateTaskRequest {
+    CreateTaskRequest {
+        name: overrides
+            .name
+            .clone()
+            .unwrap_or_else(|| template.task_name().to_string()),
+        description: overrides
+            .description
+            .clone()
+            .or_else(|| template.description.clone()),
+        status: overrides.status.clone().or_else(|| template.status.clone()),
+        priority: overrides.priority.or(template.priority),
+        due_date: overrides.due_date,
+        due_date_time: overrides.due_date.is_some().then_some(true),
+        time_estimate: overrides.time_estimate.or(template.time_estimate),
+        assignees: (!overrides.assignees.is_empty()).then(|| overrides.assignees.clone()),
+        tags: (!template.tags.is_empty()).then(|| template.tags.clone()),
+        parent: None,
+        custom_fields: None,
+        start_date: None,
+        start_date_time: None,
+        points: None,
+        notify_all: None,
+    }
+}
+
+/// Finds a ClickUp template by ID, or by name ignoring case
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if no template matches
+/// - `ClickUpError::ValidationError` if several templates have the name
+pub fn find_clickup_template<'a>(
+    templates: &'a [TaskTemplate],
+    reference: &str,
+) -> Result<&'a TaskTemplate, ClickUpError> {
+    let reference = reference.trim();
+    if let Some(template) = templates.iter().find(|template| template.id == reference) {
+        return Ok(template);
+    }
+    let matches: Vec<&TaskTemplate> = templates
+        .iter()
+        .filter(|template| template.name.eq_ignore_ascii_case(reference))
+        .collect();
+    match matches.as_slice() {
+        [template] => Ok(template),
+        [] => Err(ClickUpError::NotFoundError(format!(
+            "Template '{reference}' not found locally or in the workspace"
+        ))),
+        _ => Err(ClickUpError::ValidationError(format!(
+            "{} templates are named '{reference}'; use the template ID instead",
+            matches.len()
+        ))),
+    }
+}
+
+/// List ClickUp task templates and local templates
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `local_only` - Whether to skip the ClickUp templates
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::DeserializationError` if a local template is invalid
+async fn list_templates(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    local_only: bool,
+) -> Result<(), ClickUpError> {
+    let remote = if local_only {
+        Vec::new()
+    } else {
+        let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+        api.get_all_task_templates(&workspace_id).await?.templates
+    };
+    let local = template::load_templates(&template::templates_dir()?)?;
+
+    if remote.is_empty() && local.is_empty() {
+        DisplayUtils::display_empty_message("templates");
+        return Ok(());
+    }
+
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::name(),
+        "Source",
+        "Contents",
+    ]);
+    for template in &remote {
+        table_builder.add_row(vec![
+            template.id.clone(),
+            template.name.clone(),
+            "ClickUp".to_string(),
+            "-".to_string(),
+        ]);
+    }
+    for template in &local {
+        let file_name = template
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let summary = template.summary();
+        table_builder.add_row(vec![
+            file_name,
+            template.name.clone(),
+            "Local".to_string(),
+            if summary.is_empty() { "-".to_string() } else { summary },
+        ]);
+    }
+    table_builder.print();
+    Ok(())
+}
+
+/// Create a task from a ClickUp or local template
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `list_id` - The ID of the list to create the task in
+/// * `reference` - Local template name or file name, or ClickUp template ID or name
+/// * `workspace_id` - Workspace whose ClickUp templates are searched; defaults
+///   to the configured workspace
+/// * `overrides` - Values that replace the template's
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NotFoundError` if no template matches
+/// - `ClickUpError::ValidationError` if the reference is ambiguous, or no
+///   workspace is available to search
+/// - `ClickUpError::DeserializationError` if a local template is invalid
+/// - `ClickUpError::NetworkError` if an API request fails
+pub async fn create_task_from_template(
+    api: &ClickUpApi,
+    list_id: &str,
+    reference: &str,
+    workspace_id: Option<String>,
+    overrides: TemplateOverrides,
+) -> Result<(), ClickUpError> {
+    let local = template::load_templates(&template::templates_dir()?)?;
+    if let Some(template) = template::find_template(&local, reference)? {
+        return create_from_local_template(api, list_id, template, &overrides).await;
+    }
+
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let templates = api.get_all_task_templates(&workspace_id).await?.templates;
+    let template = find_clickup_template(&templates, reference)?;
+
+    let name = overrides
+        .name
+        .clone()
+        .unwrap_or_else(|| template.name.clone());
+    let task_id = api
+        .create_task_from_template(
+            list_id,
+            &template.id,
+            CreateTaskFromTemplateRequest { name },
+        )
+        .await?;
+
+    let request = TemplateOverridesRequest {
+        description: overrides.description,
+        status: overrides.status,
+        priority: overrides.priority,
+        due_date: overrides.due_date,
+        due_date_time: overrides.due_date.is_some().then_some(true),
+        time_estimate: overrides.time_estimate,
+        assignees: (!overrides.assignees.is_empty()).then(|| AssigneeChanges {
+            add: overrides.assignees,
+            rem: Vec::new(),
+        }),
+    };
+    let task = if request.is_empty() {
+        api.get_task(&task_id).await?
+    } else {
+        api.apply_template_overrides(&task_id, request).await?
+    };
+
+    println!(
+        "{}",
+        format!("✓ Task created from template '{}'!", template.name).green()
+    );
+    println!("ID: {}", task.id);
+    println!("Name: {}", task.name.as_deref().unwrap_or(""));
+    println!("Status: {}", task.status.status);
+    println!("URL: {}", task.url);
+    Ok(())
+}
+
+/// Creates a task with its checklists and subtasks from a local template
+async fn create_from_local_template(
+    api: &ClickUpApi,
+    list_id: &str,
+    template: &LocalTemplate,
+    overrides: &TemplateOverrides,
+) -> Result<(), ClickUpError> {
+    let task = api
+        .create_task(list_id, local_task_request(template, overrides))
+        .await?;
+    println!(
+        "{}",
+        format!("✓ Task created from local template '{}'!", template.name).green()
+    );
+    println!("ID: {}", task.id);
+    println!("Name: {}", task.name.as_deref().unwrap_or(""));
+    println!("URL: {}", task.url);
+
+    add_checklists(api, &task.id, &template.checklists).await?;
+
+    for subtask in &template.subtasks {
+        let request = CreateTaskRequest {
+            name: subtask.name.clone(),
+            description: subtask.description.clone(),
+            status: None,
+            priority: subtask.priority,
+            due_date: None,
+            due_date_time: None,
+            time_estimate: None,
+            assignees: None,
+            tags: (!subtask.tags.is_empty()).then(|| subtask.tags.clone()),
+            parent: Some(task.id.clone()),
+            custom_fields: None,
+            start_date: None,
+            start_date_time: None,
+            points: None,
+            notify_all: None,
+        };
+        let created = api.create_task(list_id, request).await?;
+        println!(
+            "{}",
+            format!("✓ Created subtask '{}' ({})", subtask.name, created.id).green()
+        );
+        add_checklists(api, &created.id, &subtask.checklists).await?;
+    }
+
+    println!("\n{}", "Summary:".bold());
+    println!("Subtasks: {}", template.subtasks.len());
+    println!("Checklists: {}", template.checklist_count());
+    Ok(())
+}
+
+/// Adds a template's checklists and their items to a task
+async fn add_checklists(
+    api: &ClickUpApi,
+    task_id: &str,
+    checklists: &[TemplateChecklist],
+) -> Result<(), ClickUpError> {
+    for checklist in checklists {
+        let created = api
+            .create_checklist(
+                task_id,
+                CreateChecklistRequest {
+                    name: checklist.name.clone(),
+                },
+            )
+            .await?;
+        for item in &checklist.items {
+            api.create_checklist_item(
+                &created.id,
+                CreateChecklistItemRequest {
+                    name: item.clone(),
+                    assignee: None,
+                },
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
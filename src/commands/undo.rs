@@ -0,0 +1,445 @@
+//! # Undo Commands
+//!
+//! This module reverts changes recorded in the local undo journal. Task
+//! creates, updates, moves and deletes, and comment updates (including
+//! resolving and reopening) and deletes, store the prior state of the task
+//! or comment, which is used here to restore it.
+//!
+//! Other changes, such as to checklists, dependencies and links, list
+//! membership, goals, webhooks, guests and templates, are not journaled and
+//! cannot be undone.
+//!
+//! ## Usage
+//!
+//! - `undo` reverts the most recent change
+//! - `undo --last N` reverts the N most recent changes, newest first
+//! - `undo --id <entry>` reverts a specific journal entry
+//! - `undo --list` shows the journal without changing anything
+//!
+//! ## Limitations
+//!
+//! A moved task is moved back to its previous list and gets its previous
+//! status again. Deleted tasks and comments are recreated with new IDs, and
+//! fields the API does not accept on creation (such as watchers or
+//! attachments) cannot be restored. Complex custom field values are skipped when restoring or
+//! recreating tasks, and fractional story points are left as they are because
+//! the API only accepts whole points. Restored dates keep the task's own
+//! date-only or timed setting; recreated tasks treat dates at midnight UTC
+//! as date-only.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::{ApiUtils, DisplayUtils, TableBuilder, TableHeaders};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::journal::{Journal, JournalEntry, JournalOperation};
+use crate::models::{
+    AssigneeChanges, Comment, CommentTarget, CreateCommentRequest, CreateTaskRequest, Task,
+    UpdateTaskRequest,
+};
+use clap::Args;
+use colored::*;
+
+/// Arguments for the undo command
+#[derive(Args)]
+pub struct UndoArgs {
+    /// Undo the N most recent changes that have not been undone yet
+    #[arg(short, long, conflicts_with = "id")]
+    pub last: Option<usize>,
+    /// Undo a specific journal entry by its ID
+    #[arg(short, long)]
+    pub id: Option<u64>,
+    /// List journal entries instead of undoing anything
+    #[arg(long, conflicts_with_all = ["last", "id"])]
+    pub list: bool,
+}
+
+/// Execute the undo command
+///
+/// This function loads the undo journal and either lists its entries or
+/// reverts the selected entries. Reverts are made through a client that
+/// does not journal, so undoing a change never creates a new entry.
+///
+/// # Arguments
+///
+/// * `args` - The undo command arguments
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if `--last` is zero
+/// - `ClickUpError::NotFoundError` if the requested entry doesn't exist
+/// - `ClickUpError::IoError` if the journal cannot be read or written
+pub async fn execute(args: UndoArgs, config: &Config) -> Result<(), ClickUpError> {
+    let api = ApiUtils::create_client(config)?;
+    // Read the journal directly: the client's own journal is disabled in dry-run mode
+    let journal = Journal::default_location()?;
+    let entries = journal.entries()?;
+
+    if args.list {
+        println!("Journal: {}", journal.path().display());
+        list_entries(&entries);
+        return Ok(());
+    }
+
+    let selected: Vec<JournalEntry> = match (args.id, args.last) {
+        (Some(id), _) => {
+            let entry = entries
+                .into_iter()
+                .find(|e| e.id == id)
+                .ok_or_else(|| ClickUpError::NotFoundError(format!("Journal entry {id} not found")))?;
+            if entry.undone {
+                return Err(ClickUpError::ValidationError(format!(
+                    "Journal entry {id} has already been undone"
+                )));
+            }
+            vec![entry]
+        }
+        (None, last) => {
+            let count = last.unwrap_or(1);
+            if count == 0 {
+                return Err(ClickUpError::ValidationError(
+                    "--last must be greater than 0".to_string(),
+                ));
+            }
+            entries
+                .into_iter()
+                .rev()
+                .filter(|e| !e.undone)
+                .take(count)
+                .collect()
+        }
+    };
+
+    if selected.is_empty() {
+        println!("{}", "Nothing to undo".yellow());
+        return Ok(());
+    }
+
+    let revert_api = api.without_journal();
+    let mut undone_count = 0;
+
+    for entry in &selected {
+        match revert_entry(&revert_api, entry).await {
+            // Nothing was reverted, so the entry stays in the journal as it is
+            Ok(_) | Err(ClickUpError::DryRun(_)) if config.dry_run => {
+                println!(
+                    "{}",
+                    format!("[DRY RUN] [{}] Would undo {} of {}", entry.id, entry.operation, entry.entity_id).yellow()
+                );
+            }
+            Ok(message) => {
+                journal.mark_undone(entry.id)?;
+                undone_count += 1;
+                println!("{}", format!("✓ [{}] {message}", entry.id).green());
+            }
+            Err(e) => {
+                println!(
+                    "{}",
+                    format!("✗ [{}] Failed to undo {} of {}: {e}", entry.id, entry.operation, entry.entity_id).red()
+                );
+            }
+        }
+    }
+
+    println!("\n{}", "Summary:".bold());
+    println!("  Entries selected: {}", selected.len());
+    println!("  Entries undone: {undone_count}");
+
+    Ok(())
+}
+
+/// Display journal entries in a table, newest first
+///
+/// # Arguments
+///
+/// * `entries` - The journal entries to display
+fn list_entries(entries: &[JournalEntry]) {
+    if entries.is_empty() {
+        DisplayUtils::display_empty_message("journal entries");
+        return;
+    }
+
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        "Time",
+        "Operation",
+        "Entity",
+        "Undone",
+    ]);
+
+    for entry in entries.iter().rev() {
+        table_builder.add_row(vec![
+            entry.id.to_string(),
+            entry.timestamp.clone(),
+            entry.operation.to_string(),
+            entry.entity_id.clone(),
+            if entry.undone { "Yes" } else { "No" }.to_string(),
+        ]);
+    }
+
+    table_builder.print();
+}
+
+/// Revert a single journal entry
+///
+/// # Arguments
+///
+/// * `api` - Reference to a ClickUp API client with journaling disabled
+/// * `entry` - The journal entry to revert
+///
+/// # Returns
+///
+/// Returns a short description of what was restored.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the entry has no usable prior state
+/// - Any error returned by the API while restoring
+async fn revert_entry(api: &ClickUpApi, entry: &JournalEntry) -> Result<String, ClickUpError> {
+    match entry.operation {
+        JournalOperation::TaskCreate => {
+            api.delete_task(&entry.entity_id).await?;
+            Ok(format!("Deleted created task {}", entry.entity_id))
+        }
+        JournalOperation::TaskUpdate => {
+            let previous: Task = previous_state(entry)?;
+            let current = api.get_task(&entry.entity_id).await?;
+            api.update_task(&entry.entity_id, task_restore_request(&previous))
+                .await?;
+            restore_assignees_tags_and_fields(api, &entry.entity_id, &previous, &current).await?;
+            Ok(format!(
+                "Restored task '{}' ({})",
+                previous.name.as_deref().unwrap_or("Unnamed"),
+                entry.entity_id
+            ))
+        }
+        JournalOperation::TaskDelete => {
+            let previous: Task = previous_state(entry)?;
+            let task = api
+                .create_task(&previous.list.id, CreateTaskRequest::from(&previous))
+                .await?;
+            Ok(format!(
+                "Recreated task '{}' as {} (was {})",
+                previous.name.as_deref().unwrap_or("Unnamed"),
+                task.id,
+                entry.entity_id
+            ))
+        }
+        JournalOperation::TaskMove => {
+            let previous: Task = previous_state(entry)?;
+            let list_id = entry.source_list_id.as_deref().unwrap_or(&previous.list.id);
+            api.move_task(&previous.team_id, &entry.entity_id, list_id)
+                .await?;
+            let current = api.get_task(&entry.entity_id).await?;
+            if current.status.status != previous.status.status {
+                let request = UpdateTaskRequest {
+                    name: None,
+                    description: None,
+                    status: Some(previous.status.status.clone()),
+                    priority: None,
+                    due_date: None,
+                    due_date_time: None,
+                    time_estimate: None,
+                    assignees: None,
+                    tags: None,
+                    parent: None,
+                    custom_fields: None,
+                    start_date: None,
+                    start_date_time: None,
+                    points: None,
+                    notify_all: None,
+                };
+                api.update_task(&entry.entity_id, request).await?;
+            }
+            Ok(format!(
+                "Moved task '{}' ({}) back to list {list_id}",
+                previous.name.as_deref().unwrap_or("Unnamed"),
+                entry.entity_id
+            ))
+        }
+        JournalOperation::CommentUpdate => {
+            let previous: Comment = previous_state(entry)?;
+            let (target, target_id) = comment_parent(entry)?;
+            let request = CreateCommentRequest {
+                resolved: Some(previous.resolved),
+                ..comment_restore_request(&previous)
+            };
+            api.update_comment(target, target_id, &previous, request)
+                .await?;
+            Ok(format!("Restored comment {}", entry.entity_id))
+        }
+        JournalOperation::CommentDelete => {
+            let previous: Comment = previous_state(entry)?;
+            let (target, target_id) = comment_parent(entry)?;
+            let created = api
+                .create_target_comment(target, target_id, comment_restore_request(&previous))
+                .await?;
+            // New comments are always open
+            if previous.resolved {
+                let created = Comment {
+                    id: created.id,
+                    ..previous.clone()
+                };
+                api.set_comment_resolved(target, target_id, &created, true)
+                    .await?;
+            }
+            Ok(format!(
+                "Recreated comment {} on {} {target_id}",
+                entry.entity_id,
+                target.endpoint_segment()
+            ))
+        }
+    }
+}
+
+/// Deserialize the prior state stored in a journal entry
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no prior state was recorded
+/// - `ClickUpError::DeserializationError` if the stored state is malformed
+fn previous_state<T>(entry: &JournalEntry) -> Result<T, ClickUpError>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    let previous = entry.previous.clone().ok_or_else(|| {
+        ClickUpError::ValidationError(format!(
+            "No prior state was recorded for journal entry {}",
+            entry.id
+        ))
+    })?;
+    serde_json::from_value(previous).map_err(|e| {
+        ClickUpError::DeserializationError(format!("Invalid journal entry {}: {e}", entry.id))
+    })
+}
+
+/// Return the kind and ID of the task, list or view a journaled comment is on
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the entry does not record the comment's parent
+fn comment_parent(entry: &JournalEntry) -> Result<(CommentTarget, &str), ClickUpError> {
+    let target_id = entry.parent_id.as_deref().ok_or_else(|| {
+        ClickUpError::ValidationError(
+            "Journal entry does not record the comment's task, list or view".to_string(),
+        )
+    })?;
+    Ok((entry.parent_kind.unwrap_or(CommentTarget::Task), target_id))
+}
+
+/// Parse a ClickUp millisecond timestamp string
+fn parse_millis(value: Option<&str>) -> Option<i64> {
+    value.and_then(|v| v.parse::<i64>().ok())
+}
+
+/// Restore the assignees, tags and custom field values of a task
+///
+/// The task update endpoint does not set these, so every difference between
+/// the task's current and previous state is reverted with its own request.
+///
+/// # Arguments
+///
+/// * `api` - Reference to a ClickUp API client with journaling disabled
+/// * `task_id` - The ID of the task to restore
+/// * `previous` - The task as it was before the change
+/// * `current` - The task as it is now
+///
+/// # Errors
+///
+/// This function can return any error returned by the API while restoring.
+async fn restore_assignees_tags_and_fields(
+    api: &ClickUpApi,
+    task_id: &str,
+    previous: &Task,
+    current: &Task,
+) -> Result<(), ClickUpError> {
+    let previous_ids: Vec<i64> = previous.assignees.iter().map(|a| a.id).collect();
+    let current_ids: Vec<i64> = current.assignees.iter().map(|a| a.id).collect();
+    let changes = AssigneeChanges {
+        add: previous_ids.iter().filter(|id| !current_ids.contains(id)).copied().collect(),
+        rem: current_ids.iter().filter(|id| !previous_ids.contains(id)).copied().collect(),
+    };
+    if !changes.add.is_empty() || !changes.rem.is_empty() {
+        api.update_task_assignees(task_id, changes).await?;
+    }
+
+    let previous_tags: Vec<&str> = previous.tags.iter().filter_map(|t| t.name.as_deref()).collect();
+    let current_tags: Vec<&str> = current.tags.iter().filter_map(|t| t.name.as_deref()).collect();
+    for tag in previous_tags.iter().filter(|tag| !current_tags.contains(tag)) {
+        api.add_task_tag(task_id, tag).await?;
+    }
+    for tag in current_tags.iter().filter(|tag| !previous_tags.contains(tag)) {
+        api.remove_task_tag(task_id, tag).await?;
+    }
+
+    for field in &previous.custom_fields {
+        let now = current
+            .custom_fields
+            .iter()
+            .find(|f| f.id == field.id)
+            .and_then(|f| f.value.as_ref());
+        match (&field.value, field.scalar_value()) {
+            (_, Some(value)) if now != Some(value) => {
+                api.set_custom_field_value(task_id, &field.id, value.clone())
+                    .await?;
+            }
+            (None, _) if now.is_some() => {
+                api.remove_custom_field_value(task_id, &field.id).await?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Build an update request that restores a task's previous field values
+fn task_restore_request(task: &Task) -> UpdateTaskRequest {
+    let due_date = parse_millis(task.due_date.as_deref());
+    let start_date = parse_millis(task.start_date.as_deref());
+    UpdateTaskRequest {
+        name: task.name.clone(),
+        description: Some(task.description.clone()),
+        status: Some(task.status.status.clone()),
+        priority: task.priority.as_ref().and_then(|p| p.id.parse().ok()),
+        due_date,
+        // The task keeps its own date-only or timed setting
+        due_date_time: None,
+        time_estimate: task.time_estimate,
+        assignees: None,
+        tags: None,
+        parent: None,
+        custom_fields: None,
+        start_date,
+        start_date_time: None,
+        points: task.whole_points(),
+        notify_all: None,
+    }
+}
+
+/// Build a comment request that restores a comment's previous content and assignee
+///
+/// Rich text blocks are sent when the comment has them, so formatting and
+/// mentions survive; the plain text is only sent for comments without blocks.
+fn comment_restore_request(comment: &Comment) -> CreateCommentRequest {
+    let (comment_text, blocks) = if comment.comment.is_empty() {
+        (comment.comment_text.clone(), None)
+    } else {
+        (String::new(), Some(comment.comment.clone()))
+    };
+    CreateCommentRequest {
+        comment_text,
+        comment: blocks,
+        assignee: comment.assignee_id(),
+        notify_all: None,
+        resolved: None,
+    }
+}
@@ -0,0 +1,390 @@
+//! # User Commands
+//!
+//! This module manages the members of a workspace for the ClickUp CLI.
+//!
+//! ## Commands
+//!
+//! - **List**: Display the members and guests of a workspace with their IDs and roles
+//! - **Invite**: Invite a user to a workspace by email
+//! - **Show**: Show a member's details and the items shared with them
+//! - **Remove**: Remove a member from a workspace
+//!
+//! Every command uses the configured workspace unless `--workspace-id` is
+//! given. Guests are managed with the `guests` commands.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::{
+    ApiUtils, CommandExecutor, DisplayUtils, PromptUtils, TableBuilder, TableHeaders,
+};
+use crate::commands::workspaces::print_shared_hierarchy;
+use crate::config::Config;
+use crate::dependency_graph::format_due_date;
+use crate::error::ClickUpError;
+use crate::models::{InviteUserRequest, MemberDetails, Workspace, WorkspaceMemberUser};
+use clap::Subcommand;
+use colored::*;
+
+/// User command variants
+///
+/// This enum defines all available user subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum UserCommands {
+    /// List the members of a workspace
+    List {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+    },
+    /// Invite a user to a workspace
+    Invite {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Email address to invite
+        #[arg(short, long)]
+        email: String,
+        /// Make the user an admin
+        #[arg(long)]
+        admin: bool,
+        /// Custom role ID to give the user
+        #[arg(long)]
+        custom_role_id: Option<i64>,
+    },
+    /// Show a member of a workspace
+    Show {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// User ID
+        #[arg(short, long)]
+        id: i64,
+    },
+    /// Remove a member from a workspace
+    Remove {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// User ID
+        #[arg(short, long)]
+        id: i64,
+    },
+}
+
+impl CommandExecutor for UserCommands {
+    type Commands = UserCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            UserCommands::List { workspace_id } => {
+                list_users(api, workspace_id).await?;
+            }
+            UserCommands::Invite {
+                workspace_id,
+                email,
+                admin,
+                custom_role_id,
+            } => {
+                let request = InviteUserRequest {
+                    email,
+                    admin,
+                    custom_role_id,
+                };
+                invite_user(api, workspace_id, request).await?;
+            }
+            UserCommands::Show { workspace_id, id } => {
+                show_user(api, workspace_id, id).await?;
+            }
+            UserCommands::Remove { workspace_id, id } => {
+                remove_user(api, workspace_id, id).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute user commands
+///
+/// This function routes user commands to their appropriate handlers.
+///
+/// # Arguments
+///
+/// * `command` - The user command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Permission errors if the user isn't a workspace admin
+/// - Validation errors if no workspace is given or configured
+pub async fn execute(command: UserCommands, config: &Config) -> Result<(), ClickUpError> {
+    UserCommands::execute(command, config).await
+}
+
+/// Finds the member of a workspace with an email address, ignoring case
+pub fn find_member_by_email<'a>(
+    workspace: &'a Workspace,
+    email: &str,
+) -> Option<&'a WorkspaceMemberUser> {
+    workspace
+        .members
+        .iter()
+        .map(|member| &member.user)
+        .find(|user| {
+            user.email
+                .as_deref()
+                .is_some_and(|member_email| member_email.eq_ignore_ascii_case(email.trim()))
+        })
+}
+
+/// Finds the member of a workspace with a username, ignoring case and spaces
+///
+/// Ignoring spaces lets a mention such as `@janedoe` match `Jane Doe`.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if more than one member has the username
+pub fn find_member_by_username<'a>(
+    workspace: &'a Workspace,
+    username: &str,
+) -> Result<Option<&'a WorkspaceMemberUser>, ClickUpError> {
+    let wanted = normalize_username(username);
+    if wanted.is_empty() {
+        return Ok(None);
+    }
+    let mut matches = workspace.members.iter().map(|member| &member.user).filter(|user| {
+        user.username
+            .as_deref()
+            .is_some_and(|name| normalize_username(name) == wanted)
+    });
+    let found = matches.next();
+    if found.is_some() && matches.next().is_some() {
+        return Err(ClickUpError::ValidationError(format!(
+            "More than one member of the workspace is named '{username}'; use their user ID"
+        )));
+    }
+    Ok(found)
+}
+
+/// Lowercases a username and removes its whitespace for comparison
+fn normalize_username(username: &str) -> String {
+    username
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Prints a member's or guest's details and the items shared with them
+///
+/// # Arguments
+///
+/// * `item_type` - Heading for the details, e.g. `User` or `Guest`
+/// * `details` - The member or guest
+pub fn print_member_details(item_type: &str, details: &MemberDetails) {
+    let user = &details.user;
+    DisplayUtils::display_details_header(item_type);
+    println!(
+        "ID: {}",
+        user.id.map(|id| id.to_string()).unwrap_or_default()
+    );
+    println!("Username: {}", user.username.as_deref().unwrap_or("Unknown user"));
+    println!("Email: {}", user.email.as_deref().unwrap_or("None"));
+    println!("Role: {}", user.role_name());
+    if let Some(status) = &user.status {
+        println!("Status: {status}");
+    }
+    for (label, timestamp) in [
+        ("Invited", &user.date_invited),
+        ("Joined", &user.date_joined),
+        ("Last active", &user.last_active),
+    ] {
+        if let Some(date) = timestamp
+            .as_deref()
+            .and_then(|timestamp| timestamp.parse().ok())
+            .and_then(format_due_date)
+        {
+            println!("{label}: {date}");
+        }
+    }
+    if let Some(invited_by) = &details.invited_by {
+        println!(
+            "Invited by: {}",
+            invited_by
+                .username
+                .as_deref()
+                .or(invited_by.email.as_deref())
+                .unwrap_or("Unknown user")
+        );
+    }
+
+    if let Some(shared) = details.shared.as_ref().filter(|shared| !shared.is_empty()) {
+        DisplayUtils::display_section_header("Shared");
+        print_shared_hierarchy(shared);
+    }
+}
+
+/// List the members of a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn list_users(api: &ClickUpApi, workspace_id: Option<String>) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let workspace = api.get_workspace(&workspace_id).await?;
+
+    if workspace.members.is_empty() {
+        DisplayUtils::display_empty_message("members");
+        return Ok(());
+    }
+
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::user(),
+        TableHeaders::email(),
+        TableHeaders::role(),
+    ]);
+    for member in &workspace.members {
+        let user = &member.user;
+        table_builder.add_row(vec![
+            user.id.map(|id| id.to_string()).unwrap_or_default(),
+            user.username.as_deref().unwrap_or("Unknown user").to_string(),
+            user.email.as_deref().unwrap_or("").to_string(),
+            user.role_name(),
+        ]);
+    }
+    table_builder.print();
+    Ok(())
+}
+
+/// Invite a user to a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `request` - The email address and role of the user
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::PermissionError` if the user isn't a workspace admin
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn invite_user(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    request: InviteUserRequest,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let email = request.email.clone();
+    let response = api.invite_user(&workspace_id, request).await?;
+
+    println!("{}", format!("✓ Invited {email}!").green());
+    if let Some(user) = find_member_by_email(&response.team, &email) {
+        if let Some(id) = user.id {
+            println!("User ID: {id}");
+        }
+        println!("Role: {}", user.role_name());
+    }
+    Ok(())
+}
+
+/// Show a member of a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `user_id` - The ID of the user
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NotFoundError` if the user isn't a member
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn show_user(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    user_id: i64,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let response = api.get_workspace_user(&workspace_id, user_id).await?;
+    print_member_details("User", &response.member);
+    Ok(())
+}
+
+/// Remove a member from a workspace
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `user_id` - The ID of the user to remove
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful removal, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NotFoundError` if the user isn't a member
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn remove_user(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    user_id: i64,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let member = api.get_workspace_user(&workspace_id, user_id).await?.member;
+    let description = match member.user.email.as_deref() {
+        Some(email) => format!("user {user_id} ({email}) from workspace {workspace_id}"),
+        None => format!("user {user_id} from workspace {workspace_id}"),
+    };
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.remove_workspace_user(&workspace_id, user_id).await?;
+
+    println!("{}", "✓ User removed from workspace!".green());
+    println!("Removed user ID: {user_id}");
+    Ok(())
+}
@@ -9,6 +9,7 @@
 //! - Command execution flow (`CommandExecutor` trait)
 //! - Table creation and formatting (`TableBuilder`)
 //! - Display formatting (`DisplayUtils`)
+//! - Confirmation prompts (`PromptUtils`)
 //! - Error handling (`ErrorUtils`)
 //! - API client creation (`ApiUtils`)
 //! - Table header constants (`TableHeaders`)
@@ -380,6 +381,64 @@ impl DisplayUtils {
     }
 }
 
+/// Prompt utilities for confirming destructive operations
+///
+/// This struct provides a standard yes/no confirmation used before deletes.
+/// Prompts are skipped when `--yes` is given, and in `--dry-run` mode since
+/// nothing will actually be changed.
+///
+/// ## Usage Pattern
+///
+/// ```rust
+/// use clickup_cli::commands::utils::PromptUtils;
+/// use clickup_cli::config::Config;
+/// use clickup_cli::error::ClickUpError;
+///
+/// fn example() -> Result<(), ClickUpError> {
+///     let config = Config {
+///         assume_yes: true,
+///         ..Default::default()
+///     };
+///     assert!(PromptUtils::confirm_delete(&config, "task 'Write docs'")?);
+///     Ok(())
+/// }
+/// ```
+pub struct PromptUtils;
+
+impl PromptUtils {
+    /// Ask the user to confirm deleting an entity
+    ///
+    /// Anything other than `y` or `yes` (including end of input) is treated
+    /// as a refusal.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Reference to the application configuration
+    /// * `description` - Human-readable description of what will be deleted
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the delete should go ahead.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if reading the answer fails
+    pub fn confirm_delete(config: &Config, description: &str) -> Result<bool, ClickUpError> {
+        if config.assume_yes || config.dry_run {
+            return Ok(true);
+        }
+
+        print!("Delete {description}? [y/N]: ");
+        std::io::Write::flush(&mut std::io::stdout())?;
+
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        let answer = input.trim().to_lowercase();
+        Ok(answer == "y" || answer == "yes")
+    }
+}
+
 /// Error utilities for consistent error handling
 ///
 /// This struct provides standardized methods for creating common error types
@@ -427,6 +486,17 @@ impl ErrorUtils {
     pub fn not_found_error(item_type: &str, id: &str) -> ClickUpError {
         ClickUpError::NotFoundError(format!("{} {} not found", item_type, id))
     }
+
+    /// Create the error for a command that can't answer from a backup
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ClickUpError::ValidationError`
+    pub fn offline_error() -> ClickUpError {
+        ClickUpError::ValidationError(
+            "This command needs ClickUp and can't be used with --offline".to_string(),
+        )
+    }
     
 
 }
@@ -470,6 +540,30 @@ impl ApiUtils {
     pub fn create_client(config: &Config) -> Result<ClickUpApi, ClickUpError> {
         ClickUpApi::new(config.clone())
     }
+
+    /// Returns the workspace ID from the argument or the configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - Reference to the ClickUp API client
+    /// * `workspace_id` - Workspace ID given on the command line, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClickUpError::ValidationError` if neither is set
+    pub fn resolve_workspace_id(
+        api: &ClickUpApi,
+        workspace_id: Option<String>,
+    ) -> Result<String, ClickUpError> {
+        workspace_id
+            .or_else(|| api.config().workspace_id.clone())
+            .ok_or_else(|| {
+                ClickUpError::ValidationError(
+                    "No workspace ID given. Use --workspace-id or set workspace_id in the configuration"
+                        .to_string(),
+                )
+            })
+    }
 }
 
 /// Common table headers for consistent column names
@@ -586,4 +680,34 @@ impl TableHeaders {
     pub fn multiple_assignees() -> &'static str {
         "Multiple Assignees"
     }
+    
+    /// Get standard Endpoint header
+    pub fn endpoint() -> &'static str {
+        "Endpoint"
+    }
+    
+    /// Get standard Events header
+    pub fn events() -> &'static str {
+        "Events"
+    }
+    
+    /// Get standard Location header
+    pub fn location() -> &'static str {
+        "Location"
+    }
+    
+    /// Get standard Health header
+    pub fn health() -> &'static str {
+        "Health"
+    }
+    
+    /// Get standard Email header
+    pub fn email() -> &'static str {
+        "Email"
+    }
+    
+    /// Get standard Role header
+    pub fn role() -> &'static str {
+        "Role"
+    }
 } 
\ No newline at end of file
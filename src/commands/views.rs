@@ -0,0 +1,385 @@
+//! # View Commands
+//!
+//! This module handles saved views for the ClickUp CLI.
+//!
+//! ## Commands
+//!
+//! - **List**: Display the views of a workspace, space, folder or list
+//! - **Show**: Show a view's type, location, filters, sorting and grouping
+//! - **Tasks**: Display the tasks a view resolves to
+//!
+//! ## Reusing Filters
+//!
+//! ClickUp applies a view's saved filters and sorting when its tasks are
+//! requested, so `views tasks` returns the same tasks the view shows in the
+//! web app without rebuilding the filters as command-line flags.
+
+use crate::api::ClickUpApi;
+use crate::commands::tasks::print_task_table;
+use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, TableBuilder, TableHeaders};
+use crate::config::Config;
+use crate::dependency_graph::format_due_date;
+use crate::error::ClickUpError;
+use crate::models::{View, ViewParentKind};
+use clap::Subcommand;
+use colored::*;
+use serde_json::Value;
+
+/// View command variants
+///
+/// This enum defines all available view subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum ViewCommands {
+    /// List views of a workspace, space, folder or list
+    ///
+    /// Without a location, the views of the configured workspace are listed.
+    List {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long, conflicts_with_all = ["space_id", "folder_id", "list_id"])]
+        workspace_id: Option<String>,
+        /// List the views of this space
+        #[arg(long, conflicts_with_all = ["folder_id", "list_id"])]
+        space_id: Option<String>,
+        /// List the views of this folder
+        #[arg(long, conflicts_with = "list_id")]
+        folder_id: Option<String>,
+        /// List the views of this list
+        #[arg(long)]
+        list_id: Option<String>,
+    },
+    /// Show a view's filters, sorting and grouping
+    Show {
+        /// View ID
+        #[arg(short, long)]
+        id: String,
+    },
+    /// List the tasks a view resolves to
+    Tasks {
+        /// View ID
+        #[arg(short, long)]
+        id: String,
+        /// Fetch only this page (30 tasks per page, starting at 0)
+        #[arg(short, long)]
+        page: Option<u32>,
+    },
+}
+
+impl CommandExecutor for ViewCommands {
+    type Commands = ViewCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            ViewCommands::List {
+                workspace_id,
+                space_id,
+                folder_id,
+                list_id,
+            } => {
+                let (kind, parent_id) = if let Some(list_id) = list_id {
+                    (ViewParentKind::List, list_id)
+                } else if let Some(folder_id) = folder_id {
+                    (ViewParentKind::Folder, folder_id)
+                } else if let Some(space_id) = space_id {
+                    (ViewParentKind::Space, space_id)
+                } else {
+                    (
+                        ViewParentKind::Workspace,
+                        ApiUtils::resolve_workspace_id(api, workspace_id)?,
+                    )
+                };
+                list_views(api, kind, &parent_id).await?;
+            }
+            ViewCommands::Show { id } => {
+                show_view(api, &id).await?;
+            }
+            ViewCommands::Tasks { id, page } => {
+                list_view_tasks(api, &id, page).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute view commands
+///
+/// This function routes view commands to their appropriate handlers.
+///
+/// # Arguments
+///
+/// * `command` - The view command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Validation errors if no workspace is given or configured
+/// - Not found errors for missing views or locations
+pub async fn execute(command: ViewCommands, config: &Config) -> Result<(), ClickUpError> {
+    ViewCommands::execute(command, config).await
+}
+
+/// Describes a view's filters, one line per condition
+///
+/// Conditions are joined with the filter's operator, and the search text and
+/// closed task setting are listed after them.
+pub fn describe_filters(filters: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
+    let join = filters
+        .get("op")
+        .and_then(Value::as_str)
+        .unwrap_or("AND")
+        .to_uppercase();
+
+    if let Some(fields) = filters.get("fields").and_then(Value::as_array) {
+        for (index, field) in fields.iter().enumerate() {
+            let name = field.get("field").and_then(Value::as_str).unwrap_or("?");
+            let op = field.get("op").and_then(Value::as_str).unwrap_or("");
+            let values = match field.get("values") {
+                Some(Value::Array(values)) => values
+                    .iter()
+                    .map(display_value)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Some(Value::Null) | None => String::new(),
+                Some(value) => display_value(value),
+            };
+            let condition = format!("{name} {op} {values}").trim().to_string();
+            if index == 0 {
+                lines.push(condition);
+            } else {
+                lines.push(format!("{join} {condition}"));
+            }
+        }
+    }
+
+    if let Some(search) = filters
+        .get("search")
+        .and_then(Value::as_str)
+        .filter(|search| !search.is_empty())
+    {
+        lines.push(format!("search \"{search}\""));
+    }
+    match filters.get("show_closed").and_then(Value::as_bool) {
+        Some(true) => lines.push("closed tasks shown".to_string()),
+        Some(false) => lines.push("closed tasks hidden".to_string()),
+        None => {}
+    }
+    lines
+}
+
+/// Describes a view's sort order, e.g. `dueDate ascending, priority descending`
+pub fn describe_sorting(sorting: &Value) -> Option<String> {
+    let fields = sorting.get("fields").and_then(Value::as_array)?;
+    let described: Vec<String> = fields
+        .iter()
+        .filter_map(|field| {
+            let name = field.get("field").and_then(Value::as_str)?;
+            let direction = match field.get("dir").and_then(Value::as_i64) {
+                Some(dir) if dir < 0 => "descending",
+                _ => "ascending",
+            };
+            Some(format!("{name} {direction}"))
+        })
+        .collect();
+    (!described.is_empty()).then(|| described.join(", "))
+}
+
+/// Describes how a view groups its tasks, or `None` when it doesn't
+pub fn describe_grouping(grouping: &Value) -> Option<String> {
+    grouping
+        .get("field")
+        .and_then(Value::as_str)
+        .filter(|field| !field.is_empty() && *field != "none")
+        .map(str::to_string)
+}
+
+/// Formats a filter value for display
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Describes where a view lives, e.g. `space 790`
+fn view_location(view: &View) -> String {
+    match &view.parent {
+        Some(parent) => match parent.kind() {
+            Some(kind) => format!("{} {}", kind.as_str(), parent.id),
+            None => parent.id.clone(),
+        },
+        None => "-".to_string(),
+    }
+}
+
+/// List the views of a workspace, space, folder or list
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `kind` - The kind of location to list views for
+/// * `parent_id` - The ID of the workspace, space, folder or list
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the location doesn't exist
+async fn list_views(
+    api: &ClickUpApi,
+    kind: ViewParentKind,
+    parent_id: &str,
+) -> Result<(), ClickUpError> {
+    let views = api.get_views(kind, parent_id).await?.all_views();
+
+    if views.is_empty() {
+        DisplayUtils::display_empty_message("views");
+        return Ok(());
+    }
+
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::name(),
+        "Type",
+        TableHeaders::location(),
+        "Visibility",
+    ]);
+    for view in &views {
+        table_builder.add_row(vec![
+            view.id.clone(),
+            view.name.clone(),
+            view.type_.clone(),
+            view_location(view),
+            view.visibility.clone().unwrap_or_else(|| "-".to_string()),
+        ]);
+    }
+    table_builder.print();
+    Ok(())
+}
+
+/// Show a view's details
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `view_id` - The ID of the view to show
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the view doesn't exist
+async fn show_view(api: &ClickUpApi, view_id: &str) -> Result<(), ClickUpError> {
+    let view = api.get_view(view_id).await?;
+
+    DisplayUtils::display_details_header("View");
+    println!("ID: {}", view.id);
+    println!("Name: {}", view.name);
+    println!("Type: {}", view.type_);
+    println!("Location: {}", view_location(&view));
+    if let Some(visibility) = &view.visibility {
+        println!("Visibility: {visibility}");
+    }
+    if view.protected == Some(true) {
+        println!("{}", "Protected".yellow());
+    }
+    if let Some(created) = view
+        .date_created
+        .as_deref()
+        .and_then(|date| date.parse::<i64>().ok())
+        .and_then(format_due_date)
+    {
+        println!("Created: {created}");
+    }
+
+    let filters = view
+        .filters
+        .as_ref()
+        .map(describe_filters)
+        .unwrap_or_default();
+    DisplayUtils::display_section_header("Filters");
+    if filters.is_empty() {
+        println!("  None");
+    }
+    for line in filters {
+        println!("  {line}");
+    }
+    if let Some(sorting) = view.sorting.as_ref().and_then(describe_sorting) {
+        println!("\nSorted by: {sorting}");
+    }
+    if let Some(grouping) = view.grouping.as_ref().and_then(describe_grouping) {
+        println!("Grouped by: {grouping}");
+    }
+    Ok(())
+}
+
+/// List the tasks a view resolves to
+///
+/// Without a page, every page is fetched. With a page, only that page is
+/// shown along with a hint when more pages follow.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `view_id` - The ID of the view
+/// * `page` - Optional page to fetch, starting at 0
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the view doesn't exist
+async fn list_view_tasks(
+    api: &ClickUpApi,
+    view_id: &str,
+    page: Option<u32>,
+) -> Result<(), ClickUpError> {
+    let (tasks, next_page) = match page {
+        Some(page) => {
+            let response = api.get_view_tasks(view_id, page).await?;
+            let next_page = (!response.last_page).then_some(page + 1);
+            (response.tasks, next_page)
+        }
+        None => (api.get_all_view_tasks(view_id).await?.tasks, None),
+    };
+
+    if tasks.is_empty() {
+        DisplayUtils::display_empty_message("tasks");
+        return Ok(());
+    }
+
+    print_task_table(&tasks);
+    println!("\n{} {}", "Tasks:".bold(), tasks.len());
+    if let Some(next_page) = next_page {
+        println!(
+            "{}",
+            format!("More tasks available. Use --page {next_page} for the next page").yellow()
+        );
+    }
+    Ok(())
+}
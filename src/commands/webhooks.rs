@@ -0,0 +1,657 @@
+//! # Webhook Commands
+//!
+//! This module handles webhook management and the local webhook receiver for
+//! the ClickUp CLI.
+//!
+//! ## Commands
+//!
+//! - **List**: Display the webhooks registered in a workspace
+//! - **Create**: Register a webhook for an endpoint
+//! - **Update**: Change a webhook's endpoint or events, or re-enable it
+//! - **Delete**: Remove a webhook
+//! - **Listen**: Run a local HTTP server that receives webhook deliveries
+//!
+//! ## Listening
+//!
+//! `webhooks listen` verifies each delivery's `X-Signature` with the webhook
+//! secret, decodes the event, and prints it or forwards it to a shell command.
+//! ClickUp must be able to reach the receiver, so it is usually run behind a
+//! reverse proxy or tunnel whose public URL is the webhook's endpoint.
+//!
+//! The secret is taken from `--secret`, the `CLICKUP_WEBHOOK_SECRET`
+//! environment variable, or looked up with `--webhook-id`.
+
+use crate::api::ClickUpApi;
+use crate::commands::utils::{
+    ApiUtils, CommandExecutor, DisplayUtils, PromptUtils, TableBuilder, TableHeaders,
+};
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::{
+    CreateWebhookRequest, UpdateWebhookRequest, Webhook, WebhookEvent, WebhookEventType,
+};
+use crate::webhook::{self, Delivery, WebhookReceiver};
+use clap::Subcommand;
+use colored::*;
+
+/// Environment variable holding the secret for `webhooks listen`
+pub const WEBHOOK_SECRET_ENV: &str = "CLICKUP_WEBHOOK_SECRET";
+
+/// Webhook command variants
+///
+/// This enum defines all available webhook subcommands with their
+/// associated parameters and help text.
+#[derive(Subcommand)]
+pub enum WebhookCommands {
+    /// List webhooks in a workspace
+    List {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+    },
+    /// Create a webhook
+    Create {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// URL that receives the events
+        #[arg(short, long)]
+        endpoint: String,
+        /// Events to subscribe to, comma-separated (default: all events)
+        #[arg(long, value_delimiter = ',', default_value = "*")]
+        events: Vec<String>,
+        /// Only send events for this space
+        #[arg(long)]
+        space_id: Option<String>,
+        /// Only send events for this folder
+        #[arg(long)]
+        folder_id: Option<String>,
+        /// Only send events for this list
+        #[arg(long)]
+        list_id: Option<String>,
+        /// Only send events for this task
+        #[arg(long)]
+        task_id: Option<String>,
+    },
+    /// Update a webhook
+    Update {
+        /// Webhook ID
+        #[arg(short, long)]
+        id: String,
+        /// New endpoint URL
+        #[arg(short, long)]
+        endpoint: Option<String>,
+        /// New events, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        events: Option<Vec<String>>,
+        /// Re-enable a webhook that ClickUp suspended after failed deliveries
+        #[arg(long)]
+        activate: bool,
+    },
+    /// Delete a webhook
+    Delete {
+        /// Webhook ID
+        #[arg(short, long)]
+        id: String,
+    },
+    /// Receive webhook deliveries on a local port
+    ///
+    /// Each verified event is printed, or passed to `--exec` with the JSON
+    /// payload on stdin and CLICKUP_WEBHOOK_EVENT, CLICKUP_TASK_ID, etc. set.
+    /// Stop with Ctrl-C.
+    Listen {
+        /// Port to listen on
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+        /// Interface to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Webhook secret for verifying signatures
+        #[arg(long)]
+        secret: Option<String>,
+        /// Look up the secret of this webhook
+        #[arg(long)]
+        webhook_id: Option<String>,
+        /// Workspace of `--webhook-id` (defaults to the configured workspace)
+        #[arg(short, long)]
+        workspace_id: Option<String>,
+        /// Accept deliveries without verifying signatures
+        #[arg(long)]
+        no_verify: bool,
+        /// Only handle these events, comma-separated
+        #[arg(long = "event", value_delimiter = ',')]
+        events: Vec<String>,
+        /// Shell command to run for each event
+        #[arg(long)]
+        exec: Option<String>,
+        /// Print each event as a line of JSON
+        #[arg(long)]
+        json: bool,
+        /// Stop after this many events
+        #[arg(long)]
+        max_events: Option<usize>,
+    },
+}
+
+impl CommandExecutor for WebhookCommands {
+    type Commands = WebhookCommands;
+
+    async fn execute(command: Self::Commands, config: &Config) -> Result<(), ClickUpError> {
+        let api = ApiUtils::create_client(config)?;
+        Self::handle_command(command, &api).await
+    }
+
+    async fn handle_command(command: Self::Commands, api: &ClickUpApi) -> Result<(), ClickUpError> {
+        match command {
+            WebhookCommands::List { workspace_id } => {
+                list_webhooks(api, workspace_id).await?;
+            }
+            WebhookCommands::Create {
+                workspace_id,
+                endpoint,
+                events,
+                space_id,
+                folder_id,
+                list_id,
+                task_id,
+            } => {
+                let request = CreateWebhookRequest {
+                    endpoint,
+                    events,
+                    space_id,
+                    folder_id,
+                    list_id,
+                    task_id,
+                };
+                create_webhook(api, workspace_id, request).await?;
+            }
+            WebhookCommands::Update {
+                id,
+                endpoint,
+                events,
+                activate,
+            } => {
+                let request = UpdateWebhookRequest {
+                    endpoint,
+                    events,
+                    status: activate.then(|| "active".to_string()),
+                };
+                update_webhook(api, &id, request).await?;
+            }
+            WebhookCommands::Delete { id } => {
+                delete_webhook(api, &id).await?;
+            }
+            WebhookCommands::Listen {
+                port,
+                host,
+                secret,
+                webhook_id,
+                workspace_id,
+                no_verify,
+                events,
+                exec,
+                json,
+                max_events,
+            } => {
+                let secret = if no_verify {
+                    None
+                } else {
+                    Some(resolve_secret(api, secret, webhook_id, workspace_id).await?)
+                };
+                let options = ListenOptions {
+                    events: parse_events(&events)?,
+                    exec,
+                    json,
+                    max_events,
+                };
+                listen(&host, port, secret, options).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Execute webhook commands
+///
+/// This function routes webhook commands to their appropriate handlers.
+///
+/// # Arguments
+///
+/// * `command` - The webhook command to execute
+/// * `config` - Reference to the application configuration
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful execution, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return various errors including:
+/// - Network errors when communicating with the API
+/// - Authentication errors if not properly authenticated
+/// - Validation errors for unknown event names or a missing secret
+/// - IO errors if the listener port cannot be bound
+pub async fn execute(command: WebhookCommands, config: &Config) -> Result<(), ClickUpError> {
+    WebhookCommands::execute(command, config).await
+}
+
+/// Options for `webhooks listen`
+struct ListenOptions {
+    /// Events to handle; empty handles all events
+    events: Vec<WebhookEventType>,
+    /// Shell command to run for each event
+    exec: Option<String>,
+    /// Whether to print events as JSON lines
+    json: bool,
+    /// Stop after this many handled events
+    max_events: Option<usize>,
+}
+
+/// Parses and validates event names
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if an event name is unknown
+fn parse_events(names: &[String]) -> Result<Vec<WebhookEventType>, ClickUpError> {
+    names
+        .iter()
+        .map(|name| {
+            WebhookEventType::from_name(name.trim()).ok_or_else(|| {
+                ClickUpError::ValidationError(format!(
+                    "Unknown webhook event '{name}'. Known events: {}",
+                    WebhookEventType::ALL.map(|e| e.as_str()).join(", ")
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Checks event names for `create` and `update`, allowing `*` for all events
+fn validate_subscription(events: &[String]) -> Result<(), ClickUpError> {
+    let named: Vec<String> = events.iter().filter(|e| e.as_str() != "*").cloned().collect();
+    parse_events(&named).map(|_| ())
+}
+
+/// Describes the location a webhook is limited to
+fn webhook_location(webhook: &Webhook) -> String {
+    [
+        ("task", &webhook.task_id),
+        ("list", &webhook.list_id),
+        ("folder", &webhook.folder_id),
+        ("space", &webhook.space_id),
+    ]
+    .into_iter()
+    .find_map(|(kind, id)| id.as_ref().map(|id| format!("{kind} {id}")))
+    .unwrap_or_else(|| "workspace".to_string())
+}
+
+/// List the webhooks in a workspace
+///
+/// This function retrieves the webhooks registered in a workspace and
+/// displays them with their events, location filter and delivery health.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful listing, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn list_webhooks(api: &ClickUpApi, workspace_id: Option<String>) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let webhooks = api.get_webhooks(&workspace_id).await?;
+
+    if webhooks.webhooks.is_empty() {
+        DisplayUtils::display_empty_message("webhooks");
+        return Ok(());
+    }
+
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::endpoint(),
+        TableHeaders::events(),
+        TableHeaders::location(),
+        TableHeaders::health(),
+    ]);
+
+    for webhook in &webhooks.webhooks {
+        let health = match &webhook.health {
+            Some(health) => format!(
+                "{} ({} failures)",
+                health.status.as_deref().unwrap_or("unknown"),
+                health.fail_count.unwrap_or(0)
+            ),
+            None => "-".to_string(),
+        };
+        table_builder.add_row(vec![
+            webhook.id.clone(),
+            webhook.endpoint.clone(),
+            webhook.events.join(", "),
+            webhook_location(webhook),
+            health,
+        ]);
+    }
+
+    table_builder.print();
+    Ok(())
+}
+
+/// Create a webhook
+///
+/// This function registers a webhook for the given endpoint and prints its
+/// secret, which `webhooks listen` needs to verify deliveries.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+/// * `request` - The endpoint, events and optional location filter
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful creation, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if an event name is unknown or no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn create_webhook(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+    request: CreateWebhookRequest,
+) -> Result<(), ClickUpError> {
+    validate_subscription(&request.events)?;
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+
+    let response = api.create_webhook(&workspace_id, request).await?;
+
+    println!("{}", "✓ Webhook created successfully!".green());
+    println!("ID: {}", response.id);
+    println!("Endpoint: {}", response.webhook.endpoint);
+    println!("Events: {}", response.webhook.events.join(", "));
+    if let Some(secret) = &response.webhook.secret {
+        println!("Secret: {secret}");
+        println!(
+            "Use it with 'clickup-cli webhooks listen --secret <secret>' or {WEBHOOK_SECRET_ENV}"
+        );
+    }
+    Ok(())
+}
+
+/// Update a webhook
+///
+/// This function changes a webhook's endpoint or events, or re-enables a
+/// webhook that ClickUp suspended after repeated delivery failures.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `webhook_id` - The ID of the webhook to update
+/// * `request` - The fields to update
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful update, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if nothing is updated or an event name is unknown
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the webhook doesn't exist
+async fn update_webhook(
+    api: &ClickUpApi,
+    webhook_id: &str,
+    request: UpdateWebhookRequest,
+) -> Result<(), ClickUpError> {
+    if request.endpoint.is_none() && request.events.is_none() && request.status.is_none() {
+        return Err(ClickUpError::ValidationError(
+            "Nothing to update. Use --endpoint, --events or --activate".to_string(),
+        ));
+    }
+    if let Some(events) = &request.events {
+        validate_subscription(events)?;
+    }
+
+    let response = api.update_webhook(webhook_id, request).await?;
+
+    println!("{}", "✓ Webhook updated successfully!".green());
+    println!("Endpoint: {}", response.webhook.endpoint);
+    println!("Events: {}", response.webhook.events.join(", "));
+    Ok(())
+}
+
+/// Delete a webhook
+///
+/// This function removes a webhook after asking for confirmation, unless
+/// `--yes` was given.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `webhook_id` - The ID of the webhook to delete
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful deletion, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::NetworkError` if the API request fails
+/// - `ClickUpError::NotFoundError` if the webhook doesn't exist
+/// - `ClickUpError::IoError` if reading the confirmation fails
+async fn delete_webhook(api: &ClickUpApi, webhook_id: &str) -> Result<(), ClickUpError> {
+    let description = format!("webhook {webhook_id}");
+    if !PromptUtils::confirm_delete(api.config(), &description)? {
+        println!("{}", "Deletion cancelled".yellow());
+        return Ok(());
+    }
+
+    api.delete_webhook(webhook_id).await?;
+
+    println!("{}", "✓ Webhook deleted successfully!".green());
+    println!("Deleted webhook ID: {webhook_id}");
+    Ok(())
+}
+
+/// Finds the secret used to verify deliveries
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no secret is available
+/// - `ClickUpError::NotFoundError` if `--webhook-id` doesn't match a webhook
+async fn resolve_secret(
+    api: &ClickUpApi,
+    secret: Option<String>,
+    webhook_id: Option<String>,
+    workspace_id: Option<String>,
+) -> Result<String, ClickUpError> {
+    if let Some(secret) = secret.or_else(|| std::env::var(WEBHOOK_SECRET_ENV).ok()) {
+        if !secret.trim().is_empty() {
+            return Ok(secret);
+        }
+    }
+    let Some(webhook_id) = webhook_id else {
+        return Err(ClickUpError::ValidationError(format!(
+            "A webhook secret is needed to verify deliveries. Use --secret, {WEBHOOK_SECRET_ENV} or --webhook-id, or --no-verify to skip verification"
+        )));
+    };
+
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let webhooks = api.get_webhooks(&workspace_id).await?;
+    webhooks
+        .webhooks
+        .into_iter()
+        .find(|w| w.id == webhook_id)
+        .ok_or_else(|| ClickUpError::NotFoundError(format!("Webhook '{webhook_id}' not found")))?
+        .secret
+        .ok_or_else(|| {
+            ClickUpError::ValidationError(format!(
+                "Webhook '{webhook_id}' has no secret; use --secret"
+            ))
+        })
+}
+
+/// Summarizes a history item value for display
+fn summarize_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "none".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Object(map) => ["status", "username", "name", "text"]
+            .iter()
+            .find_map(|key| map.get(*key).and_then(|v| v.as_str()))
+            .map(str::to_string)
+            .unwrap_or_else(|| value.to_string()),
+        other => other.to_string(),
+    }
+}
+
+/// Formats a one-line description of an event
+fn describe_event(event: &WebhookEvent) -> String {
+    let subject = [
+        ("task", &event.task_id),
+        ("list", &event.list_id),
+        ("folder", &event.folder_id),
+        ("space", &event.space_id),
+        ("goal", &event.goal_id),
+        ("key result", &event.key_result_id),
+    ]
+    .into_iter()
+    .find_map(|(kind, id)| id.as_ref().map(|id| format!("{kind} {id}")))
+    .unwrap_or_default();
+
+    let mut line = format!("{} {subject}", event.event.as_str().bold());
+    for item in &event.history_items {
+        if let Some(field) = &item.field {
+            let before = item.before.as_ref().map(summarize_value);
+            let after = item.after.as_ref().map(summarize_value);
+            match (before, after) {
+                (Some(before), Some(after)) => line.push_str(&format!(" {field}: {before} → {after}")),
+                (None, Some(after)) => line.push_str(&format!(" {field}: {after}")),
+                _ => line.push_str(&format!(" {field}")),
+            }
+        }
+        if let Some(username) = item.user.as_ref().and_then(|u| u.username.as_ref()) {
+            line.push_str(&format!(" (by {username})"));
+        }
+    }
+    line
+}
+
+/// Receive webhook deliveries until stopped
+///
+/// This function runs the webhook receiver, printing each verified event or
+/// forwarding it to a shell command. Rejected deliveries are reported and
+/// skipped. The receiver stops on Ctrl-C or after `max_events` events.
+///
+/// # Arguments
+///
+/// * `host` - The interface to listen on
+/// * `port` - The port to listen on
+/// * `secret` - The webhook secret, or `None` to skip verification
+/// * `options` - Event filter, output format and forwarding command
+///
+/// # Returns
+///
+/// Returns `Ok(())` when the receiver stops, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::IoError` if the address cannot be bound
+async fn listen(
+    host: &str,
+    port: u16,
+    secret: Option<String>,
+    options: ListenOptions,
+) -> Result<(), ClickUpError> {
+    let verify = secret.is_some();
+    let receiver = WebhookReceiver::bind(host, port, secret).await?;
+    // Keep stdout to one JSON object per line in JSON mode
+    let status = |line: String| {
+        if options.json {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+    status(
+        format!("Listening for webhooks on http://{}", receiver.local_addr()?)
+            .green()
+            .to_string(),
+    );
+    if !verify {
+        status(
+            "⚠ Signature verification is disabled; any client can send events"
+                .yellow()
+                .to_string(),
+        );
+    }
+    status("Press Ctrl-C to stop".to_string());
+
+    let mut handled = 0usize;
+    let mut rejected = 0usize;
+    while options.max_events.is_none_or(|max| handled < max) {
+        let delivery = tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            delivery = receiver.next() => delivery,
+        };
+        let (event, payload) = match delivery {
+            Ok(Delivery::Event { event, payload }) => (event, payload),
+            Ok(Delivery::Rejected { reason }) => {
+                rejected += 1;
+                eprintln!("{}", format!("✗ Rejected delivery: {reason}").red());
+                continue;
+            }
+            Err(e) => {
+                eprintln!("{}", format!("✗ Connection error: {e}").red());
+                continue;
+            }
+        };
+        if !options.events.is_empty() && !options.events.contains(&event.event) {
+            continue;
+        }
+        handled += 1;
+
+        if options.json {
+            let line = serde_json::from_str::<serde_json::Value>(&payload)
+                .map(|value| value.to_string())
+                .unwrap_or(payload.clone());
+            println!("{line}");
+        } else {
+            println!(
+                "[{}] {}",
+                chrono::Local::now().format("%H:%M:%S"),
+                describe_event(&event)
+            );
+        }
+
+        if let Some(command) = &options.exec {
+            match webhook::forward_to_command(command, &event, &payload).await {
+                Ok(status) if !status.success() => eprintln!(
+                    "{}",
+                    format!("⚠ Command exited with {status} for {}", event.event).yellow()
+                ),
+                Ok(_) => {}
+                Err(e) => eprintln!("{}", format!("✗ Could not run command: {e}").red()),
+            }
+        }
+    }
+
+    status(format!("\n{}", "Summary:".bold()));
+    status(format!("Events handled: {handled}"));
+    status(format!("Deliveries rejected: {rejected}"));
+    Ok(())
+}
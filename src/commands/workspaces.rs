@@ -8,17 +8,20 @@
 //!
 //! - **List**: Display all accessible workspaces
 //! - **Show**: Show detailed information about a specific workspace
+//! - **Shared**: Show the tasks, lists and folders shared with you
 //!
 //! ## Features
 //!
 //! Workspaces are displayed in formatted tables showing key information
 //! including member counts, colors, and roles. Detailed workspace views
-//! include member lists and role information.
+//! include member lists and role information. Members and guests are
+//! managed with the `users` and `guests` commands.
 
 use crate::api::ClickUpApi;
 use crate::config::Config;
 use crate::error::ClickUpError;
 use crate::commands::utils::{ApiUtils, CommandExecutor, DisplayUtils, TableBuilder, TableHeaders};
+use crate::models::{SharedHierarchy, SharedItem};
 use clap::Subcommand;
 
 /// Workspace command variants
@@ -36,6 +39,12 @@ pub enum WorkspaceCommands {
         #[arg(short, long)]
         id: String,
     },
+    /// Show the tasks, lists and folders shared with you
+    Shared {
+        /// Workspace ID (defaults to the configured workspace)
+        #[arg(short, long)]
+        id: Option<String>,
+    },
 }
 
 impl CommandExecutor for WorkspaceCommands {
@@ -54,6 +63,9 @@ impl CommandExecutor for WorkspaceCommands {
             WorkspaceCommands::Show { id } => {
                 show_workspace(api, &id).await?;
             }
+            WorkspaceCommands::Shared { id } => {
+                show_shared_hierarchy(api, id).await?;
+            }
         }
         Ok(())
     }
@@ -187,3 +199,78 @@ async fn show_workspace(api: &ClickUpApi, workspace_id: &str) -> Result<(), Clic
 
     Ok(())
 }
+
+/// Show the items shared with the current user
+///
+/// This function lists the tasks, lists and folders that have been shared
+/// with the authenticated user outside the spaces they belong to.
+///
+/// # Arguments
+///
+/// * `api` - Reference to the ClickUp API client
+/// * `workspace_id` - Optional workspace ID; defaults to the configured workspace
+///
+/// # Returns
+///
+/// Returns `Ok(())` on successful display, or a `ClickUpError` on failure.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if no workspace ID is available
+/// - `ClickUpError::NetworkError` if the API request fails
+async fn show_shared_hierarchy(
+    api: &ClickUpApi,
+    workspace_id: Option<String>,
+) -> Result<(), ClickUpError> {
+    let workspace_id = ApiUtils::resolve_workspace_id(api, workspace_id)?;
+    let response = api.get_shared_hierarchy(&workspace_id).await?;
+
+    if response.shared.is_empty() {
+        DisplayUtils::display_empty_message("shared items");
+        return Ok(());
+    }
+    print_shared_hierarchy(&response.shared);
+    Ok(())
+}
+
+/// Prints shared tasks, lists and folders, one table per kind
+///
+/// Kinds with nothing shared are left out.
+pub fn print_shared_hierarchy(shared: &SharedHierarchy) {
+    for (title, items) in [
+        ("Tasks", &shared.tasks),
+        ("Lists", &shared.lists),
+        ("Folders", &shared.folders),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        DisplayUtils::display_section_header(title);
+        print_shared_items(items);
+    }
+}
+
+/// Prints shared items as a table
+fn print_shared_items(items: &[SharedItem]) {
+    let mut table_builder = TableBuilder::new();
+    table_builder.add_header(vec![
+        TableHeaders::id(),
+        TableHeaders::name(),
+        TableHeaders::task_count(),
+    ]);
+    for item in items {
+        let mut name = item.name.clone().unwrap_or_default();
+        if item.archived == Some(true) {
+            name.push_str(" (archived)");
+        }
+        table_builder.add_row(vec![
+            item.id.clone(),
+            name,
+            item.task_count
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ]);
+    }
+    table_builder.print();
+}
@@ -31,6 +31,10 @@
 //! - `CLICKUP_RATE_LIMIT__AUTO_RETRY` - Whether to auto-retry rate-limited requests
 //! - `CLICKUP_RATE_LIMIT__MAX_RETRIES` - Maximum retry attempts
 //! - `CLICKUP_RATE_LIMIT__BUFFER_SECONDS` - Buffer time for rate limiting
+//! - `CLICKUP_PROFILE` - Named profile to use (overridden by `--profile`)
+//! - `CLICKUP_TOKEN_PASSPHRASE` - Passphrase for the encrypted token file
+//! - `CLICKUP_OAUTH_CLIENT_ID` - OAuth app client ID for `auth login --oauth`
+//! - `CLICKUP_OAUTH_CLIENT_SECRET` - OAuth app client secret for `auth login --oauth`
 //!
 //! ## .env Files
 //!
@@ -47,17 +51,74 @@
 //! - Windows: `%APPDATA%\clickup-cli\config.toml`
 //! - macOS: `~/Library/Application Support/clickup-cli/config.toml`
 //! - Linux: `~/.config/clickup-cli/config.toml`
+//!
+//! ## Token Storage
+//!
+//! `token_storage` records where the API token lives: `keyring` (the OS keyring),
+//! `encrypted-file` (a passphrase-encrypted file next to `config.toml`), or
+//! `config` (plaintext `api_token`, the default for older configurations).
+//! Tokens kept in the keyring or encrypted file are never written to `config.toml`.
+//!
+//! `token_type` records whether the token is a personal token (`personal`, the
+//! default) or an OAuth access token (`oauth`) from `auth login --oauth`.
+//!
+//! ## OAuth
+//!
+//! The `[oauth]` table holds the ClickUp OAuth app used by `auth login --oauth`.
+//! `authorize_url` and `token_url` only need to be set to use a different
+//! authorization server; `token_url` defaults to `<api_base_url>/oauth/token`.
+//!
+//! The client secret is kept like an API token: `client_secret_storage`
+//! records whether it is in the keyring or encrypted file. A plaintext
+//! `client_secret` is still read, and is moved out of `config.toml` on the
+//! next login.
+//!
+//! ```toml
+//! [oauth]
+//! client_id = "ABC123"
+//! client_secret_storage = "keyring"
+//! redirect_port = 4320
+//! ```
+//!
+//! ## Profiles
+//!
+//! The top-level token, workspace and list settings form the `default` profile.
+//! Additional named profiles live under `[profiles.<name>]` and are selected with
+//! `--profile`, `CLICKUP_PROFILE`, or `active_profile` (in that order). A selected
+//! profile replaces the top-level token, workspace and default list, and its
+//! `rate_limit` table (if present) replaces the top-level rate limits.
+//!
+//! ```toml
+//! api_token = "pk_company_token"
+//! active_profile = "client"
+//!
+//! [profiles.client]
+//! api_token = "pk_client_token"
+//! workspace_id = "9876"
+//!
+//! [profiles.client.rate_limit]
+//! requests_per_minute = 1000
+//! auto_retry = true
+//! max_retries = 3
+//! buffer_seconds = 5
+//! ```
 
 use crate::error::ClickUpError;
+use crate::token_store::{TokenStorage, TokenStore};
 use config::{Config as ConfigFile, Environment, File};
 use dotenvy::dotenv;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Name of the profile formed by the top-level configuration settings
+pub const DEFAULT_PROFILE: &str = "default";
 
 /// Rate limiting configuration
 ///
 /// This struct defines how the application should handle API rate limits.
 /// It controls the maximum number of requests per minute and retry behavior.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct RateLimitConfig {
     /// Maximum number of requests allowed per minute
     ///
@@ -94,6 +155,108 @@ impl Default for RateLimitConfig {
     }
 }
 
+/// Kind of API token in use
+///
+/// Personal tokens are sent as-is in the `Authorization` header, while OAuth
+/// access tokens are sent with the `Bearer` scheme.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenType {
+    /// A personal API token (`pk_...`)
+    #[default]
+    Personal,
+    /// An OAuth access token obtained with `auth login --oauth`
+    OAuth,
+}
+
+impl TokenType {
+    /// Returns whether this is a personal API token
+    pub fn is_personal(&self) -> bool {
+        *self == TokenType::Personal
+    }
+}
+
+impl std::fmt::Display for TokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TokenType::Personal => "personal API token",
+            TokenType::OAuth => "OAuth access token",
+        };
+        f.write_str(name)
+    }
+}
+
+/// OAuth app settings for `auth login --oauth`
+///
+/// The client ID and secret identify a ClickUp OAuth app, so that users can
+/// log in with their own ClickUp account instead of sharing personal tokens.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct OAuthConfig {
+    /// OAuth app client ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    /// OAuth app client secret, if kept in plaintext
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+
+    /// Where the client secret is stored when not kept in plaintext
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret_storage: Option<TokenStorage>,
+
+    /// Authorization page URL (defaults to ClickUp's)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorize_url: Option<String>,
+
+    /// Token exchange URL (defaults to `<api_base_url>/oauth/token`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_url: Option<String>,
+
+    /// Local port for the redirect listener
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_port: Option<u16>,
+}
+
+impl OAuthConfig {
+    /// Returns whether no OAuth settings are configured
+    pub fn is_empty(&self) -> bool {
+        *self == OAuthConfig::default()
+    }
+}
+
+/// Settings stored for a named profile
+///
+/// Each profile has its own token, default workspace and default list, and
+/// may override the rate limiting settings.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProfileConfig {
+    /// ClickUp API token for this profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_token: Option<String>,
+
+    /// Default workspace ID for this profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
+
+    /// Default list ID for this profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_list_id: Option<String>,
+
+    /// Rate limiting settings for this profile
+    ///
+    /// When unset, the top-level rate limiting settings are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitConfig>,
+
+    /// Where this profile's API token is stored
+    #[serde(default, skip_serializing_if = "TokenStorage::is_config")]
+    pub token_storage: TokenStorage,
+
+    /// Whether this profile's token is a personal or OAuth token
+    #[serde(default, skip_serializing_if = "TokenType::is_personal")]
+    pub token_type: TokenType,
+}
+
 /// Application configuration structure
 ///
 /// This struct holds all configuration data for the ClickUp CLI application.
@@ -109,7 +272,18 @@ pub struct Config {
     /// via the configuration file, environment variable, or CLI command.
     pub api_token: Option<String>,
 
+    /// Where the API token is stored
+    ///
+    /// When this is not `config`, `api_token` is filled in from the keyring or
+    /// encrypted file on load and is never written to `config.toml`.
+    #[serde(default, skip_serializing_if = "TokenStorage::is_config")]
+    pub token_storage: TokenStorage,
 
+    /// Whether the API token is a personal token or an OAuth access token
+    ///
+    /// Determines how the token is sent in the `Authorization` header.
+    #[serde(default, skip_serializing_if = "TokenType::is_personal")]
+    pub token_type: TokenType,
 
     /// Default workspace ID for convenience
     ///
@@ -132,6 +306,63 @@ pub struct Config {
     ///
     /// Controls how many requests can be made per minute and how to handle rate limits.
     pub rate_limit: RateLimitConfig,
+
+    /// List IDs that must never have tasks or comments deleted
+    ///
+    /// Delete operations targeting a task in one of these lists are refused
+    /// before any request is sent, regardless of `--yes`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub protected_lists: Vec<String>,
+
+    /// Space IDs that must never have tasks or comments deleted
+    ///
+    /// Works like `protected_lists` but covers every list in the space.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub protected_spaces: Vec<String>,
+
+    /// OAuth app used by `auth login --oauth`
+    #[serde(default, skip_serializing_if = "OAuthConfig::is_empty")]
+    pub oauth: OAuthConfig,
+
+    /// Print mutating requests instead of sending them
+    ///
+    /// Set from the global `--dry-run` flag; never read from or written to disk.
+    #[serde(skip)]
+    pub dry_run: bool,
+
+    /// Skip confirmation prompts for destructive operations
+    ///
+    /// Set from the global `--yes` flag; never read from or written to disk.
+    #[serde(skip)]
+    pub assume_yes: bool,
+
+    /// Backup file that read-only commands answer from instead of ClickUp
+    ///
+    /// Set from the global `--offline` option; never read from or written to disk.
+    #[serde(skip)]
+    pub offline: Option<PathBuf>,
+
+    /// Profile used when neither `--profile` nor `CLICKUP_PROFILE` is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+
+    /// Named profiles, each with its own token, workspace and rate limits
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+
+    /// Named profile applied to this configuration, if any
+    ///
+    /// When set, the token, workspace, list and rate limit fields hold the
+    /// profile's values, and saving writes them back to that profile.
+    #[serde(skip)]
+    pub profile: Option<String>,
+
+    /// Top-level settings as loaded, before a named profile was applied
+    ///
+    /// Saving while a profile is applied writes these back to the top level
+    /// so that the default profile is left untouched.
+    #[serde(skip)]
+    pub base_settings: ProfileConfig,
 }
 
 impl Config {
@@ -160,6 +391,34 @@ impl Config {
     /// - `ClickUpError::ConfigParseError` if the configuration file is invalid
     pub fn load_with_path(
         config_file_override: Option<&std::path::Path>,
+    ) -> Result<Self, ClickUpError> {
+        Self::load_with_profile(config_file_override, None)
+    }
+
+    /// Loads configuration and applies a named profile
+    ///
+    /// This behaves like `load_with_path`, and then applies the profile named by
+    /// `profile`, the `CLICKUP_PROFILE` environment variable, or `active_profile`
+    /// from the configuration file, whichever is found first. A profile that
+    /// doesn't exist yet is applied with empty settings so that it can be
+    /// created with `auth set --profile <name>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `config_file_override` - Optional path to a config file
+    /// * `profile` - Optional profile name, typically from `--profile`
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Config` instance with the profile applied, or a `ClickUpError`
+    /// if the configuration cannot be loaded.
+    ///
+    /// # Errors
+    ///
+    /// This function can return the same errors as `load_with_path()`.
+    pub fn load_with_profile(
+        config_file_override: Option<&std::path::Path>,
+        profile: Option<&str>,
     ) -> Result<Self, ClickUpError> {
         // Load .env file if it exists (highest priority), unless skipped for tests
         if std::env::var("CLICKUP_SKIP_ENV_FILE").is_err() {
@@ -170,15 +429,7 @@ impl Config {
         let config_file = if let Some(path) = config_file_override {
             path.to_path_buf()
         } else {
-            let config_dir = dirs::config_dir()
-                .ok_or_else(|| {
-                    ClickUpError::ConfigError("Could not find config directory".to_string())
-                })?
-                .join("clickup-cli");
-            std::fs::create_dir_all(&config_dir).map_err(|e| {
-                ClickUpError::ConfigError(format!("Failed to create config directory: {e}"))
-            })?;
-            config_dir.join("config.toml")
+            Self::config_dir()?.join("config.toml")
         };
 
         // Use ConfigBuilder to avoid deprecated methods
@@ -224,6 +475,32 @@ impl Config {
             ))
         })?;
 
+        // Apply the selected profile before environment token fallbacks
+        let profile = profile
+            .map(str::to_string)
+            .or_else(|| {
+                std::env::var("CLICKUP_PROFILE")
+                    .ok()
+                    .filter(|p| !p.trim().is_empty())
+            })
+            .or_else(|| config.active_profile.clone());
+        config.apply_profile(profile);
+
+        // Read the token from the keyring or encrypted file
+        if !config.token_storage.is_config() {
+            match TokenStore::for_profile(config.active_profile_name())
+                .and_then(|store| store.load(config.token_storage))
+            {
+                Ok(Some(token)) => config.api_token = Some(token),
+                Ok(None) => log::warn!(
+                    "No API token found in the {} for profile '{}'",
+                    config.token_storage,
+                    config.active_profile_name()
+                ),
+                Err(e) => log::warn!("Could not read API token: {e}"),
+            }
+        }
+
         // Load API tokens from environment variables if not already set
         if config.api_token.is_none() {
             if let Ok(token) = std::env::var("CLICKUP_API_TOKEN") {
@@ -243,9 +520,35 @@ impl Config {
         Ok(config)
     }
 
+    /// Returns the application's configuration directory
+    ///
+    /// This resolves the platform-specific config directory and appends
+    /// `clickup-cli`, creating the directory if it doesn't exist. Other local
+    /// state such as the undo journal is stored alongside `config.toml`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the path to the configuration directory, or a `ClickUpError`
+    /// if it cannot be determined or created.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the config directory cannot be found or created
+    pub fn config_dir() -> Result<std::path::PathBuf, ClickUpError> {
+        let config_dir = dirs::config_dir()
+            .ok_or_else(|| ClickUpError::ConfigError("Could not find config directory".to_string()))?
+            .join("clickup-cli");
+        std::fs::create_dir_all(&config_dir).map_err(|e| {
+            ClickUpError::ConfigError(format!("Failed to create config directory: {e}"))
+        })?;
+        Ok(config_dir)
+    }
+
     /// Loads configuration from the default location
     ///
     /// This is a convenience wrapper for `load_with_path(None)`. See that method for details.
+    #[allow(dead_code)]
     pub fn load() -> Result<Self, ClickUpError> {
         Self::load_with_path(None)
     }
@@ -263,6 +566,7 @@ impl Config {
     /// # Errors
     ///
     /// This function can return the same errors as `load_with_path()`.
+    #[allow(dead_code)]
     pub fn load_for_tests() -> Result<Self, ClickUpError> {
         // Load .env.test file for test environment
         dotenvy::from_filename(".env.test").ok();
@@ -306,18 +610,26 @@ impl Config {
         let config_file = if let Some(path) = config_file_override {
             path.to_path_buf()
         } else {
-            let config_dir = dirs::config_dir()
-                .ok_or_else(|| {
-                    ClickUpError::ConfigError("Could not find config directory".to_string())
-                })?
-                .join("clickup-cli");
-            std::fs::create_dir_all(&config_dir).map_err(|e| {
-                ClickUpError::ConfigError(format!("Failed to create config directory: {e}"))
-            })?;
-            config_dir.join("config.toml")
+            Self::config_dir()?.join("config.toml")
         };
+        // When a profile is applied, its settings go to the profile's table and
+        // the top-level (default profile) settings are written back unchanged
+        let mut to_save = self.clone();
+        if let Some(name) = &self.profile {
+            to_save.profiles.insert(name.clone(), self.profile_settings(name));
+            to_save.api_token = self.base_settings.api_token.clone();
+            to_save.workspace_id = self.base_settings.workspace_id.clone();
+            to_save.default_list_id = self.base_settings.default_list_id.clone();
+            to_save.rate_limit = self.base_settings.rate_limit.clone().unwrap_or_default();
+            to_save.token_storage = self.base_settings.token_storage;
+            to_save.token_type = self.base_settings.token_type;
+        }
+        // Tokens kept outside the config file must never be written to it
+        if !to_save.token_storage.is_config() {
+            to_save.api_token = None;
+        }
         // Serialize the configuration to TOML format
-        let config_str = toml::to_string_pretty(self).map_err(|e| {
+        let config_str = toml::to_string_pretty(&to_save).map_err(|e| {
             ClickUpError::SerializationError(format!("Failed to serialize config: {e}"))
         })?;
         // Write the configuration to the file
@@ -390,7 +702,8 @@ impl Config {
     /// Sets the API token and saves the configuration
     ///
     /// This function updates the API token in the configuration and immediately
-    /// saves the changes to the config file.
+    /// saves the changes. The token is written to wherever `token_storage`
+    /// points, so a keyring-stored token stays in the keyring.
     ///
     /// # Arguments
     ///
@@ -403,16 +716,144 @@ impl Config {
     /// # Errors
     ///
     /// This function can return the same errors as `save()`.
+    #[allow(dead_code)]
     pub fn set_api_token(&mut self, token: String) -> Result<(), ClickUpError> {
         if token.trim().is_empty() {
             return Err(ClickUpError::ValidationError(
                 "API token cannot be empty or whitespace".to_string(),
             ));
         }
+        if !self.token_storage.is_config() {
+            TokenStore::for_profile(self.active_profile_name())?
+                .save(self.token_storage, &token)?;
+        }
         self.api_token = Some(token);
         self.save()
     }
 
+    /// Stores the API token in a new location and saves the configuration
+    ///
+    /// With `storage` set to `None`, the token goes to the OS keyring, or to
+    /// the encrypted file if no keyring is available. The token is removed from
+    /// its previous location once it has been stored in the new one.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The ClickUp API token to store
+    /// * `storage` - Where to store it, or `None` for the keyring with fallback
+    ///
+    /// # Returns
+    ///
+    /// Returns where the token was stored.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if the token is empty
+    /// - `ClickUpError::ConfigError` if the keyring or passphrase is unavailable
+    /// - The same errors as `save()`
+    pub fn store_api_token(
+        &mut self,
+        token: String,
+        storage: Option<TokenStorage>,
+    ) -> Result<TokenStorage, ClickUpError> {
+        if token.trim().is_empty() {
+            return Err(ClickUpError::ValidationError(
+                "API token cannot be empty or whitespace".to_string(),
+            ));
+        }
+        let store = TokenStore::for_profile(self.active_profile_name())?;
+        let storage = match storage {
+            Some(storage) => {
+                store.save(storage, &token)?;
+                storage
+            }
+            None => store.save_preferred(&token)?,
+        };
+
+        let previous = self.token_storage;
+        self.token_storage = storage;
+        self.api_token = Some(token);
+        self.save()?;
+
+        if previous != storage {
+            if let Err(e) = store.delete(previous) {
+                log::warn!("Could not remove token from the {previous}: {e}");
+            }
+        }
+        Ok(storage)
+    }
+
+    /// Stores the OAuth app client secret and saves the configuration
+    ///
+    /// With `storage` set to `None`, the secret goes to the OS keyring, or to
+    /// the encrypted file if no keyring is available. Any plaintext secret is
+    /// removed from `config.toml` unless `TokenStorage::Config` is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The OAuth app client secret
+    /// * `storage` - Where to store it, or `None` for the keyring with fallback
+    ///
+    /// # Returns
+    ///
+    /// Returns where the secret was stored.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the keyring or passphrase is unavailable
+    /// - The same errors as `save()`
+    pub fn store_oauth_client_secret(
+        &mut self,
+        secret: String,
+        storage: Option<TokenStorage>,
+    ) -> Result<TokenStorage, ClickUpError> {
+        let store = TokenStore::for_oauth_client()?;
+        let storage = match storage {
+            Some(storage) => {
+                store.save(storage, &secret)?;
+                storage
+            }
+            None => store.save_preferred(&secret)?,
+        };
+
+        let previous = self.oauth.client_secret_storage.unwrap_or_default();
+        if storage.is_config() {
+            self.oauth.client_secret = Some(secret);
+            self.oauth.client_secret_storage = None;
+        } else {
+            self.oauth.client_secret = None;
+            self.oauth.client_secret_storage = Some(storage);
+        }
+        self.save()?;
+
+        if previous != storage {
+            if let Err(e) = store.delete(previous) {
+                log::warn!("Could not remove OAuth client secret from the {previous}: {e}");
+            }
+        }
+        Ok(storage)
+    }
+
+    /// Removes the API token from its storage and saves the configuration
+    ///
+    /// # Errors
+    ///
+    /// This function can return the same errors as `save()`. Failing to remove
+    /// the token from the keyring or encrypted file is logged, not returned.
+    pub fn clear_api_token(&mut self) -> Result<(), ClickUpError> {
+        let removed = TokenStore::for_profile(self.active_profile_name())
+            .and_then(|store| store.delete(self.token_storage));
+        if let Err(e) = removed {
+            log::warn!("Could not remove token from the {}: {e}", self.token_storage);
+        }
+        self.api_token = None;
+        self.token_storage = TokenStorage::Config;
+        self.token_type = TokenType::Personal;
+        self.save()
+    }
+
     /// Retrieves the API token from configuration
     ///
     /// This function returns a reference to the stored API token. If no token
@@ -450,6 +891,162 @@ impl Config {
     pub fn is_authenticated(&self) -> bool {
         self.api_token.is_some()
     }
+
+    /// Returns the name of the profile in effect
+    pub fn active_profile_name(&self) -> &str {
+        self.profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Applies a named profile on top of the top-level settings
+    ///
+    /// The top-level settings are kept in `base_settings` so that they can be
+    /// restored when saving. Passing `None` or `DEFAULT_PROFILE` leaves the
+    /// top-level settings in effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The profile to apply
+    pub fn apply_profile(&mut self, name: Option<String>) {
+        self.base_settings = ProfileConfig {
+            api_token: self.api_token.clone(),
+            workspace_id: self.workspace_id.clone(),
+            default_list_id: self.default_list_id.clone(),
+            rate_limit: Some(self.rate_limit.clone()),
+            token_storage: self.token_storage,
+            token_type: self.token_type,
+        };
+
+        let Some(name) = name.filter(|n| n != DEFAULT_PROFILE) else {
+            self.profile = None;
+            return;
+        };
+
+        let settings = self.profiles.get(&name).cloned().unwrap_or_default();
+        self.api_token = settings.api_token;
+        self.workspace_id = settings.workspace_id;
+        self.default_list_id = settings.default_list_id;
+        if let Some(rate_limit) = settings.rate_limit {
+            self.rate_limit = rate_limit;
+        }
+        self.token_storage = settings.token_storage;
+        self.token_type = settings.token_type;
+        self.profile = Some(name);
+    }
+
+    /// Returns the settings in effect, as they should be stored for a profile
+    ///
+    /// Rate limits are only stored when the profile already had its own or
+    /// they differ from the top-level settings.
+    fn profile_settings(&self, name: &str) -> ProfileConfig {
+        let has_own_rate_limit = self
+            .profiles
+            .get(name)
+            .is_some_and(|p| p.rate_limit.is_some());
+        let differs = self.base_settings.rate_limit.as_ref() != Some(&self.rate_limit);
+        ProfileConfig {
+            api_token: self
+                .api_token
+                .clone()
+                .filter(|_| self.token_storage.is_config()),
+            workspace_id: self.workspace_id.clone(),
+            default_list_id: self.default_list_id.clone(),
+            rate_limit: (has_own_rate_limit || differs).then(|| self.rate_limit.clone()),
+            token_storage: self.token_storage,
+            token_type: self.token_type,
+        }
+    }
+
+    /// Checks that the profile in effect exists
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if a named profile is applied but not configured
+    pub fn ensure_profile_exists(&self) -> Result<(), ClickUpError> {
+        match &self.profile {
+            Some(name) if !self.profiles.contains_key(name) => Err(ClickUpError::ConfigError(
+                format!(
+                    "Profile '{name}' does not exist. Create it with 'clickup-cli auth set --profile {name}'"
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes a named profile
+    ///
+    /// If the removed profile is currently applied, the top-level settings are
+    /// restored. If it was the `active_profile`, the default profile becomes active.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The profile to remove
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if `name` is the default profile
+    /// - `ClickUpError::NotFoundError` if the profile doesn't exist
+    pub fn remove_profile(&mut self, name: &str) -> Result<(), ClickUpError> {
+        if name == DEFAULT_PROFILE {
+            return Err(ClickUpError::ValidationError(
+                "The default profile cannot be deleted".to_string(),
+            ));
+        }
+        if self.profiles.remove(name).is_none() {
+            return Err(ClickUpError::NotFoundError(format!(
+                "Profile '{name}' not found"
+            )));
+        }
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
+        if self.profile.as_deref() == Some(name) {
+            let base = self.base_settings.clone();
+            self.api_token = base.api_token;
+            self.workspace_id = base.workspace_id;
+            self.default_list_id = base.default_list_id;
+            self.rate_limit = base.rate_limit.unwrap_or_default();
+            self.token_storage = base.token_storage;
+            self.token_type = base.token_type;
+            self.profile = None;
+        }
+        Ok(())
+    }
+
+    /// Checks whether any lists or spaces are protected from deletes
+    pub fn has_protected_locations(&self) -> bool {
+        !self.protected_lists.is_empty() || !self.protected_spaces.is_empty()
+    }
+
+    /// Refuses deletes inside protected lists or spaces
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - The list containing the entity to delete
+    /// * `space_id` - The space containing the entity to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if neither the list nor the space is protected.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::PermissionError` if the list or space is protected
+    pub fn ensure_deletable(&self, list_id: &str, space_id: &str) -> Result<(), ClickUpError> {
+        if self.protected_lists.iter().any(|id| id == list_id) {
+            return Err(ClickUpError::PermissionError(format!(
+                "List {list_id} is protected; deletes are disabled in the configuration"
+            )));
+        }
+        if self.protected_spaces.iter().any(|id| id == space_id) {
+            return Err(ClickUpError::PermissionError(format!(
+                "Space {space_id} is protected; deletes are disabled in the configuration"
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl Default for Config {
@@ -465,6 +1062,18 @@ impl Default for Config {
             default_list_id: None,
             api_base_url: "https://api.clickup.com/api/v2".to_string(),
             rate_limit: RateLimitConfig::default(),
+            protected_lists: Vec::new(),
+            protected_spaces: Vec::new(),
+            dry_run: false,
+            assume_yes: false,
+            offline: None,
+            active_profile: None,
+            profiles: BTreeMap::new(),
+            profile: None,
+            base_settings: ProfileConfig::default(),
+            token_storage: TokenStorage::Config,
+            token_type: TokenType::Personal,
+            oauth: OAuthConfig::default(),
         }
     }
 }
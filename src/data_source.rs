@@ -0,0 +1,196 @@
+//! # Data Sources
+//!
+//! This module defines where read-only commands get their data from. The
+//! `DataSource` trait covers the reads behind `tasks list`, `tasks show`,
+//! `tasks subtasks`, `lists list` and `comments list`, and is implemented by
+//! the ClickUp API client and by space backups.
+//!
+//! ## Offline Mode
+//!
+//! With the global `--offline <backup-file>` option these commands answer
+//! from a file written by `spaces backup` instead of calling ClickUp:
+//!
+//! - Only the backed-up space, its lists and its tasks can be found
+//! - Closed tasks are only shown where the live command would show them,
+//!   and not at all for backups made without closed tasks
+//! - Comments are only available on tasks, and only if the backup was made
+//!   with `--include-comments`
+//!
+//! Commands that change anything, or read data a backup doesn't hold, are
+//! refused before any request is sent.
+
+use crate::api::ClickUpApi;
+use crate::backup::SpaceBackup;
+use crate::error::ClickUpError;
+use crate::models::{CommentTarget, CommentsResponse, ListsResponse, Task, TasksResponse};
+
+/// Read access to lists, tasks and comments
+///
+/// Method names and results match the `ClickUpApi` methods of the same name.
+#[allow(async_fn_in_trait)]
+pub trait DataSource {
+    /// Returns the lists in a space, including those in folders for backups
+    async fn get_lists(&self, space_id: &str) -> Result<ListsResponse, ClickUpError>;
+
+    /// Returns the open tasks in a list
+    async fn get_tasks(&self, list_id: &str) -> Result<TasksResponse, ClickUpError>;
+
+    /// Returns the tasks in a list, including closed tasks
+    async fn get_tasks_including_closed(
+        &self,
+        list_id: &str,
+    ) -> Result<TasksResponse, ClickUpError>;
+
+    /// Returns a task
+    async fn get_task(&self, task_id: &str) -> Result<Task, ClickUpError>;
+
+    /// Returns a task with `subtasks` filled in
+    async fn get_task_with_subtasks(&self, task_id: &str) -> Result<Task, ClickUpError>;
+
+    /// Returns the comments on a task, list or chat view
+    async fn get_target_comments(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+    ) -> Result<CommentsResponse, ClickUpError>;
+}
+
+impl DataSource for ClickUpApi {
+    async fn get_lists(&self, space_id: &str) -> Result<ListsResponse, ClickUpError> {
+        ClickUpApi::get_lists(self, space_id).await
+    }
+
+    async fn get_tasks(&self, list_id: &str) -> Result<TasksResponse, ClickUpError> {
+        ClickUpApi::get_tasks(self, list_id).await
+    }
+
+    async fn get_tasks_including_closed(
+        &self,
+        list_id: &str,
+    ) -> Result<TasksResponse, ClickUpError> {
+        ClickUpApi::get_tasks_including_closed(self, list_id).await
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<Task, ClickUpError> {
+        ClickUpApi::get_task(self, task_id).await
+    }
+
+    async fn get_task_with_subtasks(&self, task_id: &str) -> Result<Task, ClickUpError> {
+        ClickUpApi::get_task_with_subtasks(self, task_id).await
+    }
+
+    async fn get_target_comments(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+    ) -> Result<CommentsResponse, ClickUpError> {
+        ClickUpApi::get_target_comments(self, target, target_id).await
+    }
+}
+
+impl DataSource for SpaceBackup {
+    async fn get_lists(&self, space_id: &str) -> Result<ListsResponse, ClickUpError> {
+        if self.metadata.space_id != space_id {
+            return Err(not_in_backup("Space", space_id));
+        }
+        Ok(ListsResponse {
+            lists: self.lists.clone(),
+        })
+    }
+
+    async fn get_tasks(&self, list_id: &str) -> Result<TasksResponse, ClickUpError> {
+        if !self.lists.iter().any(|list| list.id == list_id) {
+            return Err(not_in_backup("List", list_id));
+        }
+        Ok(TasksResponse {
+            tasks: self
+                .tasks
+                .iter()
+                .filter(|task| task.list.id == list_id && !is_closed(task))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    async fn get_tasks_including_closed(
+        &self,
+        list_id: &str,
+    ) -> Result<TasksResponse, ClickUpError> {
+        if !self.lists.iter().any(|list| list.id == list_id) {
+            return Err(not_in_backup("List", list_id));
+        }
+        Ok(TasksResponse {
+            tasks: self
+                .tasks
+                .iter()
+                .filter(|task| task.list.id == list_id)
+                .cloned()
+                .collect(),
+        })
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<Task, ClickUpError> {
+        self.tasks
+            .iter()
+            .find(|task| task.id == task_id)
+            .cloned()
+            .ok_or_else(|| not_in_backup("Task", task_id))
+    }
+
+    async fn get_task_with_subtasks(&self, task_id: &str) -> Result<Task, ClickUpError> {
+        let mut task = DataSource::get_task(self, task_id).await?;
+
+        // Collect subtasks at any depth, as the API does
+        let mut parents = vec![task.id.clone()];
+        let mut subtasks = Vec::new();
+        while let Some(parent) = parents.pop() {
+            for child in self
+                .tasks
+                .iter()
+                .filter(|child| child.parent.as_deref() == Some(parent.as_str()))
+            {
+                parents.push(child.id.clone());
+                subtasks.push(child.clone());
+            }
+        }
+        task.subtasks = Some(subtasks);
+        Ok(task)
+    }
+
+    async fn get_target_comments(
+        &self,
+        target: CommentTarget,
+        target_id: &str,
+    ) -> Result<CommentsResponse, ClickUpError> {
+        if target != CommentTarget::Task {
+            return Err(ClickUpError::ValidationError(
+                "Backups only hold task comments".to_string(),
+            ));
+        }
+        if !self.metadata.include_comments {
+            return Err(ClickUpError::ValidationError(
+                "The backup was made without comments; make one with `spaces backup --include-comments`"
+                    .to_string(),
+            ));
+        }
+        DataSource::get_task(self, target_id).await?;
+
+        let comments = self
+            .comments
+            .iter()
+            .find(|entry| entry.task_id == target_id)
+            .map(|entry| entry.comments.clone())
+            .unwrap_or_default();
+        Ok(CommentsResponse { comments })
+    }
+}
+
+/// Returns the error for an item that isn't in the backup
+fn not_in_backup(item_type: &str, id: &str) -> ClickUpError {
+    ClickUpError::NotFoundError(format!("{item_type} {id} is not in the backup"))
+}
+
+/// Returns whether a task is closed
+fn is_closed(task: &Task) -> bool {
+    task.status.type_ == "closed" || task.date_closed.is_some()
+}
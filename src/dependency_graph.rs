@@ -0,0 +1,423 @@
+//! # Dependency Graph
+//!
+//! This module builds the dependency graph behind `tasks deps graph`, checks
+//! it for problems, and renders it as Graphviz DOT or Mermaid.
+//!
+//! ## Features
+//!
+//! - **Graph Building**: Collects "waiting on" dependencies from a list's tasks
+//! - **Cycle Detection**: Finds groups of tasks that wait on each other
+//! - **Due Date Checks**: Flags tasks that are due before a task they wait on
+//! - **Rendering**: Outputs DOT for Graphviz or a Mermaid flowchart
+//!
+//! Edges point from the blocking task to the waiting task, so the graph
+//! reads in the order work has to happen.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::dependency_graph::{DependencyGraph, GraphNode};
+//!
+//! let mut graph = DependencyGraph::default();
+//! graph.insert_node(GraphNode::new("design", "Design"));
+//! graph.insert_node(GraphNode::new("build", "Build"));
+//! graph.insert_edge("design", "build");
+//! graph.insert_edge("build", "design");
+//!
+//! assert_eq!(graph.cycles(), vec![vec!["build".to_string(), "design".to_string()]]);
+//! assert!(graph.to_dot().contains("\"design\" -> \"build\""));
+//! ```
+
+use crate::models::Task;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A task in the dependency graph
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphNode {
+    /// Task ID
+    pub id: String,
+    /// Task display name (empty for tasks outside the graphed list)
+    pub name: String,
+    /// Status name
+    pub status: Option<String>,
+    /// Whether the task is closed
+    pub closed: bool,
+    /// Due date (Unix timestamp in milliseconds)
+    pub due_date: Option<i64>,
+    /// Whether the task is outside the graphed list
+    pub external: bool,
+}
+
+impl GraphNode {
+    /// Creates a node with an ID and name
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The task ID
+    /// * `name` - The task name
+    #[allow(dead_code)]
+    pub fn new(id: &str, name: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the label shown for the node
+    fn label(&self) -> String {
+        let mut lines = vec![if self.name.is_empty() {
+            self.id.clone()
+        } else {
+            self.name.clone()
+        }];
+        if let Some(status) = &self.status {
+            lines.push(status.clone());
+        }
+        if let Some(due) = self.due_date.and_then(format_due_date) {
+            lines.push(format!("due {due}"));
+        }
+        lines.join("\n")
+    }
+}
+
+impl From<&Task> for GraphNode {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id.clone(),
+            name: task.name.clone().unwrap_or_default(),
+            status: Some(task.status.status.clone()),
+            closed: task.status.type_ == "closed" || task.date_closed.is_some(),
+            due_date: task.due_date.as_deref().and_then(|due| due.parse().ok()),
+            external: false,
+        }
+    }
+}
+
+/// A task that is due before a task it waits on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueConflict {
+    /// The waiting task
+    pub task_id: String,
+    /// The blocking task
+    pub blocker_id: String,
+    /// Due date of the waiting task (Unix timestamp in milliseconds)
+    pub due_date: i64,
+    /// Due date of the blocking task (Unix timestamp in milliseconds)
+    pub blocker_due_date: i64,
+}
+
+/// Dependency graph of a set of tasks
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    /// Tasks keyed by ID
+    nodes: BTreeMap<String, GraphNode>,
+    /// Edges as (blocking task, waiting task)
+    edges: BTreeSet<(String, String)>,
+}
+
+impl DependencyGraph {
+    /// Builds the graph for a set of tasks
+    ///
+    /// Dependencies on tasks outside the set are kept, with the other task
+    /// added as an external node.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - The tasks to graph
+    pub fn from_tasks(tasks: &[Task]) -> Self {
+        let mut graph = Self::default();
+        for task in tasks {
+            graph.insert_node(GraphNode::from(task));
+        }
+        for task in tasks {
+            for dependency in &task.dependencies {
+                graph.insert_edge(&dependency.depends_on, &dependency.task_id);
+            }
+        }
+        graph
+    }
+
+    /// Adds a task, replacing any external placeholder with the same ID
+    pub fn insert_node(&mut self, node: GraphNode) {
+        self.nodes.insert(node.id.clone(), node);
+    }
+
+    /// Adds a dependency where `waiting` waits on `blocker`
+    ///
+    /// Unknown tasks are added as external nodes.
+    pub fn insert_edge(&mut self, blocker: &str, waiting: &str) {
+        for id in [blocker, waiting] {
+            self.nodes.entry(id.to_string()).or_insert_with(|| GraphNode {
+                id: id.to_string(),
+                external: true,
+                ..Default::default()
+            });
+        }
+        self.edges.insert((blocker.to_string(), waiting.to_string()));
+    }
+
+    /// Returns the number of dependencies in the graph
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Finds groups of tasks that wait on each other
+    ///
+    /// # Returns
+    ///
+    /// Returns each cycle as a sorted list of task IDs. A task that waits on
+    /// itself is reported as a cycle of one.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let ids: Vec<&str> = self.nodes.keys().map(String::as_str).collect();
+        let index: BTreeMap<&str, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        let mut successors = vec![Vec::new(); ids.len()];
+        for (from, to) in &self.edges {
+            successors[index[from.as_str()]].push(index[to.as_str()]);
+        }
+
+        let mut tarjan = Tarjan::new(ids.len());
+        for node in 0..ids.len() {
+            if tarjan.index[node].is_none() {
+                tarjan.visit(node, &successors);
+            }
+        }
+
+        let mut cycles: Vec<Vec<String>> = tarjan
+            .components
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || successors[component[0]].contains(&component[0])
+            })
+            .map(|component| {
+                let mut cycle: Vec<String> =
+                    component.into_iter().map(|i| ids[i].to_string()).collect();
+                cycle.sort();
+                cycle
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+
+    /// Finds open tasks that are due before a task they wait on
+    ///
+    /// Closed blockers are ignored, since they no longer hold anything up.
+    pub fn due_conflicts(&self) -> Vec<DueConflict> {
+        self.edges
+            .iter()
+            .filter_map(|(blocker_id, task_id)| {
+                let blocker = &self.nodes[blocker_id];
+                let task = &self.nodes[task_id];
+                if blocker.closed || task.closed {
+                    return None;
+                }
+                match (task.due_date, blocker.due_date) {
+                    (Some(due_date), Some(blocker_due_date)) if due_date < blocker_due_date => {
+                        Some(DueConflict {
+                            task_id: task_id.clone(),
+                            blocker_id: blocker_id.clone(),
+                            due_date,
+                            blocker_due_date,
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Renders the graph as Graphviz DOT
+    ///
+    /// Tasks and dependencies in cycles are drawn in red, tasks due before
+    /// their blockers in orange, closed tasks greyed out and tasks outside the
+    /// list dashed.
+    pub fn to_dot(&self) -> String {
+        let (cycle_nodes, cycle_edges) = self.cycle_members();
+        let conflicts: BTreeSet<String> =
+            self.due_conflicts().into_iter().map(|c| c.task_id).collect();
+
+        let mut dot = String::from("digraph dependencies {\n    rankdir=LR;\n    node [shape=box];\n");
+        for node in self.nodes.values() {
+            let mut attributes = vec![format!("label=\"{}\"", escape_dot(&node.label()))];
+            if cycle_nodes.contains(&node.id) {
+                attributes.push("color=red".to_string());
+            } else if conflicts.contains(&node.id) {
+                attributes.push("color=orange".to_string());
+            }
+            if node.closed {
+                attributes.push("style=filled, fillcolor=lightgrey".to_string());
+            } else if node.external {
+                attributes.push("style=dashed".to_string());
+            }
+            dot.push_str(&format!(
+                "    \"{}\" [{}];\n",
+                escape_dot(&node.id),
+                attributes.join(", ")
+            ));
+        }
+        for edge @ (from, to) in &self.edges {
+            let color = if cycle_edges.contains(edge) {
+                " [color=red]"
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\"{color};\n",
+                escape_dot(from),
+                escape_dot(to)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as a Mermaid flowchart
+    ///
+    /// Styling follows `to_dot`.
+    pub fn to_mermaid(&self) -> String {
+        let (cycle_nodes, _) = self.cycle_members();
+        let conflicts: BTreeSet<String> =
+            self.due_conflicts().into_iter().map(|c| c.task_id).collect();
+        let node_ids: BTreeMap<&str, String> = self
+            .nodes
+            .keys()
+            .enumerate()
+            .map(|(i, id)| (id.as_str(), format!("t{i}")))
+            .collect();
+
+        let mut mermaid = String::from("flowchart LR\n");
+        for node in self.nodes.values() {
+            mermaid.push_str(&format!(
+                "    {}[\"{}\"]\n",
+                node_ids[node.id.as_str()],
+                escape_mermaid(&node.label())
+            ));
+        }
+        for (from, to) in &self.edges {
+            mermaid.push_str(&format!(
+                "    {} --> {}\n",
+                node_ids[from.as_str()],
+                node_ids[to.as_str()]
+            ));
+        }
+
+        let classes = [
+            ("cycle", "stroke:#d00,stroke-width:2px", &cycle_nodes),
+            ("late", "stroke:#f90,stroke-width:2px", &conflicts),
+        ];
+        for (class, style, members) in classes {
+            if members.is_empty() {
+                continue;
+            }
+            let ids: Vec<&str> = members
+                .iter()
+                .map(|id| node_ids[id.as_str()].as_str())
+                .collect();
+            mermaid.push_str(&format!("    classDef {class} {style}\n"));
+            mermaid.push_str(&format!("    class {} {class}\n", ids.join(",")));
+        }
+        let closed: Vec<&str> = self
+            .nodes
+            .values()
+            .filter(|node| node.closed)
+            .map(|node| node_ids[node.id.as_str()].as_str())
+            .collect();
+        if !closed.is_empty() {
+            mermaid.push_str("    classDef closed fill:#eee,color:#888\n");
+            mermaid.push_str(&format!("    class {} closed\n", closed.join(",")));
+        }
+        mermaid
+    }
+
+    /// Returns the tasks and dependencies that are part of a cycle
+    fn cycle_members(&self) -> (BTreeSet<String>, BTreeSet<(String, String)>) {
+        let mut nodes = BTreeSet::new();
+        let mut edges = BTreeSet::new();
+        for cycle in self.cycles() {
+            let members: BTreeSet<&String> = cycle.iter().collect();
+            for edge @ (from, to) in &self.edges {
+                if members.contains(from) && members.contains(to) {
+                    edges.insert(edge.clone());
+                }
+            }
+            nodes.extend(cycle);
+        }
+        (nodes, edges)
+    }
+}
+
+/// State for Tarjan's strongly connected components algorithm
+struct Tarjan {
+    next_index: usize,
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan {
+    fn new(size: usize) -> Self {
+        Self {
+            next_index: 0,
+            index: vec![None; size],
+            low_link: vec![0; size],
+            on_stack: vec![false; size],
+            stack: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn visit(&mut self, node: usize, successors: &[Vec<usize>]) {
+        self.index[node] = Some(self.next_index);
+        self.low_link[node] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        for &next in &successors[node] {
+            match self.index[next] {
+                None => {
+                    self.visit(next, successors);
+                    self.low_link[node] = self.low_link[node].min(self.low_link[next]);
+                }
+                Some(next_index) if self.on_stack[next] => {
+                    self.low_link[node] = self.low_link[node].min(next_index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low_link[node]) == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// Formats a due date as `YYYY-MM-DD`
+pub fn format_due_date(timestamp_ms: i64) -> Option<String> {
+    chrono::DateTime::from_timestamp_millis(timestamp_ms)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Escapes a string for a quoted DOT ID or label
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Escapes a string for a quoted Mermaid label
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;").replace('\n', "<br/>")
+}
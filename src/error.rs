@@ -96,6 +96,14 @@ pub enum ClickUpError {
     #[error("Permission denied: {0}")]
     PermissionError(String),
 
+    /// Request skipped because dry-run mode is enabled
+    ///
+    /// This is returned in place of the response to a mutating request when
+    /// `--dry-run` is active. The request has already been printed, and no
+    /// change was made.
+    #[error("Dry run: {0}")]
+    DryRun(String),
+
     /// Serialization error
     ///
     /// This error occurs when data cannot be serialized to JSON format,
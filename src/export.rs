@@ -0,0 +1,488 @@
+//! # Task Export
+//!
+//! This module renders tasks for `tasks export` as CSV, a Markdown report,
+//! an iCalendar file or a standalone HTML report.
+//!
+//! ## Formats
+//!
+//! - **CSV**: One row per task. Custom fields are flattened into one column
+//!   per field name, after the standard columns.
+//! - **Markdown**: A report with the tasks grouped by status.
+//! - **iCalendar**: One event per task with a due date, at the due time (or
+//!   from the start date to the due date). Due dates without a time become
+//!   all-day events. Calendar apps can subscribe to the file.
+//! - **HTML**: The Markdown report's content as a single printable page
+//!   with inline styles.
+//!
+//! Dates are written in UTC.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use clickup_cli::export::{render, ExportFormat};
+//!
+//! let csv = render(ExportFormat::Csv, &tasks, "Backlog", chrono::Utc::now())?;
+//! std::fs::write("backlog.csv", csv)?;
+//! ```
+
+use crate::error::ClickUpError;
+use crate::models::{Task, TaskCustomField};
+use crate::task_tree::is_done;
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Format of an export file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Comma-separated values with custom fields as columns
+    Csv,
+    /// Markdown report grouped by status
+    #[value(alias = "md")]
+    Markdown,
+    /// iCalendar events for due dates
+    Ics,
+    /// Standalone printable HTML report
+    Html,
+}
+
+/// Renders tasks in an export format
+///
+/// # Arguments
+///
+/// * `format` - The format to render
+/// * `tasks` - The tasks to export
+/// * `title` - Report or calendar title, e.g. the list or space name
+/// * `generated_at` - Time shown as the report's creation time
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::SerializationError` if the CSV cannot be written
+pub fn render(
+    format: ExportFormat,
+    tasks: &[Task],
+    title: &str,
+    generated_at: DateTime<Utc>,
+) -> Result<String, ClickUpError> {
+    match format {
+        ExportFormat::Csv => to_csv(tasks),
+        ExportFormat::Markdown => Ok(to_markdown(tasks, title, generated_at)),
+        ExportFormat::Ics => Ok(to_ics(tasks, title, generated_at)),
+        ExportFormat::Html => Ok(to_html(tasks, title, generated_at)),
+    }
+}
+
+/// Standard CSV columns, before the custom field columns
+const CSV_COLUMNS: [&str; 15] = [
+    "ID",
+    "Name",
+    "Status",
+    "Priority",
+    "Assignees",
+    "Tags",
+    "Due Date",
+    "Start Date",
+    "Time Estimate (h)",
+    "Parent",
+    "List",
+    "Date Created",
+    "Date Closed",
+    "Description",
+    "URL",
+];
+
+/// Renders tasks as CSV
+///
+/// Each custom field gets a column named after it, in the order the fields
+/// are first seen. Dates are written as `YYYY-MM-DD HH:MM` in UTC.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::SerializationError` if the CSV cannot be written
+pub fn to_csv(tasks: &[Task]) -> Result<String, ClickUpError> {
+    let mut custom_columns: Vec<&str> = Vec::new();
+    for field in tasks.iter().flat_map(|task| &task.custom_fields) {
+        let name = custom_field_name(field);
+        if !custom_columns.contains(&name) {
+            custom_columns.push(name);
+        }
+    }
+
+    let error = |e: csv::Error| {
+        ClickUpError::SerializationError(format!("Failed to write CSV: {e}"))
+    };
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(CSV_COLUMNS.iter().chain(&custom_columns))
+        .map_err(error)?;
+    for task in tasks {
+        let mut record = vec![
+            task.id.clone(),
+            task.name.clone().unwrap_or_default(),
+            task.status.status.clone(),
+            priority_name(task).to_string(),
+            assignee_names(task).join(", "),
+            tag_names(task).join(", "),
+            format_timestamp(task.due_date.as_deref()),
+            format_timestamp(task.start_date.as_deref()),
+            task.time_estimate
+                .map(|estimate| format!("{:.2}", estimate as f64 / 3_600_000.0))
+                .unwrap_or_default(),
+            task.parent.clone().unwrap_or_default(),
+            task.list.name.clone().unwrap_or_else(|| task.list.id.clone()),
+            format_timestamp(Some(&task.date_created)),
+            format_timestamp(task.date_closed.as_deref()),
+            task.text_content.clone(),
+            task.url.clone(),
+        ];
+        for column in &custom_columns {
+            record.push(
+                task.custom_fields
+                    .iter()
+                    .find(|field| custom_field_name(field) == *column)
+                    .map(custom_field_text)
+                    .unwrap_or_default(),
+            );
+        }
+        writer.write_record(&record).map_err(error)?;
+    }
+    let data = writer
+        .into_inner()
+        .map_err(|e| ClickUpError::SerializationError(format!("Failed to write CSV: {e}")))?;
+    String::from_utf8(data)
+        .map_err(|e| ClickUpError::SerializationError(format!("Failed to write CSV: {e}")))
+}
+
+/// Returns the name a custom field is exported under
+fn custom_field_name(field: &TaskCustomField) -> &str {
+    field.name.as_deref().unwrap_or(&field.id)
+}
+
+/// Returns a custom field's value as text
+///
+/// Drop-down and label values are shown by option name, people fields by
+/// username and date fields as dates.
+pub fn custom_field_text(field: &TaskCustomField) -> String {
+    let Some(value) = &field.value else {
+        return String::new();
+    };
+    let options = field
+        .type_config
+        .as_ref()
+        .and_then(|config| config.get("options"))
+        .and_then(Value::as_array);
+    let option_name = |selected: &Value| {
+        options
+            .and_then(|options| {
+                options.iter().find(|option| {
+                    option.get("id") == Some(selected)
+                        || option.get("orderindex").is_some_and(|index| same_number(index, selected))
+                })
+            })
+            .and_then(|option| option.get("name").or_else(|| option.get("label")))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| value_text(selected))
+    };
+    match (field.type_.as_str(), value) {
+        ("drop_down", selected) => option_name(selected),
+        ("labels", Value::Array(selected)) => selected
+            .iter()
+            .map(option_name)
+            .collect::<Vec<_>>()
+            .join(", "),
+        ("users", Value::Array(users)) => users
+            .iter()
+            .map(|user| {
+                user.get("username")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| value_text(user.get("id").unwrap_or(user)))
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        ("date", date) => format_timestamp(Some(&value_text(date))),
+        (_, other) => value_text(other),
+    }
+}
+
+/// Returns whether two JSON values hold the same number, in either form
+fn same_number(a: &Value, b: &Value) -> bool {
+    let number = |value: &Value| match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.parse().ok(),
+        _ => None,
+    };
+    number(a).is_some_and(|a| Some(a) == number(b))
+}
+
+/// Returns a scalar JSON value as text, and other values as JSON
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Formats a timestamp in milliseconds as `YYYY-MM-DD HH:MM` in UTC
+fn format_timestamp(timestamp: Option<&str>) -> String {
+    parse_timestamp(timestamp)
+        .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Parses a timestamp in milliseconds
+fn parse_timestamp(timestamp: Option<&str>) -> Option<DateTime<Utc>> {
+    timestamp
+        .and_then(|timestamp| timestamp.parse().ok())
+        .and_then(DateTime::from_timestamp_millis)
+}
+
+/// Returns a task's priority name, or an empty string
+fn priority_name(task: &Task) -> &str {
+    task.priority
+        .as_ref()
+        .map(|priority| priority.priority.as_str())
+        .unwrap_or_default()
+}
+
+/// Returns the usernames of a task's assignees
+fn assignee_names(task: &Task) -> Vec<&str> {
+    task.assignees
+        .iter()
+        .map(|assignee| assignee.username.as_str())
+        .collect()
+}
+
+/// Returns the names of a task's tags
+fn tag_names(task: &Task) -> Vec<&str> {
+    task.tags.iter().filter_map(|tag| tag.name.as_deref()).collect()
+}
+
+/// Groups tasks by status, in the order of the statuses' positions
+///
+/// Statuses with the same position (e.g. from different lists) are kept
+/// apart by name and ordered by it.
+fn group_by_status(tasks: &[Task]) -> Vec<(&str, Vec<&Task>)> {
+    let mut groups: Vec<((i64, &str), Vec<&Task>)> = Vec::new();
+    for task in tasks {
+        let key = (task.status.orderindex, task.status.status.as_str());
+        match groups.iter_mut().find(|(group, _)| *group == key) {
+            Some((_, group)) => group.push(task),
+            None => groups.push((key, vec![task])),
+        }
+    }
+    groups.sort_by_key(|(key, _)| *key);
+    groups
+        .into_iter()
+        .map(|((_, status), tasks)| (status, tasks))
+        .collect()
+}
+
+/// Renders tasks as a Markdown report grouped by status
+pub fn to_markdown(tasks: &[Task], title: &str, generated_at: DateTime<Utc>) -> String {
+    let mut markdown = format!("# {title}\n\n");
+    let _ = writeln!(
+        markdown,
+        "_{} task(s), exported {}_\n",
+        tasks.len(),
+        generated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    for (status, group) in group_by_status(tasks) {
+        let _ = writeln!(markdown, "## {status} ({})\n", group.len());
+        for task in group {
+            let mark = if is_done(task) { "x" } else { " " };
+            let name = markdown_escape(task.name.as_deref().unwrap_or("Unnamed"));
+            let _ = write!(markdown, "- [{mark}] [{name}]({})", task.url);
+            let mut details = Vec::new();
+            if let Some(due) = parse_timestamp(task.due_date.as_deref()) {
+                details.push(format!("due {}", due.format("%Y-%m-%d")));
+            }
+            if !priority_name(task).is_empty() {
+                details.push(format!("{} priority", priority_name(task)));
+            }
+            details.extend(assignee_names(task).iter().map(|name| format!("@{name}")));
+            details.extend(tag_names(task).iter().map(|tag| format!("`{tag}`")));
+            if !details.is_empty() {
+                let _ = write!(markdown, " — {}", details.join(" · "));
+            }
+            markdown.push('\n');
+        }
+        markdown.push('\n');
+    }
+    markdown
+}
+
+/// Escapes characters that would end a Markdown link text
+fn markdown_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+/// Renders tasks with due dates as an iCalendar file
+///
+/// Each task becomes an event at its due time, or from its start date to its
+/// due date when it has a start date before the due date. Tasks whose due
+/// date has no time become all-day events ending on the due date.
+pub fn to_ics(tasks: &[Task], title: &str, generated_at: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//clickup-cli//Task Export//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", ics_escape(title)),
+    ];
+    let stamp = ics_time(generated_at);
+    for task in tasks {
+        let Some(due) = parse_timestamp(task.due_date.as_deref()) else {
+            continue;
+        };
+        let start = parse_timestamp(task.start_date.as_deref())
+            .filter(|start| *start < due)
+            .unwrap_or(due);
+        let mut description = task.text_content.trim().to_string();
+        if !description.is_empty() {
+            description.push_str("\n\n");
+        }
+        description.push_str(&task.url);
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@clickup-cli", task.id));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        if task.due_date_has_time() == Some(false) {
+            // All-day events end on the day after their last day
+            let end = due.date_naive() + chrono::Days::new(1);
+            lines.push(format!("DTSTART;VALUE=DATE:{}", start.format("%Y%m%d")));
+            lines.push(format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        } else {
+            lines.push(format!("DTSTART:{}", ics_time(start)));
+            lines.push(format!("DTEND:{}", ics_time(due)));
+        }
+        lines.push(format!(
+            "SUMMARY:{}",
+            ics_escape(task.name.as_deref().unwrap_or("Unnamed"))
+        ));
+        lines.push(format!("DESCRIPTION:{}", ics_escape(&description)));
+        lines.push(format!("URL:{}", task.url));
+        let tags = tag_names(task);
+        if !tags.is_empty() {
+            let tags: Vec<String> = tags.iter().map(|tag| ics_escape(tag)).collect();
+            lines.push(format!("CATEGORIES:{}", tags.join(",")));
+        }
+        if is_done(task) {
+            lines.push("TRANSP:TRANSPARENT".to_string());
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_ics_line(line)).collect()
+}
+
+/// Formats a time as an iCalendar UTC date-time
+fn ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes text for an iCalendar property value
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Folds an iCalendar line into 75-octet lines ending in CRLF
+///
+/// Continuation lines start with a space, and lines are only split between
+/// characters.
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 4);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// Inline styles of the HTML report
+const HTML_STYLE: &str = "\
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2em; color: #222; }
+h1 { margin-bottom: 0.2em; }
+.meta { color: #666; margin-top: 0; }
+h2 { border-bottom: 2px solid #ddd; padding-bottom: 0.2em; margin-top: 1.5em; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.35em 0.6em; border-bottom: 1px solid #eee; vertical-align: top; }
+th { background: #f6f6f6; }
+.closed td:first-child { text-decoration: line-through; color: #888; }
+.tag { background: #eef; border-radius: 3px; padding: 0 0.3em; margin-right: 0.2em; }
+a { color: inherit; }
+@media print { body { margin: 0; } h2 { break-after: avoid; } tr { break-inside: avoid; } }
+";
+
+/// Renders tasks as a standalone HTML report grouped by status
+pub fn to_html(tasks: &[Task], title: &str, generated_at: DateTime<Utc>) -> String {
+    let title = html_escape(title);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n<style>\n{HTML_STYLE}</style>\n</head>\n<body>\n\
+         <h1>{title}</h1>\n"
+    );
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">{} task(s), exported {}</p>",
+        tasks.len(),
+        generated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    for (status, group) in group_by_status(tasks) {
+        let _ = writeln!(
+            html,
+            "<h2>{} ({})</h2>\n<table>\n<tr><th>Task</th><th>Assignees</th>\
+             <th>Priority</th><th>Due</th><th>Tags</th></tr>",
+            html_escape(status),
+            group.len()
+        );
+        for task in group {
+            let class = if is_done(task) { " class=\"closed\"" } else { "" };
+            let tags: String = tag_names(task)
+                .iter()
+                .map(|tag| format!("<span class=\"tag\">{}</span>", html_escape(tag)))
+                .collect();
+            let _ = writeln!(
+                html,
+                "<tr{class}><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td><td>{tags}</td></tr>",
+                html_escape(&task.url),
+                html_escape(task.name.as_deref().unwrap_or("Unnamed")),
+                html_escape(&assignee_names(task).join(", ")),
+                html_escape(priority_name(task)),
+                parse_timestamp(task.due_date.as_deref())
+                    .map(|due| due.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+            );
+        }
+        html.push_str("</table>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Escapes text for HTML content and attribute values
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
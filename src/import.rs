@@ -0,0 +1,756 @@
+//! # Task Import
+//!
+//! This module reads tasks to import from CSV, JSON or Markdown files for
+//! `tasks import`, and checks them before anything is sent to ClickUp.
+//!
+//! ## Formats
+//!
+//! - **CSV**: One task per row. Columns are matched to task fields by their
+//!   header (see `default_field`), or by an explicit `Header=field` mapping.
+//!   Jira and Trello export headers are recognised.
+//! - **JSON**: An array of `CreateTaskRequest`-shaped objects. Assignees may
+//!   be user IDs, usernames or emails, and an optional `ref` names the row
+//!   so that other rows can use it as their `parent`.
+//! - **Markdown**: A task list. Nested items become subtasks of the item
+//!   above them, and checked items get the done status.
+//!
+//! ## Fields
+//!
+//! | Field | Accepts |
+//! |-------|---------|
+//! | `name` | Task name (required) |
+//! | `description` | Markdown or plain text |
+//! | `status` | A status of the target list |
+//! | `priority` | 1-4, or urgent/high/normal/low (Jira's highest/medium/lowest too) |
+//! | `due_date` | `2025-07-01`, `2025-07-01 14:30`, RFC 3339, Jira's `01/Jul/25 2:30 PM`, `07/01/2025` or a Unix timestamp in milliseconds |
+//! | `assignee` | Email, username or user ID; several can be separated by `,` or `;` |
+//! | `tags` | Tag names separated by `,` or `;` |
+//! | `parent` | The `ref` of another row, or the ID of an existing task |
+//! | `ref` | A name other rows can use as their `parent` |
+//! | `custom:<field>` | Value of the custom field with that name or ID |
+//! | `ignore` | Nothing; the column is skipped |
+//!
+//! Columns that map to `tags`, `assignee` or a custom field can repeat (Jira
+//! exports one `Labels` column per label). For other fields the first
+//! non-empty column wins.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::import::{order_rows, parse_csv};
+//! use std::collections::HashMap;
+//!
+//! let csv = "Summary,Issue id,Parent id,Priority\nEpic,1,,High\nStory,2,1,Low\n";
+//! let rows = parse_csv(csv, &HashMap::new()).unwrap();
+//! assert_eq!(rows[1].parent.as_deref(), Some("1"));
+//! assert_eq!(rows[0].request.priority, Some(2));
+//! assert_eq!(order_rows(&rows).unwrap(), vec![0, 1]);
+//! ```
+
+use crate::error::ClickUpError;
+use crate::models::{CreateTaskRequest, CustomField};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Format of an import file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// An array of task objects
+    Json,
+    /// A Markdown task list
+    #[value(alias = "md")]
+    Markdown,
+}
+
+impl ImportFormat {
+    /// Guesses the format of a file from its extension
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if the extension isn't `.csv`,
+    ///   `.json`, `.md` or `.markdown`
+    pub fn from_path(path: &Path) -> Result<Self, ClickUpError> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        match extension.as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            "md" | "markdown" => Ok(Self::Markdown),
+            _ => Err(ClickUpError::ValidationError(format!(
+                "Cannot tell the format of '{}'; use --format csv|json|markdown",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Task field a CSV column is imported into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportField {
+    /// Task name
+    Name,
+    /// Task description
+    Description,
+    /// Status name
+    Status,
+    /// Priority number or name
+    Priority,
+    /// Due date
+    DueDate,
+    /// Assignee email, username or ID
+    Assignee,
+    /// Tag names
+    Tags,
+    /// Reference to the parent row or task
+    Parent,
+    /// Reference other rows can use as their parent
+    Ref,
+    /// Value of the custom field with this name or ID
+    CustomField(String),
+    /// Column that isn't imported
+    Ignore,
+}
+
+impl FromStr for ImportField {
+    type Err = ClickUpError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if let Some(field) = value.strip_prefix("custom:") {
+            return Ok(Self::CustomField(field.trim().to_string()));
+        }
+        match value.to_lowercase().replace('-', "_").as_str() {
+            "name" => Ok(Self::Name),
+            "description" => Ok(Self::Description),
+            "status" => Ok(Self::Status),
+            "priority" => Ok(Self::Priority),
+            "due_date" | "due" => Ok(Self::DueDate),
+            "assignee" | "assignees" => Ok(Self::Assignee),
+            "tags" | "tag" => Ok(Self::Tags),
+            "parent" => Ok(Self::Parent),
+            "ref" => Ok(Self::Ref),
+            "ignore" => Ok(Self::Ignore),
+            _ => Err(ClickUpError::ValidationError(format!(
+                "Unknown import field '{value}'. Use name, description, status, priority, \
+                 due_date, assignee, tags, parent, ref, custom:<field> or ignore"
+            ))),
+        }
+    }
+}
+
+/// Returns the field a CSV column with this header is imported into by default
+///
+/// Headers are matched ignoring case. Jira's `Custom field (Name)` headers
+/// map to the custom field `Name`.
+pub fn default_field(header: &str) -> Option<ImportField> {
+    let header = header.trim();
+    if let Some(field) = header
+        .strip_prefix("Custom field (")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return Some(ImportField::CustomField(field.to_string()));
+    }
+    if let Ok(field) = header.parse() {
+        return Some(field);
+    }
+    match header.to_lowercase().as_str() {
+        "title" | "summary" | "task name" | "card name" => Some(ImportField::Name),
+        "card description" | "notes" => Some(ImportField::Description),
+        "due date" | "due" => Some(ImportField::DueDate),
+        "assignee email" | "assigned to" | "owner" | "members" => Some(ImportField::Assignee),
+        "labels" | "label" => Some(ImportField::Tags),
+        "parent id" | "parent task" | "parent ref" => Some(ImportField::Parent),
+        "id" | "issue id" | "card id" => Some(ImportField::Ref),
+        _ => None,
+    }
+}
+
+/// Parses `Header=field` column mappings given on the command line
+///
+/// # Returns
+///
+/// Returns the fields keyed by lowercased header.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if a mapping has no `=` or names an
+///   unknown field
+pub fn parse_mapping(mappings: &[String]) -> Result<HashMap<String, ImportField>, ClickUpError> {
+    mappings
+        .iter()
+        .map(|mapping| {
+            let (header, field) = mapping.split_once('=').ok_or_else(|| {
+                ClickUpError::ValidationError(format!(
+                    "Invalid column mapping '{mapping}'. Use Header=field, e.g. Summary=name"
+                ))
+            })?;
+            Ok((header.trim().to_lowercase(), field.parse()?))
+        })
+        .collect()
+}
+
+/// A task read from an import file
+///
+/// Assignees and custom fields are kept as written until they are looked
+/// up in the workspace and list, and `parent` until the rows are ordered.
+#[derive(Debug, Default)]
+pub struct ImportRow {
+    /// Line of the file the task starts on (1-based)
+    pub line: usize,
+    /// Reference other rows can use as their parent
+    pub reference: Option<String>,
+    /// The parent row's reference, or the ID of an existing task
+    pub parent: Option<String>,
+    /// The task, without assignees given by name or custom fields given by name
+    pub request: CreateTaskRequest,
+    /// Assignees given as emails, usernames or user IDs
+    pub assignees: Vec<String>,
+    /// Custom field values as (field name or ID, value)
+    pub custom_fields: Vec<(String, String)>,
+}
+
+/// A problem with one row of an import file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    /// Line of the file the row starts on
+    pub line: usize,
+    /// What is wrong
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Turns row errors into one validation error listing all of them
+///
+/// # Returns
+///
+/// Returns `Ok(())` if there are no errors.
+pub fn check_errors(errors: Vec<RowError>) -> Result<(), ClickUpError> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let lines: Vec<String> = errors.iter().map(|error| format!("  {error}")).collect();
+    Err(ClickUpError::ValidationError(format!(
+        "{} problem(s) found, nothing was imported:\n{}",
+        errors.len(),
+        lines.join("\n")
+    )))
+}
+
+/// The outcome of importing one row, as written to the result file
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ImportResult {
+    /// Line of the file the row starts on
+    pub line: usize,
+    /// The row's reference, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// Task name
+    pub name: String,
+    /// ID of the created task
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// URL of the created task
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Why the task wasn't created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Writes the outcome of the rows imported so far to the result file
+///
+/// The file is rewritten after every row, through a temporary file renamed
+/// over it, so an interrupted import still leaves the IDs of the tasks it
+/// created.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::SerializationError` if the results cannot be serialized
+/// - `ClickUpError::IoError` if the file cannot be written
+pub fn write_results(path: &Path, results: &[ImportResult]) -> Result<(), ClickUpError> {
+    let json = serde_json::to_string_pretty(results).map_err(|e| {
+        ClickUpError::SerializationError(format!("Failed to serialize import results: {e}"))
+    })?;
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    std::fs::write(&temp_path, json)?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Reads tasks from CSV with a header row
+///
+/// # Arguments
+///
+/// * `data` - The CSV text
+/// * `mapping` - Fields for headers (lowercased) that override `default_field`
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the CSV is malformed, has no name
+///   column, or any row has a missing name or an invalid value
+pub fn parse_csv(
+    data: &str,
+    mapping: &HashMap<String, ImportField>,
+) -> Result<Vec<ImportRow>, ClickUpError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(data.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| ClickUpError::ValidationError(format!("Invalid CSV header: {e}")))?
+        .clone();
+    let fields: Vec<Option<ImportField>> = headers
+        .iter()
+        .map(|header| {
+            mapping
+                .get(&header.trim().to_lowercase())
+                .cloned()
+                .or_else(|| default_field(header))
+        })
+        .collect();
+    if !fields.contains(&Some(ImportField::Name)) {
+        return Err(ClickUpError::ValidationError(
+            "The CSV has no name column; map one with --map <Header>=name".to_string(),
+        ));
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let record =
+            record.map_err(|e| ClickUpError::ValidationError(format!("Invalid CSV: {e}")))?;
+        let line = record.position().map_or(0, |position| position.line() as usize);
+        let mut row = ImportRow {
+            line,
+            ..Default::default()
+        };
+        let mut values: Vec<(&ImportField, &str)> = Vec::new();
+        for (field, value) in fields.iter().zip(record.iter()) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match field {
+                Some(ImportField::Assignee) => row.assignees.extend(split_list(value)),
+                Some(ImportField::Tags) => row
+                    .request
+                    .tags
+                    .get_or_insert_with(Vec::new)
+                    .extend(split_list(value)),
+                Some(ImportField::CustomField(name)) => {
+                    row.custom_fields.push((name.clone(), value.to_string()))
+                }
+                Some(ImportField::Ignore) | None => {}
+                Some(field) => {
+                    if !values.iter().any(|(set, _)| *set == field) {
+                        values.push((field, value));
+                    }
+                }
+            }
+        }
+        for (field, value) in values {
+            if let Err(message) = set_field(&mut row, field, value) {
+                errors.push(RowError { line, message });
+            }
+        }
+        if row.request.name.is_empty() {
+            errors.push(RowError {
+                line,
+                message: "the task has no name".to_string(),
+            });
+        }
+        rows.push(row);
+    }
+    check_errors(errors)?;
+    Ok(rows)
+}
+
+/// Sets a single-valued field of a row from its text
+fn set_field(row: &mut ImportRow, field: &ImportField, value: &str) -> Result<(), String> {
+    match field {
+        ImportField::Name => row.request.name = value.to_string(),
+        ImportField::Description => row.request.description = Some(value.to_string()),
+        ImportField::Status => row.request.status = Some(value.to_string()),
+        ImportField::Priority => row.request.priority = Some(parse_priority(value)?),
+        ImportField::DueDate => {
+            let (due_date, with_time) = parse_due_date(value)?;
+            row.request.due_date = Some(due_date);
+            row.request.due_date_time = Some(with_time);
+        }
+        ImportField::Parent => row.parent = Some(value.to_string()),
+        ImportField::Ref => row.reference = Some(value.to_string()),
+        ImportField::Assignee
+        | ImportField::Tags
+        | ImportField::CustomField(_)
+        | ImportField::Ignore => {}
+    }
+    Ok(())
+}
+
+/// Splits a list of values separated by `,` or `;`
+fn split_list(value: &str) -> impl Iterator<Item = String> + '_ {
+    value
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+}
+
+/// Parses a priority given as 1-4 or by name
+///
+/// # Errors
+///
+/// Returns a message if the priority isn't recognised.
+pub fn parse_priority(value: &str) -> Result<i64, String> {
+    match value.trim().to_lowercase().as_str() {
+        "1" | "urgent" | "highest" | "critical" | "blocker" => Ok(1),
+        "2" | "high" | "major" => Ok(2),
+        "3" | "normal" | "medium" => Ok(3),
+        "4" | "low" | "lowest" | "minor" | "trivial" => Ok(4),
+        _ => Err(format!(
+            "invalid priority '{value}'; use 1-4 or urgent, high, normal or low"
+        )),
+    }
+}
+
+/// Parses a due date
+///
+/// Dates without a time zone are read as UTC.
+///
+/// # Returns
+///
+/// Returns the Unix timestamp in milliseconds and whether a time of day was given.
+///
+/// # Errors
+///
+/// Returns a message if the date isn't in a recognised format.
+pub fn parse_due_date(value: &str) -> Result<(i64, bool), String> {
+    let value = value.trim();
+    if value.len() >= 10 && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        if let Ok(milliseconds) = value.parse() {
+            return Ok((milliseconds, true));
+        }
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Ok((date_time.timestamp_millis(), true));
+    }
+    const DATE_TIME_FORMATS: [&str; 5] = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%d/%b/%y %I:%M %p",
+        "%m/%d/%Y %H:%M",
+    ];
+    for format in DATE_TIME_FORMATS {
+        if let Ok(date_time) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok((date_time.and_utc().timestamp_millis(), true));
+        }
+    }
+    for format in ["%Y-%m-%d", "%d/%b/%y", "%m/%d/%Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+            return Ok((midnight.and_utc().timestamp_millis(), false));
+        }
+    }
+    Err(format!(
+        "invalid due date '{value}'; use e.g. 2025-07-01 or 2025-07-01 14:30"
+    ))
+}
+
+/// Reads tasks from a JSON array of task objects
+///
+/// Objects have the fields of `CreateTaskRequest`. In addition, `ref` names
+/// the row, `parent` may be another row's `ref`, and `assignees` may hold
+/// emails and usernames as well as user IDs.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the file isn't a JSON array or any
+///   object doesn't describe a task
+pub fn parse_json(data: &str) -> Result<Vec<ImportRow>, ClickUpError> {
+    let objects: Vec<Value> = serde_json::from_str(data).map_err(|e| {
+        ClickUpError::ValidationError(format!("Expected a JSON array of tasks: {e}"))
+    })?;
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (index, mut object) in objects.into_iter().enumerate() {
+        // Rows in JSON are numbered by their position in the array
+        let line = index + 1;
+        let Some(fields) = object.as_object_mut() else {
+            errors.push(RowError {
+                line,
+                message: "expected a task object".to_string(),
+            });
+            continue;
+        };
+        let reference = fields.remove("ref").and_then(|value| text(&value));
+        let assignees: Vec<String> = match fields.remove("assignees") {
+            Some(Value::Array(assignees)) => assignees.iter().filter_map(text).collect(),
+            _ => Vec::new(),
+        };
+        match serde_json::from_value::<CreateTaskRequest>(object) {
+            Ok(mut request) => {
+                let parent = request.parent.take();
+                if request.name.trim().is_empty() {
+                    errors.push(RowError {
+                        line,
+                        message: "the task has no name".to_string(),
+                    });
+                }
+                rows.push(ImportRow {
+                    line,
+                    reference,
+                    parent,
+                    request,
+                    assignees,
+                    custom_fields: Vec::new(),
+                });
+            }
+            Err(e) => errors.push(RowError {
+                line,
+                message: e.to_string(),
+            }),
+        }
+    }
+    check_errors(errors)?;
+    Ok(rows)
+}
+
+/// Returns a JSON string or number as text
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+/// Reads tasks from a Markdown task list
+///
+/// Every list item becomes a task, and items nested under another item
+/// become its subtasks. Checked items (`- [x]`) get `done_status`. Other
+/// lines are ignored.
+///
+/// # Arguments
+///
+/// * `data` - The Markdown text
+/// * `done_status` - Status given to checked items
+pub fn parse_markdown(data: &str, done_status: &str) -> Vec<ImportRow> {
+    let mut rows = Vec::new();
+    // Indentation and reference of the items enclosing the current one
+    let mut open: Vec<(usize, String)> = Vec::new();
+    for (index, line) in data.lines().enumerate() {
+        let indent: usize = line
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| line.trim_start().strip_prefix(bullet))
+        else {
+            continue;
+        };
+        let item = item.trim_start();
+        let (name, done) = if let Some(rest) = item.strip_prefix("[ ]") {
+            (rest, false)
+        } else if let Some(rest) = item
+            .strip_prefix("[x]")
+            .or_else(|| item.strip_prefix("[X]"))
+        {
+            (rest, true)
+        } else {
+            (item, false)
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+
+        while open.last().is_some_and(|(open_indent, _)| *open_indent >= indent) {
+            open.pop();
+        }
+        let line = index + 1;
+        let reference = format!("line {line}");
+        rows.push(ImportRow {
+            line,
+            reference: Some(reference.clone()),
+            parent: open.last().map(|(_, parent)| parent.clone()),
+            request: CreateTaskRequest {
+                name: name.to_string(),
+                status: done.then(|| done_status.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        open.push((indent, reference));
+    }
+    rows
+}
+
+/// Orders rows so that every parent row comes before its subtasks
+///
+/// Rows otherwise keep their order in the file. A `parent` that isn't the
+/// reference of any row is taken to be the ID of an existing task.
+///
+/// # Returns
+///
+/// Returns the indexes of the rows in the order to create them.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if two rows have the same reference,
+///   or rows are their own parents directly or through other rows
+pub fn order_rows(rows: &[ImportRow]) -> Result<Vec<usize>, ClickUpError> {
+    let mut errors = Vec::new();
+    let mut references = HashMap::new();
+    for (index, row) in rows.iter().enumerate() {
+        if let Some(reference) = &row.reference {
+            if let Some(&first) = references.get(reference.as_str()) {
+                let first: &ImportRow = &rows[first];
+                errors.push(RowError {
+                    line: row.line,
+                    message: format!(
+                        "the reference '{reference}' is already used on line {}",
+                        first.line
+                    ),
+                });
+            } else {
+                references.insert(reference.as_str(), index);
+            }
+        }
+    }
+    check_errors(std::mem::take(&mut errors))?;
+
+    let parent_of = |index: usize| {
+        rows[index]
+            .parent
+            .as_deref()
+            .and_then(|parent| references.get(parent).copied())
+    };
+    let mut order = Vec::with_capacity(rows.len());
+    let mut placed = HashSet::new();
+    for (start, row) in rows.iter().enumerate() {
+        // Walk up to the first ancestor that hasn't been placed yet
+        let mut chain = vec![start];
+        let mut seen = HashSet::from([start]);
+        while let Some(parent) = parent_of(*chain.last().unwrap_or(&start)) {
+            if placed.contains(&parent) {
+                break;
+            }
+            if !seen.insert(parent) {
+                errors.push(RowError {
+                    line: row.line,
+                    message: "the parent rows of the task form a cycle".to_string(),
+                });
+                chain.clear();
+                break;
+            }
+            chain.push(parent);
+        }
+        for index in chain.into_iter().rev() {
+            if placed.insert(index) {
+                order.push(index);
+            }
+        }
+    }
+    check_errors(errors)?;
+    Ok(order)
+}
+
+/// Finds a custom field by ID, or by name ignoring case
+pub fn find_custom_field<'a>(fields: &'a [CustomField], key: &str) -> Option<&'a CustomField> {
+    fields.iter().find(|field| field.id == key).or_else(|| {
+        fields.iter().find(|field| {
+            field
+                .name
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(key))
+        })
+    })
+}
+
+/// Converts the text of a custom field value to the value the API expects
+///
+/// Numbers, checkboxes, drop-downs (by option name) and labels (option
+/// names separated by `,` or `;`) are converted; other fields take the text.
+///
+/// # Errors
+///
+/// Returns a message if the text isn't valid for the field.
+pub fn custom_field_value(field: &CustomField, value: &str) -> Result<Value, String> {
+    let name = field.name.as_deref().unwrap_or(&field.id);
+    match field.type_.as_str() {
+        "number" | "currency" | "emoji" => value
+            .parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| format!("'{value}' is not a number for the custom field '{name}'")),
+        "checkbox" => match value.to_lowercase().as_str() {
+            "true" | "yes" | "1" | "x" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" | "" => Ok(Value::Bool(false)),
+            _ => Err(format!(
+                "'{value}' is not true or false for the custom field '{name}'"
+            )),
+        },
+        "drop_down" => option_id(field, value).map(Value::String),
+        "labels" => split_list(value)
+            .map(|label| option_id(field, &label).map(Value::String))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        _ => Ok(Value::String(value.to_string())),
+    }
+}
+
+/// Returns the ID of a drop-down or label option, found by name or ID
+fn option_id(field: &CustomField, value: &str) -> Result<String, String> {
+    let options = field
+        .type_config
+        .as_ref()
+        .and_then(|config| config.get("options"))
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    options
+        .iter()
+        .find(|option| {
+            option.get("id").and_then(Value::as_str) == Some(value)
+                || ["name", "label"].iter().any(|key| {
+                    option
+                        .get(key)
+                        .and_then(Value::as_str)
+                        .is_some_and(|name| name.eq_ignore_ascii_case(value))
+                })
+        })
+        .and_then(|option| option.get("id").and_then(Value::as_str))
+        .map(str::to_string)
+        .ok_or_else(|| {
+            format!(
+                "'{value}' is not an option of the custom field '{}'",
+                field.name.as_deref().unwrap_or(&field.id)
+            )
+        })
+}
@@ -0,0 +1,378 @@
+//! # Undo Journal
+//!
+//! This module provides a local journal of task and comment changes so that
+//! they can be reverted later with `clickup-cli undo`. The operations that
+//! are journaled are listed in `JournalOperation`.
+//!
+//! ## Features
+//!
+//! - **Prior State Capture**: Stores the task or comment as it was before the change
+//! - **Append-Only Log**: Entries are stored as JSON lines in the config directory
+//! - **Undo Tracking**: Entries are marked as undone once they have been reverted
+//!
+//! ## Storage
+//!
+//! The journal is stored next to `config.toml` as `journal.jsonl`:
+//! - Windows: `%APPDATA%\clickup-cli\journal.jsonl`
+//! - macOS: `~/Library/Application Support/clickup-cli/journal.jsonl`
+//! - Linux: `~/.config/clickup-cli/journal.jsonl`
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::journal::{Journal, JournalOperation};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let dir = tempfile::tempdir()?;
+//!     let journal = Journal::new(dir.path().join("journal.jsonl"));
+//!
+//!     // Record a task update together with the task's previous state
+//!     let previous = serde_json::json!({"id": "task_123", "name": "Old name"});
+//!     journal.record(JournalOperation::TaskUpdate, "task_123", Some(previous), None)?;
+//!
+//!     let entries = journal.entries()?;
+//!     assert_eq!(entries.len(), 1);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::CommentTarget;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Kind of mutating operation recorded in the journal
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalOperation {
+    /// A task was created (undo deletes it)
+    TaskCreate,
+    /// A task was updated (undo restores the previous fields)
+    TaskUpdate,
+    /// A task was deleted (undo recreates it)
+    TaskDelete,
+    /// A task was moved to another list (undo moves it back and restores its status)
+    TaskMove,
+    /// A comment was updated (undo restores the previous text)
+    CommentUpdate,
+    /// A comment was deleted (undo recreates it on its task, list or view)
+    CommentDelete,
+}
+
+impl std::fmt::Display for JournalOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            JournalOperation::TaskCreate => "task create",
+            JournalOperation::TaskUpdate => "task update",
+            JournalOperation::TaskDelete => "task delete",
+            JournalOperation::TaskMove => "task move",
+            JournalOperation::CommentUpdate => "comment update",
+            JournalOperation::CommentDelete => "comment delete",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single journal entry
+///
+/// Each entry describes one mutating API call along with the state of the
+/// affected entity before the call was made.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JournalEntry {
+    /// Sequential entry identifier
+    pub id: u64,
+    /// Time the change was made (RFC 3339)
+    pub timestamp: String,
+    /// Operation that was performed
+    pub operation: JournalOperation,
+    /// ID of the task or comment that was changed
+    pub entity_id: String,
+    /// Task, list or view ID the entity belongs to (for comments)
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Kind of item `parent_id` refers to; entries without one belong to a task
+    #[serde(default)]
+    pub parent_kind: Option<CommentTarget>,
+    /// List a moved task came from (for task moves)
+    #[serde(default)]
+    pub source_list_id: Option<String>,
+    /// Entity state before the change, as returned by the API
+    #[serde(default)]
+    pub previous: Option<serde_json::Value>,
+    /// Whether the entry has already been undone
+    #[serde(default)]
+    pub undone: bool,
+}
+
+/// Local journal of mutating operations
+///
+/// The journal is cheap to construct; the file is only touched when entries
+/// are recorded or read.
+#[derive(Debug, Clone)]
+pub struct Journal {
+    /// Path to the JSON lines journal file
+    path: PathBuf,
+    /// Whether new entries are recorded
+    enabled: bool,
+    /// ID for the next entry, shared by clones; read from the file on the
+    /// first record
+    next_id: Arc<Mutex<Option<u64>>>,
+}
+
+impl Journal {
+    /// Creates a journal backed by the given file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the journal file
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            enabled: true,
+            next_id: Arc::default(),
+        }
+    }
+
+    /// Creates a journal at the default location in the config directory
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the config directory cannot be determined
+    pub fn default_location() -> Result<Self, ClickUpError> {
+        Ok(Self::new(Config::config_dir()?.join("journal.jsonl")))
+    }
+
+    /// Creates a journal that never records entries
+    pub fn disabled() -> Self {
+        Self {
+            path: PathBuf::new(),
+            enabled: false,
+            next_id: Arc::default(),
+        }
+    }
+
+    /// Returns whether new entries are recorded
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the path to the journal file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends a new entry to the journal
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation that was performed
+    /// * `entity_id` - ID of the task or comment that was changed
+    /// * `previous` - Entity state before the change
+    /// * `parent_id` - Task ID the entity belongs to (for comments)
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded entry, or `None` if the journal is disabled.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the journal file cannot be written
+    /// - `ClickUpError::JsonError` if the entry cannot be serialized
+    pub fn record(
+        &self,
+        operation: JournalOperation,
+        entity_id: &str,
+        previous: Option<serde_json::Value>,
+        parent_id: Option<String>,
+    ) -> Result<Option<JournalEntry>, ClickUpError> {
+        let parent = parent_id.map(|id| (CommentTarget::Task, id));
+        self.record_with_parent(operation, entity_id, previous, parent)
+    }
+
+    /// Appends a new entry for an entity that belongs to a task, list or view
+    ///
+    /// The journal is only read for the first entry a client records; later
+    /// IDs come from a counter, so bulk commands don't reread the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation that was performed
+    /// * `entity_id` - ID of the task or comment that was changed
+    /// * `previous` - Entity state before the change
+    /// * `parent` - Kind and ID of the item the entity belongs to
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded entry, or `None` if the journal is disabled.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the journal file cannot be written
+    /// - `ClickUpError::JsonError` if the entry cannot be serialized
+    pub fn record_with_parent(
+        &self,
+        operation: JournalOperation,
+        entity_id: &str,
+        previous: Option<serde_json::Value>,
+        parent: Option<(CommentTarget, String)>,
+    ) -> Result<Option<JournalEntry>, ClickUpError> {
+        if !self.enabled {
+            return Ok(None);
+        }
+
+        let (parent_kind, parent_id) = parent.unzip();
+        self.append(JournalEntry {
+            parent_id,
+            parent_kind,
+            ..Self::new_entry(operation, entity_id, previous)
+        })
+    }
+
+    /// Appends a new entry for a task that was moved to another list
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - ID of the task that was moved
+    /// * `previous` - Task state before the move
+    /// * `source_list_id` - List the task was moved from
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded entry, or `None` if the journal is disabled.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the journal file cannot be written
+    /// - `ClickUpError::JsonError` if the entry cannot be serialized
+    pub fn record_move(
+        &self,
+        task_id: &str,
+        previous: Option<serde_json::Value>,
+        source_list_id: Option<String>,
+    ) -> Result<Option<JournalEntry>, ClickUpError> {
+        if !self.enabled {
+            return Ok(None);
+        }
+
+        self.append(JournalEntry {
+            source_list_id,
+            ..Self::new_entry(JournalOperation::TaskMove, task_id, previous)
+        })
+    }
+
+    /// Builds an entry without a parent; its ID is set when it is appended
+    fn new_entry(
+        operation: JournalOperation,
+        entity_id: &str,
+        previous: Option<serde_json::Value>,
+    ) -> JournalEntry {
+        JournalEntry {
+            id: 0,
+            timestamp: Utc::now().to_rfc3339(),
+            operation,
+            entity_id: entity_id.to_string(),
+            parent_id: None,
+            parent_kind: None,
+            source_list_id: None,
+            previous,
+            undone: false,
+        }
+    }
+
+    /// Gives an entry the next ID and writes it to the end of the journal
+    fn append(&self, mut entry: JournalEntry) -> Result<Option<JournalEntry>, ClickUpError> {
+        let mut next_id = self
+            .next_id
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entry.id = match *next_id {
+            Some(id) => id,
+            None => self.entries()?.iter().map(|e| e.id + 1).max().unwrap_or(1),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        *next_id = Some(entry.id + 1);
+
+        Ok(Some(entry))
+    }
+
+    /// Reads all entries from the journal, oldest first
+    ///
+    /// Lines that cannot be parsed are skipped so that a single corrupt
+    /// line does not make the rest of the journal unusable.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the journal file exists but cannot be read
+    pub fn entries(&self) -> Result<Vec<JournalEntry>, ClickUpError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&self.path)?;
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+
+    /// Marks the given entry as undone
+    ///
+    /// The journal is rewritten to a temporary file next to it and renamed
+    /// over it, so an interrupted write never loses the undo history. Lines
+    /// that cannot be parsed are kept as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - ID of the entry to mark
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::NotFoundError` if no entry has the given ID
+    /// - `ClickUpError::IoError` if the journal file cannot be rewritten
+    pub fn mark_undone(&self, entry_id: u64) -> Result<(), ClickUpError> {
+        let not_found = || ClickUpError::NotFoundError(format!("Journal entry {entry_id} not found"));
+        if !self.path.exists() {
+            return Err(not_found());
+        }
+        let mut found = false;
+        let mut content = String::new();
+        for line in fs::read_to_string(&self.path)?.lines() {
+            match serde_json::from_str::<JournalEntry>(line) {
+                Ok(mut entry) if entry.id == entry_id => {
+                    entry.undone = true;
+                    found = true;
+                    content.push_str(&serde_json::to_string(&entry)?);
+                }
+                _ => content.push_str(line),
+            }
+            content.push('\n');
+        }
+        if !found {
+            return Err(not_found());
+        }
+
+        let temp_path = self.path.with_extension("jsonl.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+}
@@ -7,11 +7,27 @@
 //! ## Modules
 //!
 //! - **api**: ClickUp API client for making authenticated requests
+//! - **backup**: Space backup files written by `spaces backup`
 //! - **commands**: CLI command implementations for all operations
 //! - **config**: Configuration management and settings
+//! - **data_source**: Reads answered by the ClickUp API or a space backup for `--offline`
+//! - **dependency_graph**: Dependency graphs with cycle and due date checks
 //! - **error**: Error handling and custom error types
+//! - **export**: Task exports as CSV, Markdown, iCalendar and HTML
+//! - **import**: Reading and checking tasks to import from CSV, JSON or Markdown
+//! - **journal**: Local undo journal for mutating operations
 //! - **models**: Data structures for API communication
+//! - **oauth**: OAuth2 authorization-code login flow
 //! - **rate_limiter**: Rate limiting functionality for API requests
+//! - **reports**: Workload and burndown reports with ASCII charts
+//! - **rich_text**: Markdown, mentions and terminal rendering for comments
+//! - **search**: Matching and ranking for full-text task search
+//! - **space_diff**: Differences between two space backups for `spaces diff`
+//! - **task_tree**: Subtask trees with progress and time estimate rollups
+//! - **template**: Local task templates read from YAML files
+//! - **token_store**: Keyring and encrypted-file storage for API tokens
+//! - **watch**: Snapshots and change detection for `tasks watch`
+//! - **webhook**: Local receiver for signed webhook deliveries
 //!
 //! ## Usage
 //!
@@ -19,8 +35,24 @@
 //! used as a dependency in other Rust projects that need ClickUp API functionality.
 
 pub mod api;
+pub mod backup;
 pub mod commands;
 pub mod config;
+pub mod data_source;
+pub mod dependency_graph;
 pub mod error;
+pub mod export;
+pub mod import;
+pub mod journal;
 pub mod models;
+pub mod oauth;
 pub mod rate_limiter;
+pub mod reports;
+pub mod rich_text;
+pub mod search;
+pub mod space_diff;
+pub mod task_tree;
+pub mod template;
+pub mod token_store;
+pub mod watch;
+pub mod webhook;
@@ -14,6 +14,14 @@
 //! - **List Management**: View and manage lists within spaces
 //! - **Task Management**: Create, read, update, and delete tasks
 //! - **Comment Management**: Add and view comments on tasks
+//! - **Undo**: Revert changes recorded in the local undo journal
+//! - **Webhooks**: Manage webhooks and receive their events locally
+//! - **Goals**: Track goals and their key results
+//! - **Views**: List saved views and fetch the tasks they resolve to
+//! - **Templates**: List task templates, including local YAML templates
+//! - **Users and Guests**: Invite and remove workspace members and guests
+//! - **Search**: Full-text search across task names, descriptions and comments
+//! - **Reports**: Workload per assignee, status or list, and list burndowns
 //!
 //! ## Usage
 //!
@@ -34,18 +42,46 @@
 //! - Windows: `%APPDATA%\clickup-cli\config.toml`
 //! - macOS: `~/Library/Application Support/clickup-cli/config.toml`
 //! - Linux: `~/.config/clickup-cli/config.toml`
+//!
+//! Multiple accounts can be configured as named profiles and selected with
+//! `--profile <name>` or the `CLICKUP_PROFILE` environment variable.
+//!
+//! `--offline <backup-file>` answers task, list, comment and search reads
+//! from a file written by `spaces backup`, without calling ClickUp.
 
 use clap::{Parser, Subcommand};
+use colored::Colorize;
 use log::{error, info};
+use std::path::PathBuf;
 
 mod api;
+mod backup;
 mod commands;
 mod config;
+mod data_source;
+mod dependency_graph;
 mod error;
+mod export;
+mod import;
+mod journal;
 mod models;
+mod oauth;
 mod rate_limiter;
+mod reports;
+mod rich_text;
+mod search;
+mod space_diff;
+mod task_tree;
+mod template;
+mod token_store;
+mod watch;
+mod webhook;
 
-use crate::commands::{auth, comments, lists, spaces, tasks, teams, workspaces};
+use crate::commands::{
+    auth, comments, goals, guests, lists, spaces, tasks, teams, templates, undo, users, views,
+    webhooks, workspaces,
+};
+use crate::commands::utils::ErrorUtils;
 use crate::config::Config;
 
 /// Main CLI application structure
@@ -68,6 +104,22 @@ struct Cli {
     /// Enable debug logging for detailed output
     #[arg(long, global = true)]
     debug: bool,
+
+    /// Print create, update and delete requests instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Skip confirmation prompts for destructive operations
+    #[arg(short = 'y', long, global = true)]
+    yes: bool,
+
+    /// Named profile to use (overrides CLICKUP_PROFILE and the active profile)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Answer task, list, comment and search reads from a `spaces backup` file
+    #[arg(long, global = true, visible_alias = "snapshot", value_name = "BACKUP_FILE")]
+    offline: Option<PathBuf>,
 }
 
 /// Available subcommands for the ClickUp CLI
@@ -118,6 +170,52 @@ enum Commands {
         #[command(subcommand)]
         command: comments::CommentCommands,
     },
+    /// Revert changes recorded in the local undo journal
+    Undo(undo::UndoArgs),
+    /// Webhook management and a local webhook receiver
+    Webhooks {
+        /// The webhook subcommand to execute
+        #[command(subcommand)]
+        command: webhooks::WebhookCommands,
+    },
+    /// Goals and key results
+    Goals {
+        /// The goal subcommand to execute
+        #[command(subcommand)]
+        command: goals::GoalCommands,
+    },
+    /// Saved views and the tasks they resolve to
+    Views {
+        /// The view subcommand to execute
+        #[command(subcommand)]
+        command: views::ViewCommands,
+    },
+    /// Task templates in ClickUp and the config directory
+    Templates {
+        /// The template subcommand to execute
+        #[command(subcommand)]
+        command: templates::TemplateCommands,
+    },
+    /// Workspace members
+    Users {
+        /// The user subcommand to execute
+        #[command(subcommand)]
+        command: users::UserCommands,
+    },
+    /// Workspace guests and the items shared with them
+    Guests {
+        /// The guest subcommand to execute
+        #[command(subcommand)]
+        command: guests::GuestCommands,
+    },
+    /// Search task names, descriptions and comments
+    Search(commands::search::SearchArgs),
+    /// Workload and burndown reports
+    Reports {
+        /// The report subcommand to execute
+        #[command(subcommand)]
+        command: commands::reports::ReportCommands,
+    },
 }
 
 /// Main application entry point
@@ -151,35 +249,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting ClickUp CLI...");
 
     // Load application configuration
-    let mut config = Config::load().map_err(|e| {
+    let mut config = Config::load_with_profile(None, cli.profile.as_deref()).map_err(|e| {
         error!("Failed to load configuration: {e}");
         e
     })?;
+    config.dry_run = cli.dry_run;
+    config.assume_yes = cli.yes;
+    config.offline = cli.offline;
+
+    // Only reads that a backup can answer run offline
+    if config.offline.is_some()
+        && !matches!(
+            cli.command,
+            Commands::Tasks { .. }
+                | Commands::Lists { .. }
+                | Commands::Comments { .. }
+                | Commands::Search(_)
+        )
+    {
+        return Err(ErrorUtils::offline_error().into());
+    }
+
+    // Only auth commands may refer to a profile that doesn't exist yet,
+    // since `auth set --profile <name>` is how profiles are created
+    if !matches!(cli.command, Commands::Auth { .. }) {
+        config.ensure_profile_exists()?;
+    }
 
     // Route the command to the appropriate handler
-    match cli.command {
-        Commands::Auth { command } => {
-            auth::execute(command, &mut config).await?;
-        }
-        Commands::Workspaces { command } => {
-            workspaces::execute(command, &config).await?;
-        }
-        Commands::Teams { command } => {
-            teams::execute(command, &config).await?;
-        }
-        Commands::Spaces { command } => {
-            spaces::execute(command, &config).await?;
-        }
-        Commands::Lists { command } => {
-            lists::execute(command, &config).await?;
-        }
-        Commands::Tasks { command } => {
-            tasks::execute(command, &config).await?;
-        }
-        Commands::Comments { command } => {
-            comments::execute(command, &config).await?;
+    let result = match cli.command {
+        Commands::Auth { command } => auth::execute(command, &mut config).await,
+        Commands::Workspaces { command } => workspaces::execute(command, &config).await,
+        Commands::Teams { command } => teams::execute(command, &config).await,
+        Commands::Spaces { command } => spaces::execute(command, &config).await,
+        Commands::Lists { command } => lists::execute(command, &config).await,
+        Commands::Tasks { command } => tasks::execute(command, &config).await,
+        Commands::Comments { command } => comments::execute(command, &config).await,
+        Commands::Undo(args) => undo::execute(args, &config).await,
+        Commands::Webhooks { command } => webhooks::execute(command, &config).await,
+        Commands::Goals { command } => goals::execute(command, &config).await,
+        Commands::Views { command } => views::execute(command, &config).await,
+        Commands::Templates { command } => templates::execute(command, &config).await,
+        Commands::Users { command } => users::execute(command, &config).await,
+        Commands::Guests { command } => guests::execute(command, &config).await,
+        Commands::Search(args) => commands::search::execute(args, &config).await,
+        Commands::Reports { command } => commands::reports::execute(command, &config).await,
+    };
+
+    match result {
+        // A dry run stops early only at a request it can't stand in a response for
+        Ok(()) | Err(error::ClickUpError::DryRun(_)) if config.dry_run => {
+            println!("{}", "[DRY RUN] No changes were made.".yellow());
+            Ok(())
         }
+        other => Ok(other?),
     }
-
-    Ok(())
 }
@@ -123,6 +123,25 @@ where
     deserializer.deserialize_option(OptI64Visitor)
 }
 
+/// Deserializes a field that can be either a string or a number into an Option<f64>
+fn opt_f64_from_string_or_number<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let val: Option<serde_json::Value> = Deserialize::deserialize(deserializer)?;
+    match val {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => Ok(n.as_f64()),
+        Some(serde_json::Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => s
+            .trim()
+            .parse::<f64>()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom("invalid string for f64")),
+        Some(_) => Err(serde::de::Error::custom("unexpected type for Option<f64>")),
+    }
+}
+
 /// Deserializes a field that can be either a string or a number into an i64
 fn i64_from_string_or_number<'de, D>(deserializer: D) -> Result<i64, D::Error>
 where
@@ -288,6 +307,16 @@ where
     deserializer.deserialize_any(OptBoolOrIntVisitor)
 }
 
+/// Deserializes an optional value, treating a value of an unexpected shape as absent
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| serde_json::from_value(value).ok()))
+}
+
 // User models
 
 /// Complete user information response from ClickUp API
@@ -413,6 +442,23 @@ pub struct WorkspaceMemberUser {
     pub banned_date: Option<String>,
 }
 
+impl WorkspaceMemberUser {
+    /// Returns the name of the user's role, e.g. `admin` or `guest`
+    pub fn role_name(&self) -> String {
+        if let Some(key) = self.role_key.as_deref().filter(|key| !key.is_empty()) {
+            return key.to_string();
+        }
+        match self.role {
+            Some(1) => "owner".to_string(),
+            Some(2) => "admin".to_string(),
+            Some(3) => "member".to_string(),
+            Some(4) => "guest".to_string(),
+            Some(role) => format!("role {role}"),
+            None => "unknown".to_string(),
+        }
+    }
+}
+
 /// Workspace role definition
 ///
 /// This struct defines a role that can be assigned to users within a workspace.
@@ -564,7 +610,7 @@ pub struct PortfolioFeatures {
 /// Space status information
 ///
 /// This struct represents a status that can be assigned to tasks in a space.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SpaceStatus {
     /// Unique status identifier
     pub id: String,
@@ -646,7 +692,7 @@ pub struct ListsResponse {
 ///
 /// A list represents a collection of tasks within a space.
 /// It can be organized in folders or directly in the space.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct List {
     #[serde(deserialize_with = "string_or_number")]
     /// Unique list identifier
@@ -683,7 +729,7 @@ pub struct List {
 }
 
 /// List status configuration
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListStatus {
     /// Status display name
     pub status: String,
@@ -694,7 +740,7 @@ pub struct ListStatus {
 }
 
 /// List priority configuration
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListPriority {
     /// Priority display name
     pub priority: String,
@@ -703,7 +749,7 @@ pub struct ListPriority {
 }
 
 /// List assignee information
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListAssignee {
     /// Unique user identifier
     pub id: i64,
@@ -720,7 +766,7 @@ pub struct ListAssignee {
 }
 
 /// List folder information
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListFolder {
     #[serde(deserialize_with = "string_or_number")]
     /// Unique folder identifier
@@ -734,7 +780,7 @@ pub struct ListFolder {
 }
 
 /// List space information
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListSpace {
     #[serde(deserialize_with = "string_or_number")]
     /// Unique space identifier
@@ -754,6 +800,51 @@ pub struct TasksResponse {
     pub tasks: Vec<Task>,
 }
 
+/// One page of tasks from the workspace-wide filtered task endpoint
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FilteredTasksResponse {
+    /// Tasks on the page
+    pub tasks: Vec<Task>,
+    /// Whether this is the last page; older responses leave it out
+    #[serde(default)]
+    pub last_page: bool,
+}
+
+/// Filters for the workspace-wide filtered task endpoint
+///
+/// Empty ID lists don't restrict the search.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TeamTaskFilter {
+    /// Only return tasks in these lists
+    pub list_ids: Vec<String>,
+    /// Only return tasks in these spaces
+    pub space_ids: Vec<String>,
+    /// Whether to return closed tasks
+    pub include_closed: bool,
+}
+
+impl TeamTaskFilter {
+    /// Returns the query parameters for one page of results
+    pub fn query_params(&self, page: u32) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("page".to_string(), page.to_string()),
+            ("subtasks".to_string(), "true".to_string()),
+            ("include_closed".to_string(), self.include_closed.to_string()),
+        ];
+        params.extend(
+            self.list_ids
+                .iter()
+                .map(|id| ("list_ids[]".to_string(), id.clone())),
+        );
+        params.extend(
+            self.space_ids
+                .iter()
+                .map(|id| ("space_ids[]".to_string(), id.clone())),
+        );
+        params
+    }
+}
+
 /// Task information
 ///
 /// This struct represents a task in ClickUp with all its properties,
@@ -805,12 +896,22 @@ pub struct Task {
     pub time_estimate: Option<i64>,
     /// Task time spent (in milliseconds)
     pub time_spent: Option<i64>,
+    /// Task story points (Sprint Points ClickApp)
+    #[serde(default)]
+    pub points: Option<f64>,
     /// Task custom fields
     pub custom_fields: Vec<TaskCustomField>,
-    /// Task dependencies
-    pub dependencies: Vec<String>,
+    /// Task dependencies, in both directions
+    #[serde(default)]
+    pub dependencies: Vec<TaskDependency>,
     /// Task linked tasks
-    pub linked_tasks: Vec<String>,
+    #[serde(default)]
+    pub linked_tasks: Vec<TaskLink>,
+    /// Files attached to the task
+    ///
+    /// Only returned when a single task is fetched.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
     /// Task team ID
     pub team_id: String,
     /// Task list information
@@ -825,6 +926,38 @@ pub struct Task {
     pub subtasks: Option<Vec<Task>>,
 }
 
+impl Task {
+    /// Returns whether the task's due date includes a time of day, or `None`
+    /// if it has no due date
+    ///
+    /// The API does not say whether a date was set with a time, so a date at
+    /// midnight UTC is taken to be date-only, as `update-overdue-by-tag` does.
+    pub fn due_date_has_time(&self) -> Option<bool> {
+        timestamp_has_time(self.due_date.as_deref()?)
+    }
+
+    /// Returns whether the task's start date includes a time of day, or
+    /// `None` if it has no start date
+    pub fn start_date_has_time(&self) -> Option<bool> {
+        timestamp_has_time(self.start_date.as_deref()?)
+    }
+
+    /// Returns the task's story points if they can be sent back as whole points
+    ///
+    /// The API only accepts whole points, so fractional points give `None`.
+    pub fn whole_points(&self) -> Option<i64> {
+        self.points
+            .filter(|points| points.fract() == 0.0)
+            .map(|points| points as i64)
+    }
+}
+
+/// Returns whether a millisecond timestamp is at a time other than midnight UTC
+fn timestamp_has_time(millis: &str) -> Option<bool> {
+    let millis = millis.parse::<i64>().ok()?;
+    Some(millis.rem_euclid(24 * 60 * 60 * 1000) != 0)
+}
+
 /// Task status information
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TaskStatus {
@@ -910,10 +1043,18 @@ pub struct TaskChecklist {
     pub parent: Option<serde_json::Value>,
     /// Checklist creation timestamp
     pub date_created: String,
-    /// Child checklist items
+    /// Checklist items (sent by the API as `items`)
+    #[serde(alias = "items")]
     pub children: Option<Vec<TaskChecklistItem>>,
 }
 
+impl TaskChecklist {
+    /// Returns the checklist items, or an empty slice if there are none
+    pub fn items(&self) -> &[TaskChecklistItem] {
+        self.children.as_deref().unwrap_or_default()
+    }
+}
+
 /// Task checklist item information
 ///
 /// This struct represents an individual item within a task checklist.
@@ -989,6 +1130,147 @@ pub struct TaskCustomField {
     pub value: Option<serde_json::Value>,
 }
 
+impl TaskCustomField {
+    /// Returns the field's value if it is a scalar that can be sent back as-is
+    pub fn scalar_value(&self) -> Option<&serde_json::Value> {
+        self.value.as_ref().filter(|value| {
+            matches!(
+                value,
+                serde_json::Value::String(_) | serde_json::Value::Number(_) | serde_json::Value::Bool(_)
+            )
+        })
+    }
+}
+
+/// Response containing the custom fields accessible from a list
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomFieldsResponse {
+    /// Custom fields of the list and of the folder, space and workspace above it
+    pub fields: Vec<CustomField>,
+}
+
+/// Custom field definition
+///
+/// This struct describes a custom field that tasks in a list can have a
+/// value for. Drop-down and label fields list their options in `type_config`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CustomField {
+    /// Custom field identifier
+    pub id: String,
+    /// Custom field display name
+    pub name: Option<String>,
+    /// Custom field type
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Custom field type configuration
+    pub type_config: Option<serde_json::Value>,
+}
+
+/// Task dependency information
+///
+/// A dependency records that `task_id` is waiting on `depends_on`. A task's
+/// `dependencies` holds both the tasks it waits on and the tasks it blocks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskDependency {
+    /// The waiting task
+    pub task_id: String,
+    /// The task being waited on
+    pub depends_on: String,
+    /// Dependency type as reported by the API
+    #[serde(rename = "type", default, deserialize_with = "opt_i64_from_string_or_number")]
+    pub type_: Option<i64>,
+    /// Dependency creation timestamp
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub date_created: Option<String>,
+    /// ID of the user who added the dependency
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub userid: Option<String>,
+}
+
+impl TaskDependency {
+    /// Returns the task this dependency connects to, seen from `task_id`
+    ///
+    /// # Returns
+    ///
+    /// Returns `Waiting(id)` if the task waits on `id`, `Blocking(id)` if it
+    /// blocks `id`, or `None` if the dependency doesn't involve the task.
+    pub fn relation_to(&self, task_id: &str) -> Option<DependencyRelation> {
+        if self.task_id == task_id {
+            Some(DependencyRelation::WaitingOn(self.depends_on.clone()))
+        } else if self.depends_on == task_id {
+            Some(DependencyRelation::Blocking(self.task_id.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Direction of a dependency from one task's point of view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyRelation {
+    /// The task waits on the other task
+    WaitingOn(String),
+    /// The task blocks the other task
+    Blocking(String),
+}
+
+/// Linked task information
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskLink {
+    /// The task the link belongs to
+    pub task_id: String,
+    /// The linked task
+    pub link_id: String,
+    /// Link creation timestamp
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub date_created: Option<String>,
+    /// ID of the user who added the link
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub userid: Option<String>,
+}
+
+impl TaskLink {
+    /// Returns the task on the other end of the link
+    ///
+    /// Links are symmetric, so the API may report either end as `task_id`.
+    pub fn other_task(&self, task_id: &str) -> &str {
+        if self.task_id == task_id {
+            &self.link_id
+        } else {
+            &self.task_id
+        }
+    }
+}
+
+/// Request body for adding a dependency
+///
+/// Exactly one of the fields should be set. The query parameters for
+/// removing a dependency use the same names.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DependencyRequest {
+    /// Task that the task will wait on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<String>,
+    /// Task that the task will block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_of: Option<String>,
+}
+
+impl From<DependencyRelation> for DependencyRequest {
+    fn from(relation: DependencyRelation) -> Self {
+        match relation {
+            DependencyRelation::WaitingOn(id) => Self {
+                depends_on: Some(id),
+                dependency_of: None,
+            },
+            DependencyRelation::Blocking(id) => Self {
+                depends_on: None,
+                dependency_of: Some(id),
+            },
+        }
+    }
+}
+
 /// Task list information
 ///
 /// This struct contains information about the list containing the task.
@@ -1034,6 +1316,142 @@ pub struct TaskSpace {
     pub name: Option<String>,
 }
 
+// Checklist models
+
+/// Response containing a single checklist
+///
+/// Creating a checklist or changing its items returns the whole checklist.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChecklistResponse {
+    /// The checklist
+    pub checklist: TaskChecklist,
+}
+
+/// Request data for creating a checklist
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateChecklistRequest {
+    /// Checklist display name
+    pub name: String,
+}
+
+/// Request data for renaming or reordering a checklist
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct EditChecklistRequest {
+    /// New checklist name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New position among the task's checklists (0 is first)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+}
+
+/// Request data for adding a checklist item
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateChecklistItemRequest {
+    /// Item display name
+    pub name: String,
+    /// User ID to assign the item to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<i64>,
+}
+
+/// Request data for changing a checklist item
+///
+/// Fields left as `None` are not changed. `assignee: Some(None)` removes
+/// the assignee.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct EditChecklistItemRequest {
+    /// New item name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New assignee user ID, or `Some(None)` to unassign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Option<i64>>,
+    /// Whether the item is resolved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<bool>,
+}
+
+// Attachment models
+
+/// File attached to a task
+///
+/// Uploads return a subset of these fields, so everything except the ID
+/// is optional.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Attachment {
+    /// Unique attachment identifier
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// File name as shown in ClickUp
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Upload timestamp
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub date: Option<String>,
+    /// File extension without the leading dot
+    #[serde(default)]
+    pub extension: Option<String>,
+    /// MIME type of the file
+    #[serde(default)]
+    pub mimetype: Option<String>,
+    /// File size in bytes
+    #[serde(default, deserialize_with = "opt_i64_from_string_or_number")]
+    pub size: Option<i64>,
+    /// Download URL
+    #[serde(default)]
+    pub url: String,
+}
+
+impl Attachment {
+    /// Returns a file name that is safe to create in a local directory
+    ///
+    /// Path separators and control characters in the title are replaced,
+    /// and attachments without a usable title are named after their ID.
+    pub fn file_name(&self) -> String {
+        let title: String = self
+            .title
+            .as_deref()
+            .unwrap_or("")
+            .trim()
+            .chars()
+            .map(|c| if matches!(c, '/' | '\\' | ':') || c.is_control() { '_' } else { c })
+            .collect();
+        let title = title.trim_start_matches('.');
+        if !title.is_empty() {
+            return title.to_string();
+        }
+        match self.extension.as_deref().filter(|ext| !ext.is_empty()) {
+            Some(extension) => format!("{}.{extension}", self.id),
+            None => self.id.clone(),
+        }
+    }
+
+    /// Returns local file names for a set of attachments, one per attachment
+    ///
+    /// Files with the same name are told apart by adding the attachment ID
+    /// before the extension of every name after the first, so repeated
+    /// downloads of the same task write to the same files.
+    pub fn unique_file_names(attachments: &[Attachment]) -> Vec<String> {
+        let mut taken = std::collections::HashSet::new();
+        attachments
+            .iter()
+            .map(|attachment| {
+                let name = attachment.file_name();
+                if taken.insert(name.to_lowercase()) {
+                    return name;
+                }
+                let name = match name.rsplit_once('.').filter(|(stem, _)| !stem.is_empty()) {
+                    Some((stem, extension)) => format!("{stem}-{}.{extension}", attachment.id),
+                    None => format!("{name}-{}", attachment.id),
+                };
+                taken.insert(name.to_lowercase());
+                name
+            })
+            .collect()
+    }
+}
+
 // Request models
 
 /// Request data for creating a new task
@@ -1074,6 +1492,43 @@ pub struct CreateTaskRequest {
     pub notify_all: Option<bool>,
 }
 
+impl From<&Task> for CreateTaskRequest {
+    /// Builds a create request that recreates a task as closely as possible
+    ///
+    /// Complex custom field values and fractional points are left out, since
+    /// they cannot be sent back as they were read.
+    fn from(task: &Task) -> Self {
+        let custom_fields: Vec<CustomFieldValue> = task
+            .custom_fields
+            .iter()
+            .filter_map(|field| {
+                field.scalar_value().map(|value| CustomFieldValue {
+                    id: field.id.clone(),
+                    value: value.clone(),
+                })
+            })
+            .collect();
+
+        CreateTaskRequest {
+            name: task.name.clone().unwrap_or_else(|| "Restored task".to_string()),
+            description: Some(task.description.clone()),
+            status: Some(task.status.status.clone()),
+            priority: task.priority.as_ref().and_then(|p| p.id.parse().ok()),
+            due_date: task.due_date.as_deref().and_then(|d| d.parse().ok()),
+            due_date_time: task.due_date_has_time(),
+            time_estimate: task.time_estimate,
+            assignees: Some(task.assignees.iter().map(|a| a.id).collect()),
+            tags: Some(task.tags.iter().filter_map(|t| t.name.clone()).collect()),
+            parent: task.parent.clone(),
+            custom_fields: (!custom_fields.is_empty()).then_some(custom_fields),
+            start_date: task.start_date.as_deref().and_then(|d| d.parse().ok()),
+            start_date_time: task.start_date_has_time(),
+            points: task.whole_points(),
+            notify_all: None,
+        }
+    }
+}
+
 /// Request data for updating an existing task
 ///
 /// This struct contains the data needed to update an existing task in ClickUp.
@@ -1091,6 +1546,7 @@ pub struct UpdateTaskRequest {
     /// Task due date (Unix timestamp in milliseconds)
     pub due_date: Option<i64>,
     /// Whether the due date includes time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub due_date_time: Option<bool>,
     /// Task time estimate (in milliseconds)
     pub time_estimate: Option<i64>,
@@ -1105,8 +1561,10 @@ pub struct UpdateTaskRequest {
     /// Task start date (Unix timestamp in milliseconds)
     pub start_date: Option<i64>,
     /// Whether the start date includes time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_date_time: Option<bool>,
-    /// Task story points
+    /// Task story points; left out when not given so existing points are kept
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub points: Option<i64>,
     /// Whether to notify assignees
     pub notify_all: Option<bool>,
@@ -1138,7 +1596,7 @@ pub struct CommentsResponse {
 ///
 /// This struct represents a comment on a task, including its content,
 /// author, and metadata.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Comment {
     /// Unique comment identifier
     #[serde(deserialize_with = "string_or_number")]
@@ -1182,22 +1640,96 @@ pub struct Comment {
     pub reply_count: i64,
 }
 
+impl Comment {
+    /// Returns the ID of the comment's assignee, if it has one
+    pub fn assignee_id(&self) -> Option<i64> {
+        let id = self.assignee.as_ref()?.get("id")?;
+        id.as_i64()
+            .or_else(|| id.as_str().and_then(|id| id.parse().ok()))
+    }
+}
+
 /// Comment text segment
 ///
-/// This struct represents a segment of rich text within a comment.
-#[derive(Debug, Serialize, Deserialize)]
+/// This struct represents a segment of rich text within a comment. Inline
+/// formatting applies to the segment's text; line formatting such as lists
+/// and headings is carried by the `"\n"` segment that ends the line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CommentText {
-    /// Text content
+    /// Text content (empty for mentions sent to the API)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub text: String,
-    /// Text type (e.g., "text", "link", "mention")
-    #[serde(rename = "type", default)]
+    /// Text type (e.g., "text", "tag" for a mention)
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
     pub type_: Option<String>,
+    /// Formatting of the segment; unrecognised formatting is ignored
+    #[serde(default, deserialize_with = "lenient", skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<CommentAttributes>,
+    /// Mentioned user, for `tag` segments
+    #[serde(default, deserialize_with = "lenient", skip_serializing_if = "Option::is_none")]
+    pub user: Option<CommentMention>,
+}
+
+/// Formatting of a comment text segment
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CommentAttributes {
+    /// Bold text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    /// Italic text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    /// Inline code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<bool>,
+    /// Link target
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    /// List the line belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list: Option<CommentList>,
+    /// Heading level of the line
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<u8>,
+    /// Whether the line is quoted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blockquote: Option<bool>,
+    /// Code block the line belongs to
+    #[serde(rename = "code-block", default, skip_serializing_if = "Option::is_none")]
+    pub code_block: Option<serde_json::Value>,
+}
+
+impl CommentAttributes {
+    /// Returns whether these attributes format a whole line
+    pub fn is_line_format(&self) -> bool {
+        self.list.is_some()
+            || self.header.is_some()
+            || self.blockquote.is_some()
+            || self.code_block.is_some()
+    }
+}
+
+/// List formatting of a comment line
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentList {
+    /// List kind: "bullet" or "ordered"
+    pub list: String,
+}
+
+/// User mentioned in a comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentMention {
+    /// Unique user identifier
+    pub id: i64,
+    /// User's display name (only present in responses)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
 }
 
 /// Comment user information
 ///
 /// This struct contains information about the user who wrote the comment.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CommentUser {
     /// Unique user identifier
     pub id: i64,
@@ -1212,12 +1744,1081 @@ pub struct CommentUser {
 /// Request data for creating a new comment
 ///
 /// This struct contains the data needed to create a new comment on a task.
+/// Either `comment_text` or `comment` holds the content.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateCommentRequest {
-    /// Comment content
+    /// Comment content as plain text (omitted when `comment` is sent)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub comment_text: String,
+    /// Comment content as rich text segments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Vec<CommentText>>,
     /// Comment assignee (if any)
     pub assignee: Option<i64>,
     /// Whether to notify assignee
     pub notify_all: Option<bool>,
+    /// Whether the comment is resolved (only used when updating)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<bool>,
+}
+
+/// Response returned when a comment is created
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreatedComment {
+    /// ID of the new comment
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+}
+
+/// Request data for resolving or reopening a comment
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResolveCommentRequest {
+    /// Whether the comment is resolved
+    pub resolved: bool,
+}
+
+/// Kind of item a comment thread belongs to
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentTarget {
+    /// A task
+    Task,
+    /// A list
+    List,
+    /// A chat view
+    View,
+}
+
+impl CommentTarget {
+    /// Returns the path segment used in comment endpoints
+    pub fn endpoint_segment(&self) -> &'static str {
+        match self {
+            CommentTarget::Task => "task",
+            CommentTarget::List => "list",
+            CommentTarget::View => "view",
+        }
+    }
+}
+
+// Webhook models
+
+/// Response containing a list of webhooks
+///
+/// This struct represents the API response when fetching the webhooks of a workspace.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhooksResponse {
+    /// Webhooks registered in the workspace
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Webhook information
+///
+/// This struct represents a webhook subscription, including the endpoint that
+/// receives events, the events it is subscribed to, and its health.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Webhook {
+    /// Unique webhook identifier
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// ID of the user who created the webhook
+    #[serde(default)]
+    pub userid: Option<i64>,
+    /// Workspace (team) the webhook belongs to
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub team_id: Option<String>,
+    /// URL that receives webhook events
+    pub endpoint: String,
+    /// Subscribed event names (`*` for all events)
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Task the webhook is limited to (if any)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub task_id: Option<String>,
+    /// List the webhook is limited to (if any)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub list_id: Option<String>,
+    /// Folder the webhook is limited to (if any)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub folder_id: Option<String>,
+    /// Space the webhook is limited to (if any)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub space_id: Option<String>,
+    /// Delivery health of the webhook
+    #[serde(default)]
+    pub health: Option<WebhookHealth>,
+    /// Secret used to sign webhook deliveries
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
+/// Webhook delivery health
+///
+/// ClickUp suspends webhooks whose deliveries keep failing.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookHealth {
+    /// Health status (e.g., "active", "failing", "suspended")
+    pub status: Option<String>,
+    /// Number of consecutive failed deliveries
+    pub fail_count: Option<i64>,
+}
+
+/// Response from creating or updating a webhook
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookResponse {
+    /// Webhook identifier
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// The created or updated webhook
+    pub webhook: Webhook,
+}
+
+/// Request data for creating a webhook
+///
+/// Setting one of the location IDs limits the webhook to events in that
+/// space, folder, list or task.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct CreateWebhookRequest {
+    /// URL that receives webhook events
+    pub endpoint: String,
+    /// Event names to subscribe to (`*` for all events)
+    pub events: Vec<String>,
+    /// Space to limit events to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space_id: Option<String>,
+    /// Folder to limit events to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<String>,
+    /// List to limit events to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<String>,
+    /// Task to limit events to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+}
+
+/// Request data for updating a webhook
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct UpdateWebhookRequest {
+    /// New endpoint URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// New event subscriptions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<String>>,
+    /// New status (`active` re-enables a suspended webhook)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// Webhook event types sent by ClickUp
+///
+/// Event names that this version doesn't know about deserialize as `Unknown`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebhookEventType {
+    TaskCreated,
+    TaskUpdated,
+    TaskDeleted,
+    TaskPriorityUpdated,
+    TaskStatusUpdated,
+    TaskAssigneeUpdated,
+    TaskDueDateUpdated,
+    TaskTagUpdated,
+    TaskMoved,
+    TaskCommentPosted,
+    TaskCommentUpdated,
+    TaskTimeEstimateUpdated,
+    TaskTimeTrackedUpdated,
+    ListCreated,
+    ListUpdated,
+    ListDeleted,
+    FolderCreated,
+    FolderUpdated,
+    FolderDeleted,
+    SpaceCreated,
+    SpaceUpdated,
+    SpaceDeleted,
+    GoalCreated,
+    GoalUpdated,
+    GoalDeleted,
+    KeyResultCreated,
+    KeyResultUpdated,
+    KeyResultDeleted,
+    /// An event type not known to this version
+    #[serde(other)]
+    Unknown,
+}
+
+impl WebhookEventType {
+    /// All known event types, in the order ClickUp documents them
+    pub const ALL: [WebhookEventType; 28] = [
+        WebhookEventType::TaskCreated,
+        WebhookEventType::TaskUpdated,
+        WebhookEventType::TaskDeleted,
+        WebhookEventType::TaskPriorityUpdated,
+        WebhookEventType::TaskStatusUpdated,
+        WebhookEventType::TaskAssigneeUpdated,
+        WebhookEventType::TaskDueDateUpdated,
+        WebhookEventType::TaskTagUpdated,
+        WebhookEventType::TaskMoved,
+        WebhookEventType::TaskCommentPosted,
+        WebhookEventType::TaskCommentUpdated,
+        WebhookEventType::TaskTimeEstimateUpdated,
+        WebhookEventType::TaskTimeTrackedUpdated,
+        WebhookEventType::ListCreated,
+        WebhookEventType::ListUpdated,
+        WebhookEventType::ListDeleted,
+        WebhookEventType::FolderCreated,
+        WebhookEventType::FolderUpdated,
+        WebhookEventType::FolderDeleted,
+        WebhookEventType::SpaceCreated,
+        WebhookEventType::SpaceUpdated,
+        WebhookEventType::SpaceDeleted,
+        WebhookEventType::GoalCreated,
+        WebhookEventType::GoalUpdated,
+        WebhookEventType::GoalDeleted,
+        WebhookEventType::KeyResultCreated,
+        WebhookEventType::KeyResultUpdated,
+        WebhookEventType::KeyResultDeleted,
+    ];
+
+    /// Returns the event name as used by the ClickUp API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::TaskCreated => "taskCreated",
+            WebhookEventType::TaskUpdated => "taskUpdated",
+            WebhookEventType::TaskDeleted => "taskDeleted",
+            WebhookEventType::TaskPriorityUpdated => "taskPriorityUpdated",
+            WebhookEventType::TaskStatusUpdated => "taskStatusUpdated",
+            WebhookEventType::TaskAssigneeUpdated => "taskAssigneeUpdated",
+            WebhookEventType::TaskDueDateUpdated => "taskDueDateUpdated",
+            WebhookEventType::TaskTagUpdated => "taskTagUpdated",
+            WebhookEventType::TaskMoved => "taskMoved",
+            WebhookEventType::TaskCommentPosted => "taskCommentPosted",
+            WebhookEventType::TaskCommentUpdated => "taskCommentUpdated",
+            WebhookEventType::TaskTimeEstimateUpdated => "taskTimeEstimateUpdated",
+            WebhookEventType::TaskTimeTrackedUpdated => "taskTimeTrackedUpdated",
+            WebhookEventType::ListCreated => "listCreated",
+            WebhookEventType::ListUpdated => "listUpdated",
+            WebhookEventType::ListDeleted => "listDeleted",
+            WebhookEventType::FolderCreated => "folderCreated",
+            WebhookEventType::FolderUpdated => "folderUpdated",
+            WebhookEventType::FolderDeleted => "folderDeleted",
+            WebhookEventType::SpaceCreated => "spaceCreated",
+            WebhookEventType::SpaceUpdated => "spaceUpdated",
+            WebhookEventType::SpaceDeleted => "spaceDeleted",
+            WebhookEventType::GoalCreated => "goalCreated",
+            WebhookEventType::GoalUpdated => "goalUpdated",
+            WebhookEventType::GoalDeleted => "goalDeleted",
+            WebhookEventType::KeyResultCreated => "keyResultCreated",
+            WebhookEventType::KeyResultUpdated => "keyResultUpdated",
+            WebhookEventType::KeyResultDeleted => "keyResultDeleted",
+            WebhookEventType::Unknown => "unknown",
+        }
+    }
+
+    /// Looks up an event type by its API name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == name)
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Webhook event delivered by ClickUp
+///
+/// Which location IDs are set depends on the event type; task events carry
+/// `task_id`, list events `list_id`, and so on.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    /// The event type
+    pub event: WebhookEventType,
+    /// Webhook that delivered the event
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub webhook_id: Option<String>,
+    /// Task the event is about
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub task_id: Option<String>,
+    /// List the event is about
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub list_id: Option<String>,
+    /// Folder the event is about
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub folder_id: Option<String>,
+    /// Space the event is about
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub space_id: Option<String>,
+    /// Goal the event is about
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub goal_id: Option<String>,
+    /// Key result the event is about
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub key_result_id: Option<String>,
+    /// Changes that triggered the event
+    #[serde(default)]
+    pub history_items: Vec<WebhookHistoryItem>,
+}
+
+/// A single change reported by a webhook event
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookHistoryItem {
+    /// History item identifier
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub id: Option<String>,
+    /// Changed field (e.g., "status", "assignee_add", "comment")
+    #[serde(default)]
+    pub field: Option<String>,
+    /// Change timestamp (Unix timestamp in milliseconds)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub date: Option<String>,
+    /// User who made the change
+    #[serde(default)]
+    pub user: Option<WebhookUser>,
+    /// Value before the change
+    #[serde(default)]
+    pub before: Option<serde_json::Value>,
+    /// Value after the change
+    #[serde(default)]
+    pub after: Option<serde_json::Value>,
+    /// Comment details, for comment events
+    #[serde(default)]
+    pub comment: Option<serde_json::Value>,
+}
+
+/// User information in webhook history items
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookUser {
+    /// Unique user identifier
+    pub id: i64,
+    /// User's display name
+    #[serde(default)]
+    pub username: Option<String>,
+    /// User's email address
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+// Goal models
+
+/// Response containing the goals of a workspace
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalsResponse {
+    /// Goals in the workspace
+    pub goals: Vec<Goal>,
+}
+
+/// Response containing a single goal
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GoalResponse {
+    /// The goal
+    pub goal: Goal,
+}
+
+/// Goal information
+///
+/// Key results are only included when a single goal is fetched.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Goal {
+    /// Unique goal identifier
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// Short goal number shown in ClickUp
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub pretty_id: Option<String>,
+    /// Goal display name
+    pub name: String,
+    /// Workspace the goal belongs to
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub team_id: Option<String>,
+    /// Goal description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Goal color
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Due date (Unix timestamp in milliseconds)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub due_date: Option<String>,
+    /// Creation timestamp
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub date_created: Option<String>,
+    /// Whether the goal is archived
+    #[serde(default, deserialize_with = "opt_bool_from_bool_or_int")]
+    pub archived: Option<bool>,
+    /// Goal owners
+    #[serde(default)]
+    pub owners: Vec<GoalOwner>,
+    /// Number of key results
+    #[serde(default, deserialize_with = "opt_i64_from_string_or_number")]
+    pub key_result_count: Option<i64>,
+    /// Progress as reported by ClickUp (0-100)
+    #[serde(default, deserialize_with = "opt_f64_from_string_or_number")]
+    pub percent_completed: Option<f64>,
+    /// Goal folder
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub folder_id: Option<String>,
+    /// Key results (targets) of the goal
+    #[serde(default)]
+    pub key_results: Vec<KeyResult>,
+}
+
+impl Goal {
+    /// Returns the goal's progress as a percentage
+    ///
+    /// ClickUp computes goal progress as the average of its key results, so
+    /// that is used when the key results are known. Otherwise the progress
+    /// reported by the API is used.
+    pub fn progress(&self) -> Option<f64> {
+        let progress: Vec<f64> = self
+            .key_results
+            .iter()
+            .filter_map(KeyResult::progress)
+            .collect();
+        if progress.is_empty() {
+            return self.percent_completed;
+        }
+        Some(progress.iter().sum::<f64>() / progress.len() as f64)
+    }
+}
+
+/// Owner of a goal or key result
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GoalOwner {
+    /// Unique user identifier
+    #[serde(deserialize_with = "i64_from_string_or_number")]
+    pub id: i64,
+    /// User's display name
+    #[serde(default)]
+    pub username: Option<String>,
+    /// User's email address
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Response containing a single key result
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeyResultResponse {
+    /// The key result
+    pub key_result: KeyResult,
+}
+
+/// Key result (target) of a goal
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KeyResult {
+    /// Unique key result identifier
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// Goal the key result belongs to
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub goal_id: Option<String>,
+    /// Key result display name
+    pub name: String,
+    /// How progress is measured (`number`, `currency`, `boolean`, `percentage` or `automatic`)
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Unit shown next to values, e.g. a currency code
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Starting value
+    #[serde(default, deserialize_with = "opt_f64_from_string_or_number")]
+    pub steps_start: Option<f64>,
+    /// Target value
+    #[serde(default, deserialize_with = "opt_f64_from_string_or_number")]
+    pub steps_end: Option<f64>,
+    /// Current value
+    #[serde(default, deserialize_with = "opt_f64_from_string_or_number")]
+    pub steps_current: Option<f64>,
+    /// Progress as reported by ClickUp (0-100)
+    #[serde(default, deserialize_with = "opt_f64_from_string_or_number")]
+    pub percent_completed: Option<f64>,
+    /// Whether the key result is complete
+    #[serde(default, deserialize_with = "opt_bool_from_bool_or_int")]
+    pub completed: Option<bool>,
+    /// Key result owners
+    #[serde(default)]
+    pub owners: Vec<GoalOwner>,
+    /// Tasks tracked by an automatic key result
+    #[serde(default)]
+    pub task_ids: Vec<String>,
+    /// Lists tracked by an automatic key result
+    #[serde(default, alias = "subcategory_ids")]
+    pub list_ids: Vec<String>,
+}
+
+impl KeyResult {
+    /// Returns how progress is measured, if the type is known
+    pub fn kind(&self) -> Option<KeyResultType> {
+        KeyResultType::from_name(&self.type_)
+    }
+
+    /// Returns the key result's progress as a percentage between 0 and 100
+    ///
+    /// Progress is computed from the start, current and target values, so it
+    /// reflects updates made in this session. Automatic key results and key
+    /// results without values use the progress reported by the API.
+    pub fn progress(&self) -> Option<f64> {
+        if self.kind() == Some(KeyResultType::Automatic) {
+            return self.percent_completed;
+        }
+        let (Some(end), Some(current)) = (self.steps_end, self.steps_current) else {
+            return self.percent_completed;
+        };
+        let start = self.steps_start.unwrap_or(0.0);
+        let progress = if end == start {
+            if current >= end {
+                100.0
+            } else {
+                0.0
+            }
+        } else {
+            (current - start) / (end - start) * 100.0
+        };
+        Some(progress.clamp(0.0, 100.0))
+    }
+}
+
+/// How the progress of a key result is measured
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyResultType {
+    /// A number moving from a start to a target value
+    Number,
+    /// An amount of money moving from a start to a target value
+    Currency,
+    /// Done or not done
+    Boolean,
+    /// A percentage moving from a start to a target value
+    Percentage,
+    /// Completion of linked tasks or lists
+    Automatic,
+}
+
+impl KeyResultType {
+    /// All key result types
+    pub const ALL: [KeyResultType; 5] = [
+        KeyResultType::Number,
+        KeyResultType::Currency,
+        KeyResultType::Boolean,
+        KeyResultType::Percentage,
+        KeyResultType::Automatic,
+    ];
+
+    /// Returns the type name as used by the ClickUp API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyResultType::Number => "number",
+            KeyResultType::Currency => "currency",
+            KeyResultType::Boolean => "boolean",
+            KeyResultType::Percentage => "percentage",
+            KeyResultType::Automatic => "automatic",
+        }
+    }
+
+    /// Looks up a key result type by its API name, ignoring case
+    pub fn from_name(name: &str) -> Option<KeyResultType> {
+        KeyResultType::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Request data for creating a goal
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct CreateGoalRequest {
+    /// Goal display name
+    pub name: String,
+    /// Due date (Unix timestamp in milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<i64>,
+    /// Goal description
+    pub description: String,
+    /// Whether the goal has more than one owner
+    pub multiple_owners: bool,
+    /// User IDs of the goal owners
+    pub owners: Vec<i64>,
+    /// Goal color, e.g. `#32a852`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Request data for updating a goal
+///
+/// All fields are optional, allowing partial updates.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct UpdateGoalRequest {
+    /// New name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New due date (Unix timestamp in milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<i64>,
+    /// New description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// New color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// User IDs to add as owners
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub add_owners: Vec<i64>,
+    /// User IDs to remove as owners
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rem_owners: Vec<i64>,
+}
+
+/// Request data for creating a key result
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateKeyResultRequest {
+    /// Key result display name
+    pub name: String,
+    /// User IDs of the key result owners
+    pub owners: Vec<i64>,
+    /// How progress is measured
+    #[serde(rename = "type")]
+    pub type_: KeyResultType,
+    /// Starting value
+    pub steps_start: f64,
+    /// Target value
+    pub steps_end: f64,
+    /// Unit shown next to values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Tasks tracked by an automatic key result
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub task_ids: Vec<String>,
+    /// Lists tracked by an automatic key result
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub list_ids: Vec<String>,
+}
+
+/// Request data for recording progress on a key result
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct UpdateKeyResultRequest {
+    /// New current value
+    pub steps_current: f64,
+    /// Check-in note
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+// View models
+
+/// Response containing the views of a workspace, space, folder or list
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ViewsResponse {
+    /// Views created by users
+    #[serde(default)]
+    pub views: Vec<View>,
+    /// Built-in views keyed by view type; only returned for spaces, folders and lists
+    #[serde(default)]
+    pub required_views: std::collections::BTreeMap<String, Option<View>>,
+}
+
+impl ViewsResponse {
+    /// Returns the built-in views followed by the other views, without duplicates
+    pub fn all_views(self) -> Vec<View> {
+        let mut views: Vec<View> = self.required_views.into_values().flatten().collect();
+        for view in self.views {
+            if !views.iter().any(|existing| existing.id == view.id) {
+                views.push(view);
+            }
+        }
+        views
+    }
+}
+
+/// Response containing a single view
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ViewResponse {
+    /// The view
+    pub view: View,
+}
+
+/// Saved view with its filters, sorting and grouping
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct View {
+    /// Unique view identifier
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// View display name
+    pub name: String,
+    /// View type, e.g. `list`, `board` or `calendar`
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Location the view belongs to
+    #[serde(default)]
+    pub parent: Option<ViewParent>,
+    /// Creation date (Unix timestamp in milliseconds)
+    #[serde(default, deserialize_with = "opt_string_from_string_or_number")]
+    pub date_created: Option<String>,
+    /// User ID of the creator
+    #[serde(default, deserialize_with = "opt_i64_from_string_or_number")]
+    pub creator: Option<i64>,
+    /// Who can see the view, e.g. `public` or `private`
+    #[serde(default)]
+    pub visibility: Option<String>,
+    /// Whether the view is protected from changes
+    #[serde(default, deserialize_with = "opt_bool_from_bool_or_int")]
+    pub protected: Option<bool>,
+    /// Filters applied to the tasks in the view
+    #[serde(default)]
+    pub filters: Option<serde_json::Value>,
+    /// Sort order of the tasks in the view
+    #[serde(default)]
+    pub sorting: Option<serde_json::Value>,
+    /// How tasks are grouped
+    #[serde(default)]
+    pub grouping: Option<serde_json::Value>,
+}
+
+/// Location a view belongs to
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ViewParent {
+    /// ID of the workspace, space, folder or list
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// Location type code as used by the ClickUp API
+    #[serde(rename = "type", deserialize_with = "i64_from_string_or_number")]
+    pub type_: i64,
+}
+
+impl ViewParent {
+    /// Returns the kind of location, if the type code is known
+    pub fn kind(&self) -> Option<ViewParentKind> {
+        ViewParentKind::from_code(self.type_)
+    }
+}
+
+/// Kind of location a view can belong to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewParentKind {
+    /// Views at the workspace level ("Everything")
+    Workspace,
+    /// Views of a space
+    Space,
+    /// Views of a folder
+    Folder,
+    /// Views of a list
+    List,
+}
+
+impl ViewParentKind {
+    /// Looks up a location kind by its API type code
+    pub fn from_code(code: i64) -> Option<ViewParentKind> {
+        match code {
+            7 => Some(ViewParentKind::Workspace),
+            4 => Some(ViewParentKind::Space),
+            5 => Some(ViewParentKind::Folder),
+            6 => Some(ViewParentKind::List),
+            _ => None,
+        }
+    }
+
+    /// Returns the path segment used in view endpoints, e.g. `/space/{id}/view`
+    pub fn endpoint_segment(&self) -> &'static str {
+        match self {
+            ViewParentKind::Workspace => "team",
+            ViewParentKind::Space => "space",
+            ViewParentKind::Folder => "folder",
+            ViewParentKind::List => "list",
+        }
+    }
+
+    /// Returns the display name of the location kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViewParentKind::Workspace => "workspace",
+            ViewParentKind::Space => "space",
+            ViewParentKind::Folder => "folder",
+            ViewParentKind::List => "list",
+        }
+    }
+}
+
+/// Response containing one page of the tasks in a view
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ViewTasksResponse {
+    /// Tasks on this page
+    pub tasks: Vec<Task>,
+    /// Whether this is the last page; assumed when the API leaves it out
+    #[serde(default = "last_page_default")]
+    pub last_page: bool,
+}
+
+/// Treats a view task page without `last_page` as the last one
+fn last_page_default() -> bool {
+    true
+}
+
+// Template models
+
+/// Response containing one page of a workspace's task templates
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TaskTemplatesResponse {
+    /// Task templates
+    #[serde(default)]
+    pub templates: Vec<TaskTemplate>,
+}
+
+/// Task template saved in ClickUp
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskTemplate {
+    /// Unique template identifier, e.g. `t-15363293`
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// Template display name
+    pub name: String,
+}
+
+/// Request data for creating a task from a template
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateTaskFromTemplateRequest {
+    /// Name of the new task
+    pub name: String,
+}
+
+/// Response to creating a task from a template
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplateTaskResponse {
+    /// ID of the new task
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+}
+
+/// Changes to a task's assignees
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct AssigneeChanges {
+    /// User IDs to assign
+    pub add: Vec<i64>,
+    /// User IDs to unassign
+    pub rem: Vec<i64>,
+}
+
+/// Request data for changing only a task's assignees
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskAssigneesRequest {
+    /// Assignees to add and remove
+    pub assignees: AssigneeChanges,
+}
+
+/// Request data for overriding fields of a task created from a template
+///
+/// Fields left as `None` keep the template's value.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TemplateOverridesRequest {
+    /// Task description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Task status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Task priority (1-4, where 1 is highest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// Task due date (Unix timestamp in milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<i64>,
+    /// Whether the due date includes time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date_time: Option<bool>,
+    /// Task time estimate (in milliseconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_estimate: Option<i64>,
+    /// Assignees to add
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<AssigneeChanges>,
+}
+
+impl TemplateOverridesRequest {
+    /// Returns whether no field is overridden
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none()
+            && self.status.is_none()
+            && self.priority.is_none()
+            && self.due_date.is_none()
+            && self.time_estimate.is_none()
+            && self.assignees.is_none()
+    }
+}
+
+// Member models
+
+/// Response wrapping a workspace, returned when members or guests change
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamResponse {
+    /// The workspace with its updated members
+    pub team: Workspace,
+}
+
+/// Request data for inviting a user to a workspace
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InviteUserRequest {
+    /// Email address to invite
+    pub email: String,
+    /// Whether the user becomes an admin
+    pub admin: bool,
+    /// Custom role to give the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_role_id: Option<i64>,
+}
+
+/// Request data for inviting a guest to a workspace
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct InviteGuestRequest {
+    /// Email address to invite
+    pub email: String,
+    /// Whether the guest can create and edit tags
+    pub can_edit_tags: bool,
+    /// Whether the guest can see time tracked on tasks
+    pub can_see_time_spent: bool,
+    /// Whether the guest can see time estimates
+    pub can_see_time_estimated: bool,
+    /// Whether the guest can create views
+    pub can_create_views: bool,
+    /// Custom role to give the guest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_role_id: Option<i64>,
+}
+
+/// Response containing a workspace member
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MemberResponse {
+    /// The member
+    pub member: MemberDetails,
+}
+
+/// Response containing a workspace guest
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestResponse {
+    /// The guest
+    pub guest: MemberDetails,
+}
+
+/// A workspace member or guest with the items shared with them
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MemberDetails {
+    /// User information
+    pub user: WorkspaceMemberUser,
+    /// Information about who invited this user
+    pub invited_by: Option<WorkspaceMemberUser>,
+    /// Tasks, lists and folders shared with the user
+    pub shared: Option<SharedHierarchy>,
+}
+
+/// Request data for giving a guest access to a task, list or folder
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestPermissionRequest {
+    /// One of `read`, `comment`, `edit` or `create`
+    pub permission_level: String,
+}
+
+/// Response containing the items shared with the current user
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharedHierarchyResponse {
+    /// The shared items
+    pub shared: SharedHierarchy,
+}
+
+/// Tasks, lists and folders shared with a user outside their spaces
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct SharedHierarchy {
+    /// Shared tasks
+    #[serde(default)]
+    pub tasks: Vec<SharedItem>,
+    /// Shared lists
+    #[serde(default)]
+    pub lists: Vec<SharedItem>,
+    /// Shared folders
+    #[serde(default)]
+    pub folders: Vec<SharedItem>,
+}
+
+impl SharedHierarchy {
+    /// Returns whether nothing is shared
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty() && self.lists.is_empty() && self.folders.is_empty()
+    }
+}
+
+/// A shared task, list or folder
+///
+/// Shared tasks may be returned as bare IDs, in which case only `id` is set.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(from = "SharedItemValue")]
+pub struct SharedItem {
+    /// Unique item identifier
+    pub id: String,
+    /// Item display name
+    pub name: Option<String>,
+    /// Number of tasks in a shared list or folder
+    pub task_count: Option<i64>,
+    /// Whether the item is archived
+    pub archived: Option<bool>,
+}
+
+/// The forms a shared item takes in API responses
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SharedItemValue {
+    Id(#[serde(deserialize_with = "string_or_number")] String),
+    Item {
+        #[serde(deserialize_with = "string_or_number")]
+        id: String,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(deserialize_with = "opt_i64_from_string_or_number", default)]
+        task_count: Option<i64>,
+        #[serde(default)]
+        archived: Option<bool>,
+    },
+}
+
+impl From<SharedItemValue> for SharedItem {
+    fn from(value: SharedItemValue) -> Self {
+        match value {
+            SharedItemValue::Id(id) => SharedItem {
+                id,
+                ..Default::default()
+            },
+            SharedItemValue::Item {
+                id,
+                name,
+                task_count,
+                archived,
+            } => SharedItem {
+                id,
+                name,
+                task_count,
+                archived,
+            },
+        }
+    }
+}
+
+/// Kind of item a guest can be given access to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedItemKind {
+    /// A task
+    Task,
+    /// A list
+    List,
+    /// A folder
+    Folder,
+}
+
+impl SharedItemKind {
+    /// Returns the path segment used in guest access endpoints
+    pub fn endpoint_segment(&self) -> &'static str {
+        match self {
+            SharedItemKind::Task => "task",
+            SharedItemKind::List => "list",
+            SharedItemKind::Folder => "folder",
+        }
+    }
 }
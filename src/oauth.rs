@@ -0,0 +1,409 @@
+//! # OAuth Login
+//!
+//! This module implements the OAuth2 authorization-code flow used by
+//! `auth login --oauth`, so that users can log in with their own ClickUp
+//! account through a shared OAuth app instead of a personal API token.
+//!
+//! ## Flow
+//!
+//! 1. A listener is bound on `localhost` to receive the redirect
+//! 2. The user opens the authorization URL and approves the app
+//! 3. ClickUp redirects to the listener with an authorization code
+//! 4. The code is exchanged for an access token at the token endpoint
+//!
+//! The `state` parameter is checked on the redirect to ignore requests that
+//! didn't come from this login attempt.
+//!
+//! ## Endpoints
+//!
+//! The authorization and token URLs default to ClickUp's, and can be pointed
+//! at a local stand-in server through the `[oauth]` configuration table.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::oauth::OAuthClient;
+//!
+//! let client = OAuthClient::new(
+//!     "client_id",
+//!     "client_secret",
+//!     "https://app.clickup.com/api",
+//!     "https://api.clickup.com/api/v2/oauth/token",
+//! );
+//! let url = client.authorization_url("http://localhost:4320/callback", "state123");
+//! assert!(url.starts_with("https://app.clickup.com/api?client_id=client_id"));
+//! ```
+
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::token_store::TokenStore;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::OsRng;
+use reqwest::Url;
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// ClickUp's OAuth authorization page
+pub const DEFAULT_AUTHORIZE_URL: &str = "https://app.clickup.com/api";
+
+/// Default local port for the redirect listener
+pub const DEFAULT_REDIRECT_PORT: u16 = 4320;
+
+/// Environment variable holding the OAuth app client ID
+pub const CLIENT_ID_ENV: &str = "CLICKUP_OAUTH_CLIENT_ID";
+
+/// Environment variable holding the OAuth app client secret
+pub const CLIENT_SECRET_ENV: &str = "CLICKUP_OAUTH_CLIENT_SECRET";
+
+/// Path the redirect listener accepts the authorization code on
+const CALLBACK_PATH: &str = "/callback";
+
+/// Response body of the token endpoint
+#[derive(Deserialize)]
+struct TokenResponse {
+    /// The OAuth access token
+    access_token: String,
+}
+
+/// Client for an OAuth app's authorization and token endpoints
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    /// OAuth app client ID
+    client_id: String,
+    /// OAuth app client secret
+    client_secret: String,
+    /// Authorization page URL
+    authorize_url: String,
+    /// Token exchange URL
+    token_url: String,
+    /// HTTP client for the token exchange
+    http: reqwest::Client,
+}
+
+impl OAuthClient {
+    /// Creates a client for the given OAuth app and endpoints
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - The OAuth app client ID
+    /// * `client_secret` - The OAuth app client secret
+    /// * `authorize_url` - The authorization page URL
+    /// * `token_url` - The token exchange URL
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        authorize_url: impl Into<String>,
+        token_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            authorize_url: authorize_url.into(),
+            token_url: token_url.into(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Creates a client from the configuration and optional overrides
+    ///
+    /// The client ID and secret are taken from the arguments, then the
+    /// `CLICKUP_OAUTH_CLIENT_ID`/`CLICKUP_OAUTH_CLIENT_SECRET` environment
+    /// variables, then the `[oauth]` configuration table. A secret moved out
+    /// of the table is read from the keyring or encrypted file.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The application configuration
+    /// * `client_id` - Optional client ID, typically from `--client-id`
+    /// * `client_secret` - Optional client secret, typically from `--client-secret`
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the client ID or secret is not configured,
+    ///   or the keyring or passphrase holding the secret is unavailable
+    /// - `ClickUpError::AuthError` if the stored secret cannot be decrypted
+    pub fn from_config(
+        config: &Config,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+    ) -> Result<Self, ClickUpError> {
+        let resolve = |arg: Option<String>, env: &str, configured: &Option<String>| {
+            arg.or_else(|| std::env::var(env).ok())
+                .or_else(|| configured.clone())
+                .filter(|v| !v.trim().is_empty())
+        };
+        let client_id = resolve(client_id, CLIENT_ID_ENV, &config.oauth.client_id)
+            .ok_or_else(|| {
+                ClickUpError::ConfigError(format!(
+                    "OAuth client ID not configured. Use --client-id or set {CLIENT_ID_ENV}"
+                ))
+            })?;
+        let mut client_secret =
+            resolve(client_secret, CLIENT_SECRET_ENV, &config.oauth.client_secret);
+        if let (None, Some(storage)) = (&client_secret, config.oauth.client_secret_storage) {
+            client_secret = TokenStore::for_oauth_client()?
+                .load(storage)?
+                .filter(|v| !v.trim().is_empty());
+        }
+        let client_secret = client_secret.ok_or_else(|| {
+            ClickUpError::ConfigError(format!(
+                "OAuth client secret not configured. Use --client-secret or set {CLIENT_SECRET_ENV}"
+            ))
+        })?;
+        let authorize_url = config
+            .oauth
+            .authorize_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_AUTHORIZE_URL.to_string());
+        let token_url = config.oauth.token_url.clone().unwrap_or_else(|| {
+            format!("{}/oauth/token", config.api_base_url.trim_end_matches('/'))
+        });
+        Ok(Self::new(client_id, client_secret, authorize_url, token_url))
+    }
+
+    /// Returns the OAuth app client ID
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Returns the OAuth app client secret
+    pub fn client_secret(&self) -> &str {
+        &self.client_secret
+    }
+
+    /// Builds the URL the user opens to approve the app
+    ///
+    /// # Arguments
+    ///
+    /// * `redirect_uri` - Where the authorization server sends the code
+    /// * `state` - Value echoed back on the redirect
+    pub fn authorization_url(&self, redirect_uri: &str, state: &str) -> String {
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("redirect_uri", redirect_uri),
+            ("state", state),
+        ];
+        match Url::parse_with_params(&self.authorize_url, params) {
+            Ok(url) => url.to_string(),
+            // Fall back to plain concatenation for URLs the parser rejects
+            Err(_) => format!(
+                "{}?client_id={}&redirect_uri={redirect_uri}&state={state}",
+                self.authorize_url, self.client_id
+            ),
+        }
+    }
+
+    /// Exchanges an authorization code for an access token
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The authorization code from the redirect
+    ///
+    /// # Returns
+    ///
+    /// Returns the access token.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::HttpError` if the token endpoint cannot be reached
+    /// - `ClickUpError::AuthError` if the token endpoint rejects the code
+    /// - `ClickUpError::DeserializationError` if the response has no access token
+    pub async fn exchange_code(&self, code: &str) -> Result<String, ClickUpError> {
+        let response = self
+            .http
+            .post(&self.token_url)
+            .query(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("code", code),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(ClickUpError::AuthError(format!(
+                "Token exchange failed ({status}): {body}"
+            )));
+        }
+        let token: TokenResponse = serde_json::from_str(&body).map_err(|e| {
+            ClickUpError::DeserializationError(format!("Invalid token response: {e}"))
+        })?;
+        Ok(token.access_token)
+    }
+}
+
+/// Local listener that receives the OAuth redirect
+pub struct RedirectListener {
+    /// The bound TCP listener
+    listener: TcpListener,
+    /// The port the listener is bound to
+    port: u16,
+}
+
+impl RedirectListener {
+    /// Binds the listener on `127.0.0.1`
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - The port to listen on, or `0` for any free port
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the port cannot be bound
+    pub async fn bind(port: u16) -> Result<Self, ClickUpError> {
+        let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+        let port = listener.local_addr()?.port();
+        Ok(Self { listener, port })
+    }
+
+    /// Returns the redirect URI to register with the authorization request
+    pub fn redirect_uri(&self) -> String {
+        format!("http://localhost:{}{CALLBACK_PATH}", self.port)
+    }
+
+    /// Waits for the redirect and returns the authorization code
+    ///
+    /// Requests for other paths (such as `/favicon.ico`) are answered with
+    /// `404 Not Found` and ignored, as are redirects carrying a different
+    /// `state`, which get `400 Bad Request`. The browser is shown a short page
+    /// telling the user whether they can return to the terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The state sent with the authorization request
+    /// * `timeout` - How long to wait for the redirect before giving up
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::AuthError` if authorization was denied, no code was
+    ///   returned, or no redirect arrived within `timeout`
+    /// - `ClickUpError::IoError` if the connection fails
+    pub async fn wait_for_code(
+        &self,
+        state: &str,
+        timeout: Duration,
+    ) -> Result<String, ClickUpError> {
+        tokio::time::timeout(timeout, self.accept_redirect(state))
+            .await
+            .map_err(|_| ClickUpError::AuthError("Timed out waiting for authorization".to_string()))?
+    }
+
+    /// Accepts connections until a redirect with the expected state arrives
+    async fn accept_redirect(&self, state: &str) -> Result<String, ClickUpError> {
+        loop {
+            let (stream, _) = self.listener.accept().await?;
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).await?;
+            // Drain the request headers before responding
+            let mut header = String::new();
+            while reader.read_line(&mut header).await? > 2 {
+                header.clear();
+            }
+
+            let target = request_line.split_whitespace().nth(1).unwrap_or("/");
+            let url = Url::parse(&format!("http://localhost{target}")).map_err(|e| {
+                ClickUpError::AuthError(format!("Invalid redirect request: {e}"))
+            })?;
+            let mut stream = reader.into_inner();
+            if url.path() != CALLBACK_PATH {
+                respond(&mut stream, "404 Not Found", "Not found").await?;
+                continue;
+            }
+
+            let param = |name: &str| {
+                url.query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.into_owned())
+            };
+            if param("state").as_deref() != Some(state) {
+                respond(
+                    &mut stream,
+                    "400 Bad Request",
+                    "OAuth state mismatch; the redirect did not come from this login",
+                )
+                .await?;
+                continue;
+            }
+
+            let result = if let Some(error) = param("error") {
+                Err(ClickUpError::AuthError(format!("Authorization denied: {error}")))
+            } else {
+                param("code").filter(|c| !c.is_empty()).ok_or_else(|| {
+                    ClickUpError::AuthError("No authorization code in redirect".to_string())
+                })
+            };
+
+            match &result {
+                Ok(_) => {
+                    respond(
+                        &mut stream,
+                        "200 OK",
+                        "ClickUp CLI login complete. You can close this window.",
+                    )
+                    .await?
+                }
+                Err(e) => respond(&mut stream, "400 Bad Request", &e.to_string()).await?,
+            }
+            return result;
+        }
+    }
+}
+
+/// Writes a minimal plain-text response to the browser
+///
+/// Messages can echo the redirect's `error` parameter, so they are never
+/// sent as HTML.
+async fn respond(
+    stream: &mut tokio::net::TcpStream,
+    status: &str,
+    message: &str,
+) -> Result<(), ClickUpError> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nX-Content-Type-Options: nosniff\r\nConnection: close\r\n\r\n{message}",
+        message.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Generates a random `state` value for an authorization request
+pub fn generate_state() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// Tries to open a URL in the user's browser
+///
+/// # Returns
+///
+/// Returns `true` if a browser command was started.
+pub fn open_browser(url: &str) -> bool {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(windows) {
+        // Avoids `cmd /C start`, which splits URLs on `&`
+        let mut command = std::process::Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+    command
+        .arg(url)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .is_ok()
+}
@@ -0,0 +1,295 @@
+//! # Reports
+//!
+//! This module computes the workload and burndown reports behind
+//! `reports workload` and `reports burndown`, and draws them as ASCII charts.
+//!
+//! ## Workload
+//!
+//! Open tasks are grouped by assignee, status or list. Each group counts its
+//! tasks and overdue tasks and sums their time estimates and story points. A
+//! task with several assignees counts towards each of them, so the groups can
+//! add up to more than the total.
+//!
+//! ## Burndown
+//!
+//! The tasks remaining at the end of each day (UTC) are reconstructed from
+//! `date_created` and `date_closed`: a task counts from the day it was
+//! created until the day it was closed. Done tasks without a close date are
+//! taken as closed when they were last updated. Each day also sums the story
+//! points and time estimates of the remaining tasks. An ideal line falls
+//! evenly from the first day's remaining work to zero on the last day.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use clickup_cli::reports::{self, GroupBy};
+//!
+//! let rows = reports::workload(&tasks, GroupBy::Assignee, now);
+//! println!("{}", reports::workload_chart(&rows));
+//! ```
+
+use crate::models::Task;
+use crate::task_tree;
+use chrono::{Duration, NaiveDate};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Width of the longest bar in a chart, in characters
+const CHART_WIDTH: usize = 40;
+
+/// How open tasks are grouped in a workload report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    /// One group per assignee, plus one for unassigned tasks
+    Assignee,
+    /// One group per status
+    Status,
+    /// One group per list
+    List,
+}
+
+/// How a report is printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// A table
+    Table,
+    /// JSON
+    Json,
+    /// An ASCII bar chart
+    Chart,
+}
+
+/// Open work in one group of a workload report
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct WorkloadRow {
+    /// Assignee username, status or list name
+    pub group: String,
+    /// Number of open tasks
+    pub tasks: usize,
+    /// Summed time estimates (in milliseconds)
+    pub time_estimate: i64,
+    /// Summed story points
+    pub points: f64,
+    /// Number of open tasks past their due date
+    pub overdue: usize,
+}
+
+impl WorkloadRow {
+    /// Adds a task to the row
+    fn add(&mut self, task: &Task, now: i64) {
+        self.tasks += 1;
+        self.time_estimate += task.time_estimate.unwrap_or(0);
+        self.points += task.points.unwrap_or(0.0);
+        if is_overdue(task, now) {
+            self.overdue += 1;
+        }
+    }
+}
+
+/// Work remaining at the end of one day of a burndown report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BurndownDay {
+    /// The day (UTC)
+    pub date: NaiveDate,
+    /// Number of tasks created and not yet closed
+    pub remaining: usize,
+    /// Story points of the remaining tasks
+    pub remaining_points: f64,
+    /// Summed time estimates of the remaining tasks (in milliseconds)
+    pub remaining_estimate: i64,
+    /// Tasks remaining on an even burn from the first day to zero
+    pub ideal: f64,
+}
+
+/// Returns whether an open task is past its due date
+fn is_overdue(task: &Task, now: i64) -> bool {
+    task.due_date
+        .as_deref()
+        .and_then(|due| due.parse::<i64>().ok())
+        .is_some_and(|due| due < now)
+}
+
+/// Groups open tasks and sums their work
+///
+/// # Arguments
+///
+/// * `tasks` - The tasks to report on; done tasks are skipped
+/// * `by` - How to group the tasks
+/// * `now` - The current time (Unix timestamp in milliseconds), for overdue counts
+///
+/// # Returns
+///
+/// Returns one row per group, with the most open tasks first.
+pub fn workload(tasks: &[Task], by: GroupBy, now: i64) -> Vec<WorkloadRow> {
+    let mut groups: HashMap<String, WorkloadRow> = HashMap::new();
+    for task in tasks.iter().filter(|task| !task_tree::is_done(task)) {
+        let keys = match by {
+            GroupBy::Assignee if task.assignees.is_empty() => vec!["Unassigned".to_string()],
+            GroupBy::Assignee => task
+                .assignees
+                .iter()
+                .map(|assignee| assignee.username.clone())
+                .collect(),
+            GroupBy::Status => vec![task.status.status.clone()],
+            GroupBy::List => vec![task.list.name.clone().unwrap_or_else(|| task.list.id.clone())],
+        };
+        for key in keys {
+            groups
+                .entry(key.clone())
+                .or_insert_with(|| WorkloadRow {
+                    group: key,
+                    ..Default::default()
+                })
+                .add(task, now);
+        }
+    }
+
+    let mut rows: Vec<WorkloadRow> = groups.into_values().collect();
+    rows.sort_by(|a, b| b.tasks.cmp(&a.tasks).then_with(|| a.group.cmp(&b.group)));
+    rows
+}
+
+/// Sums the work of all open tasks, counting each task once
+pub fn workload_total(tasks: &[Task], now: i64) -> WorkloadRow {
+    let mut total = WorkloadRow {
+        group: "Total".to_string(),
+        ..Default::default()
+    };
+    for task in tasks.iter().filter(|task| !task_tree::is_done(task)) {
+        total.add(task, now);
+    }
+    total
+}
+
+/// Reconstructs the work remaining at the end of each day
+///
+/// # Arguments
+///
+/// * `tasks` - Every task in the list, including closed tasks
+/// * `from` - The first day
+/// * `to` - The last day (not before `from`)
+///
+/// # Returns
+///
+/// Returns one entry per day from `from` to `to`.
+pub fn burndown(tasks: &[Task], from: NaiveDate, to: NaiveDate) -> Vec<BurndownDay> {
+    // When each task was created and closed, as Unix timestamps in
+    // milliseconds, with its points and time estimate
+    let spans: Vec<(i64, Option<i64>, f64, i64)> = tasks
+        .iter()
+        .filter_map(|task| {
+            let created = task.date_created.parse::<i64>().ok()?;
+            let closed = task
+                .date_closed
+                .as_deref()
+                .or_else(|| task_tree::is_done(task).then_some(task.date_updated.as_str()))
+                .and_then(|date| date.parse::<i64>().ok());
+            Some((
+                created,
+                closed,
+                task.points.unwrap_or(0.0),
+                task.time_estimate.unwrap_or(0),
+            ))
+        })
+        .collect();
+
+    let mut days = Vec::new();
+    let mut date = from;
+    while date <= to {
+        let end_of_day = (date + Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .map_or(i64::MAX, |end| end.and_utc().timestamp_millis());
+        let open: Vec<&(i64, Option<i64>, f64, i64)> = spans
+            .iter()
+            .filter(|(created, closed, _, _)| {
+                *created < end_of_day && closed.is_none_or(|closed| closed >= end_of_day)
+            })
+            .collect();
+        days.push(BurndownDay {
+            date,
+            remaining: open.len(),
+            remaining_points: open.iter().map(|(_, _, points, _)| points).sum(),
+            remaining_estimate: open.iter().map(|(_, _, _, estimate)| estimate).sum(),
+            ideal: 0.0,
+        });
+        date += Duration::days(1);
+    }
+
+    if let Some(start) = days.first().map(|day| day.remaining as f64) {
+        let steps = days.len().saturating_sub(1).max(1) as f64;
+        for (index, day) in days.iter_mut().enumerate() {
+            day.ideal = start - start * index as f64 / steps;
+        }
+    }
+    days
+}
+
+/// Formats story points without a fraction when they are whole, e.g. `3` or `2.5`
+pub fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{points:.0}")
+    } else {
+        format!("{points:.1}")
+    }
+}
+
+/// Returns a bar of `value` scaled so that `max` fills the chart width
+fn bar(value: f64, max: f64) -> String {
+    if max <= 0.0 {
+        return String::new();
+    }
+    "█".repeat((value / max * CHART_WIDTH as f64).round() as usize)
+}
+
+/// Draws the open tasks of each workload group as a horizontal bar chart
+pub fn workload_chart(rows: &[WorkloadRow]) -> String {
+    let label_width = rows
+        .iter()
+        .map(|row| row.group.chars().count())
+        .max()
+        .unwrap_or(0);
+    let max = rows.iter().map(|row| row.tasks).max().unwrap_or(0) as f64;
+    rows.iter()
+        .map(|row| {
+            let overdue = if row.overdue > 0 {
+                format!(" ({} overdue)", row.overdue)
+            } else {
+                String::new()
+            };
+            format!(
+                "{:<label_width$} │{} {}{overdue}\n",
+                row.group,
+                bar(row.tasks as f64, max),
+                row.tasks
+            )
+        })
+        .collect()
+}
+
+/// Draws the remaining tasks of each day as a horizontal bar chart
+///
+/// The ideal burn is marked with `┆` where it falls beyond the bar.
+pub fn burndown_chart(days: &[BurndownDay]) -> String {
+    let max = days
+        .iter()
+        .map(|day| (day.remaining as f64).max(day.ideal))
+        .fold(0.0, f64::max);
+    days.iter()
+        .map(|day| {
+            let mut line = bar(day.remaining as f64, max);
+            let ideal = if max > 0.0 {
+                (day.ideal / max * CHART_WIDTH as f64).round() as usize
+            } else {
+                0
+            };
+            let length = line.chars().count();
+            if ideal > length {
+                line.push_str(&" ".repeat(ideal - length - 1));
+                line.push('┆');
+            }
+            format!("{} │{line} {}\n", day.date, day.remaining)
+        })
+        .collect()
+}
@@ -0,0 +1,512 @@
+//! # Rich Comment Text
+//!
+//! This module converts between the text typed on the command line and the
+//! rich text segments ClickUp stores for a comment.
+//!
+//! ## Markdown
+//!
+//! `markdown_to_blocks` understands the subset of Markdown that ClickUp
+//! comments can hold:
+//!
+//! - `**bold**` or `__bold__`, `*italic*` or `_italic_`, and `` `code` ``
+//! - `[text](url)` links
+//! - `- item` or `* item` bullets and `1. item` numbered items
+//! - `#` headings, `>` quotes and fenced code blocks
+//!
+//! Anything else is kept as plain text.
+//!
+//! ## Mentions
+//!
+//! `@name` outside code becomes a mention once `link_mentions` resolves the
+//! name to a user ID. Names that do not resolve stay as text.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::rich_text::{link_mentions, markdown_to_blocks, plain_text};
+//!
+//! let blocks = markdown_to_blocks("Ask **@sam** about [the spec](https://example.com)");
+//! let blocks = link_mentions(blocks, |name| Ok((name == "sam").then_some(42))).unwrap();
+//! assert_eq!(blocks[1].user.as_ref().unwrap().id, 42);
+//! assert_eq!(plain_text(&blocks), "Ask @42 about the spec (https://example.com)");
+//! ```
+
+use crate::error::ClickUpError;
+use crate::models::{Comment, CommentAttributes, CommentList, CommentMention, CommentText};
+use colored::*;
+
+/// Segment type ClickUp uses for mentions
+const MENTION_TYPE: &str = "tag";
+
+/// Converts Markdown into rich text segments
+pub fn markdown_to_blocks(markdown: &str) -> Vec<CommentText> {
+    let mut blocks = Vec::new();
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut in_code_block = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        let last = index + 1 == lines.len();
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            push_text(&mut blocks, line, None);
+            push_text(
+                &mut blocks,
+                "\n",
+                Some(CommentAttributes {
+                    code_block: Some(serde_json::json!({"code-block": "plain"})),
+                    ..Default::default()
+                }),
+            );
+            continue;
+        }
+
+        match line_format(line) {
+            Some((content, format)) => {
+                parse_inline(content, &CommentAttributes::default(), &mut blocks);
+                push_text(&mut blocks, "\n", Some(format));
+            }
+            None => {
+                parse_inline(line, &CommentAttributes::default(), &mut blocks);
+                if !last {
+                    push_text(&mut blocks, "\n", None);
+                }
+            }
+        }
+    }
+    blocks
+}
+
+/// Wraps plain text in a single segment
+pub fn text_blocks(text: &str) -> Vec<CommentText> {
+    let mut blocks = Vec::new();
+    push_text(&mut blocks, text, None);
+    blocks
+}
+
+/// Returns the names written as `@name` in segments that can hold mentions
+pub fn mention_names(blocks: &[CommentText]) -> Vec<String> {
+    blocks
+        .iter()
+        .filter(|block| can_mention(block))
+        .flat_map(|block| mention_spans(&block.text))
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Turns each `@name` that `resolve` maps to a user ID into a mention
+///
+/// # Errors
+///
+/// This function returns any error returned by `resolve`.
+pub fn link_mentions<F>(
+    blocks: Vec<CommentText>,
+    mut resolve: F,
+) -> Result<Vec<CommentText>, ClickUpError>
+where
+    F: FnMut(&str) -> Result<Option<i64>, ClickUpError>,
+{
+    let mut linked = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        if !can_mention(&block) {
+            linked.push(block);
+            continue;
+        }
+        let mut written = 0;
+        for (start, name) in mention_spans(&block.text) {
+            if let Some(id) = resolve(name)? {
+                push_text(&mut linked, &block.text[written..start], block.attributes.clone());
+                linked.push(CommentText {
+                    text: String::new(),
+                    type_: Some(MENTION_TYPE.to_string()),
+                    attributes: None,
+                    user: Some(CommentMention { id, username: None }),
+                });
+                written = start + 1 + name.len();
+            }
+        }
+        push_text(&mut linked, &block.text[written..], block.attributes.clone());
+    }
+    Ok(linked)
+}
+
+/// Returns the number of mentions in a list of segments
+pub fn mention_count(blocks: &[CommentText]) -> usize {
+    blocks.iter().filter(|block| is_mention(block)).count()
+}
+
+/// Renders segments with terminal colors and styles
+///
+/// Bullets and numbers are drawn for list items, headings are bold, quotes
+/// and code blocks are marked with a bar, and links show their target.
+pub fn render(blocks: &[CommentText]) -> String {
+    layout(blocks, true)
+}
+
+/// Returns the text of segments laid out as `render` does, without styles
+pub fn plain_text(blocks: &[CommentText]) -> String {
+    layout(blocks, false)
+}
+
+/// Lays out segments line by line, with or without terminal styles
+fn layout(blocks: &[CommentText], styled: bool) -> String {
+    let mut output = String::new();
+    let mut line = String::new();
+    let mut ordinal = 0;
+
+    for block in blocks {
+        if is_mention(block) {
+            let label = mention_label(block);
+            if styled {
+                line.push_str(&label.blue().bold().to_string());
+            } else {
+                line.push_str(&label);
+            }
+            continue;
+        }
+        let mut pieces = block.text.split('\n').peekable();
+        while let Some(piece) = pieces.next() {
+            if !piece.is_empty() {
+                line.push_str(&style(piece, block.attributes.as_ref(), styled));
+            }
+            if pieces.peek().is_some() {
+                end_line(&mut output, &line, block.attributes.as_ref(), &mut ordinal, styled);
+                line.clear();
+            }
+        }
+    }
+    output.push_str(&line);
+    output.trim_end_matches('\n').to_string()
+}
+
+/// Renders a comment, falling back to its plain text if it has no segments
+pub fn render_comment(comment: &Comment) -> String {
+    if comment.comment.is_empty() {
+        comment.comment_text.clone()
+    } else {
+        render(&comment.comment)
+    }
+}
+
+/// Returns whether a segment is a mention
+fn is_mention(block: &CommentText) -> bool {
+    block.type_.as_deref() == Some(MENTION_TYPE)
+}
+
+/// Returns whether `@name` in a segment may become a mention
+fn can_mention(block: &CommentText) -> bool {
+    block.type_.is_none()
+        && block.user.is_none()
+        && block.attributes.as_ref().is_none_or(|attributes| {
+            attributes.code != Some(true) && attributes.code_block.is_none()
+        })
+}
+
+/// Returns the text shown for a mention
+fn mention_label(block: &CommentText) -> String {
+    if !block.text.is_empty() {
+        return block.text.clone();
+    }
+    match &block.user {
+        Some(CommentMention {
+            username: Some(username),
+            ..
+        }) => format!("@{username}"),
+        Some(user) => format!("@{}", user.id),
+        None => "@unknown".to_string(),
+    }
+}
+
+/// Appends text to the segments, merging it into the previous segment when
+/// both have the same inline formatting
+fn push_text(blocks: &mut Vec<CommentText>, text: &str, attributes: Option<CommentAttributes>) {
+    if text.is_empty() {
+        return;
+    }
+    let attributes = attributes.filter(|attributes| *attributes != CommentAttributes::default());
+    let line_format = attributes
+        .as_ref()
+        .is_some_and(CommentAttributes::is_line_format);
+    if let Some(previous) = blocks.last_mut() {
+        let previous_line_format = previous
+            .attributes
+            .as_ref()
+            .is_some_and(CommentAttributes::is_line_format);
+        if !line_format
+            && !previous_line_format
+            && previous.type_.is_none()
+            && previous.user.is_none()
+            && previous.attributes == attributes
+        {
+            previous.text.push_str(text);
+            return;
+        }
+    }
+    blocks.push(CommentText {
+        text: text.to_string(),
+        type_: None,
+        attributes,
+        user: None,
+    });
+}
+
+/// Splits a Markdown line into its content and line formatting, if any
+fn line_format(line: &str) -> Option<(&str, CommentAttributes)> {
+    let trimmed = line.trim_start();
+
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&level) {
+        if let Some(content) = trimmed[level..].strip_prefix(' ') {
+            return Some((
+                content.trim_start(),
+                CommentAttributes {
+                    header: Some(level as u8),
+                    ..Default::default()
+                },
+            ));
+        }
+    }
+
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(content) = trimmed.strip_prefix(bullet) {
+            return Some((content.trim_start(), list_format("bullet")));
+        }
+    }
+
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        let rest = &trimmed[digits..];
+        if let Some(content) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some((content.trim_start(), list_format("ordered")));
+        }
+    }
+
+    if let Some(content) = trimmed.strip_prefix('>') {
+        return Some((
+            content.trim_start(),
+            CommentAttributes {
+                blockquote: Some(true),
+                ..Default::default()
+            },
+        ));
+    }
+    None
+}
+
+/// Returns the formatting of a list line
+fn list_format(kind: &str) -> CommentAttributes {
+    CommentAttributes {
+        list: Some(CommentList {
+            list: kind.to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+/// A formatted span found in a line
+enum Span<'a> {
+    /// Inline code, which is not parsed further
+    Code(&'a str),
+    /// Text with extra formatting, which may contain more spans
+    Styled(&'a str, CommentAttributes),
+}
+
+/// Parses inline Markdown into segments with the given base formatting
+fn parse_inline(text: &str, style: &CommentAttributes, blocks: &mut Vec<CommentText>) {
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some((span, after)) = inline_span(rest, previous, style) {
+            push_text(blocks, &plain, Some(style.clone()));
+            plain.clear();
+            match span {
+                Span::Code(code) => push_text(
+                    blocks,
+                    code,
+                    Some(CommentAttributes {
+                        code: Some(true),
+                        ..style.clone()
+                    }),
+                ),
+                Span::Styled(inner, nested) => parse_inline(inner, &nested, blocks),
+            }
+            previous = rest[..rest.len() - after.len()].chars().last();
+            rest = after;
+            continue;
+        }
+        plain.push(c);
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    push_text(blocks, &plain, Some(style.clone()));
+}
+
+/// Returns the span starting at the beginning of `rest` and the text after it
+fn inline_span<'a>(
+    rest: &'a str,
+    previous: Option<char>,
+    style: &CommentAttributes,
+) -> Option<(Span<'a>, &'a str)> {
+    if let Some(after_tick) = rest.strip_prefix('`') {
+        let end = after_tick.find('`').filter(|end| *end > 0)?;
+        return Some((Span::Code(&after_tick[..end]), &after_tick[end + 1..]));
+    }
+
+    if rest.starts_with('[') {
+        let close = rest.find("](")?;
+        let target_start = close + 2;
+        let target_end = target_start + rest[target_start..].find(')')?;
+        let label = &rest[1..close];
+        let target = &rest[target_start..target_end];
+        if label.is_empty() || target.is_empty() {
+            return None;
+        }
+        let attributes = CommentAttributes {
+            link: Some(target.to_string()),
+            ..style.clone()
+        };
+        return Some((Span::Styled(label, attributes), &rest[target_end + 1..]));
+    }
+
+    let at_word_start = previous.is_none_or(|c| !c.is_alphanumeric());
+    for (delimiter, bold) in [("**", true), ("__", true), ("*", false), ("_", false)] {
+        if delimiter.starts_with('_') && !at_word_start {
+            continue;
+        }
+        let Some(after_open) = rest.strip_prefix(delimiter) else {
+            continue;
+        };
+        if after_open.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let Some(end) = find_closing(after_open, delimiter) else {
+            continue;
+        };
+        let attributes = if bold {
+            CommentAttributes {
+                bold: Some(true),
+                ..style.clone()
+            }
+        } else {
+            CommentAttributes {
+                italic: Some(true),
+                ..style.clone()
+            }
+        };
+        return Some((
+            Span::Styled(&after_open[..end], attributes),
+            &after_open[end + delimiter.len()..],
+        ));
+    }
+    None
+}
+
+/// Finds the delimiter that closes an emphasis span
+///
+/// The closing delimiter must follow non-whitespace, and `_` must not be
+/// followed by a letter or digit, so `snake_case` stays as text.
+fn find_closing(text: &str, delimiter: &str) -> Option<usize> {
+    let mut from = text.chars().next()?.len_utf8();
+    while let Some(offset) = text[from..].find(delimiter) {
+        let end = from + offset;
+        let after = &text[end + delimiter.len()..];
+        let closes = !text[..end].ends_with(char::is_whitespace)
+            && (!delimiter.starts_with('_') || !after.starts_with(char::is_alphanumeric));
+        if closes {
+            return Some(end);
+        }
+        from = end + delimiter.len();
+    }
+    None
+}
+
+/// Returns the byte offset of each `@` that starts a mention, with the name
+fn mention_spans(text: &str) -> Vec<(usize, &str)> {
+    let mut spans = Vec::new();
+    let mut previous: Option<char> = None;
+    for (start, c) in text.char_indices() {
+        if c == '@' && previous.is_none_or(|p| !p.is_alphanumeric()) {
+            let name_start = start + 1;
+            let length = text[name_start..]
+                .find(|c: char| !(c.is_alphanumeric() || matches!(c, '.' | '_' | '-')))
+                .unwrap_or(text.len() - name_start);
+            let name = text[name_start..name_start + length].trim_end_matches('.');
+            if !name.is_empty() {
+                spans.push((start, name));
+            }
+        }
+        previous = Some(c);
+    }
+    spans
+}
+
+/// Applies inline formatting to a piece of text
+///
+/// Without styles only link targets are added.
+fn style(text: &str, attributes: Option<&CommentAttributes>, styled: bool) -> String {
+    let Some(attributes) = attributes else {
+        return text.to_string();
+    };
+    if !styled {
+        return match &attributes.link {
+            Some(link) if link != text => format!("{text} ({link})"),
+            _ => text.to_string(),
+        };
+    }
+    let mut painted = text.normal();
+    if attributes.code == Some(true) {
+        painted = painted.cyan();
+    }
+    if attributes.bold == Some(true) {
+        painted = painted.bold();
+    }
+    if attributes.italic == Some(true) {
+        painted = painted.italic();
+    }
+    match &attributes.link {
+        Some(link) if link == text => painted.underline().to_string(),
+        Some(link) => format!("{} ({})", painted.underline(), link.dimmed()),
+        None => painted.to_string(),
+    }
+}
+
+/// Writes a finished line to the output with its line formatting
+fn end_line(
+    output: &mut String,
+    line: &str,
+    attributes: Option<&CommentAttributes>,
+    ordinal: &mut usize,
+    styled: bool,
+) {
+    let list = attributes.and_then(|attributes| attributes.list.as_ref());
+    if list.is_none_or(|list| list.list != "ordered") {
+        *ordinal = 0;
+    }
+    let bar = if styled { "│".dimmed().to_string() } else { "│".to_string() };
+
+    let formatted = match (list, attributes) {
+        (Some(list), _) if list.list == "ordered" => {
+            *ordinal += 1;
+            format!("  {}. {line}", *ordinal)
+        }
+        (Some(_), _) => format!("  • {line}"),
+        (None, Some(attributes)) if attributes.header.is_some() && styled => {
+            line.bold().underline().to_string()
+        }
+        (None, Some(attributes)) if attributes.code_block.is_some() => {
+            let code = if styled { line.cyan().to_string() } else { line.to_string() };
+            format!("{bar} {code}")
+        }
+        (None, Some(attributes)) if attributes.blockquote.is_some() => {
+            let quote = if styled { line.italic().to_string() } else { line.to_string() };
+            format!("{bar} {quote}")
+        }
+        _ => line.to_string(),
+    };
+    output.push_str(&formatted);
+    output.push('\n');
+}
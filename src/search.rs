@@ -0,0 +1,263 @@
+//! # Full-Text Search
+//!
+//! This module provides the matching and ranking behind the `search`
+//! command, which looks for text in task names, task text and comments.
+//!
+//! ## Matching
+//!
+//! The query is matched literally unless regex matching is requested, and
+//! case-sensitively unless case is ignored.
+//!
+//! ## Ranking
+//!
+//! Every match adds the weight of the field it is in: 10 for the task name,
+//! 3 for the task text and 1 for a comment. A name that matches in full
+//! scores another 10. Results are ordered by score, then by task name.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::search::{Matcher, MatchField, SearchOptions};
+//!
+//! let matcher = Matcher::new("log.n", &SearchOptions { regex: true, ignore_case: true }).unwrap();
+//! let snippet = matcher.snippet("Fix the LOGIN page").unwrap();
+//! assert_eq!(snippet.matched, "LOGIN");
+//! assert_eq!(snippet.to_string(), "Fix the LOGIN page");
+//! assert_eq!(MatchField::Name.weight(), 10);
+//! ```
+
+use crate::error::ClickUpError;
+use crate::models::{Comment, Task};
+use regex::{Regex, RegexBuilder};
+use std::cmp::Reverse;
+use std::fmt;
+
+/// Characters of context kept on each side of a match in a snippet
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Score added when the whole task name matches
+const FULL_NAME_BONUS: u32 = 10;
+
+/// How the query is matched
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Treat the query as a regular expression
+    pub regex: bool,
+    /// Ignore case when matching
+    pub ignore_case: bool,
+}
+
+/// Where in a task a match was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchField {
+    /// The task name
+    Name,
+    /// The task's plain-text description
+    Text,
+    /// A comment on the task
+    Comment,
+}
+
+impl MatchField {
+    /// Returns the score each match in this field adds
+    pub fn weight(&self) -> u32 {
+        match self {
+            MatchField::Name => 10,
+            MatchField::Text => 3,
+            MatchField::Comment => 1,
+        }
+    }
+
+    /// Returns the field name shown with a result
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchField::Name => "name",
+            MatchField::Text => "text",
+            MatchField::Comment => "comment",
+        }
+    }
+}
+
+/// A match with the text around it
+///
+/// Whitespace is collapsed, and `…` marks text cut from either side.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Snippet {
+    /// Text before the match
+    pub before: String,
+    /// The matched text
+    pub matched: String,
+    /// Text after the match
+    pub after: String,
+}
+
+impl fmt::Display for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.before, self.matched, self.after)
+    }
+}
+
+/// A task that matched a search
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    /// Task ID
+    pub task_id: String,
+    /// Task name
+    pub task_name: String,
+    /// Task URL
+    pub url: String,
+    /// Relevance score; higher is better
+    pub score: u32,
+    /// Total number of matches in the task
+    pub matches: usize,
+    /// Field the snippet was taken from
+    pub field: MatchField,
+    /// The first match in the highest-weighted field that matched
+    pub snippet: Snippet,
+}
+
+/// A compiled search query
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    regex: Regex,
+}
+
+impl Matcher {
+    /// Compiles a search query
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if the query is empty or not a valid regex
+    pub fn new(query: &str, options: &SearchOptions) -> Result<Self, ClickUpError> {
+        if query.trim().is_empty() {
+            return Err(ClickUpError::ValidationError(
+                "Search text cannot be empty".to_string(),
+            ));
+        }
+        let pattern = if options.regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(options.ignore_case)
+            .build()
+            .map_err(|e| ClickUpError::ValidationError(format!("Invalid regex '{query}': {e}")))?;
+        Ok(Self { regex })
+    }
+
+    /// Returns the number of non-empty matches in a text
+    pub fn count(&self, text: &str) -> usize {
+        self.regex
+            .find_iter(text)
+            .filter(|found| !found.is_empty())
+            .count()
+    }
+
+    /// Returns the first non-empty match in a text with its context
+    pub fn snippet(&self, text: &str) -> Option<Snippet> {
+        let found = self.regex.find_iter(text).find(|found| !found.is_empty())?;
+        let before = &text[..found.start()];
+        let after = &text[found.end()..];
+
+        let before_chars = before.chars().count();
+        let before = if before_chars > SNIPPET_CONTEXT {
+            let kept: String = before.chars().skip(before_chars - SNIPPET_CONTEXT).collect();
+            format!("…{}", collapse_whitespace(&kept).trim_start())
+        } else {
+            collapse_whitespace(before).trim_start().to_string()
+        };
+        let after = if after.chars().count() > SNIPPET_CONTEXT {
+            let kept: String = after.chars().take(SNIPPET_CONTEXT).collect();
+            format!("{}…", collapse_whitespace(&kept).trim_end())
+        } else {
+            collapse_whitespace(after).trim_end().to_string()
+        };
+
+        Some(Snippet {
+            before,
+            matched: collapse_whitespace(found.as_str()),
+            after,
+        })
+    }
+
+    /// Returns whether the whole text matches
+    fn matches_fully(&self, text: &str) -> bool {
+        let text = text.trim();
+        self.regex
+            .find(text)
+            .is_some_and(|found| found.start() == 0 && found.end() == text.len())
+    }
+
+    /// Matches a task and its comments
+    ///
+    /// # Returns
+    ///
+    /// Returns the scored result, or `None` if nothing matched.
+    pub fn search_task(&self, task: &Task, comments: &[Comment]) -> Option<SearchResult> {
+        let name = task.name.as_deref().unwrap_or("");
+        let fields = std::iter::once((MatchField::Name, name))
+            .chain(std::iter::once((MatchField::Text, task.text_content.as_str())))
+            .chain(
+                comments
+                    .iter()
+                    .map(|comment| (MatchField::Comment, comment.comment_text.as_str())),
+            );
+
+        let mut score = 0;
+        let mut matches = 0;
+        let mut best: Option<(MatchField, Snippet)> = None;
+        for (field, text) in fields {
+            let count = self.count(text);
+            if count == 0 {
+                continue;
+            }
+            matches += count;
+            score += field.weight() * count as u32;
+            if best.is_none() {
+                best = self.snippet(text).map(|snippet| (field, snippet));
+            }
+        }
+        let (field, snippet) = best?;
+        if self.matches_fully(name) {
+            score += FULL_NAME_BONUS;
+        }
+
+        Some(SearchResult {
+            task_id: task.id.clone(),
+            task_name: name.to_string(),
+            url: task.url.clone(),
+            score,
+            matches,
+            field,
+            snippet,
+        })
+    }
+}
+
+/// Orders results by score, highest first, then by task name
+pub fn rank(results: &mut [SearchResult]) {
+    results.sort_by(|a, b| {
+        (Reverse(a.score), a.task_name.to_lowercase())
+            .cmp(&(Reverse(b.score), b.task_name.to_lowercase()))
+    });
+}
+
+/// Replaces runs of whitespace, including newlines, with a single space
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
@@ -0,0 +1,272 @@
+//! # Space Diffs
+//!
+//! This module compares two space backups for `spaces diff` and reports the
+//! folders, lists and tasks that were added, removed or changed.
+//!
+//! ## Compared Fields
+//!
+//! - **Folders**: name, content, hidden and archived
+//! - **Lists**: name, folder, content and archived
+//! - **Tasks**: name, list, parent, status, priority, assignees, due and start
+//!   dates, tags and description
+//!
+//! Items are matched by ID, so a renamed item is reported as changed rather
+//! than as removed and added. Assignees and tags are compared as sorted names.
+//! Backups hold closed tasks too, so a task closed between two backups is
+//! reported as a status change. Backups made by older versions hold open
+//! tasks only; closed tasks that only the other backup holds are left out
+//! when comparing with one, since they may have been closed before it.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use clickup_cli::backup::SpaceBackup;
+//! use clickup_cli::space_diff;
+//!
+//! let before = SpaceBackup::load(Path::new("monday.json"))?;
+//! let after = SpaceBackup::load(Path::new("friday.json"))?;
+//! for task in &space_diff::diff(&before, &after).tasks {
+//!     println!("{:?} {} {}", task.change, task.id, task.name);
+//! }
+//! ```
+
+use crate::backup::SpaceBackup;
+use crate::models::{Folder, List, Task};
+use crate::watch::FieldChange;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+/// Compared fields of an item, in the same order for every item of a kind
+type Fields = Vec<(&'static str, Value)>;
+
+/// Kind of difference between two backups
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// The item is only in the later backup
+    Added,
+    /// The item is only in the earlier backup
+    Removed,
+    /// The item is in both backups with different fields
+    Changed,
+}
+
+/// A folder, list or task that differs between two backups
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ItemDiff {
+    /// Kind of difference
+    pub change: ChangeKind,
+    /// ID of the item
+    pub id: String,
+    /// Display name (the earlier name for removed items)
+    pub name: String,
+    /// Fields that changed (empty for added and removed items)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
+/// Everything that differs between two backups of a space
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SpaceDiff {
+    /// ID of the earlier backup's space
+    pub space_id: String,
+    /// When the earlier backup was made (RFC 3339)
+    pub from: String,
+    /// When the later backup was made (RFC 3339)
+    pub to: String,
+    /// Folders added, removed or changed
+    pub folders: Vec<ItemDiff>,
+    /// Lists added, removed or changed
+    pub lists: Vec<ItemDiff>,
+    /// Tasks added, removed or changed
+    pub tasks: Vec<ItemDiff>,
+}
+
+impl SpaceDiff {
+    /// Returns whether the backups have no differences
+    pub fn is_empty(&self) -> bool {
+        self.folders.is_empty() && self.lists.is_empty() && self.tasks.is_empty()
+    }
+
+    /// Counts the folders, lists and tasks with a kind of difference
+    pub fn count(&self, change: ChangeKind) -> usize {
+        [&self.folders, &self.lists, &self.tasks]
+            .iter()
+            .flat_map(|items| items.iter())
+            .filter(|item| item.change == change)
+            .count()
+    }
+}
+
+/// Compares two backups of a space
+///
+/// # Arguments
+///
+/// * `before` - The earlier backup
+/// * `after` - The later backup
+///
+/// # Returns
+///
+/// Returns the differences in the order of the later backup, with removed
+/// items last.
+pub fn diff(before: &SpaceBackup, after: &SpaceBackup) -> SpaceDiff {
+    SpaceDiff {
+        space_id: before.metadata.space_id.clone(),
+        from: before.metadata.created_at.clone(),
+        to: after.metadata.created_at.clone(),
+        folders: diff_items(&before.folders, &after.folders, |folder: &Folder| {
+            (&folder.id, folder.name.clone().unwrap_or_default(), folder_fields(folder))
+        }),
+        lists: diff_items(&before.lists, &after.lists, |list: &List| {
+            (&list.id, list.name.clone().unwrap_or_default(), list_fields(list))
+        }),
+        tasks: diff_items(
+            &comparable_tasks(before, after),
+            &comparable_tasks(after, before),
+            |task: &&Task| (&task.id, task.name.clone().unwrap_or_default(), task_fields(task)),
+        ),
+    }
+}
+
+/// Returns the tasks of a backup that can be compared with another backup
+///
+/// A backup made without closed tasks can't tell a task that was closed
+/// before it from one that didn't exist yet, so closed tasks that only the
+/// other backup holds are left out.
+fn comparable_tasks<'a>(backup: &'a SpaceBackup, other: &SpaceBackup) -> Vec<&'a Task> {
+    let other_ids: HashSet<&String> = other.tasks.iter().map(|task| &task.id).collect();
+    backup
+        .tasks
+        .iter()
+        .filter(|task| {
+            other.metadata.include_closed || !is_closed(task) || other_ids.contains(&task.id)
+        })
+        .collect()
+}
+
+/// Returns whether a task is closed
+fn is_closed(task: &Task) -> bool {
+    task.status.type_ == "closed" || task.date_closed.is_some()
+}
+
+/// Matches items by ID and compares their fields
+///
+/// `describe` returns an item's ID, name and compared fields.
+fn diff_items<'a, T>(
+    before: &'a [T],
+    after: &'a [T],
+    describe: impl Fn(&'a T) -> (&'a String, String, Fields),
+) -> Vec<ItemDiff> {
+    let earlier: HashMap<&String, (String, Fields)> = before
+        .iter()
+        .map(|item| {
+            let (id, name, fields) = describe(item);
+            (id, (name, fields))
+        })
+        .collect();
+
+    let mut diffs = Vec::new();
+    let mut seen = HashSet::new();
+    for item in after {
+        let (id, name, fields) = describe(item);
+        seen.insert(id);
+        let Some((_, earlier_fields)) = earlier.get(id) else {
+            diffs.push(ItemDiff {
+                change: ChangeKind::Added,
+                id: id.clone(),
+                name,
+                changes: Vec::new(),
+            });
+            continue;
+        };
+
+        let changes: Vec<FieldChange> = earlier_fields
+            .iter()
+            .zip(fields)
+            .filter(|((_, before), (_, after))| before != after)
+            .map(|((field, before), (_, after))| FieldChange {
+                field: field.to_string(),
+                before: before.clone(),
+                after,
+            })
+            .collect();
+        if !changes.is_empty() {
+            diffs.push(ItemDiff {
+                change: ChangeKind::Changed,
+                id: id.clone(),
+                name,
+                changes,
+            });
+        }
+    }
+
+    for item in before {
+        let (id, name, _) = describe(item);
+        if !seen.contains(id) {
+            diffs.push(ItemDiff {
+                change: ChangeKind::Removed,
+                id: id.clone(),
+                name,
+                changes: Vec::new(),
+            });
+        }
+    }
+    diffs
+}
+
+/// Returns the compared fields of a folder
+fn folder_fields(folder: &Folder) -> Fields {
+    vec![
+        ("name", folder.name.clone().into()),
+        ("content", folder.content.clone().into()),
+        ("hidden", folder.hidden.unwrap_or(false).into()),
+        ("archived", folder.archived.unwrap_or(false).into()),
+    ]
+}
+
+/// Returns the compared fields of a list
+fn list_fields(list: &List) -> Fields {
+    // Lists outside folders belong to a hidden placeholder folder
+    let folder = list
+        .folder
+        .as_ref()
+        .filter(|folder| !folder.hidden.unwrap_or(false))
+        .map(|folder| folder.name.clone());
+    vec![
+        ("name", list.name.clone().into()),
+        ("folder", folder.into()),
+        ("content", list.content.clone().into()),
+        ("archived", list.archived.unwrap_or(false).into()),
+    ]
+}
+
+/// Returns the compared fields of a task
+fn task_fields(task: &Task) -> Fields {
+    let mut assignees: Vec<String> = task
+        .assignees
+        .iter()
+        .map(|assignee| assignee.username.clone())
+        .collect();
+    assignees.sort();
+    let mut tags: Vec<String> = task.tags.iter().filter_map(|tag| tag.name.clone()).collect();
+    tags.sort();
+    vec![
+        ("name", task.name.clone().into()),
+        ("list", task.list.id.clone().into()),
+        ("parent", task.parent.clone().into()),
+        ("status", task.status.status.clone().into()),
+        (
+            "priority",
+            task.priority
+                .as_ref()
+                .map(|priority| priority.priority.clone())
+                .into(),
+        ),
+        ("assignees", assignees.into()),
+        ("due_date", task.due_date.clone().into()),
+        ("start_date", task.start_date.clone().into()),
+        ("tags", tags.into()),
+        ("description", task.description.clone().into()),
+    ]
+}
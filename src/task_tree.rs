@@ -0,0 +1,161 @@
+//! # Task Tree
+//!
+//! This module nests subtasks under their parents for `tasks list --tree`,
+//! `tasks show` and `tasks subtasks`, and rolls up progress and time
+//! estimates over each subtree.
+//!
+//! ## Features
+//!
+//! - **Tree Building**: Nests tasks by `parent`, keeping the order they were given in
+//! - **Rollups**: Counts done and total subtasks and sums time estimates
+//! - **Flattening**: Lists a tree depth-first with each task's depth for indented output
+//!
+//! Tasks whose parent isn't among the given tasks become roots, so a page of
+//! subtasks or a list that holds only part of a tree still renders.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use clickup_cli::task_tree::{build_tree, flatten};
+//!
+//! let tree = build_tree(&tasks);
+//! for (depth, node) in flatten(&tree) {
+//!     let rollup = node.rollup();
+//!     println!("{}{} {}/{}", "  ".repeat(depth), node.task.id, rollup.done, rollup.total);
+//! }
+//! ```
+
+use crate::models::Task;
+use std::collections::{HashMap, HashSet};
+
+/// A task and the subtasks nested under it
+#[derive(Debug, Clone)]
+pub struct TaskNode<'a> {
+    /// The task
+    pub task: &'a Task,
+    /// Direct subtasks, in the order they were given in
+    pub children: Vec<TaskNode<'a>>,
+}
+
+/// Progress and time estimates summed over a task's subtasks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rollup {
+    /// Number of done subtasks at any depth
+    pub done: usize,
+    /// Number of subtasks at any depth
+    pub total: usize,
+    /// Time estimate of the task and all its subtasks (in milliseconds)
+    pub time_estimate: i64,
+}
+
+impl TaskNode<'_> {
+    /// Rolls up the done and total subtask counts and the time estimates
+    ///
+    /// The counts cover the subtasks only; the time estimate includes the
+    /// task's own estimate.
+    pub fn rollup(&self) -> Rollup {
+        let mut rollup = Rollup {
+            time_estimate: self.task.time_estimate.unwrap_or(0),
+            ..Default::default()
+        };
+        for child in &self.children {
+            let child_rollup = child.rollup();
+            rollup.total += 1 + child_rollup.total;
+            rollup.done += usize::from(is_done(child.task)) + child_rollup.done;
+            rollup.time_estimate += child_rollup.time_estimate;
+        }
+        rollup
+    }
+
+    /// Finds the node of a task in this subtree
+    pub fn find(&self, task_id: &str) -> Option<&TaskNode<'_>> {
+        if self.task.id == task_id {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(task_id))
+    }
+}
+
+/// Returns whether a task is done (a closed or done status, or a close date)
+pub fn is_done(task: &Task) -> bool {
+    matches!(task.status.type_.as_str(), "closed" | "done") || task.date_closed.is_some()
+}
+
+/// Nests tasks under their parents
+///
+/// # Arguments
+///
+/// * `tasks` - The tasks to arrange, e.g. a list fetched with subtasks
+///
+/// # Returns
+///
+/// Returns the root tasks with their subtasks nested under them.
+pub fn build_tree(tasks: &[Task]) -> Vec<TaskNode<'_>> {
+    let ids: HashSet<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    let mut children: HashMap<&str, Vec<&Task>> = HashMap::new();
+    let mut roots = Vec::new();
+    for task in tasks {
+        match task.parent.as_deref() {
+            Some(parent) if parent != task.id && ids.contains(parent) => {
+                children.entry(parent).or_default().push(task)
+            }
+            _ => roots.push(task),
+        }
+    }
+    let mut visited = HashSet::new();
+    roots
+        .into_iter()
+        .filter_map(|task| build_node(task, &children, &mut visited))
+        .collect()
+}
+
+/// Builds the node of a task, skipping tasks that were already placed
+///
+/// The visited set guards against parent cycles in malformed data.
+fn build_node<'a>(
+    task: &'a Task,
+    children: &HashMap<&str, Vec<&'a Task>>,
+    visited: &mut HashSet<&'a str>,
+) -> Option<TaskNode<'a>> {
+    if !visited.insert(task.id.as_str()) {
+        return None;
+    }
+    let nested = children
+        .get(task.id.as_str())
+        .map(|subtasks| {
+            subtasks
+                .iter()
+                .filter_map(|subtask| build_node(subtask, children, visited))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(TaskNode {
+        task,
+        children: nested,
+    })
+}
+
+/// Lists the nodes of a tree depth-first with their depth
+///
+/// # Arguments
+///
+/// * `nodes` - The root nodes; they are returned at depth 0
+pub fn flatten<'t, 'a>(nodes: &'t [TaskNode<'a>]) -> Vec<(usize, &'t TaskNode<'a>)> {
+    let mut flat = Vec::new();
+    let mut stack: Vec<(usize, &TaskNode)> = nodes.iter().rev().map(|node| (0, node)).collect();
+    while let Some((depth, node)) = stack.pop() {
+        flat.push((depth, node));
+        stack.extend(node.children.iter().rev().map(|child| (depth + 1, child)));
+    }
+    flat
+}
+
+/// Formats a time estimate in milliseconds as hours and minutes, e.g. `2h 30m`
+pub fn format_estimate(milliseconds: i64) -> String {
+    let minutes = milliseconds / 60_000;
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{minutes}m"),
+        (hours, 0) => format!("{hours}h"),
+        (hours, minutes) => format!("{hours}h {minutes}m"),
+    }
+}
@@ -0,0 +1,276 @@
+//! # Local Task Templates
+//!
+//! This module loads task templates kept as YAML files in the config
+//! directory, for teams whose ClickUp plan has no task templates.
+//!
+//! ## Storage
+//!
+//! Templates are read from `templates/*.yaml` (or `*.yml`) next to `config.toml`:
+//! - Windows: `%APPDATA%\clickup-cli\templates\`
+//! - macOS: `~/Library/Application Support/clickup-cli/templates/`
+//! - Linux: `~/.config/clickup-cli/templates/`
+//!
+//! ## Format
+//!
+//! Every field is optional. The template name defaults to the file name
+//! without its extension, and the task name defaults to the template name.
+//!
+//! ```yaml
+//! name: Bug report
+//! task_name: "Bug: "
+//! description: |
+//!   Steps to reproduce:
+//! priority: 2
+//! tags: [bug]
+//! checklists:
+//!   - name: Triage
+//!     items: [Reproduce, Assign severity]
+//! subtasks:
+//!   - name: Write a failing test
+//!     tags: [testing]
+//!   - name: Fix
+//!     checklists:
+//!       - name: Review
+//!         items: [Code review, QA]
+//! ```
+
+use crate::config::Config;
+use crate::error::ClickUpError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A task template read from a YAML file
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LocalTemplate {
+    /// Template name; defaults to the file name without its extension
+    #[serde(default)]
+    pub name: String,
+    /// Name of tasks created from the template; defaults to the template name
+    #[serde(default)]
+    pub task_name: Option<String>,
+    /// Task description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Task status
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Task priority (1-4, where 1 is highest)
+    #[serde(default)]
+    pub priority: Option<i64>,
+    /// Task time estimate (in milliseconds)
+    #[serde(default)]
+    pub time_estimate: Option<i64>,
+    /// Tag names
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Checklists added to the task
+    #[serde(default)]
+    pub checklists: Vec<TemplateChecklist>,
+    /// Subtasks created under the task
+    #[serde(default)]
+    pub subtasks: Vec<TemplateSubtask>,
+    /// File the template was read from
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+/// A checklist in a template
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateChecklist {
+    /// Checklist name
+    pub name: String,
+    /// Item names
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+/// A subtask in a template
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateSubtask {
+    /// Subtask name
+    pub name: String,
+    /// Subtask description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Subtask priority (1-4, where 1 is highest)
+    #[serde(default)]
+    pub priority: Option<i64>,
+    /// Tag names
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Checklists added to the subtask
+    #[serde(default)]
+    pub checklists: Vec<TemplateChecklist>,
+}
+
+impl LocalTemplate {
+    /// Parses a template from YAML
+    ///
+    /// # Arguments
+    ///
+    /// * `yaml` - The template contents
+    /// * `default_name` - Name used when the template doesn't set one
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::DeserializationError` if the YAML is invalid or has unknown fields
+    /// - `ClickUpError::ValidationError` if the priority is not between 1 and 4
+    pub fn from_yaml(yaml: &str, default_name: &str) -> Result<Self, ClickUpError> {
+        // An empty file is a template that only sets the name
+        let mut template: LocalTemplate = if yaml.trim().is_empty() {
+            LocalTemplate::default()
+        } else {
+            serde_yaml::from_str(yaml)
+                .map_err(|e| ClickUpError::DeserializationError(e.to_string()))?
+        };
+        if template.name.trim().is_empty() {
+            template.name = default_name.to_string();
+        }
+
+        let priorities = std::iter::once(template.priority)
+            .chain(template.subtasks.iter().map(|subtask| subtask.priority));
+        for priority in priorities.flatten() {
+            if !(1..=4).contains(&priority) {
+                return Err(ClickUpError::ValidationError(format!(
+                    "Priority {priority} in template '{}' must be between 1 and 4",
+                    template.name
+                )));
+            }
+        }
+        Ok(template)
+    }
+
+    /// Returns the name for tasks created from the template
+    pub fn task_name(&self) -> &str {
+        self.task_name
+            .as_deref()
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or(&self.name)
+    }
+
+    /// Returns the number of checklists, including those of subtasks
+    pub fn checklist_count(&self) -> usize {
+        self.checklists.len()
+            + self
+                .subtasks
+                .iter()
+                .map(|subtask| subtask.checklists.len())
+                .sum::<usize>()
+    }
+
+    /// Summarizes what the template creates, e.g. `2 subtasks, 1 checklist, 1 tag`
+    pub fn summary(&self) -> String {
+        let plural = |count: usize, noun: &str| {
+            format!("{count} {noun}{}", if count == 1 { "" } else { "s" })
+        };
+        [
+            (self.subtasks.len(), "subtask"),
+            (self.checklist_count(), "checklist"),
+            (self.tags.len(), "tag"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, noun)| plural(count, noun))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// Returns the directory holding local templates
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ConfigError` if the config directory cannot be determined
+pub fn templates_dir() -> Result<PathBuf, ClickUpError> {
+    Ok(Config::config_dir()?.join("templates"))
+}
+
+/// Loads every template in a directory, sorted by name
+///
+/// # Returns
+///
+/// Returns the templates, or an empty list if the directory doesn't exist.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::IoError` if the directory or a file cannot be read
+/// - `ClickUpError::DeserializationError` if a template is invalid; the
+///   message names the file
+pub fn load_templates(dir: &Path) -> Result<Vec<LocalTemplate>, ClickUpError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut templates = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_yaml = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                extension.eq_ignore_ascii_case("yaml") || extension.eq_ignore_ascii_case("yml")
+            });
+        if !is_yaml || !path.is_file() {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let content = fs::read_to_string(&path)?;
+        let mut template = LocalTemplate::from_yaml(&content, &stem).map_err(|e| match e {
+            ClickUpError::DeserializationError(message) => ClickUpError::DeserializationError(
+                format!("Invalid template {}: {message}", path.display()),
+            ),
+            other => other,
+        })?;
+        template.path = path;
+        templates.push(template);
+    }
+    templates.sort_by_key(|template| template.name.to_lowercase());
+    Ok(templates)
+}
+
+/// Finds a template by name or file name, ignoring case
+///
+/// # Returns
+///
+/// Returns the matching template, or `None` if no template matches.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if several templates have the name
+pub fn find_template<'a>(
+    templates: &'a [LocalTemplate],
+    reference: &str,
+) -> Result<Option<&'a LocalTemplate>, ClickUpError> {
+    let reference = reference.trim();
+    let matches: Vec<&LocalTemplate> = templates
+        .iter()
+        .filter(|template| template.name.eq_ignore_ascii_case(reference))
+        .collect();
+    match matches.as_slice() {
+        [template] => return Ok(Some(template)),
+        [] => {}
+        _ => {
+            return Err(ClickUpError::ValidationError(format!(
+                "{} local templates are named '{reference}'; use the file name instead",
+                matches.len()
+            )))
+        }
+    }
+    Ok(templates.iter().find(|template| {
+        template
+            .path
+            .file_stem()
+            .is_some_and(|stem| stem.to_string_lossy().eq_ignore_ascii_case(reference))
+    }))
+}
@@ -0,0 +1,393 @@
+//! # Token Storage
+//!
+//! This module keeps API tokens out of `config.toml`. Tokens are stored in the
+//! OS keyring where one is available, with an encrypted file as a fallback for
+//! headless machines. The configuration only records where the token lives.
+//!
+//! ## Storage Backends
+//!
+//! - **Keyring**: Secret Service on Linux, Keychain on macOS, Credential Manager on Windows
+//! - **Encrypted File**: AES-256-GCM encrypted file in the config directory, with
+//!   the key derived from a passphrase using PBKDF2-SHA256
+//! - **Config**: Plaintext in `config.toml` (the behaviour before keyring support)
+//!
+//! ## Passphrase
+//!
+//! The encrypted file passphrase is read from `CLICKUP_TOKEN_PASSPHRASE`, or
+//! prompted for when running in a terminal.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::token_store::{decrypt_token, encrypt_token};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let data = encrypt_token("pk_123_secret", "correct horse battery staple")?;
+//!     let token = decrypt_token(&data, "correct horse battery staple")?;
+//!     assert_eq!(token, "pk_123_secret");
+//!     Ok(())
+//! }
+//! ```
+
+use crate::config::Config;
+use crate::error::ClickUpError;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+/// Service name used for keyring entries
+pub const KEYRING_SERVICE: &str = "clickup-cli";
+
+/// Keyring account for the OAuth app client secret
+///
+/// Profile names can't start with a dot, so this never clashes with a profile.
+const OAUTH_CLIENT_ACCOUNT: &str = ".oauth-client";
+
+/// Environment variable holding the encrypted token file passphrase
+pub const PASSPHRASE_ENV: &str = "CLICKUP_TOKEN_PASSPHRASE";
+
+/// PBKDF2 iteration count for deriving the token file key
+const PBKDF2_ROUNDS: u32 = 600_000;
+
+/// Where an API token is stored
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum TokenStorage {
+    /// Plaintext in `config.toml`
+    #[default]
+    Config,
+    /// The OS keyring
+    Keyring,
+    /// An encrypted file in the config directory
+    EncryptedFile,
+}
+
+impl TokenStorage {
+    /// Returns whether the token is stored in plaintext in `config.toml`
+    pub fn is_config(&self) -> bool {
+        *self == TokenStorage::Config
+    }
+}
+
+impl std::fmt::Display for TokenStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TokenStorage::Config => "config file (plaintext)",
+            TokenStorage::Keyring => "OS keyring",
+            TokenStorage::EncryptedFile => "encrypted file",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Encrypted token file contents
+#[derive(Serialize, Deserialize)]
+struct EncryptedToken {
+    /// Hex-encoded PBKDF2 salt
+    salt: String,
+    /// Hex-encoded AES-GCM nonce
+    nonce: String,
+    /// Hex-encoded ciphertext
+    ciphertext: String,
+}
+
+/// Token storage for a single profile
+///
+/// Each profile has its own keyring entry and encrypted file, so profiles
+/// never share a token by accident.
+#[derive(Debug, Clone)]
+pub struct TokenStore {
+    /// Profile the token belongs to (used as the keyring account name)
+    profile: String,
+    /// Path to the encrypted token file
+    encrypted_path: PathBuf,
+}
+
+impl TokenStore {
+    /// Creates a token store for a profile at the default location
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile name
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if the profile name is not a valid file name
+    /// - `ClickUpError::ConfigError` if the config directory cannot be determined
+    pub fn for_profile(profile: &str) -> Result<Self, ClickUpError> {
+        if profile.is_empty() || profile.contains(['/', '\\']) || profile.starts_with('.') {
+            return Err(ClickUpError::ValidationError(format!(
+                "Invalid profile name '{profile}'"
+            )));
+        }
+        let path = Config::config_dir()?.join(format!("token-{profile}.json"));
+        Ok(Self::with_path(profile, path))
+    }
+
+    /// Creates a store for the OAuth app client secret at the default location
+    ///
+    /// The secret belongs to the OAuth app rather than a profile, so all
+    /// profiles share it.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the config directory cannot be determined
+    pub fn for_oauth_client() -> Result<Self, ClickUpError> {
+        let path = Config::config_dir()?.join("oauth-client-secret.json");
+        Ok(Self::with_path(OAUTH_CLIENT_ACCOUNT, path))
+    }
+
+    /// Creates a token store that uses the given encrypted file path
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile name
+    /// * `encrypted_path` - Path to the encrypted token file
+    pub fn with_path(profile: &str, encrypted_path: PathBuf) -> Self {
+        Self {
+            profile: profile.to_string(),
+            encrypted_path,
+        }
+    }
+
+    /// Reads the token from the given storage
+    ///
+    /// # Returns
+    ///
+    /// Returns the token, or `None` if nothing is stored. Tokens stored in
+    /// `config.toml` are read with the rest of the configuration, so
+    /// `TokenStorage::Config` always returns `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the keyring or passphrase is unavailable
+    /// - `ClickUpError::AuthError` if the token file cannot be decrypted
+    /// - `ClickUpError::IoError` if the token file cannot be read
+    pub fn load(&self, storage: TokenStorage) -> Result<Option<String>, ClickUpError> {
+        match storage {
+            TokenStorage::Config => Ok(None),
+            TokenStorage::Keyring => match self.keyring_entry()?.get_password() {
+                Ok(token) => Ok(Some(token)),
+                Err(keyring::Error::NoEntry) => Ok(None),
+                Err(e) => Err(keyring_error(e)),
+            },
+            TokenStorage::EncryptedFile => {
+                if !self.encrypted_path.exists() {
+                    return Ok(None);
+                }
+                let data = std::fs::read_to_string(&self.encrypted_path)?;
+                decrypt_token(&data, &passphrase()?).map(Some)
+            }
+        }
+    }
+
+    /// Writes the token to the given storage
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the keyring or passphrase is unavailable
+    /// - `ClickUpError::IoError` if the token file cannot be written
+    pub fn save(&self, storage: TokenStorage, token: &str) -> Result<(), ClickUpError> {
+        match storage {
+            TokenStorage::Config => Ok(()),
+            TokenStorage::Keyring => self
+                .keyring_entry()?
+                .set_password(token)
+                .map_err(keyring_error),
+            TokenStorage::EncryptedFile => {
+                let data = encrypt_token(token, &passphrase()?)?;
+                if let Some(parent) = self.encrypted_path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                write_private(&self.encrypted_path, &data)
+            }
+        }
+    }
+
+    /// Writes the token to the keyring, falling back to the encrypted file
+    ///
+    /// # Returns
+    ///
+    /// Returns the storage the token was written to.
+    ///
+    /// # Errors
+    ///
+    /// This function can return the errors of `save()` for the encrypted file,
+    /// if the keyring is unavailable as well.
+    pub fn save_preferred(&self, token: &str) -> Result<TokenStorage, ClickUpError> {
+        match self.save(TokenStorage::Keyring, token) {
+            Ok(()) => Ok(TokenStorage::Keyring),
+            Err(e) => {
+                log::warn!("OS keyring unavailable ({e}), using encrypted token file");
+                self.save(TokenStorage::EncryptedFile, token)?;
+                Ok(TokenStorage::EncryptedFile)
+            }
+        }
+    }
+
+    /// Removes the token from the given storage
+    ///
+    /// Removing a token that isn't stored is not an error.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ConfigError` if the keyring is unavailable
+    /// - `ClickUpError::IoError` if the token file cannot be removed
+    pub fn delete(&self, storage: TokenStorage) -> Result<(), ClickUpError> {
+        match storage {
+            TokenStorage::Config => Ok(()),
+            TokenStorage::Keyring => match self.keyring_entry()?.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(keyring_error(e)),
+            },
+            TokenStorage::EncryptedFile => {
+                if self.encrypted_path.exists() {
+                    std::fs::remove_file(&self.encrypted_path)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the keyring entry for this profile
+    fn keyring_entry(&self) -> Result<keyring::Entry, ClickUpError> {
+        keyring::Entry::new(KEYRING_SERVICE, &self.profile).map_err(keyring_error)
+    }
+}
+
+/// Converts a keyring error into a `ClickUpError`
+fn keyring_error(e: keyring::Error) -> ClickUpError {
+    ClickUpError::ConfigError(format!("Keyring error: {e}"))
+}
+
+/// Reads the token file passphrase from the environment or the terminal
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ConfigError` if no passphrase is set and stdin is not a terminal
+/// - `ClickUpError::IoError` if reading the passphrase fails
+fn passphrase() -> Result<String, ClickUpError> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        if !passphrase.is_empty() {
+            return Ok(passphrase);
+        }
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(ClickUpError::ConfigError(format!(
+            "Set {PASSPHRASE_ENV} to use the encrypted token file"
+        )));
+    }
+    let passphrase = rpassword::prompt_password("Token file passphrase: ")?;
+    if passphrase.is_empty() {
+        return Err(ClickUpError::ValidationError(
+            "Passphrase cannot be empty".to_string(),
+        ));
+    }
+    Ok(passphrase)
+}
+
+/// Derives a 256-bit key from a passphrase and salt
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    key
+}
+
+/// Encrypts a token with a passphrase
+///
+/// # Arguments
+///
+/// * `token` - The API token to encrypt
+/// * `passphrase` - The passphrase to derive the key from
+///
+/// # Returns
+///
+/// Returns the JSON contents of an encrypted token file.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::SerializationError` if encryption fails
+pub fn encrypt_token(token: &str, passphrase: &str) -> Result<String, ClickUpError> {
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(passphrase, &salt);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, token.as_bytes())
+        .map_err(|e| ClickUpError::SerializationError(format!("Failed to encrypt token: {e}")))?;
+
+    let file = EncryptedToken {
+        salt: hex::encode(salt),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    };
+    Ok(serde_json::to_string_pretty(&file)?)
+}
+
+/// Decrypts a token encrypted with `encrypt_token`
+///
+/// # Arguments
+///
+/// * `data` - The JSON contents of an encrypted token file
+/// * `passphrase` - The passphrase used to encrypt the token
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::DeserializationError` if the data is malformed
+/// - `ClickUpError::AuthError` if the passphrase is wrong
+pub fn decrypt_token(data: &str, passphrase: &str) -> Result<String, ClickUpError> {
+    let malformed =
+        |e: &dyn std::fmt::Display| ClickUpError::DeserializationError(format!("Invalid token file: {e}"));
+    let file: EncryptedToken = serde_json::from_str(data).map_err(|e| malformed(&e))?;
+    let salt = hex::decode(&file.salt).map_err(|e| malformed(&e))?;
+    let nonce = hex::decode(&file.nonce).map_err(|e| malformed(&e))?;
+    let ciphertext = hex::decode(&file.ciphertext).map_err(|e| malformed(&e))?;
+    if nonce.len() != 12 {
+        return Err(malformed(&"nonce has the wrong length"));
+    }
+
+    let key = derive_key(passphrase, &salt);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| {
+            ClickUpError::AuthError("Could not decrypt token file: wrong passphrase".to_string())
+        })?;
+    String::from_utf8(plaintext).map_err(|e| malformed(&e))
+}
+
+/// Writes a file readable only by the current user where supported
+fn write_private(path: &Path, contents: &str) -> Result<(), ClickUpError> {
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::OpenOptionsExt;
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        file.write_all(contents.as_bytes())?;
+        Ok(())
+    }
+    #[cfg(not(unix))]
+    {
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,415 @@
+//! # Task Watching
+//!
+//! This module provides the snapshot and diffing logic behind `tasks watch`,
+//! which polls a list and reports what changed between polls.
+//!
+//! ## Features
+//!
+//! - **Snapshots**: Keeps the fields that matter for change detection per task
+//! - **Diffing**: Compares snapshots on `date_updated`, status, assignees and due date
+//! - **Events**: Reports tasks as created, updated, closed or deleted
+//! - **Persistent State**: Saves the last snapshot so restarts don't replay old changes
+//!
+//! ## Storage
+//!
+//! Snapshots are stored next to `config.toml` as `watch/<list_id>.json`:
+//! - Windows: `%APPDATA%\clickup-cli\watch\<list_id>.json`
+//! - macOS: `~/Library/Application Support/clickup-cli/watch/<list_id>.json`
+//! - Linux: `~/.config/clickup-cli/watch/<list_id>.json`
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::watch::{diff, TaskSnapshot, WatchEventKind, WatchState};
+//!
+//! let task = TaskSnapshot {
+//!     name: "Write docs".to_string(),
+//!     status: "to do".to_string(),
+//!     date_updated: "1700000000000".to_string(),
+//!     ..Default::default()
+//! };
+//! let mut previous = WatchState::new("list_123");
+//! previous.tasks.insert("abc".to_string(), task.clone());
+//!
+//! let mut current = WatchState::new("list_123");
+//! current.tasks.insert(
+//!     "abc".to_string(),
+//!     TaskSnapshot {
+//!         status: "in progress".to_string(),
+//!         date_updated: "1700000060000".to_string(),
+//!         ..task
+//!     },
+//! );
+//!
+//! let events = diff(&previous, &current);
+//! assert_eq!(events.len(), 1);
+//! assert_eq!(events[0].event, WatchEventKind::Updated);
+//! assert_eq!(events[0].changes[0].field, "status");
+//! ```
+
+use crate::config::Config;
+use crate::error::ClickUpError;
+use crate::models::Task;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Shortest interval accepted between polls
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Fields of a task that are compared between polls
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct TaskSnapshot {
+    /// Task display name
+    pub name: String,
+    /// Status name
+    pub status: String,
+    /// Whether the task is closed
+    #[serde(default)]
+    pub closed: bool,
+    /// Usernames of the assignees, sorted
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// Due date (Unix timestamp in milliseconds)
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// Last update timestamp (Unix timestamp in milliseconds)
+    pub date_updated: String,
+    /// Task URL
+    #[serde(default)]
+    pub url: String,
+}
+
+impl From<&Task> for TaskSnapshot {
+    fn from(task: &Task) -> Self {
+        let mut assignees: Vec<String> =
+            task.assignees.iter().map(|a| a.username.clone()).collect();
+        assignees.sort();
+        Self {
+            name: task.name.clone().unwrap_or_default(),
+            status: task.status.status.clone(),
+            closed: task.status.type_ == "closed" || task.date_closed.is_some(),
+            assignees,
+            due_date: task.due_date.clone(),
+            date_updated: task.date_updated.clone(),
+            url: task.url.clone(),
+        }
+    }
+}
+
+/// Snapshot of every task in a watched list
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct WatchState {
+    /// ID of the watched list
+    pub list_id: String,
+    /// Time of the poll that produced this snapshot (RFC 3339)
+    #[serde(default)]
+    pub polled_at: Option<String>,
+    /// Task snapshots keyed by task ID
+    #[serde(default)]
+    pub tasks: BTreeMap<String, TaskSnapshot>,
+}
+
+impl WatchState {
+    /// Creates an empty state for a list
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - ID of the watched list
+    #[allow(dead_code)]
+    pub fn new(list_id: &str) -> Self {
+        Self {
+            list_id: list_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds the state for a list from the tasks returned by a poll
+    ///
+    /// # Arguments
+    ///
+    /// * `list_id` - ID of the watched list
+    /// * `tasks` - Every task currently in the list, including closed ones
+    pub fn from_tasks(list_id: &str, tasks: &[Task]) -> Self {
+        Self {
+            list_id: list_id.to_string(),
+            polled_at: Some(Utc::now().to_rfc3339()),
+            tasks: tasks
+                .iter()
+                .map(|task| (task.id.clone(), TaskSnapshot::from(task)))
+                .collect(),
+        }
+    }
+
+    /// Returns the default state file for a list in the config directory
+    ///
+    /// The list ID becomes the file name, so only plain alphanumeric IDs are
+    /// accepted; anything else could point outside the `watch` directory.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::ValidationError` if the list ID is not alphanumeric
+    /// - `ClickUpError::ConfigError` if the config directory cannot be determined
+    pub fn default_path(list_id: &str) -> Result<PathBuf, ClickUpError> {
+        if list_id.is_empty() || !list_id.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(ClickUpError::ValidationError(format!(
+                "Invalid list ID '{list_id}': expected letters and digits only"
+            )));
+        }
+        Ok(Config::config_dir()?
+            .join("watch")
+            .join(format!("{list_id}.json")))
+    }
+
+    /// Loads a saved state
+    ///
+    /// # Returns
+    ///
+    /// Returns the saved state, or `None` if the file doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the file cannot be read
+    /// - `ClickUpError::JsonError` if the file is not a valid state
+    pub fn load(path: &Path) -> Result<Option<Self>, ClickUpError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Saves the state, replacing any previous file atomically
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the file cannot be written
+    /// - `ClickUpError::JsonError` if the state cannot be serialized
+    pub fn save(&self, path: &Path) -> Result<(), ClickUpError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write then rename, so an interrupted watch never leaves a torn file
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+}
+
+/// Kind of change detected between two polls
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchEventKind {
+    /// The task appeared in the list
+    Created,
+    /// The task changed
+    Updated,
+    /// The task moved to a closed status
+    Closed,
+    /// The task disappeared from the list
+    Deleted,
+}
+
+impl std::fmt::Display for WatchEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            WatchEventKind::Created => "created",
+            WatchEventKind::Updated => "updated",
+            WatchEventKind::Closed => "closed",
+            WatchEventKind::Deleted => "deleted",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single field that changed between two polls
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Name of the field, such as `status`, `assignees` or `due_date`
+    pub field: String,
+    /// Value before the change
+    pub before: serde_json::Value,
+    /// Value after the change
+    pub after: serde_json::Value,
+}
+
+/// Formats a changed field value for text output
+///
+/// Lists are joined with commas, missing values shown as `none` and
+/// timestamps in `*_date` fields as `YYYY-MM-DD`.
+pub fn format_change_value(field: &str, value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "none".to_string(),
+        serde_json::Value::Array(items) if items.is_empty() => "none".to_string(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .filter_map(|item| item.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        serde_json::Value::String(text) if field.ends_with("_date") => text
+            .parse::<i64>()
+            .ok()
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| text.clone()),
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// A change to a task detected between two polls
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchEvent {
+    /// Kind of change
+    pub event: WatchEventKind,
+    /// ID of the task
+    pub task_id: String,
+    /// Task display name (the last known name for deleted tasks)
+    pub name: String,
+    /// Task URL
+    pub url: String,
+    /// Fields that changed (empty for created and deleted tasks)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
+/// Compares two snapshots of a list
+///
+/// A task that is updated and closed in the same interval is reported as
+/// closed, with the other changes attached. A task that only had its
+/// `date_updated` bumped (for example by an edited description) is reported
+/// as updated with no field changes.
+///
+/// # Arguments
+///
+/// * `previous` - The snapshot from the previous poll
+/// * `current` - The snapshot from the latest poll
+///
+/// # Returns
+///
+/// Returns the events ordered by task ID, with deletions last.
+pub fn diff(previous: &WatchState, current: &WatchState) -> Vec<WatchEvent> {
+    let mut events = Vec::new();
+
+    for (id, task) in &current.tasks {
+        let event = |kind, changes| WatchEvent {
+            event: kind,
+            task_id: id.clone(),
+            name: task.name.clone(),
+            url: task.url.clone(),
+            changes,
+        };
+        let Some(before) = previous.tasks.get(id) else {
+            events.push(event(WatchEventKind::Created, Vec::new()));
+            continue;
+        };
+        if before == task {
+            continue;
+        }
+
+        let changes = field_changes(before, task);
+        if task.closed && !before.closed {
+            events.push(event(WatchEventKind::Closed, changes));
+        } else if !changes.is_empty() || before.date_updated != task.date_updated {
+            events.push(event(WatchEventKind::Updated, changes));
+        }
+    }
+
+    for (id, task) in &previous.tasks {
+        if !current.tasks.contains_key(id) {
+            events.push(WatchEvent {
+                event: WatchEventKind::Deleted,
+                task_id: id.clone(),
+                name: task.name.clone(),
+                url: task.url.clone(),
+                changes: Vec::new(),
+            });
+        }
+    }
+
+    events
+}
+
+/// Lists the tracked fields that differ between two snapshots of a task
+fn field_changes(before: &TaskSnapshot, after: &TaskSnapshot) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let mut compare = |field: &str, before: serde_json::Value, after: serde_json::Value| {
+        if before != after {
+            changes.push(FieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            });
+        }
+    };
+    compare("name", before.name.clone().into(), after.name.clone().into());
+    compare(
+        "status",
+        before.status.clone().into(),
+        after.status.clone().into(),
+    );
+    compare(
+        "assignees",
+        before.assignees.clone().into(),
+        after.assignees.clone().into(),
+    );
+    compare(
+        "due_date",
+        before.due_date.clone().into(),
+        after.due_date.clone().into(),
+    );
+    changes
+}
+
+/// Parses a poll interval such as `60s`, `5m` or `1h`
+///
+/// A bare number is read as seconds.
+///
+/// # Arguments
+///
+/// * `value` - The interval to parse
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::ValidationError` if the interval is malformed or shorter
+///   than `MIN_INTERVAL`
+pub fn parse_interval(value: &str) -> Result<Duration, ClickUpError> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number.parse().map_err(|_| {
+        ClickUpError::ValidationError(format!(
+            "Invalid interval '{value}'. Use a number followed by s, m or h, e.g. 60s"
+        ))
+    })?;
+    let seconds = match unit {
+        "" | "s" => Some(number),
+        "m" => number.checked_mul(60),
+        "h" => number.checked_mul(3600),
+        _ => {
+            return Err(ClickUpError::ValidationError(format!(
+                "Invalid interval unit '{unit}'. Use s, m or h"
+            )))
+        }
+    }
+    .ok_or_else(|| ClickUpError::ValidationError(format!("Interval '{value}' is too long")))?;
+
+    let interval = Duration::from_secs(seconds);
+    if interval < MIN_INTERVAL {
+        return Err(ClickUpError::ValidationError(format!(
+            "Interval must be at least {}s",
+            MIN_INTERVAL.as_secs()
+        )));
+    }
+    Ok(interval)
+}
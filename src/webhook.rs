@@ -0,0 +1,290 @@
+//! # Webhook Receiver
+//!
+//! This module implements the local HTTP server behind `webhooks listen`. It
+//! accepts ClickUp webhook deliveries, verifies their signatures, and decodes
+//! them into typed `WebhookEvent`s.
+//!
+//! ## Signatures
+//!
+//! ClickUp signs each delivery with the webhook's secret and sends the
+//! hex-encoded HMAC-SHA256 of the request body in the `X-Signature` header.
+//! Deliveries with a missing or wrong signature are rejected with
+//! `401 Unauthorized` and never decoded.
+//!
+//! ## Forwarding
+//!
+//! `forward_to_command` runs a shell command for an event, with the raw
+//! payload on stdin and the event type and IDs in environment variables, for
+//! example to trigger a CI job when a task changes status.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clickup_cli::webhook::verify_signature;
+//!
+//! let body = b"The quick brown fox jumps over the lazy dog";
+//! let signature = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8";
+//! assert!(verify_signature("key", body, signature));
+//! assert!(!verify_signature("other", body, signature));
+//! ```
+
+use crate::error::ClickUpError;
+use crate::models::WebhookEvent;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::net::SocketAddr;
+use std::process::{ExitStatus, Stdio};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Header carrying the delivery signature
+pub const SIGNATURE_HEADER: &str = "x-signature";
+
+/// Largest request body accepted from a client
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// How long a client may take to send a request
+const REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Result of handling a single webhook request
+#[derive(Debug)]
+pub enum Delivery {
+    /// A delivery that passed verification and was decoded
+    Event {
+        /// The decoded event
+        event: WebhookEvent,
+        /// The raw JSON payload
+        payload: String,
+    },
+    /// A delivery that was rejected
+    Rejected {
+        /// Why the delivery was rejected
+        reason: String,
+    },
+}
+
+/// Local HTTP server that receives webhook deliveries
+pub struct WebhookReceiver {
+    /// The bound TCP listener
+    listener: TcpListener,
+    /// Secret used to verify signatures, or `None` to skip verification
+    secret: Option<String>,
+}
+
+impl WebhookReceiver {
+    /// Binds the receiver to an address
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The interface to listen on
+    /// * `port` - The port to listen on, or `0` for any free port
+    /// * `secret` - The webhook secret, or `None` to accept unsigned deliveries
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the address cannot be bound
+    pub async fn bind(host: &str, port: u16, secret: Option<String>) -> Result<Self, ClickUpError> {
+        let listener = TcpListener::bind((host, port)).await?;
+        Ok(Self { listener, secret })
+    }
+
+    /// Returns the address the receiver is bound to
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if the address cannot be determined
+    pub fn local_addr(&self) -> Result<SocketAddr, ClickUpError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Waits for the next webhook delivery
+    ///
+    /// Requests other than `POST` (such as health checks) are answered and
+    /// skipped. Each delivery is answered before this returns, so ClickUp
+    /// doesn't wait on any work done for the event.
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded event, or the reason the delivery was rejected.
+    ///
+    /// # Errors
+    ///
+    /// This function can return:
+    /// - `ClickUpError::IoError` if accepting or reading the connection fails
+    pub async fn next(&self) -> Result<Delivery, ClickUpError> {
+        loop {
+            let (stream, _) = self.listener.accept().await?;
+            // A client that stalls mid-request must not block later deliveries
+            match tokio::time::timeout(REQUEST_TIMEOUT, self.handle(stream)).await {
+                Ok(Ok(Some(delivery))) => return Ok(delivery),
+                Ok(Ok(None)) => continue,
+                Ok(Err(e)) => return Err(e),
+                Err(_) => log::warn!("Timed out reading webhook request"),
+            }
+        }
+    }
+
+    /// Reads one request and answers it
+    ///
+    /// Returns `None` for requests that aren't webhook deliveries.
+    async fn handle(&self, stream: TcpStream) -> Result<Option<Delivery>, ClickUpError> {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).await?;
+        let mut content_length = 0usize;
+        let mut signature = None;
+        let mut header = String::new();
+        while reader.read_line(&mut header).await? > 2 {
+            if let Some((name, value)) = header.split_once(':') {
+                let name = name.trim().to_ascii_lowercase();
+                let value = value.trim();
+                if name == "content-length" {
+                    content_length = value.parse().unwrap_or(0);
+                } else if name == SIGNATURE_HEADER {
+                    signature = Some(value.to_string());
+                }
+            }
+            header.clear();
+        }
+
+        let method = request_line.split_whitespace().next().unwrap_or_default();
+        if method != "POST" {
+            let status = if method == "GET" { "200 OK" } else { "405 Method Not Allowed" };
+            respond(reader.get_mut(), status, "clickup-cli webhook receiver").await?;
+            return Ok(None);
+        }
+        if content_length > MAX_BODY_BYTES {
+            respond(reader.get_mut(), "413 Payload Too Large", "Payload too large").await?;
+            return Ok(Some(Delivery::Rejected {
+                reason: format!("payload of {content_length} bytes is too large"),
+            }));
+        }
+
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body).await?;
+        let stream = reader.get_mut();
+
+        if let Some(secret) = &self.secret {
+            let reason = match &signature {
+                None => Some("missing X-Signature header"),
+                Some(signature) if !verify_signature(secret, &body, signature) => {
+                    Some("invalid signature")
+                }
+                Some(_) => None,
+            };
+            if let Some(reason) = reason {
+                respond(stream, "401 Unauthorized", reason).await?;
+                return Ok(Some(Delivery::Rejected {
+                    reason: reason.to_string(),
+                }));
+            }
+        }
+
+        let payload = String::from_utf8_lossy(&body).into_owned();
+        match serde_json::from_str::<WebhookEvent>(&payload) {
+            Ok(event) => {
+                respond(stream, "200 OK", "OK").await?;
+                Ok(Some(Delivery::Event { event, payload }))
+            }
+            Err(e) => {
+                respond(stream, "400 Bad Request", "Invalid payload").await?;
+                Ok(Some(Delivery::Rejected {
+                    reason: format!("invalid payload: {e}"),
+                }))
+            }
+        }
+    }
+}
+
+/// Writes a plain-text response
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) -> Result<(), ClickUpError> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Checks a delivery signature in constant time
+///
+/// # Arguments
+///
+/// * `secret` - The webhook secret
+/// * `body` - The raw request body
+/// * `signature` - The hex-encoded signature from `X-Signature`
+///
+/// # Returns
+///
+/// Returns `true` if the signature matches the body.
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let Ok(expected) = hex::decode(signature.trim()) else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Runs a shell command for a webhook event
+///
+/// The command runs through `sh -c` (`cmd /C` on Windows) with the raw JSON
+/// payload on stdin and these environment variables set:
+/// `CLICKUP_WEBHOOK_EVENT`, `CLICKUP_WEBHOOK_ID`, `CLICKUP_TASK_ID`,
+/// `CLICKUP_LIST_ID`, `CLICKUP_FOLDER_ID` and `CLICKUP_SPACE_ID` (empty when
+/// the event has no such ID).
+///
+/// # Arguments
+///
+/// * `command` - The shell command to run
+/// * `event` - The decoded event
+/// * `payload` - The raw JSON payload
+///
+/// # Returns
+///
+/// Returns the command's exit status.
+///
+/// # Errors
+///
+/// This function can return:
+/// - `ClickUpError::IoError` if the command cannot be started
+pub async fn forward_to_command(
+    command: &str,
+    event: &WebhookEvent,
+    payload: &str,
+) -> Result<ExitStatus, ClickUpError> {
+    let mut process = if cfg!(windows) {
+        let mut process = tokio::process::Command::new("cmd");
+        process.arg("/C");
+        process
+    } else {
+        let mut process = tokio::process::Command::new("sh");
+        process.arg("-c");
+        process
+    };
+    let id = |id: &Option<String>| id.clone().unwrap_or_default();
+    let mut child = process
+        .arg(command)
+        .env("CLICKUP_WEBHOOK_EVENT", event.event.as_str())
+        .env("CLICKUP_WEBHOOK_ID", id(&event.webhook_id))
+        .env("CLICKUP_TASK_ID", id(&event.task_id))
+        .env("CLICKUP_LIST_ID", id(&event.list_id))
+        .env("CLICKUP_FOLDER_ID", id(&event.folder_id))
+        .env("CLICKUP_SPACE_ID", id(&event.space_id))
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that doesn't read stdin closes the pipe early; that's fine
+        let _ = stdin.write_all(payload.as_bytes()).await;
+    }
+    Ok(child.wait().await?)
+}
@@ -16,21 +16,19 @@
 //! Tests use a temporary configuration directory to avoid interfering with
 //! the user's actual configuration files.
 
-use clickup_cli::api::ClickUpApi;
 use clickup_cli::config::{Config, RateLimitConfig};
 mod test_utils;
-use test_utils::{TestApiUtils, TestConfig};
+use test_utils::TestApiUtils;
 use std::sync::Once;
 use tempfile::TempDir;
 use std::cell::RefCell;
-use dotenvy;
 
 /// Global test initialization state
 static INIT: Once = Once::new();
 
 // Thread-local storage for test configuration
 thread_local! {
-    static TEST_CONFIG: RefCell<Option<TempDir>> = RefCell::new(None);
+    static TEST_CONFIG: RefCell<Option<TempDir>> = const { RefCell::new(None) };
 }
 
 /// Sets up the test environment with a temporary configuration directory
@@ -96,6 +94,7 @@ fn test_api_client_creation_without_token() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -133,6 +132,7 @@ fn test_api_client_with_oauth_token() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -152,6 +152,7 @@ fn test_api_client_with_custom_base_url() {
         default_list_id: None,
         api_base_url: "https://custom.api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -171,6 +172,7 @@ fn test_api_client_creation_failure() {
         default_list_id: None,
         api_base_url: "invalid-url".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -190,6 +192,7 @@ fn test_api_client_config_clone() {
         default_list_id: Some("list_456".to_string()),
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api1 = TestApiUtils::create_test_client(&config);
@@ -213,6 +216,7 @@ fn test_api_client_with_empty_token() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -232,6 +236,7 @@ fn test_api_client_with_whitespace_token() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -252,6 +257,7 @@ fn test_api_client_multiple_instances() {
         default_list_id: Some("list1".to_string()),
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let config2 = Config {
@@ -260,6 +266,7 @@ fn test_api_client_multiple_instances() {
         default_list_id: Some("list2".to_string()),
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api1 = TestApiUtils::create_test_client(&config1);
@@ -283,6 +290,7 @@ fn test_api_client_with_none_values() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -304,6 +312,7 @@ fn test_api_client_with_long_token() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -325,6 +334,7 @@ fn test_api_client_with_special_characters_in_token() {
         default_list_id: None,
         api_base_url: "https://api.clickup.com/api/v2".to_string(),
         rate_limit: RateLimitConfig::default(),
+        ..Default::default()
     };
 
     let api = TestApiUtils::create_test_client(&config);
@@ -381,9 +391,11 @@ fn test_update_overdue_by_tag_functionality() {
         start_date: None,
         time_estimate: None,
         time_spent: None,
+        points: None,
         custom_fields: vec![],
         dependencies: vec![],
         linked_tasks: vec![],
+        attachments: vec![],
         team_id: "team_1".to_string(),
         list: TaskList {
             id: "list_1".to_string(),
@@ -465,9 +477,11 @@ fn test_update_overdue_by_tag_time_preservation() {
         start_date: None,
         time_estimate: None,
         time_spent: None,
+        points: None,
         custom_fields: vec![],
         dependencies: vec![],
         linked_tasks: vec![],
+        attachments: vec![],
         team_id: "team_1".to_string(),
         list: TaskList {
             id: "list_1".to_string(),
@@ -527,3 +541,58 @@ fn test_update_overdue_by_tag_time_preservation() {
 
     println!("✓ Test time preservation logic works correctly");
 }
+
+/// Tests that dry-run mode intercepts mutating requests
+///
+/// This test verifies that create and delete calls succeed with stand-in
+/// responses without contacting the API. The base URL
+/// points at a closed local port, so any request that slipped through would
+/// fail with a network error.
+#[tokio::test]
+async fn test_api_dry_run_intercepts_mutating_requests() {
+    setup_test_env();
+    let config = Config {
+        api_token: Some("pk_test_token".to_string()),
+        api_base_url: "http://127.0.0.1:9".to_string(),
+        dry_run: true,
+        ..Default::default()
+    };
+    let api = TestApiUtils::create_test_client(&config).unwrap();
+
+    let task_data = clickup_cli::models::CreateTaskRequest {
+        name: "Dry run task".to_string(),
+        ..Default::default()
+    };
+    let task = api.create_task("list_123", task_data).await.unwrap();
+    assert_eq!(task.name.as_deref(), Some("Dry run task"));
+    assert_eq!(task.list.id, "list_123");
+
+    api.delete_task("task_123").await.unwrap();
+
+    // Each stand-in gets its own placeholder ID
+    let other = api
+        .create_task("list_123", clickup_cli::models::CreateTaskRequest::default())
+        .await
+        .unwrap();
+    assert_ne!(task.id, other.id);
+}
+
+/// Tests that dry-run mode still sends GET requests
+///
+/// This test verifies that read requests are not intercepted, so they fail
+/// with a network error against the closed local port instead.
+#[tokio::test]
+async fn test_api_dry_run_allows_get_requests() {
+    setup_test_env();
+    let config = Config {
+        api_token: Some("pk_test_token".to_string()),
+        api_base_url: "http://127.0.0.1:9".to_string(),
+        dry_run: true,
+        ..Default::default()
+    };
+    let api = TestApiUtils::create_test_client(&config).unwrap();
+
+    let result = api.get_task("task_123").await;
+    assert!(result.is_err());
+    assert!(!matches!(result, Err(clickup_cli::error::ClickUpError::DryRun(_))));
+}
@@ -0,0 +1,262 @@
+//! # Attachment Tests
+//!
+//! This module contains tests for attachment models and the `tasks attach`
+//! and `tasks attachments download` commands.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for decoding attachments and choosing local file names
+//! - **Upload**: Tests for multipart uploads, file validation and dry-run mode
+//! - **Download**: Tests for saving attachments into a directory
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that serves a
+//! task with attachments, the attachment files and the upload endpoint, and
+//! records the requests it receives.
+
+use clickup_cli::commands::tasks::{execute, AttachmentCommands, TaskCommands};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{Attachment, Task};
+mod test_utils;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use test_utils::{server_config, task_json, MockResponse, MockServer, TestConfig};
+
+/// Returns an attachment with the given ID and title
+fn attachment(id: &str, title: Option<&str>, extension: Option<&str>) -> Attachment {
+    Attachment {
+        id: id.to_string(),
+        title: title.map(str::to_string),
+        extension: extension.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+/// Tests decoding attachments on a task and from an upload response
+#[test]
+fn test_decode_attachments() {
+    let attachment: Attachment = serde_json::from_str(
+        r#"{
+            "id": "a1b2-3c4d", "version": "0", "date": 1700000000000, "title": "build.zip",
+            "extension": "zip", "mimetype": "application/zip", "size": "2048",
+            "thumbnail_small": null, "thumbnail_large": null,
+            "url": "https://t.clickup-attachments.com/a1b2/build.zip"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(attachment.date.as_deref(), Some("1700000000000"));
+    assert_eq!(attachment.size, Some(2048));
+
+    let uploaded: Attachment =
+        serde_json::from_str(r#"{"id": "e5f6", "title": "notes.txt", "url": "https://x/notes.txt"}"#)
+            .unwrap();
+    assert_eq!(uploaded.size, None);
+    assert_eq!(uploaded.file_name(), "notes.txt");
+}
+
+/// Tests that attachment titles are turned into safe file names
+#[test]
+fn test_attachment_file_name() {
+    assert_eq!(attachment("1", Some("report.pdf"), None).file_name(), "report.pdf");
+    assert_eq!(attachment("1", Some("../../etc/passwd"), None).file_name(), "_.._etc_passwd");
+    assert_eq!(attachment("1", Some("a\\b:c\n.txt"), None).file_name(), "a_b_c_.txt");
+    assert_eq!(attachment("1", Some("..."), Some("png")).file_name(), "1.png");
+    assert_eq!(attachment("1", Some("  "), None).file_name(), "1");
+    assert_eq!(attachment("1", None, Some("")).file_name(), "1");
+}
+
+/// Tests that attachments sharing a name get distinct file names
+#[test]
+fn test_unique_file_names() {
+    let attachments = vec![
+        attachment("a", Some("build.zip"), None),
+        attachment("b", Some("Build.ZIP"), None),
+        attachment("c", Some("notes.txt"), None),
+        attachment("d", Some("README"), None),
+        attachment("e", Some("README"), None),
+    ];
+
+    assert_eq!(
+        Attachment::unique_file_names(&attachments),
+        vec!["build.zip", "Build-b.ZIP", "notes.txt", "README", "README-e"]
+    );
+}
+
+/// Starts a stand-in server for task `task_1`
+///
+/// The task has three attachments served from `/files/`, the second of which
+/// has the same name as the first. `/files/missing.txt` returns 404 and
+/// uploads return a new attachment. Every request is recorded.
+async fn spawn_attachment_server() -> MockServer {
+    MockServer::start(|request| {
+        let files = &format!("http://{}", request.header("host").unwrap());
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/task/task_1") => attachment_task(files).into(),
+            ("POST", "/task/task_1/attachment") => MockResponse::from(json!({
+                "id": "new_1", "title": "upload", "url": format!("{files}/files/new")
+            })),
+            ("GET", "/files/build.zip") => MockResponse::ok("first build"),
+            ("GET", "/files/build-2.zip") => MockResponse::ok("second build"),
+            _ => MockResponse::new("404 Not Found", ""),
+        }
+    })
+    .await
+}
+
+/// Returns task `task_1` with attachments served from `files`
+fn attachment_task(files: &str) -> Value {
+    let mut task = task_json("task_1");
+    task["attachments"] = json!([
+        {"id": "att_1", "title": "build.zip", "size": 11, "url": format!("{files}/files/build.zip")},
+        {"id": "att_2", "title": "build.zip", "size": 12, "url": format!("{files}/files/build-2.zip")},
+        {"id": "att_3", "title": "notes.txt", "url": format!("{files}/files/missing.txt")}
+    ]);
+    // Make sure the fixture stays a valid task
+    serde_json::from_value::<Task>(task.clone()).unwrap();
+    task
+}
+
+/// Tests uploading files as multipart form data with the API token
+///
+/// The base URL is given with a trailing slash, which must not end up in the
+/// upload path.
+#[tokio::test]
+async fn test_attach_uploads_multipart() {
+    let _test_config = TestConfig::new();
+    let server = spawn_attachment_server().await;
+    let temp_dir = TempDir::new().unwrap();
+    let first = temp_dir.path().join("app-2.4.0.tar.gz");
+    let second = temp_dir.path().join("CHANGELOG.md");
+    std::fs::write(&first, b"binary artifact").unwrap();
+    std::fs::write(&second, b"# 2.4.0").unwrap();
+
+    execute(
+        TaskCommands::Attach {
+            id: "task_1".to_string(),
+            files: vec![first, second],
+        },
+        &server_config(&format!("{}/", server.url())),
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    for request in &requests {
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/task/task_1/attachment");
+        assert_eq!(request.header("authorization"), Some("pk_test"));
+        assert!(request
+            .header("content-type")
+            .unwrap()
+            .starts_with("multipart/form-data; boundary="));
+    }
+    assert!(requests[0]
+        .body
+        .contains(r#"name="attachment"; filename="app-2.4.0.tar.gz""#));
+    assert!(requests[0].body.contains("binary artifact"));
+    assert!(requests[1].body.contains(r#"filename="CHANGELOG.md""#));
+}
+
+/// Tests that a missing file is rejected before anything is uploaded
+#[tokio::test]
+async fn test_attach_missing_file() {
+    let _test_config = TestConfig::new();
+    let server = spawn_attachment_server().await;
+    let temp_dir = TempDir::new().unwrap();
+    let existing = temp_dir.path().join("present.txt");
+    std::fs::write(&existing, b"here").unwrap();
+
+    let result = execute(
+        TaskCommands::Attach {
+            id: "task_1".to_string(),
+            files: vec![existing, temp_dir.path().join("absent.txt"), temp_dir.path().into()],
+        },
+        &server_config(&server.url()),
+    )
+    .await;
+
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+    assert!(server.requests().is_empty());
+}
+
+/// Tests that uploads are printed but not sent in dry-run mode
+#[tokio::test]
+async fn test_attach_dry_run() {
+    let _test_config = TestConfig::new();
+    let server = spawn_attachment_server().await;
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("artifact.bin");
+    std::fs::write(&file, b"data").unwrap();
+
+    let result = execute(
+        TaskCommands::Attach {
+            id: "task_1".to_string(),
+            files: vec![file],
+        },
+        &Config {
+            dry_run: true,
+            ..server_config(&server.url())
+        },
+    )
+    .await;
+
+    assert!(result.is_ok());
+    assert!(server.requests().is_empty());
+}
+
+/// Tests downloading all attachments of a task
+///
+/// This test verifies that files sharing a name are both kept, and that a
+/// failed download is reported after the other files are saved.
+#[tokio::test]
+async fn test_download_attachments() {
+    let _test_config = TestConfig::new();
+    let server = spawn_attachment_server().await;
+    let temp_dir = TempDir::new().unwrap();
+    let out = temp_dir.path().join("artifacts");
+
+    let result = execute(
+        TaskCommands::Attachments {
+            command: AttachmentCommands::Download {
+                id: "task_1".to_string(),
+                out: out.clone(),
+                attachment: None,
+            },
+        },
+        &server_config(&server.url()),
+    )
+    .await;
+
+    assert!(matches!(result, Err(ClickUpError::ApiError(_))));
+    assert_eq!(std::fs::read_to_string(out.join("build.zip")).unwrap(), "first build");
+    assert_eq!(std::fs::read_to_string(out.join("build-att_2.zip")).unwrap(), "second build");
+    assert!(!out.join("notes.txt").exists());
+}
+
+/// Tests downloading a single attachment by ID or file name
+#[tokio::test]
+async fn test_download_single_attachment() {
+    let _test_config = TestConfig::new();
+    let server = spawn_attachment_server().await;
+    let temp_dir = TempDir::new().unwrap();
+    let download = |attachment: &str| TaskCommands::Attachments {
+        command: AttachmentCommands::Download {
+            id: "task_1".to_string(),
+            out: temp_dir.path().to_path_buf(),
+            attachment: Some(attachment.to_string()),
+        },
+    };
+
+    execute(download("att_2"), &server_config(&server.url())).await.unwrap();
+    assert!(temp_dir.path().join("build-att_2.zip").exists());
+    assert!(!temp_dir.path().join("build.zip").exists());
+
+    execute(download("BUILD.zip"), &server_config(&server.url())).await.unwrap();
+    assert!(temp_dir.path().join("build.zip").exists());
+
+    let result = execute(download("changelog.md"), &server_config(&server.url())).await;
+    assert!(matches!(result, Err(ClickUpError::NotFoundError(_))));
+}
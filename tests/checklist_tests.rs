@@ -0,0 +1,278 @@
+//! # Checklist Tests
+//!
+//! This module contains tests for checklist models and the
+//! `tasks checklist` commands.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for decoding checklists and encoding item changes
+//! - **References**: Tests for finding checklists and items by ID, number, or name
+//! - **Markdown**: Tests for parsing Markdown task lists
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that serves one
+//! task with a checklist and records the requests it receives.
+
+use clickup_cli::commands::checklists::{
+    execute, find_checklist, find_item, parse_markdown_checklist, ChecklistCommands,
+    ChecklistItemCommands, MarkdownChecklist,
+};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{ChecklistResponse, EditChecklistItemRequest, TaskChecklist};
+mod test_utils;
+use serde_json::{json, Value};
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// A checklist as returned by the API, with its items under `items`
+const CHECKLIST: &str = r#"{
+    "id": "cl_qa",
+    "task_id": "task_1",
+    "name": "QA sign-off",
+    "date_created": "1700000000000",
+    "orderindex": 0,
+    "creator": 183,
+    "resolved": 1,
+    "unresolved": 2,
+    "items": [
+        {"id": "it_1", "name": "Smoke test", "orderindex": 0, "assignee": {"id": 183, "username": "qa"},
+         "resolved": true, "parent": null, "date_created": "1700000000001", "children": []},
+        {"id": "it_2", "name": "Regression suite", "orderindex": 1, "assignee": null,
+         "resolved": false, "parent": null, "date_created": "1700000000002", "children": []},
+        {"id": "it_3", "name": "Release notes", "orderindex": 2, "assignee": null,
+         "resolved": false, "parent": null, "date_created": "1700000000003", "children": []}
+    ]
+}"#;
+
+/// Returns checklists with the given IDs and names and no items
+fn checklists(entries: &[(&str, &str)]) -> Vec<TaskChecklist> {
+    entries
+        .iter()
+        .map(|(id, name)| {
+            serde_json::from_str(&format!(
+                r#"{{"id": "{id}", "name": "{name}", "orderindex": 0, "resolved": 0, "date_created": "1"}}"#
+            ))
+            .unwrap()
+        })
+        .collect()
+}
+
+/// Tests decoding a checklist with items
+#[test]
+fn test_decode_checklist_items() {
+    let checklist: TaskChecklist = serde_json::from_str(CHECKLIST).unwrap();
+
+    assert_eq!(checklist.items().len(), 3);
+    assert_eq!(checklist.items()[0].resolved, Some(true));
+    assert_eq!(checklist.items()[0].assignee.as_ref().unwrap()["username"], "qa");
+
+    let empty = &checklists(&[("cl", "Empty")])[0];
+    assert!(empty.items().is_empty());
+}
+
+/// Tests that item changes only send the fields being changed
+#[test]
+fn test_edit_item_request() {
+    let resolve = EditChecklistItemRequest {
+        resolved: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(resolve).unwrap(),
+        serde_json::json!({"resolved": true})
+    );
+
+    let unassign = EditChecklistItemRequest {
+        assignee: Some(None),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(unassign).unwrap(),
+        serde_json::json!({"assignee": null})
+    );
+}
+
+/// Tests finding checklists by ID, number, and name
+#[test]
+fn test_find_checklist() {
+    let lists = checklists(&[("cl_1", "QA sign-off"), ("cl_2", "Deploy"), ("cl_3", "deploy")]);
+
+    assert_eq!(find_checklist(&lists, Some("cl_2")).unwrap().id, "cl_2");
+    assert_eq!(find_checklist(&lists, Some("1")).unwrap().id, "cl_1");
+    assert_eq!(find_checklist(&lists, Some("qa SIGN-OFF")).unwrap().id, "cl_1");
+
+    assert!(matches!(
+        find_checklist(&lists, Some("deploy")),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        find_checklist(&lists, Some("4")),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+    assert!(matches!(
+        find_checklist(&lists, Some("0")),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+    assert!(matches!(
+        find_checklist(&lists, Some("Launch")),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Tests choosing a checklist when no reference is given
+#[test]
+fn test_find_checklist_default() {
+    let single = checklists(&[("cl_1", "Only")]);
+    assert_eq!(find_checklist(&single, None).unwrap().id, "cl_1");
+
+    let several = checklists(&[("cl_1", "One"), ("cl_2", "Two")]);
+    assert!(matches!(
+        find_checklist(&several, None),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        find_checklist(&[], None),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Tests finding items by ID, number, and name
+#[test]
+fn test_find_item() {
+    let checklist: TaskChecklist = serde_json::from_str(CHECKLIST).unwrap();
+
+    assert_eq!(find_item(&checklist, "it_3").unwrap().id, "it_3");
+    assert_eq!(find_item(&checklist, "2").unwrap().id, "it_2");
+    assert_eq!(find_item(&checklist, " smoke test ").unwrap().id, "it_1");
+    assert!(matches!(
+        find_item(&checklist, "Deploy"),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Tests parsing a Markdown task list
+///
+/// This test verifies checkbox states, bullet styles, nesting, the heading
+/// used as a title, and that other lines are ignored.
+#[test]
+fn test_parse_markdown_checklist() {
+    let markdown = "\
+# Release 2.4 QA
+
+Run these before sign-off:
+
+- [ ] Smoke test
+- [x] Unit tests
+* [X] Lint
++ Update changelog
+  - [ ] Nested step
+- [ ]
+1. Numbered lines are not items
+## Later heading
+";
+
+    assert_eq!(
+        parse_markdown_checklist(markdown),
+        MarkdownChecklist {
+            title: Some("Release 2.4 QA".to_string()),
+            items: vec![
+                ("Smoke test".to_string(), false),
+                ("Unit tests".to_string(), true),
+                ("Lint".to_string(), true),
+                ("Update changelog".to_string(), false),
+                ("Nested step".to_string(), false),
+            ],
+        }
+    );
+    assert_eq!(parse_markdown_checklist("no list here"), MarkdownChecklist::default());
+}
+
+/// Starts a stand-in server for one task with the QA checklist
+///
+/// `GET /task/task_1` returns the task and `PUT` requests to checklist items
+/// return the checklist. Every request is recorded.
+async fn spawn_checklist_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.method == "GET" && request.path == "/task/task_1" {
+            let mut task = task_json("task_1");
+            task["checklists"] = json!([serde_json::from_str::<Value>(CHECKLIST).unwrap()]);
+            task
+        } else {
+            json!({"checklist": serde_json::from_str::<Value>(CHECKLIST).unwrap()})
+        }
+    })
+    .await
+}
+
+/// Tests resolving and unassigning items referred to by name and number
+#[tokio::test]
+async fn test_edit_items_by_reference() {
+    let _test_config = TestConfig::new();
+    let server = spawn_checklist_server().await;
+    let config = server_config(&server.url());
+
+    execute(
+        ChecklistCommands::Item {
+            command: ChecklistItemCommands::Resolve {
+                task_id: "task_1".to_string(),
+                checklist: None,
+                item: "regression suite".to_string(),
+            },
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    execute(
+        ChecklistCommands::Item {
+            command: ChecklistItemCommands::Assign {
+                task_id: "task_1".to_string(),
+                checklist: Some("QA sign-off".to_string()),
+                item: "1".to_string(),
+                assignee: None,
+                unassign: true,
+            },
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let edits = server.changes();
+    assert_eq!(edits.len(), 2);
+    assert_eq!(edits[0].1, "/checklist/cl_qa/checklist_item/it_2");
+    assert_eq!(edits[0].2, r#"{"resolved":true}"#);
+    assert_eq!(edits[1].1, "/checklist/cl_qa/checklist_item/it_1");
+    assert_eq!(edits[1].2, r#"{"assignee":null}"#);
+}
+
+/// Tests that the checklist response decodes for item changes
+#[test]
+fn test_decode_checklist_response() {
+    let response: ChecklistResponse =
+        serde_json::from_str(&format!(r#"{{"checklist": {CHECKLIST}}}"#)).unwrap();
+    assert_eq!(response.checklist.id, "cl_qa");
+}
+
+/// Tests that creating a checklist needs a name
+#[tokio::test]
+async fn test_create_checklist_requires_name() {
+    let _test_config = TestConfig::new();
+    let config = Config {
+        api_token: Some("pk_test".to_string()),
+        ..Default::default()
+    };
+
+    let result = execute(
+        ChecklistCommands::Create {
+            task_id: "task_1".to_string(),
+            name: None,
+            from_markdown: false,
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
@@ -21,7 +21,6 @@
 //! ```
 
 use assert_cmd::prelude::*;
-use chrono;
 use std::env;
 use std::process::Command;
 use std::process::Stdio;
@@ -159,7 +158,7 @@ fn get_or_discover_workspace_id() -> Option<String> {
             && line.chars().filter(|&c| c == '|').count() > 2
         {
             let parts: Vec<_> = line.split('|').map(|s| s.trim()).collect();
-            if parts.len() > 1 && parts[1].chars().all(|c| c.is_digit(10)) {
+            if parts.len() > 1 && parts[1].chars().all(|c| c.is_ascii_digit()) {
                 return Some(parts[1].to_string());
             }
         }
@@ -177,7 +176,7 @@ fn get_or_discover_space_id(workspace_id: &str) -> Option<String> {
             && line.chars().filter(|&c| c == '|').count() > 2
         {
             let parts: Vec<_> = line.split('|').map(|s| s.trim()).collect();
-            if parts.len() > 1 && parts[1].chars().all(|c| c.is_digit(10)) {
+            if parts.len() > 1 && parts[1].chars().all(|c| c.is_ascii_digit()) {
                 return Some(parts[1].to_string());
             }
         }
@@ -202,7 +201,7 @@ fn get_or_discover_test_list_id(space_id: &str) -> Option<(String, bool)> {
             && line.chars().filter(|&c| c == '|').count() > 2
         {
             let parts: Vec<_> = line.split('|').map(|s| s.trim()).collect();
-            if parts.len() > 1 && parts[1].chars().all(|c| c.is_digit(10)) {
+            if parts.len() > 1 && parts[1].chars().all(|c| c.is_ascii_digit()) {
                 return Some((parts[1].to_string(), false)); // false = not created by test
             }
         }
@@ -269,7 +268,7 @@ fn test_authentication() {
         "Authentication failed: {:?}",
         String::from_utf8_lossy(&output.stderr)
     );
-    assert!(output.stdout.len() > 0, "No output from workspaces list");
+    assert!(!output.stdout.is_empty(), "No output from workspaces list");
 
     // Verify the output contains workspace information
     let output_str = String::from_utf8_lossy(&output.stdout);
@@ -302,7 +301,7 @@ fn test_workspace_team_listing() {
         "Workspace listing failed: {:?}",
         String::from_utf8_lossy(&output.stderr)
     );
-    assert!(output.stdout.len() > 0, "No output from workspaces list");
+    assert!(!output.stdout.is_empty(), "No output from workspaces list");
 
     // Test listing teams (if any exist)
     let output = run_cli_with_test_env(&["teams", "list"]);
@@ -715,7 +714,7 @@ fn test_error_handling() {
         !output.status.success(),
         "Expected failure for non-existent task"
     );
-    assert!(output.stderr.len() > 0, "Should have error output");
+    assert!(!output.stderr.is_empty(), "Should have error output");
 
     // Test 2: Try to create a task with invalid list ID
     let mut cmd = Command::cargo_bin("clickup-cli").unwrap();
@@ -735,7 +734,7 @@ fn test_error_handling() {
         !output.status.success(),
         "Expected failure for invalid list ID"
     );
-    assert!(output.stderr.len() > 0, "Should have error output");
+    assert!(!output.stderr.is_empty(), "Should have error output");
 
     // Test 3: Try to show a non-existent list
     let mut cmd = Command::cargo_bin("clickup-cli").unwrap();
@@ -748,7 +747,7 @@ fn test_error_handling() {
         !output.status.success(),
         "Expected failure for non-existent list"
     );
-    assert!(output.stderr.len() > 0, "Should have error output");
+    assert!(!output.stderr.is_empty(), "Should have error output");
 
     // Test 4: Try to add a comment to a non-existent task
     let mut cmd = Command::cargo_bin("clickup-cli").unwrap();
@@ -768,7 +767,7 @@ fn test_error_handling() {
         !output.status.success(),
         "Expected failure for non-existent task in comment creation"
     );
-    assert!(output.stderr.len() > 0, "Should have error output");
+    assert!(!output.stderr.is_empty(), "Should have error output");
 
     // Test 5: Try to update a non-existent comment
     let mut cmd = Command::cargo_bin("clickup-cli").unwrap();
@@ -788,7 +787,7 @@ fn test_error_handling() {
         !output.status.success(),
         "Expected failure for non-existent comment"
     );
-    assert!(output.stderr.len() > 0, "Should have error output");
+    assert!(!output.stderr.is_empty(), "Should have error output");
 }
 
 /// Tests rate limiting handling
@@ -922,7 +921,7 @@ fn test_invalid_authentication() {
         !output.status.success(),
         "Expected failure with invalid token"
     );
-    assert!(output.stderr.len() > 0, "Should have error output");
+    assert!(!output.stderr.is_empty(), "Should have error output");
 
     // Restore original token if it existed
     if let Some(token) = original_token {
@@ -944,7 +943,7 @@ fn test_cli_basic_commands() {
 
     let output = cmd.output().unwrap();
     assert!(output.status.success(), "Help command failed");
-    assert!(output.stdout.len() > 0, "Help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Help output should not be empty");
 
     // Test version command
     let mut cmd = Command::cargo_bin("clickup-cli").unwrap();
@@ -953,7 +952,7 @@ fn test_cli_basic_commands() {
     let output = cmd.output().unwrap();
     assert!(output.status.success(), "Version command failed");
     assert!(
-        output.stdout.len() > 0,
+        !output.stdout.is_empty(),
         "Version output should not be empty"
     );
 
@@ -963,5 +962,5 @@ fn test_cli_basic_commands() {
 
     let output = cmd.output().unwrap();
     assert!(!output.status.success(), "Invalid command should fail");
-    assert!(output.stderr.len() > 0, "Error output should not be empty");
+    assert!(!output.stderr.is_empty(), "Error output should not be empty");
 }
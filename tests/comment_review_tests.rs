@@ -0,0 +1,164 @@
+//! # Comment Review Tests
+//!
+//! This module contains tests for resolving comments and for the inbox of
+//! unresolved comments assigned to the current user.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for reading a comment's assignee
+//! - **Inbox**: Tests for choosing and listing assigned comments
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with a fixed user, two tasks and their
+//! comments.
+
+use clickup_cli::commands::comments::{execute, inbox_comments, CommentCommands};
+use clickup_cli::journal::Journal;
+use clickup_cli::models::{Comment, CommentTarget};
+use serde_json::json;
+mod test_utils;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns an open task with the given ID and name
+fn named_task(id: &str, name: &str) -> serde_json::Value {
+    let mut task = task_json(id);
+    task["name"] = json!(name);
+    task["status"]["status"] = json!("open");
+    task
+}
+
+/// Returns a comment with the given assignee and resolved state
+fn comment_json(id: &str, assignee: serde_json::Value, resolved: bool) -> serde_json::Value {
+    json!({
+        "id": id, "comment": [{"text": format!("Comment {id}")}],
+        "comment_text": format!("Comment {id}"),
+        "user": {"id": 1, "username": "owner"},
+        "assignee": assignee, "resolved": resolved,
+        "reactions": [], "date": "1700000000000", "reply_count": 0
+    })
+}
+
+/// Returns a comment decoded from `comment_json`
+fn comment(id: &str, assignee: serde_json::Value, resolved: bool) -> Comment {
+    serde_json::from_value(comment_json(id, assignee, resolved)).unwrap()
+}
+
+/// Tests reading an assignee ID given as a number or a string
+#[test]
+fn test_comment_assignee_id() {
+    assert_eq!(comment("c1", json!({"id": 7, "username": "me"}), false).assignee_id(), Some(7));
+    assert_eq!(comment("c2", json!({"id": "7"}), false).assignee_id(), Some(7));
+    assert_eq!(comment("c3", json!(null), false).assignee_id(), None);
+}
+
+/// Tests keeping only unresolved comments assigned to the user
+#[test]
+fn test_inbox_comments() {
+    let comments = vec![
+        comment("c1", json!({"id": 7}), false),
+        comment("c2", json!({"id": 7}), true),
+        comment("c3", json!({"id": 8}), false),
+        comment("c4", json!(null), false),
+        comment("c5", json!({"id": "7"}), false),
+    ];
+    let ids: Vec<String> = inbox_comments(comments, 7)
+        .into_iter()
+        .map(|comment| comment.id)
+        .collect();
+    assert_eq!(ids, vec!["c1", "c5"]);
+}
+
+/// Starts a stand-in server for inbox and resolve requests
+async fn spawn_review_server() -> MockServer {
+    MockServer::start(|request| {
+        let path = request.path.as_str();
+        if path == "/user" {
+            json!({"user": {"id": 7, "username": "me", "email": "me@acme.test"}})
+        } else if path.starts_with("/team/9/task") {
+            json!({"tasks": [named_task("t1", "Launch"), named_task("t2", "Docs")], "last_page": true})
+        } else if path == "/task/t1/comment" {
+            json!({"comments": [
+                comment_json("c1", json!({"id": 7, "username": "me"}), false),
+                comment_json("c2", json!({"id": 7, "username": "me"}), true)
+            ]})
+        } else if path == "/task/t2/comment" {
+            json!({"comments": [comment_json("c3", json!({"id": 8}), false)]})
+        } else {
+            json!({})
+        }
+    })
+    .await
+}
+
+/// Tests that the inbox checks every task in the space for assigned comments
+#[tokio::test]
+async fn test_inbox_checks_space_tasks() {
+    let _test_config = TestConfig::new();
+    let server = spawn_review_server().await;
+
+    execute(
+        CommentCommands::Inbox {
+            workspace_id: None,
+            space_id: Some("77".to_string()),
+            include_closed: false,
+        },
+        &server_config(&server.url()),
+    )
+    .await
+    .unwrap();
+
+    let paths = server.paths();
+    assert_eq!(paths[0], "/user");
+    assert!(paths[1].starts_with("/team/9/task?"));
+    assert!(paths[1].contains("space_ids[]=77"));
+    assert_eq!(&paths[2..], ["/task/t1/comment", "/task/t2/comment"]);
+}
+
+/// Tests that resolving and reopening send only the resolved state
+///
+/// The comment is read from its task's thread and journaled from there,
+/// without searching the workspace for it.
+#[tokio::test]
+async fn test_resolve_and_unresolve() {
+    let _test_config = TestConfig::new();
+    let server = spawn_review_server().await;
+    let config = server_config(&server.url());
+
+    let resolve = CommentCommands::Resolve {
+        id: "c1".to_string(),
+        task_id: Some("t1".to_string()),
+        list_id: None,
+        view_id: None,
+    };
+    execute(resolve, &config).await.unwrap();
+    let unresolve = CommentCommands::Unresolve {
+        id: "c1".to_string(),
+        task_id: Some("t1".to_string()),
+        list_id: None,
+        view_id: None,
+    };
+    execute(unresolve, &config).await.unwrap();
+
+    let changes = server.changes();
+    let updates: Vec<(&str, &str)> = changes
+        .iter()
+        .map(|(_, path, body)| (path.as_str(), body.as_str()))
+        .collect();
+    assert_eq!(
+        updates,
+        vec![
+            ("/comment/c1", r#"{"resolved":true}"#),
+            ("/comment/c1", r#"{"resolved":false}"#),
+        ]
+    );
+    assert!(server.paths().iter().all(|path| path != "/team"));
+
+    let entries = Journal::default_location().unwrap().entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].parent_id.as_deref(), Some("t1"));
+    assert_eq!(entries[0].parent_kind, Some(CommentTarget::Task));
+    assert_eq!(entries[0].previous.as_ref().unwrap()["id"], "c1");
+}
@@ -6,6 +6,7 @@
 //! ## Test Categories
 //!
 //! - **Comment Listing**: Tests for listing comments on a task
+//! - **Comment Targets**: Tests for choosing a task, list or view thread
 //! - **Comment Creation**: Tests for creating new comments
 //! - **Comment Updates**: Tests for updating existing comments
 //! - **Comment Deletion**: Tests for deleting comments
@@ -17,13 +18,15 @@
 //! Tests use temporary directories and isolated environment variables to avoid
 //! interfering with the user's actual configuration files.
 
-use clickup_cli::commands::comments::{execute, CommentCommands};
+use clickup_cli::commands::comments::{comment_target, execute, CommentCommands};
+use clickup_cli::commands::undo::{self, UndoArgs};
 use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::journal::{Journal, JournalOperation};
+use clickup_cli::models::CommentTarget;
+use serde_json::json;
 mod test_utils;
-use test_utils::TestConfig;
-use std::env;
-use std::fs;
-use tempfile::TempDir;
+use test_utils::{server_config, MockServer, TestConfig};
 
 
 
@@ -40,7 +43,7 @@ async fn test_comment_list_no_auth() {
     assert!(!config.is_authenticated());
     
     // Execute the List command
-    let result = execute(CommentCommands::List { task_id: "task_123".to_string() }, &config).await;
+    let result = execute(CommentCommands::List { task_id: Some("task_123".to_string()), list_id: None, view_id: None }, &config).await;
     assert!(result.is_err());
 }
 
@@ -60,7 +63,7 @@ async fn test_comment_list_with_auth() {
     
     // Execute the List command
     // Note: This will likely fail due to invalid token, but the command structure is valid
-    let _result = execute(CommentCommands::List { task_id: "task_123".to_string() }, &config).await;
+    let _result = execute(CommentCommands::List { task_id: Some("task_123".to_string()), list_id: None, view_id: None }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
 
@@ -78,7 +81,7 @@ async fn test_comment_list_empty_task_id() {
     assert!(config.is_authenticated());
     
     // Execute the List command with empty task ID
-    let result = execute(CommentCommands::List { task_id: "".to_string() }, &config).await;
+    let result = execute(CommentCommands::List { task_id: Some("".to_string()), list_id: None, view_id: None }, &config).await;
     // This should fail due to invalid task ID
     assert!(result.is_err());
 }
@@ -152,10 +155,14 @@ async fn test_comment_create_no_auth() {
     
     // Execute the Create command
     let result = execute(CommentCommands::Create { 
-        task_id: "task_123".to_string(),
+        task_id: Some("task_123".to_string()),
+        list_id: None,
+        view_id: None,
         text: "Test comment".to_string(),
-        assignee: Some(123),
+        assignee: Some("123".to_string()),
         notify_all: Some(true),
+        markdown: false,
+        workspace_id: None,
     }, &config).await;
     assert!(result.is_err());
 }
@@ -176,10 +183,14 @@ async fn test_comment_create_with_auth() {
     // Execute the Create command
     // Note: This will likely fail due to invalid token, but the command structure is valid
     let _result = execute(CommentCommands::Create { 
-        task_id: "task_123".to_string(),
+        task_id: Some("task_123".to_string()),
+        list_id: None,
+        view_id: None,
         text: "Test comment".to_string(),
-        assignee: Some(123),
+        assignee: Some("123".to_string()),
         notify_all: Some(true),
+        markdown: false,
+        workspace_id: None,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
@@ -200,10 +211,14 @@ async fn test_comment_create_minimal_params() {
     // Execute the Create command with minimal parameters
     // Note: This will likely fail due to invalid token, but the command structure is valid
     let _result = execute(CommentCommands::Create { 
-        task_id: "task_123".to_string(),
+        task_id: Some("task_123".to_string()),
+        list_id: None,
+        view_id: None,
         text: "Test comment".to_string(),
         assignee: None,
         notify_all: None,
+        markdown: false,
+        workspace_id: None,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
@@ -223,10 +238,14 @@ async fn test_comment_create_empty_text() {
     
     // Execute the Create command with empty text
     let result = execute(CommentCommands::Create { 
-        task_id: "task_123".to_string(),
+        task_id: Some("task_123".to_string()),
+        list_id: None,
+        view_id: None,
         text: "".to_string(),
         assignee: None,
         notify_all: None,
+        markdown: false,
+        workspace_id: None,
     }, &config).await;
     // This should fail due to empty comment text
     assert!(result.is_err());
@@ -247,9 +266,13 @@ async fn test_comment_update_no_auth() {
     // Execute the Update command
     let result = execute(CommentCommands::Update { 
         id: "comment_123".to_string(),
+        task_id: None,
+        list_id: None,
+        view_id: None,
         text: "Updated comment".to_string(),
-        assignee: Some(456),
+        assignee: Some("456".to_string()),
         notify_all: Some(false),
+        workspace_id: None,
     }, &config).await;
     assert!(result.is_err());
 }
@@ -271,9 +294,13 @@ async fn test_comment_update_with_auth() {
     // Note: This will likely fail due to invalid token, but the command structure is valid
     let _result = execute(CommentCommands::Update { 
         id: "comment_123".to_string(),
+        task_id: None,
+        list_id: None,
+        view_id: None,
         text: "Updated comment".to_string(),
-        assignee: Some(456),
+        assignee: Some("456".to_string()),
         notify_all: Some(false),
+        workspace_id: None,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
@@ -294,9 +321,13 @@ async fn test_comment_update_empty_text() {
     // Execute the Update command with empty text
     let result = execute(CommentCommands::Update { 
         id: "comment_123".to_string(),
+        task_id: None,
+        list_id: None,
+        view_id: None,
         text: "".to_string(),
         assignee: None,
         notify_all: None,
+        workspace_id: None,
     }, &config).await;
     // This should fail due to empty comment text
     assert!(result.is_err());
@@ -315,7 +346,7 @@ async fn test_comment_delete_no_auth() {
     assert!(!config.is_authenticated());
     
     // Execute the Delete command
-    let result = execute(CommentCommands::Delete { id: "comment_123".to_string() }, &config).await;
+    let result = execute(CommentCommands::Delete { id: "comment_123".to_string(), task_id: None, list_id: None, view_id: None }, &config).await;
     assert!(result.is_err());
 }
 
@@ -334,7 +365,7 @@ async fn test_comment_delete_with_auth() {
     
     // Execute the Delete command
     // Note: This will likely fail due to invalid token, but the command structure is valid
-    let _result = execute(CommentCommands::Delete { id: "comment_123".to_string() }, &config).await;
+    let _result = execute(CommentCommands::Delete { id: "comment_123".to_string(), task_id: None, list_id: None, view_id: None }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
 
@@ -352,7 +383,200 @@ async fn test_comment_delete_empty_id() {
     assert!(config.is_authenticated());
     
     // Execute the Delete command with empty ID
-    let result = execute(CommentCommands::Delete { id: "".to_string() }, &config).await;
+    let result = execute(CommentCommands::Delete { id: "".to_string(), task_id: None, list_id: None, view_id: None }, &config).await;
     // This should fail due to invalid comment ID
     assert!(result.is_err());
-} 
\ No newline at end of file
+}
+
+/// Tests choosing the comment thread of a task, list or view
+///
+/// This test verifies that exactly the given item is used and that a
+/// missing item is reported before any request is made.
+#[test]
+fn test_comment_target_selection() {
+    assert_eq!(
+        comment_target(Some("abc".to_string()), None, None).unwrap(),
+        (CommentTarget::Task, "abc".to_string())
+    );
+    assert_eq!(
+        comment_target(None, Some("901".to_string()), None).unwrap(),
+        (CommentTarget::List, "901".to_string())
+    );
+    assert_eq!(
+        comment_target(None, None, Some("3v-1".to_string())).unwrap(),
+        (CommentTarget::View, "3v-1".to_string())
+    );
+    assert!(matches!(
+        comment_target(None, None, None),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert_eq!(CommentTarget::View.endpoint_segment(), "view");
+}
+
+/// Returns comment c1 with the given rich text blocks and resolved state
+fn list_comment(blocks: serde_json::Value, text: &str, resolved: bool) -> serde_json::Value {
+    json!({
+        "id": "c1", "comment": blocks, "comment_text": text,
+        "user": {"id": 1, "username": "owner"},
+        "resolved": resolved, "reactions": [], "date": "1700000000000", "reply_count": 0
+    })
+}
+
+/// Returns comment c1 as unformatted, open release notes
+fn release_notes() -> serde_json::Value {
+    list_comment(json!([{"text": "Release notes"}]), "Release notes", false)
+}
+
+/// Starts a stand-in server with a comment in the thread of list 901,
+/// which is in space 77
+async fn spawn_list_comment_server(comment: serde_json::Value) -> MockServer {
+    MockServer::start(move |request| match (request.method.as_str(), request.route()) {
+        ("GET", "/list/901/comment") => json!({ "comments": [comment.clone()] }),
+        ("GET", "/list/901") => json!({"id": "901", "orderindex": 0, "space": {"id": "77", "name": "Product"}}),
+        ("POST", "/list/901/comment") => json!({"id": 458, "date": 1700000000100_i64}),
+        _ => json!({}),
+    })
+    .await
+}
+
+/// Returns the Delete command for comment c1 in the thread of list 901
+fn delete_list_comment() -> CommentCommands {
+    CommentCommands::Delete {
+        id: "c1".to_string(),
+        task_id: None,
+        list_id: Some("901".to_string()),
+        view_id: None,
+    }
+}
+
+/// Tests deleting a comment from a list's thread and undoing the delete
+///
+/// This test verifies that the comment is looked up in the list's thread
+/// rather than in task comments, and that undo recreates it on the list.
+#[tokio::test]
+async fn test_comment_delete_from_list() {
+    let _test_config = TestConfig::new();
+    let server = spawn_list_comment_server(release_notes()).await;
+    let config = Config {
+        assume_yes: true,
+        ..server_config(&server.url())
+    };
+
+    execute(delete_list_comment(), &config).await.unwrap();
+
+    assert_eq!(server.paths(), vec!["/list/901/comment", "/comment/c1"]);
+    let entries = Journal::default_location().unwrap().entries().unwrap();
+    assert_eq!(entries[0].operation, JournalOperation::CommentDelete);
+    assert_eq!(entries[0].parent_kind, Some(CommentTarget::List));
+    assert_eq!(entries[0].parent_id.as_deref(), Some("901"));
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    undo::execute(args, &config).await.unwrap();
+    let changes = server.changes();
+    assert_eq!(changes[1].0, "POST");
+    assert_eq!(changes[1].1, "/list/901/comment");
+    assert!(changes[1].2.contains("Release notes"));
+}
+
+/// Tests that a comment on a list in a protected space is not deleted
+#[tokio::test]
+async fn test_comment_delete_from_protected_list() {
+    let _test_config = TestConfig::new();
+    let server = spawn_list_comment_server(release_notes()).await;
+    let config = Config {
+        assume_yes: true,
+        protected_spaces: vec!["77".to_string()],
+        ..server_config(&server.url())
+    };
+
+    let result = execute(delete_list_comment(), &config).await;
+
+    assert!(matches!(result, Err(ClickUpError::PermissionError(_))));
+    assert!(server.changes().is_empty());
+}
+
+/// Tests that undoing a comment delete restores its formatting and resolved state
+///
+/// The comment is recreated from its rich text blocks rather than its plain
+/// text, and resolved again because new comments are always open.
+#[tokio::test]
+async fn test_comment_delete_undo_restores_blocks_and_resolved() {
+    let _test_config = TestConfig::new();
+    let blocks = json!([
+        {"text": "Ship "},
+        {"text": "today", "attributes": {"bold": true}}
+    ]);
+    let server = spawn_list_comment_server(list_comment(blocks, "Ship today", true)).await;
+    let config = Config {
+        assume_yes: true,
+        ..server_config(&server.url())
+    };
+
+    execute(delete_list_comment(), &config).await.unwrap();
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    undo::execute(args, &config).await.unwrap();
+
+    let changes = server.changes();
+    assert_eq!(changes.len(), 3);
+    assert_eq!((changes[1].0.as_str(), changes[1].1.as_str()), ("POST", "/list/901/comment"));
+    let created: serde_json::Value = serde_json::from_str(&changes[1].2).unwrap();
+    assert_eq!(created["comment"][1]["attributes"]["bold"], true);
+    assert!(created.get("comment_text").is_none());
+    assert_eq!((changes[2].0.as_str(), changes[2].1.as_str()), ("PUT", "/comment/458"));
+    let resolved: serde_json::Value = serde_json::from_str(&changes[2].2).unwrap();
+    assert_eq!(resolved["resolved"], true);
+    assert!(Journal::default_location().unwrap().entries().unwrap()[0].undone);
+}
+
+/// Tests editing a comment in a list's thread and undoing the edit
+///
+/// This test verifies that the comment is read from the list's thread, that
+/// the journal entry records the list and the comment's prior text, and that
+/// undo puts the prior text back.
+#[tokio::test]
+async fn test_comment_update_on_list_undo() {
+    let _test_config = TestConfig::new();
+    let server = spawn_list_comment_server(release_notes()).await;
+    let config = server_config(&server.url());
+
+    let update = CommentCommands::Update {
+        id: "c1".to_string(),
+        task_id: None,
+        list_id: Some("901".to_string()),
+        view_id: None,
+        text: "Release notes v2".to_string(),
+        assignee: None,
+        notify_all: None,
+        workspace_id: None,
+    };
+    execute(update, &config).await.unwrap();
+
+    assert_eq!(server.paths(), vec!["/list/901/comment", "/comment/c1"]);
+    let entries = Journal::default_location().unwrap().entries().unwrap();
+    assert_eq!(entries[0].operation, JournalOperation::CommentUpdate);
+    assert_eq!(entries[0].parent_kind, Some(CommentTarget::List));
+    assert_eq!(entries[0].parent_id.as_deref(), Some("901"));
+    assert_eq!(entries[0].previous.as_ref().unwrap()["comment_text"], "Release notes");
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    undo::execute(args, &config).await.unwrap();
+    let changes = server.changes();
+    assert_eq!(changes.len(), 2);
+    assert_eq!((changes[1].0.as_str(), changes[1].1.as_str()), ("PUT", "/comment/c1"));
+    let restored: serde_json::Value = serde_json::from_str(&changes[1].2).unwrap();
+    assert_eq!(restored["comment"][0]["text"], "Release notes");
+    assert_eq!(restored["resolved"], false);
+    assert!(Journal::default_location().unwrap().entries().unwrap()[0].undone);
+}
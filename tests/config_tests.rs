@@ -16,12 +16,9 @@
 //! Tests use temporary directories and isolated environment variables to avoid
 //! interfering with the user's actual configuration files.
 
-use clickup_cli::config::Config;
+use clickup_cli::config::{Config, ProfileConfig, RateLimitConfig, DEFAULT_PROFILE};
 mod test_utils;
 use test_utils::TestConfig;
-use std::env;
-use std::fs;
-use tempfile::TempDir;
 
 
 
@@ -95,11 +92,13 @@ fn test_config_is_authenticated() {
 fn test_config_save_and_load() {
     let test_config = TestConfig::new();
     // Create a config with all fields set
-    let mut config = Config::default();
-    config.api_token = Some("test_token_456".to_string());
-    config.workspace_id = Some("workspace_123".to_string());
-    config.default_list_id = Some("list_456".to_string());
-    config.api_base_url = "https://test.api.clickup.com/api/v2".to_string();
+    let config = Config {
+        api_token: Some("test_token_456".to_string()),
+        workspace_id: Some("workspace_123".to_string()),
+        default_list_id: Some("list_456".to_string()),
+        api_base_url: "https://test.api.clickup.com/api/v2".to_string(),
+        ..Default::default()
+    };
     // Save the config to the temp file
     let save_result = config.save_with_path(Some(&test_config.config_file));
     assert!(
@@ -150,3 +149,182 @@ fn test_config_validation_whitespace_token() {
         _ => panic!("Expected ValidationError for whitespace-only token"),
     }
 }
+
+/// Tests that deletes in protected lists and spaces are refused
+///
+/// This test verifies that `ensure_deletable` returns a permission error
+/// for protected locations and allows everything else.
+#[test]
+fn test_config_protected_locations() {
+    let _test_config = TestConfig::new();
+    let mut config = Config::default();
+    assert!(!config.has_protected_locations());
+    assert!(config.ensure_deletable("list_1", "space_1").is_ok());
+
+    config.protected_lists = vec!["list_1".to_string()];
+    config.protected_spaces = vec!["space_2".to_string()];
+    assert!(config.has_protected_locations());
+
+    match config.ensure_deletable("list_1", "space_1") {
+        Err(clickup_cli::error::ClickUpError::PermissionError(_)) => {}
+        _ => panic!("Expected PermissionError for protected list"),
+    }
+    match config.ensure_deletable("list_3", "space_2") {
+        Err(clickup_cli::error::ClickUpError::PermissionError(_)) => {}
+        _ => panic!("Expected PermissionError for protected space"),
+    }
+    assert!(config.ensure_deletable("list_3", "space_1").is_ok());
+}
+
+/// Tests that protected locations survive a save and load
+///
+/// This test also verifies that the runtime-only dry-run and confirmation
+/// flags are never written to the configuration file.
+#[test]
+fn test_config_protected_locations_save_and_load() {
+    let test_config = TestConfig::new();
+    let config = Config {
+        protected_lists: vec!["list_1".to_string()],
+        protected_spaces: vec!["space_1".to_string()],
+        dry_run: true,
+        assume_yes: true,
+        ..Default::default()
+    };
+    config.save_with_path(Some(&test_config.config_file)).unwrap();
+
+    let loaded = Config::load_with_path(Some(&test_config.config_file)).unwrap();
+    assert_eq!(loaded.protected_lists, config.protected_lists);
+    assert_eq!(loaded.protected_spaces, config.protected_spaces);
+    assert!(!loaded.dry_run);
+    assert!(!loaded.assume_yes);
+}
+
+/// Tests applying a named profile over the top-level settings
+///
+/// This test verifies that a profile replaces the token, workspace and list,
+/// that its rate limits are used when present, and that the top-level
+/// settings are kept in `base_settings`.
+#[test]
+fn test_config_apply_profile() {
+    let _test_config = TestConfig::new();
+    let mut config = Config {
+        api_token: Some("company_token".to_string()),
+        workspace_id: Some("company_ws".to_string()),
+        ..Default::default()
+    };
+    let client_rate_limit = RateLimitConfig {
+        requests_per_minute: 500,
+        ..Default::default()
+    };
+    config.profiles.insert(
+        "client".to_string(),
+        ProfileConfig {
+            api_token: Some("client_token".to_string()),
+            rate_limit: Some(client_rate_limit),
+            ..Default::default()
+        },
+    );
+
+    config.apply_profile(Some("client".to_string()));
+    assert_eq!(config.active_profile_name(), "client");
+    assert_eq!(config.api_token.as_deref(), Some("client_token"));
+    assert!(config.workspace_id.is_none());
+    assert_eq!(config.rate_limit.requests_per_minute, 500);
+    assert_eq!(config.base_settings.api_token.as_deref(), Some("company_token"));
+
+    let mut config = Config::default();
+    config.apply_profile(Some(DEFAULT_PROFILE.to_string()));
+    assert!(config.profile.is_none());
+    assert_eq!(config.active_profile_name(), DEFAULT_PROFILE);
+}
+
+/// Tests saving and loading a configuration with a profile applied
+///
+/// This test verifies that saving writes the profile's settings to its own
+/// table and leaves the top-level settings untouched.
+#[test]
+fn test_config_profile_save_and_load() {
+    let test_config = TestConfig::new();
+    let config = Config {
+        api_token: Some("company_token".to_string()),
+        ..Default::default()
+    };
+    config.save_with_path(Some(&test_config.config_file)).unwrap();
+
+    let mut client =
+        Config::load_with_profile(Some(&test_config.config_file), Some("client")).unwrap();
+    assert!(client.api_token.is_none());
+    client.api_token = Some("client_token".to_string());
+    client.rate_limit.requests_per_minute = 250;
+    client.save_with_path(Some(&test_config.config_file)).unwrap();
+
+    let default = Config::load_with_path(Some(&test_config.config_file)).unwrap();
+    assert_eq!(default.api_token.as_deref(), Some("company_token"));
+    assert_eq!(default.rate_limit.requests_per_minute, 100);
+    assert!(default.profiles.contains_key("client"));
+
+    let client =
+        Config::load_with_profile(Some(&test_config.config_file), Some("client")).unwrap();
+    assert_eq!(client.api_token.as_deref(), Some("client_token"));
+    assert_eq!(client.rate_limit.requests_per_minute, 250);
+}
+
+/// Tests that `active_profile` selects a profile when none is requested
+#[test]
+fn test_config_active_profile_is_applied() {
+    let test_config = TestConfig::new();
+    let mut config = Config {
+        active_profile: Some("client".to_string()),
+        ..Default::default()
+    };
+    config.profiles.insert(
+        "client".to_string(),
+        ProfileConfig {
+            workspace_id: Some("client_ws".to_string()),
+            ..Default::default()
+        },
+    );
+    config.save_with_path(Some(&test_config.config_file)).unwrap();
+
+    let loaded = Config::load_with_path(Some(&test_config.config_file)).unwrap();
+    assert_eq!(loaded.profile.as_deref(), Some("client"));
+    assert_eq!(loaded.workspace_id.as_deref(), Some("client_ws"));
+
+    let loaded =
+        Config::load_with_profile(Some(&test_config.config_file), Some(DEFAULT_PROFILE)).unwrap();
+    assert!(loaded.profile.is_none());
+    assert!(loaded.workspace_id.is_none());
+}
+
+/// Tests profile existence checks and removal
+///
+/// This test verifies that unknown profiles are reported, that the default
+/// profile cannot be removed, and that removing the applied profile restores
+/// the top-level settings.
+#[test]
+fn test_config_remove_profile() {
+    let _test_config = TestConfig::new();
+    let mut config = Config {
+        api_token: Some("company_token".to_string()),
+        ..Default::default()
+    };
+    config.apply_profile(Some("missing".to_string()));
+    assert!(config.ensure_profile_exists().is_err());
+
+    config.profiles.insert(
+        "missing".to_string(),
+        ProfileConfig {
+            api_token: Some("client_token".to_string()),
+            ..Default::default()
+        },
+    );
+    config.active_profile = Some("missing".to_string());
+    assert!(config.ensure_profile_exists().is_ok());
+    assert!(config.remove_profile(DEFAULT_PROFILE).is_err());
+    assert!(config.remove_profile("other").is_err());
+
+    config.remove_profile("missing").unwrap();
+    assert!(config.profile.is_none());
+    assert!(config.active_profile.is_none());
+    assert_eq!(config.api_token.as_deref(), Some("company_token"));
+}
@@ -0,0 +1,246 @@
+//! # Dependency Tests
+//!
+//! This module contains tests for task dependency models, the dependency
+//! graph behind `tasks deps graph`, and the `tasks deps` and `tasks link`
+//! commands.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for decoding dependencies and links on tasks
+//! - **Graph**: Tests for building graphs, cycle detection and due date checks
+//! - **Rendering**: Tests for DOT and Mermaid output
+//! - **Command**: Tests for argument validation in the dependency commands
+
+use clickup_cli::commands::tasks::{execute, DepsCommands, LinkCommands, TaskCommands};
+use clickup_cli::config::Config;
+use clickup_cli::dependency_graph::{DependencyGraph, GraphNode};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{DependencyRelation, DependencyRequest, Task};
+mod test_utils;
+use test_utils::TestConfig;
+
+/// Returns a task with the given dependencies as returned by the API
+fn task_with_dependencies(id: &str, due_date: Option<&str>, dependencies: &str) -> Task {
+    let due_date = due_date.map_or("null".to_string(), |due| format!("\"{due}\""));
+    serde_json::from_str(&format!(
+        r##"{{
+            "id": "{id}", "name": "Task {id}", "text_content": "", "description": "",
+            "status": {{"id": "s", "status": "to do", "color": "#000", "orderindex": 0, "type": "open"}},
+            "orderindex": "1", "date_created": "1", "date_updated": "1", "due_date": {due_date},
+            "creator": {{"id": 1, "username": "owner", "color": "#000"}},
+            "assignees": [], "watchers": [], "checklists": [], "tags": [], "custom_fields": [],
+            "dependencies": [{dependencies}],
+            "linked_tasks": [{{"task_id": "{id}", "link_id": "other", "date_created": "1", "userid": 7}}],
+            "team_id": "1", "list": {{"id": "list_1", "access": true}}, "space": {{"id": "space_1"}},
+            "url": "https://app.clickup.com/t/{id}"
+        }}"##
+    ))
+    .unwrap()
+}
+
+/// Returns a dependency where `task_id` waits on `depends_on`
+fn dependency(task_id: &str, depends_on: &str) -> String {
+    format!(
+        r#"{{"task_id": "{task_id}", "depends_on": "{depends_on}", "type": 1, "date_created": "1", "userid": "7"}}"#
+    )
+}
+
+/// Returns a node with a due date
+fn node(id: &str, due_date: Option<i64>) -> GraphNode {
+    GraphNode {
+        due_date,
+        ..GraphNode::new(id, &format!("Task {id}"))
+    }
+}
+
+/// Tests decoding dependencies and links in both directions
+#[test]
+fn test_decode_dependencies_and_links() {
+    let task = task_with_dependencies(
+        "b",
+        None,
+        &format!("{},{}", dependency("b", "a"), dependency("c", "b")),
+    );
+
+    let relations: Vec<DependencyRelation> = task
+        .dependencies
+        .iter()
+        .filter_map(|d| d.relation_to(&task.id))
+        .collect();
+    assert_eq!(
+        relations,
+        vec![
+            DependencyRelation::WaitingOn("a".to_string()),
+            DependencyRelation::Blocking("c".to_string())
+        ]
+    );
+    assert_eq!(task.linked_tasks[0].other_task("b"), "other");
+    assert_eq!(task.linked_tasks[0].other_task("other"), "b");
+    assert_eq!(task.linked_tasks[0].userid.as_deref(), Some("7"));
+}
+
+/// Tests the request bodies for each dependency direction
+#[test]
+fn test_dependency_request() {
+    let waiting: DependencyRequest = DependencyRelation::WaitingOn("a".to_string()).into();
+    assert_eq!(serde_json::to_value(waiting).unwrap(), serde_json::json!({"depends_on": "a"}));
+
+    let blocking: DependencyRequest = DependencyRelation::Blocking("c".to_string()).into();
+    assert_eq!(
+        serde_json::to_value(blocking).unwrap(),
+        serde_json::json!({"dependency_of": "c"})
+    );
+}
+
+/// Tests building a graph from tasks, including tasks outside the list
+#[test]
+fn test_graph_from_tasks() {
+    let tasks = vec![
+        task_with_dependencies("a", None, ""),
+        task_with_dependencies("b", None, &format!("{},{}", dependency("b", "a"), dependency("b", "x"))),
+        // The same dependency is reported on both tasks
+        task_with_dependencies("c", None, &dependency("b", "a")),
+    ];
+    let graph = DependencyGraph::from_tasks(&tasks);
+
+    assert_eq!(graph.edge_count(), 2);
+    let dot = graph.to_dot();
+    assert!(dot.contains("\"a\" -> \"b\";"));
+    assert!(dot.contains("\"x\" -> \"b\";"));
+    assert!(dot.contains("\"x\" [label=\"x\", style=dashed];"));
+    assert!(graph.cycles().is_empty());
+}
+
+/// Tests that cycles of any length are found
+#[test]
+fn test_graph_cycles() {
+    let mut graph = DependencyGraph::default();
+    for id in ["a", "b", "c", "d", "e"] {
+        graph.insert_node(node(id, None));
+    }
+    // a -> b -> c -> a is a cycle; d waits on c but is not part of it
+    graph.insert_edge("a", "b");
+    graph.insert_edge("b", "c");
+    graph.insert_edge("c", "a");
+    graph.insert_edge("c", "d");
+    // e waits on itself
+    graph.insert_edge("e", "e");
+
+    assert_eq!(
+        graph.cycles(),
+        vec![
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vec!["e".to_string()]
+        ]
+    );
+    let dot = graph.to_dot();
+    assert!(dot.contains("\"a\" -> \"b\" [color=red];"));
+    assert!(dot.contains("\"c\" -> \"d\";"));
+}
+
+/// Tests that tasks due before their blockers are flagged
+///
+/// This test also verifies that closed tasks and missing due dates are ignored.
+#[test]
+fn test_graph_due_conflicts() {
+    let mut graph = DependencyGraph::default();
+    graph.insert_node(node("blocker", Some(1_700_086_400_000)));
+    graph.insert_node(node("early", Some(1_700_000_000_000)));
+    graph.insert_node(node("late", Some(1_700_172_800_000)));
+    graph.insert_node(node("undated", None));
+    graph.insert_node(GraphNode {
+        closed: true,
+        ..node("done", Some(1_800_000_000_000))
+    });
+    for waiting in ["early", "late", "undated"] {
+        graph.insert_edge("blocker", waiting);
+    }
+    graph.insert_edge("done", "early");
+
+    let conflicts = graph.due_conflicts();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].task_id, "early");
+    assert_eq!(conflicts[0].blocker_id, "blocker");
+    assert!(graph.to_dot().contains("color=orange"));
+}
+
+/// Tests Mermaid output and label escaping
+#[test]
+fn test_graph_mermaid() {
+    let mut graph = DependencyGraph::default();
+    graph.insert_node(GraphNode {
+        status: Some("to do".to_string()),
+        ..GraphNode::new("a", "Say \"hi\"")
+    });
+    graph.insert_node(GraphNode {
+        closed: true,
+        ..GraphNode::new("b", "Ship")
+    });
+    graph.insert_edge("a", "b");
+    graph.insert_edge("b", "a");
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.starts_with("flowchart LR\n"));
+    assert!(mermaid.contains("t0[\"Say #quot;hi#quot;<br/>to do\"]"));
+    assert!(mermaid.contains("t0 --> t1"));
+    assert!(mermaid.contains("class t0,t1 cycle"));
+    assert!(mermaid.contains("class t1 closed"));
+}
+
+/// Returns a configuration with a token, so commands get past client creation
+fn test_config() -> Config {
+    Config {
+        api_token: Some("pk_test".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Tests that a task cannot depend on or link to itself
+#[tokio::test]
+async fn test_self_dependency_rejected() {
+    let _test_config = TestConfig::new();
+
+    let result = execute(
+        TaskCommands::Deps {
+            command: DepsCommands::Add {
+                id: "a".to_string(),
+                waiting_on: None,
+                blocking: Some("a".to_string()),
+            },
+        },
+        &test_config(),
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+
+    let result = execute(
+        TaskCommands::Link {
+            command: LinkCommands::Add {
+                id: "a".to_string(),
+                to: "a".to_string(),
+            },
+        },
+        &test_config(),
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
+
+/// Tests that removing a dependency needs exactly one direction
+#[tokio::test]
+async fn test_dependency_direction_required() {
+    let _test_config = TestConfig::new();
+
+    let result = execute(
+        TaskCommands::Deps {
+            command: DepsCommands::Remove {
+                id: "a".to_string(),
+                waiting_on: Some("b".to_string()),
+                blocking: Some("c".to_string()),
+            },
+        },
+        &test_config(),
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
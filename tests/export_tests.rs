@@ -0,0 +1,276 @@
+//! # Export Tests
+//!
+//! This module contains tests for exporting tasks as CSV, Markdown,
+//! iCalendar and HTML.
+//!
+//! ## Test Categories
+//!
+//! - **CSV**: Tests for the columns and flattened custom fields
+//! - **Reports**: Tests for the status-grouped Markdown and HTML reports
+//! - **Calendar**: Tests for due date events and iCalendar escaping
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with a space holding a folderless list
+//! and a folder list, both containing the same task.
+
+use chrono::{DateTime, Utc};
+use clickup_cli::commands::tasks::{execute, TaskCommands};
+use clickup_cli::export::{
+    custom_field_text, render, to_csv, to_html, to_ics, to_markdown, ExportFormat,
+};
+use clickup_cli::models::{Task, TaskCustomField};
+use serde_json::json;
+mod test_utils;
+use tempfile::TempDir;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns a task with the given ID, status and position of the status
+fn export_task(id: &str, name: &str, status: &str, orderindex: i64) -> serde_json::Value {
+    let type_ = if status == "complete" { "closed" } else { "custom" };
+    let mut task = task_json(id);
+    task["name"] = json!(name);
+    task["text_content"] = json!("Notes; with, commas");
+    task["status"] = json!({"id": "s", "status": status, "color": "#000", "orderindex": orderindex, "type": type_});
+    task["date_created"] = json!("1751328000000");
+    task["date_updated"] = json!("1751328000000");
+    task
+}
+
+/// Returns three tasks: one with every detail, one done and one undated
+fn tasks() -> Vec<Task> {
+    let mut full = export_task("t1", "Ship <v2>", "in progress", 1);
+    full["due_date"] = json!("1751380200000");
+    full["start_date"] = json!("1751360400000");
+    full["time_estimate"] = json!(5_400_000);
+    full["priority"] = json!({"id": "2", "priority": "high", "color": "#f00", "orderindex": "2"});
+    full["assignees"] = json!([{"id": 1, "username": "jane", "email": "jane@acme.test", "color": "#000"}]);
+    full["tags"] = json!([
+        {"name": "api", "tag_fg": "#000", "tag_bg": "#fff", "creator": 1},
+        {"name": "urgent", "tag_fg": "#000", "tag_bg": "#fff", "creator": 1}
+    ]);
+    full["custom_fields"] = json!([
+        {"id": "cf1", "name": "Size", "type": "drop_down",
+         "type_config": {"options": [{"id": "o1", "name": "Small", "orderindex": 0},
+                                     {"id": "o2", "name": "Large", "orderindex": 1}]},
+         "value": 1},
+        {"id": "cf2", "name": "Points", "type": "number", "type_config": {}, "value": "5"}
+    ]);
+    let mut done = export_task("t2", "Write notes", "complete", 2);
+    done["due_date"] = json!("1751414400000");
+    done["date_closed"] = json!("1751414400000");
+    done["custom_fields"] = json!([
+        {"id": "cf2", "name": "Points", "type": "number", "type_config": {}},
+        {"id": "cf3", "name": "Reviewers", "type": "users", "type_config": {},
+         "value": [{"id": 2, "username": "sam"}]}
+    ]);
+    let undated = export_task("t3", "Plan [later]", "to do", 0);
+    [full, done, undated]
+        .into_iter()
+        .map(|task| serde_json::from_value(task).unwrap())
+        .collect()
+}
+
+/// Returns the time used as the export time
+fn generated_at() -> DateTime<Utc> {
+    DateTime::from_timestamp(1_751_500_000, 0).unwrap()
+}
+
+/// Tests the CSV columns, with one column per custom field
+#[test]
+fn test_to_csv() {
+    let csv = to_csv(&tasks()).unwrap();
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let headers: Vec<String> = reader.headers().unwrap().iter().map(str::to_string).collect();
+    assert_eq!(headers[0], "ID");
+    assert_eq!(&headers[15..], ["Size", "Points", "Reviewers"]);
+
+    let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(&rows[0][1], "Ship <v2>");
+    assert_eq!(&rows[0][3], "high");
+    assert_eq!(&rows[0][4], "jane");
+    assert_eq!(&rows[0][5], "api, urgent");
+    assert_eq!(&rows[0][6], "2025-07-01 14:30");
+    assert_eq!(&rows[0][8], "1.50");
+    assert_eq!(&rows[0][10], "Backlog");
+    assert_eq!(&rows[0][13], "Notes; with, commas");
+    assert_eq!(&rows[0][15], "Large");
+    assert_eq!(&rows[0][16], "5");
+    assert_eq!(&rows[0][17], "");
+    assert_eq!(&rows[1][12], "2025-07-02 00:00");
+    assert_eq!(&rows[1][16], "");
+    assert_eq!(&rows[1][17], "sam");
+}
+
+/// Tests showing custom field values by option name
+#[test]
+fn test_custom_field_text() {
+    let field: TaskCustomField = serde_json::from_value(json!({
+        "id": "cf", "name": "Areas", "type": "labels",
+        "type_config": {"options": [{"id": "a", "label": "Web"}, {"id": "b", "label": "iOS"}]},
+        "value": ["b", "a", "gone"]
+    }))
+    .unwrap();
+    assert_eq!(custom_field_text(&field), "iOS, Web, gone");
+
+    let date: TaskCustomField = serde_json::from_value(json!({
+        "id": "cf", "name": "Launch", "type": "date", "value": "1751380200000"
+    }))
+    .unwrap();
+    assert_eq!(custom_field_text(&date), "2025-07-01 14:30");
+}
+
+/// Tests grouping the Markdown report by status in status order
+#[test]
+fn test_to_markdown() {
+    let markdown = to_markdown(&tasks(), "Backlog", generated_at());
+    assert!(markdown.starts_with("# Backlog\n\n_3 task(s), exported 2025-07-02 23:46 UTC_\n"));
+    let to_do = markdown.find("## to do (1)").unwrap();
+    let in_progress = markdown.find("## in progress (1)").unwrap();
+    let complete = markdown.find("## complete (1)").unwrap();
+    assert!(to_do < in_progress && in_progress < complete);
+    assert!(markdown.contains(
+        "- [ ] [Ship <v2>](https://app.clickup.com/t/t1) — due 2025-07-01 · high priority · @jane · `api` · `urgent`\n"
+    ));
+    assert!(markdown.contains("- [x] [Write notes](https://app.clickup.com/t/t2) — due 2025-07-02\n"));
+    assert!(markdown.contains("- [ ] [Plan \\[later\\]](https://app.clickup.com/t/t3)\n"));
+}
+
+/// Tests that the HTML report is standalone and escapes task text
+#[test]
+fn test_to_html() {
+    let html = to_html(&tasks(), "Q3 & beyond", generated_at());
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Q3 &amp; beyond</title>"));
+    assert!(html.contains("@media print"));
+    assert!(html.contains("<h2>in progress (1)</h2>"));
+    assert!(html.contains("<a href=\"https://app.clickup.com/t/t1\">Ship &lt;v2&gt;</a>"));
+    assert!(html.contains("<tr class=\"closed\">"));
+    assert!(html.contains("<span class=\"tag\">urgent</span>"));
+    assert!(html.trim_end().ends_with("</html>"));
+}
+
+/// Tests that only tasks with due dates become events, with date-only due
+/// dates as all-day events
+#[test]
+fn test_to_ics() {
+    let ics = to_ics(&tasks(), "Backlog", generated_at());
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+    assert!(ics.contains("UID:t1@clickup-cli\r\n"));
+    assert!(ics.contains("DTSTART:20250701T090000Z\r\nDTEND:20250701T143000Z\r\n"));
+    assert!(ics.contains("DTSTART;VALUE=DATE:20250702\r\nDTEND;VALUE=DATE:20250703\r\n"));
+    assert!(ics.contains("DESCRIPTION:Notes\\; with\\, commas\\n\\nhttps://app.clickup.com/t/t1"));
+    assert!(ics.contains("CATEGORIES:api,urgent\r\n"));
+    assert!(!ics.contains("t3@clickup-cli"));
+    assert!(ics
+        .split("\r\n")
+        .all(|line| line.len() <= 75));
+}
+
+/// Tests folding long iCalendar lines
+#[test]
+fn test_ics_folds_long_lines() {
+    let mut task = export_task("t1", &"ä".repeat(60), "to do", 0);
+    task["due_date"] = json!("1751380200000");
+    let task: Task = serde_json::from_value(task).unwrap();
+    let ics = render(ExportFormat::Ics, &[task], "Backlog", generated_at()).unwrap();
+    let summary = ics.split("SUMMARY:").nth(1).unwrap();
+    assert!(summary.contains("\r\n ä"));
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+}
+
+/// Starts a stand-in server with space 77 holding lists 901 and 902
+async fn spawn_export_server() -> MockServer {
+    MockServer::start(|request| {
+        let list = |id: &str, name: &str| {
+            json!({"id": id, "name": name, "orderindex": 0, "space": {"id": "77", "name": "Engineering"}})
+        };
+        match request.route() {
+            "/api/v2/list/901" => list("901", "Backlog"),
+            "/api/v2/team" => json!({"teams": [{"id": "9", "name": "Acme", "members": []}]}),
+            "/api/v2/team/9/space" => json!({"spaces": [{
+                "id": "77", "name": "Engineering", "private": false,
+                "multiple_assignees": true, "archived": false, "statuses": []
+            }]}),
+            "/api/v2/space/77/list" => json!({"lists": [list("901", "Backlog")]}),
+            "/api/v2/space/77/folder" => json!({"folders": [
+                {"id": "5", "name": "Sprints", "orderindex": 0, "space": {"id": "77", "name": "Engineering"}}
+            ]}),
+            "/api/v2/folder/5/list" => json!({"lists": [list("902", "Sprint 1")]}),
+            "/api/v2/list/901/task" => json!({"tasks": [
+                export_task("t1", "Shared", "to do", 0),
+                export_task("t2", "Backlog only", "to do", 0)
+            ]}),
+            "/api/v2/list/902/task" => json!({"tasks": [
+                export_task("t1", "Shared", "to do", 0),
+                export_task("t3", "Sprint only", "to do", 0)
+            ]}),
+            _ => json!({}),
+        }
+    })
+    .await
+}
+
+/// Tests exporting a list to a file, including closed tasks when asked
+#[tokio::test]
+async fn test_export_list() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("backlog.md");
+    let server = spawn_export_server().await;
+
+    execute(
+        TaskCommands::Export {
+            list_id: Some("901".to_string()),
+            space_id: None,
+            format: ExportFormat::Markdown,
+            output: Some(output.clone()),
+            include_closed: true,
+        },
+        &server_config(&server.api_url()),
+    )
+    .await
+    .unwrap();
+
+    let markdown = std::fs::read_to_string(output).unwrap();
+    assert!(markdown.starts_with("# Backlog\n"));
+    assert!(markdown.contains("## to do (2)"));
+    assert!(server
+        .paths()
+        .iter()
+        .any(|path| path.starts_with("/api/v2/list/901/task?") && path.contains("include_closed=true")));
+}
+
+/// Tests exporting every list of a space, with each task once
+#[tokio::test]
+async fn test_export_space() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("space.csv");
+    let server = spawn_export_server().await;
+
+    execute(
+        TaskCommands::Export {
+            list_id: None,
+            space_id: Some("77".to_string()),
+            format: ExportFormat::Csv,
+            output: Some(output.clone()),
+            include_closed: false,
+        },
+        &server_config(&server.api_url()),
+    )
+    .await
+    .unwrap();
+
+    let csv = std::fs::read_to_string(output).unwrap();
+    let ids: Vec<String> = csv::Reader::from_reader(csv.as_bytes())
+        .records()
+        .map(|record| record.unwrap()[0].to_string())
+        .collect();
+    assert_eq!(ids, vec!["t1", "t2", "t3"]);
+}
@@ -0,0 +1,308 @@
+//! # Goal Tests
+//!
+//! This module contains tests for goal and key result models and the
+//! `goals` commands.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for decoding goals and computing progress
+//! - **Validation**: Tests for key result ranges and progress values
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that returns a
+//! key result for every request and records the requests it receives.
+
+use clickup_cli::commands::goals::{
+    execute, key_result_range, parse_progress_value, progress_bar, GoalCommands,
+    KeyResultCommands,
+};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{
+    CreateKeyResultRequest, GoalResponse, KeyResult, KeyResultType, UpdateGoalRequest,
+};
+mod test_utils;
+use serde_json::json;
+use test_utils::{server_config, MockServer, TestConfig};
+
+/// A goal as returned by the API, with values sent as strings and numbers
+const GOAL: &str = r##"{
+    "goal": {
+        "id": "e53a033c-900e-462d-a849-4a216b06d930",
+        "pretty_id": "6",
+        "name": "Grow signups",
+        "team_id": 512,
+        "date_created": "1700000000000",
+        "description": "Quarterly growth target",
+        "color": "#32a852",
+        "due_date": "1711929600000",
+        "archived": false,
+        "owners": [{"id": 183, "username": "growth", "email": "growth@example.com"}],
+        "key_result_count": 3,
+        "percent_completed": 10,
+        "key_results": [
+            {"id": "kr_1", "goal_id": "e53a033c", "name": "Weekly signups", "type": "number",
+             "unit": "signups", "steps_start": 0, "steps_end": "200", "steps_current": "50",
+             "completed": false, "owners": []},
+            {"id": "kr_2", "name": "Launch referral page", "type": "boolean",
+             "steps_start": 0, "steps_end": 1, "steps_current": 1, "completed": true},
+            {"id": "kr_3", "name": "Onboarding tasks", "type": "automatic",
+             "percent_completed": "0", "task_ids": ["t1", "t2"], "subcategory_ids": ["l1"]}
+        ]
+    }
+}"##;
+
+/// Returns a key result of the given type with start, target and current values
+fn key_result(kind: &str, start: f64, end: f64, current: f64) -> KeyResult {
+    serde_json::from_value(serde_json::json!({
+        "id": "kr",
+        "name": "Target",
+        "type": kind,
+        "steps_start": start,
+        "steps_end": end,
+        "steps_current": current,
+    }))
+    .unwrap()
+}
+
+/// Tests decoding a goal with key results
+#[test]
+fn test_decode_goal() {
+    let goal = serde_json::from_str::<GoalResponse>(GOAL).unwrap().goal;
+
+    assert_eq!(goal.team_id.as_deref(), Some("512"));
+    assert_eq!(goal.owners[0].username.as_deref(), Some("growth"));
+    assert_eq!(goal.key_results.len(), 3);
+    assert_eq!(goal.key_results[0].steps_end, Some(200.0));
+    assert_eq!(goal.key_results[0].steps_current, Some(50.0));
+    assert_eq!(goal.key_results[1].kind(), Some(KeyResultType::Boolean));
+    assert_eq!(goal.key_results[2].list_ids, vec!["l1".to_string()]);
+}
+
+/// Tests that goal progress averages the key results
+#[test]
+fn test_goal_progress() {
+    let mut goal = serde_json::from_str::<GoalResponse>(GOAL).unwrap().goal;
+
+    // 25% signups, 100% launched, 0% of the automatic tasks
+    assert_eq!(goal.key_results[0].progress(), Some(25.0));
+    assert_eq!(goal.key_results[1].progress(), Some(100.0));
+    assert_eq!(goal.key_results[2].progress(), Some(0.0));
+    assert!((goal.progress().unwrap() - 125.0 / 3.0).abs() < 1e-9);
+
+    goal.key_results.clear();
+    assert_eq!(goal.progress(), Some(10.0));
+}
+
+/// Tests key result progress for decreasing targets and out-of-range values
+#[test]
+fn test_key_result_progress_bounds() {
+    // Reducing open bugs from 40 to 10
+    assert_eq!(key_result("number", 40.0, 10.0, 25.0).progress(), Some(50.0));
+    assert_eq!(key_result("number", 0.0, 100.0, 150.0).progress(), Some(100.0));
+    assert_eq!(key_result("number", 0.0, 100.0, -5.0).progress(), Some(0.0));
+    assert_eq!(key_result("currency", 5.0, 5.0, 5.0).progress(), Some(100.0));
+}
+
+/// Tests looking up key result types by name
+#[test]
+fn test_key_result_type_names() {
+    for kind in KeyResultType::ALL {
+        assert_eq!(KeyResultType::from_name(kind.as_str()), Some(kind));
+        assert_eq!(
+            serde_json::to_value(kind).unwrap(),
+            serde_json::json!(kind.as_str())
+        );
+    }
+    assert_eq!(KeyResultType::from_name(" Currency "), Some(KeyResultType::Currency));
+    assert_eq!(KeyResultType::from_name("money"), None);
+}
+
+/// Tests the default and required ranges for each key result type
+#[test]
+fn test_key_result_range() {
+    assert_eq!(key_result_range(KeyResultType::Boolean, None, None).unwrap(), (0.0, 1.0));
+    assert_eq!(key_result_range(KeyResultType::Percentage, None, None).unwrap(), (0.0, 100.0));
+    assert_eq!(
+        key_result_range(KeyResultType::Number, Some(40.0), Some(10.0)).unwrap(),
+        (40.0, 10.0)
+    );
+
+    assert!(matches!(
+        key_result_range(KeyResultType::Number, None, None),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        key_result_range(KeyResultType::Boolean, None, Some(1.0)),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        key_result_range(KeyResultType::Currency, Some(5.0), Some(5.0)),
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests parsing progress values
+#[test]
+fn test_parse_progress_value() {
+    assert_eq!(parse_progress_value("42").unwrap(), 42.0);
+    assert_eq!(parse_progress_value("-3.5").unwrap(), -3.5);
+    assert_eq!(parse_progress_value("75%").unwrap(), 75.0);
+    assert_eq!(parse_progress_value("Done").unwrap(), 1.0);
+    assert_eq!(parse_progress_value("no").unwrap(), 0.0);
+
+    assert!(matches!(
+        parse_progress_value("lots"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        parse_progress_value("NaN"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests the progress bar formatting
+#[test]
+fn test_progress_bar() {
+    assert_eq!(progress_bar(50.0, 10), "[#####-----]  50%");
+    assert_eq!(progress_bar(120.0, 4), "[####] 100%");
+    assert_eq!(progress_bar(0.0, 4), "[----]   0%");
+}
+
+/// Tests that requests only send the fields being set
+#[test]
+fn test_request_serialization() {
+    let update = UpdateGoalRequest {
+        name: Some("Grow signups faster".to_string()),
+        add_owners: vec![183],
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(update).unwrap(),
+        serde_json::json!({"name": "Grow signups faster", "add_owners": [183]})
+    );
+
+    let create = CreateKeyResultRequest {
+        name: "Onboarding tasks".to_string(),
+        owners: vec![],
+        type_: KeyResultType::Automatic,
+        steps_start: 0.0,
+        steps_end: 100.0,
+        unit: None,
+        task_ids: vec!["t1".to_string()],
+        list_ids: vec![],
+    };
+    assert_eq!(
+        serde_json::to_value(create).unwrap(),
+        serde_json::json!({
+            "name": "Onboarding tasks", "owners": [], "type": "automatic",
+            "steps_start": 0.0, "steps_end": 100.0, "task_ids": ["t1"]
+        })
+    );
+}
+
+/// Starts a stand-in server that answers every request with a key result
+async fn spawn_goal_server() -> MockServer {
+    MockServer::start(|_| {
+        json!({"key_result": {"id": "kr_1", "name": "Weekly signups", "type": "number",
+            "unit": "signups", "steps_start": 0, "steps_end": 200, "steps_current": 120}})
+    })
+    .await
+}
+
+/// Tests recording progress on a key result with a note
+#[tokio::test]
+async fn test_update_key_result() {
+    let _test_config = TestConfig::new();
+    let server = spawn_goal_server().await;
+    let config = server_config(&server.url());
+
+    execute(
+        GoalCommands::KeyResult {
+            command: KeyResultCommands::Update {
+                id: "kr_1".to_string(),
+                current: "120".to_string(),
+                note: Some("Week 12 check-in".to_string()),
+            },
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(requests[0].path, "/key_result/kr_1");
+    assert_eq!(
+        requests[0].json(),
+        json!({"steps_current": 120.0, "note": "Week 12 check-in"})
+    );
+}
+
+/// Tests that invalid commands fail before any request is sent
+#[tokio::test]
+async fn test_command_validation() {
+    let _test_config = TestConfig::new();
+    let server = spawn_goal_server().await;
+    let config = server_config(&server.url());
+
+    let nothing_to_update = execute(
+        GoalCommands::Update {
+            id: "goal_1".to_string(),
+            name: None,
+            description: None,
+            due_date: None,
+            color: None,
+            add_owners: vec![],
+            remove_owners: vec![],
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(nothing_to_update, Err(ClickUpError::ValidationError(_))));
+
+    let automatic_without_targets = execute(
+        GoalCommands::KeyResult {
+            command: KeyResultCommands::Add {
+                goal_id: "goal_1".to_string(),
+                name: "Onboarding tasks".to_string(),
+                kind: KeyResultType::Automatic,
+                start: None,
+                target: None,
+                unit: None,
+                owners: vec![],
+                task_ids: vec![],
+                list_ids: vec![],
+            },
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(
+        automatic_without_targets,
+        Err(ClickUpError::ValidationError(_))
+    ));
+
+    let tasks_on_number = execute(
+        GoalCommands::KeyResult {
+            command: KeyResultCommands::Add {
+                goal_id: "goal_1".to_string(),
+                name: "Weekly signups".to_string(),
+                kind: KeyResultType::Number,
+                start: None,
+                target: Some(200.0),
+                unit: None,
+                owners: vec![],
+                task_ids: vec!["t1".to_string()],
+                list_ids: vec![],
+            },
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(tasks_on_number, Err(ClickUpError::ValidationError(_))));
+
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,523 @@
+//! # Import Tests
+//!
+//! This module contains tests for importing tasks from CSV, JSON and
+//! Markdown files.
+//!
+//! ## Test Categories
+//!
+//! - **Parsing**: Tests for reading each format and mapping CSV columns
+//! - **Values**: Tests for priorities, due dates and custom field values
+//! - **Ordering**: Tests for creating parents first and rejecting bad references
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with list 901, its custom fields, a
+//! workspace with two members, and a new task for every task created.
+
+use clickup_cli::commands::tasks::{execute, TaskCommands};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::import::{
+    custom_field_value, order_rows, parse_csv, parse_due_date, parse_json, parse_mapping,
+    parse_markdown, parse_priority, ImportField, ImportFormat,
+};
+use clickup_cli::models::CustomField;
+use serde_json::json;
+mod test_utils;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+use test_utils::{server_config, task_json, MockResponse, MockServer, TestConfig};
+
+/// A Jira-style export with a parent, repeated labels and a custom field
+const JIRA_CSV: &str = "\
+Summary,Issue key,Issue id,Parent id,Priority,Due Date,Assignee,Labels,Labels,Custom field (Points)
+Epic,ACME-1,10,,Highest,01/Jul/25 2:30 PM,jane@acme.test,backend,api,8
+Story,ACME-2,11,10,Medium,2025-07-02,,,,3
+";
+
+/// Returns a custom field of the given type with two options
+fn field(type_: &str) -> CustomField {
+    CustomField {
+        id: "cf1".to_string(),
+        name: Some("Size".to_string()),
+        type_: type_.to_string(),
+        type_config: Some(json!({"options": [
+            {"id": "o1", "name": "Small", "orderindex": 0},
+            {"id": "o2", "label": "Large", "orderindex": 1}
+        ]})),
+    }
+}
+
+/// Tests reading a Jira export with the default column mapping
+#[test]
+fn test_parse_jira_csv() {
+    let rows = parse_csv(JIRA_CSV, &HashMap::new()).unwrap();
+    assert_eq!(rows.len(), 2);
+    let epic = &rows[0];
+    assert_eq!(epic.line, 2);
+    assert_eq!(epic.request.name, "Epic");
+    assert_eq!(epic.reference.as_deref(), Some("10"));
+    assert_eq!(epic.request.priority, Some(1));
+    assert_eq!(epic.request.due_date, Some(1_751_380_200_000));
+    assert_eq!(epic.request.due_date_time, Some(true));
+    assert_eq!(epic.assignees, vec!["jane@acme.test"]);
+    assert_eq!(
+        epic.request.tags.as_deref(),
+        Some(&["backend".to_string(), "api".to_string()][..])
+    );
+    assert_eq!(
+        epic.custom_fields,
+        vec![("Points".to_string(), "8".to_string())]
+    );
+    assert_eq!(rows[1].parent.as_deref(), Some("10"));
+    assert_eq!(rows[1].request.due_date_time, Some(false));
+    assert!(rows[1].request.tags.is_none());
+}
+
+/// Tests that explicit mappings override the defaults
+#[test]
+fn test_parse_csv_with_mapping() {
+    let mapping = parse_mapping(&[
+        "Card=name".to_string(),
+        "Summary=description".to_string(),
+        "Estimate=custom:Hours".to_string(),
+    ])
+    .unwrap();
+    let rows = parse_csv("Card,Summary,Estimate\nWrite docs,For users,4\n", &mapping).unwrap();
+    assert_eq!(rows[0].request.name, "Write docs");
+    assert_eq!(rows[0].request.description.as_deref(), Some("For users"));
+    assert_eq!(rows[0].custom_fields[0].0, "Hours");
+
+    assert!(parse_mapping(&["Summary".to_string()]).is_err());
+    assert!(parse_mapping(&["Summary=title".to_string()]).is_err());
+    assert_eq!(
+        "custom:Story Points".parse::<ImportField>().unwrap(),
+        ImportField::CustomField("Story Points".to_string())
+    );
+}
+
+/// Tests that every invalid row is reported at once
+#[test]
+fn test_parse_csv_reports_all_errors() {
+    let csv = "name,priority,due date\nA,urgent,\n,2,\nC,soon,tomorrow\n";
+    let Err(ClickUpError::ValidationError(message)) = parse_csv(csv, &HashMap::new()) else {
+        panic!("expected a validation error");
+    };
+    assert!(message.starts_with("3 problem(s) found"));
+    assert!(message.contains("line 3: the task has no name"));
+    assert!(message.contains("line 4: invalid priority 'soon'"));
+    assert!(message.contains("line 4: invalid due date 'tomorrow'"));
+
+    assert!(parse_csv("Title Text,Body\nA,B\n", &HashMap::new()).is_err());
+}
+
+/// Tests reading JSON task objects with references and assignees by email
+#[test]
+fn test_parse_json() {
+    let rows = parse_json(
+        r#"[
+            {"name": "Parent", "ref": "p", "priority": 2, "tags": ["x"], "assignees": [42, "sam@acme.test"]},
+            {"name": "Child", "parent": "p", "time_estimate": 60000}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(rows[0].reference.as_deref(), Some("p"));
+    assert_eq!(rows[0].assignees, vec!["42", "sam@acme.test"]);
+    assert!(rows[0].request.assignees.is_none());
+    assert_eq!(rows[1].parent.as_deref(), Some("p"));
+    assert!(rows[1].request.parent.is_none());
+    assert_eq!(rows[1].request.time_estimate, Some(60_000));
+
+    assert!(parse_json(r#"{"name": "Not an array"}"#).is_err());
+    let Err(ClickUpError::ValidationError(message)) =
+        parse_json(r#"[{"name": "A"}, {"priority": 1}, 7]"#)
+    else {
+        panic!("expected a validation error");
+    };
+    assert!(message.contains("line 2: missing field `name`"));
+    assert!(message.contains("line 3: expected a task object"));
+}
+
+/// Tests reading a nested Markdown task list
+#[test]
+fn test_parse_markdown() {
+    let markdown = "# Release\n\n- [ ] Prepare\n  - [x] Changelog\n  - [ ] Notes\n    * Proofread\n- Publish\nSome text\n";
+    let rows = parse_markdown(markdown, "done");
+    let summary: Vec<(&str, Option<&str>, Option<&str>)> = rows
+        .iter()
+        .map(|row| {
+            (
+                row.request.name.as_str(),
+                row.parent.as_deref(),
+                row.request.status.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Prepare", None, None),
+            ("Changelog", Some("line 3"), Some("done")),
+            ("Notes", Some("line 3"), None),
+            ("Proofread", Some("line 5"), None),
+            ("Publish", None, None),
+        ]
+    );
+}
+
+/// Tests priorities and due dates in the accepted formats
+#[test]
+fn test_parse_values() {
+    assert_eq!(parse_priority("URGENT"), Ok(1));
+    assert_eq!(parse_priority("3"), Ok(3));
+    assert_eq!(parse_priority("Lowest"), Ok(4));
+    assert!(parse_priority("5").is_err());
+
+    assert_eq!(parse_due_date("2025-07-01"), Ok((1_751_328_000_000, false)));
+    assert_eq!(parse_due_date("07/01/2025"), Ok((1_751_328_000_000, false)));
+    assert_eq!(parse_due_date("2025-07-01 14:30"), Ok((1_751_380_200_000, true)));
+    assert_eq!(
+        parse_due_date("2025-07-01T16:30:00+02:00"),
+        Ok((1_751_380_200_000, true))
+    );
+    assert_eq!(parse_due_date("1751380200000"), Ok((1_751_380_200_000, true)));
+    assert!(parse_due_date("next week").is_err());
+}
+
+/// Tests converting custom field values for each field type
+#[test]
+fn test_custom_field_value() {
+    assert_eq!(custom_field_value(&field("number"), "2.5"), Ok(json!(2.5)));
+    assert!(custom_field_value(&field("number"), "big").is_err());
+    assert_eq!(custom_field_value(&field("checkbox"), "Yes"), Ok(json!(true)));
+    assert_eq!(custom_field_value(&field("drop_down"), "small"), Ok(json!("o1")));
+    assert_eq!(
+        custom_field_value(&field("labels"), "Large; o1"),
+        Ok(json!(["o2", "o1"]))
+    );
+    assert!(custom_field_value(&field("drop_down"), "Medium").is_err());
+    assert_eq!(custom_field_value(&field("text"), "Hi"), Ok(json!("Hi")));
+}
+
+/// Tests that parents are created before their subtasks
+#[test]
+fn test_order_rows() {
+    let rows = parse_json(
+        r#"[
+            {"name": "Grandchild", "parent": "c"},
+            {"name": "Child", "ref": "c", "parent": "p"},
+            {"name": "Elsewhere", "parent": "86abc"},
+            {"name": "Parent", "ref": "p"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(order_rows(&rows).unwrap(), vec![3, 1, 0, 2]);
+}
+
+/// Tests that duplicate references and parent cycles are rejected
+#[test]
+fn test_order_rows_rejects_bad_references() {
+    let duplicates =
+        parse_json(r#"[{"name": "A", "ref": "x"}, {"name": "B", "ref": "x"}]"#).unwrap();
+    let Err(ClickUpError::ValidationError(message)) = order_rows(&duplicates) else {
+        panic!("expected a validation error");
+    };
+    assert!(message.contains("line 2: the reference 'x' is already used on line 1"));
+
+    let cycle = parse_json(
+        r#"[{"name": "A", "ref": "a", "parent": "b"}, {"name": "B", "ref": "b", "parent": "a"}]"#,
+    )
+    .unwrap();
+    assert!(order_rows(&cycle).is_err());
+}
+
+/// Tests guessing the format from the file extension
+#[test]
+fn test_format_from_path() {
+    assert_eq!(
+        ImportFormat::from_path(Path::new("jira.CSV")).unwrap(),
+        ImportFormat::Csv
+    );
+    assert_eq!(
+        ImportFormat::from_path(Path::new("plan.md")).unwrap(),
+        ImportFormat::Markdown
+    );
+    assert!(ImportFormat::from_path(Path::new("tasks.txt")).is_err());
+}
+
+/// Starts a stand-in server for list 901 and workspace 9
+async fn spawn_import_server() -> MockServer {
+    let mut created = 0;
+    MockServer::start(move |request| match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/api/v2/list/901") => MockResponse::from(json!({
+            "id": "901", "name": "Backlog", "orderindex": 0,
+            "space": {"id": "77", "name": "Eng"},
+            "statuses": [
+                {"id": "a", "status": "to do", "color": "#000", "orderindex": 0, "type": "open"},
+                {"id": "b", "status": "Complete", "color": "#000", "orderindex": 1, "type": "closed"}
+            ]
+        })),
+        ("GET", "/api/v2/list/901/field") => json!({"fields": [
+            {"id": "cf_points", "name": "Points", "type": "number", "type_config": {}}
+        ]})
+        .into(),
+        ("GET", "/api/v2/team/9") => json!({
+            "id": 9, "name": "Acme", "color": "#000",
+            "members": [
+                {"user": {"id": 1, "username": "Jane Doe", "email": "jane@acme.test"}},
+                {"user": {"id": 2, "username": "sam", "email": "sam@acme.test"}}
+            ]
+        })
+        .into(),
+        ("POST", "/api/v2/list/901/task") => {
+            let body = request.json();
+            if body["name"] == "Broken" {
+                MockResponse::new(
+                    "400 Bad Request",
+                    json!({"err": "Task name invalid", "ECODE": "INPUT_005"}).to_string(),
+                )
+            } else {
+                created += 1;
+                let id = format!("n{created}");
+                let mut task = task_json(&id);
+                task["name"] = body["name"].clone();
+                task["status"]["id"] = json!("a");
+                task["parent"] = body["parent"].clone();
+                task.into()
+            }
+        }
+        _ => json!({}).into(),
+    })
+    .await
+}
+
+/// Writes an import file into a temporary directory
+fn write_file(dir: &TempDir, name: &str, contents: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+/// Returns an import command for list 901
+fn import_command(file: PathBuf) -> TaskCommands {
+    TaskCommands::Import {
+        list_id: "901".to_string(),
+        file,
+        format: None,
+        mappings: Vec::new(),
+        done_status: "complete".to_string(),
+        per_minute: None,
+        results: None,
+        workspace_id: None,
+    }
+}
+
+/// Returns the bodies of the tasks the server was asked to create
+fn created_tasks(server: &MockServer) -> Vec<serde_json::Value> {
+    server
+        .requests()
+        .iter()
+        .filter(|request| request.method == "POST")
+        .map(|request| request.json())
+        .collect()
+}
+
+/// Tests importing a CSV: parents first, assignees and custom fields
+/// resolved, and the new IDs written to the result file
+#[tokio::test]
+async fn test_import_csv() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let file = write_file(&dir, "jira.csv", JIRA_CSV);
+    let server = spawn_import_server().await;
+
+    execute(import_command(file.clone()), &server_config(&server.api_url()))
+        .await
+        .unwrap();
+
+    let created = created_tasks(&server);
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[0]["name"], "Epic");
+    assert_eq!(created[0]["assignees"], json!([1]));
+    assert_eq!(created[0]["tags"], json!(["backend", "api"]));
+    assert_eq!(
+        created[0]["custom_fields"],
+        json!([{"id": "cf_points", "value": 8.0}])
+    );
+    assert_eq!(created[1]["name"], "Story");
+    assert_eq!(created[1]["parent"], "n1");
+
+    let results: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(dir.path().join("jira.csv.results.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(results[0]["id"], "n1");
+    assert_eq!(results[0]["reference"], "10");
+    assert_eq!(results[1]["id"], "n2");
+    assert_eq!(results[1]["url"], "https://app.clickup.com/t/n2");
+}
+
+/// Tests that a Markdown list is nested and checked items get the list's
+/// spelling of the done status
+#[tokio::test]
+async fn test_import_markdown() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let file = write_file(&dir, "plan.md", "- [ ] Launch\n  - [x] Write post\n");
+    let server = spawn_import_server().await;
+
+    execute(import_command(file), &server_config(&server.api_url()))
+        .await
+        .unwrap();
+
+    let created = created_tasks(&server);
+    assert_eq!(created[0]["status"], json!(null));
+    assert_eq!(created[1]["status"], "Complete");
+    assert_eq!(created[1]["parent"], "n1");
+}
+
+/// Tests that invalid statuses, assignees and custom fields stop the import
+/// before any task is created
+#[tokio::test]
+async fn test_import_validates_before_sending() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let file = write_file(
+        &dir,
+        "tasks.json",
+        r#"[
+            {"name": "Good"},
+            {"name": "Bad status", "status": "blocked"},
+            {"name": "Bad assignee", "assignees": ["nobody@acme.test"]}
+        ]"#,
+    );
+    let server = spawn_import_server().await;
+
+    let result = execute(import_command(file), &server_config(&server.api_url())).await;
+
+    let Err(ClickUpError::ValidationError(message)) = result else {
+        panic!("expected a validation error");
+    };
+    assert!(message.contains("line 2: the list has no status 'blocked'"));
+    assert!(message.contains("line 3:"));
+    assert!(created_tasks(&server).is_empty());
+    assert!(!dir.path().join("tasks.json.results.json").exists());
+}
+
+/// Tests that a dry run checks the file but creates nothing
+#[tokio::test]
+async fn test_import_dry_run() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let file = write_file(&dir, "jira.csv", JIRA_CSV);
+    let server = spawn_import_server().await;
+    let config = Config {
+        dry_run: true,
+        ..server_config(&server.api_url())
+    };
+
+    let result = execute(import_command(file.clone()), &config).await;
+
+    assert!(result.is_ok());
+    assert!(created_tasks(&server).is_empty());
+    assert!(server
+        .paths()
+        .iter()
+        .any(|path| path == "/api/v2/list/901/field"));
+    let mut results_path = file.into_os_string();
+    results_path.push(".results.json");
+    assert!(!std::path::Path::new(&results_path).exists());
+}
+
+/// Tests that a failed parent skips its subtasks and is recorded in the
+/// result file
+#[tokio::test]
+async fn test_import_records_failures() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let file = write_file(
+        &dir,
+        "tasks.json",
+        r#"[{"name": "Broken", "ref": "b"}, {"name": "Child", "parent": "b"}, {"name": "Fine"}]"#,
+    );
+    let results_file = dir.path().join("out.json");
+    let server = spawn_import_server().await;
+    let command = TaskCommands::Import {
+        list_id: "901".to_string(),
+        file,
+        format: Some(ImportFormat::Json),
+        mappings: Vec::new(),
+        done_status: "complete".to_string(),
+        per_minute: Some(6000),
+        results: Some(results_file.clone()),
+        workspace_id: None,
+    };
+
+    let result = execute(command, &server_config(&server.api_url())).await;
+
+    assert!(matches!(result, Err(ClickUpError::ApiError(_))));
+    assert_eq!(created_tasks(&server).len(), 2);
+    let results: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(results_file).unwrap()).unwrap();
+    assert!(results[0]["error"].is_string());
+    assert_eq!(
+        results[1]["error"],
+        "its parent on line 1 was not imported"
+    );
+    assert_eq!(results[2]["id"], "n1");
+}
+
+/// Tests that each row's outcome is in the result file before the next
+/// task is created, so an interrupted import keeps the IDs it created
+#[tokio::test]
+async fn test_import_writes_results_as_it_goes() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let file = write_file(
+        &dir,
+        "tasks.json",
+        r#"[{"name": "First"}, {"name": "Second"}]"#,
+    );
+    let results_file = dir.path().join("out.json");
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let server = {
+        let (results_file, seen) = (results_file.clone(), seen.clone());
+        MockServer::start(move |request| {
+            if request.method != "POST" {
+                return json!({"id": "901", "orderindex": 0, "space": {"id": "77", "name": "Eng"}});
+            }
+            // What the result file held when this task was requested
+            let written = std::fs::read_to_string(&results_file).unwrap_or_default();
+            let mut seen = seen.lock().unwrap();
+            seen.push(written);
+            task_json(&format!("n{}", seen.len()))
+        })
+        .await
+    };
+    let command = TaskCommands::Import {
+        list_id: "901".to_string(),
+        file,
+        format: Some(ImportFormat::Json),
+        mappings: Vec::new(),
+        done_status: "complete".to_string(),
+        per_minute: Some(6000),
+        results: Some(results_file.clone()),
+        workspace_id: None,
+    };
+
+    execute(command, &server_config(&server.api_url())).await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    assert!(seen[0].is_empty());
+    let first: serde_json::Value = serde_json::from_str(&seen[1]).unwrap();
+    assert_eq!(first[0]["id"], "n1");
+    let results: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(results_file).unwrap()).unwrap();
+    assert_eq!(results[1]["id"], "n2");
+}
+
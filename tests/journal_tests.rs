@@ -0,0 +1,343 @@
+//! # Undo Journal Tests
+//!
+//! This module contains tests for the undo journal and the `undo` command.
+//!
+//! ## Test Categories
+//!
+//! - **Recording**: Tests for appending entries and assigning IDs
+//! - **Reading**: Tests for loading entries and skipping malformed lines
+//! - **Undo Tracking**: Tests for marking entries as undone without losing
+//!   malformed lines
+//! - **Command**: Tests for the undo command with an empty journal, for
+//!   restoring a task's assignees, tags and custom fields, and for keeping
+//!   fractional points and date-only dates
+//!
+//! ## Test Environment
+//!
+//! Tests use temporary directories so the user's real journal is never touched.
+//! Restores are sent to a small stand-in server on `127.0.0.1`.
+
+use clickup_cli::commands::undo::{execute, UndoArgs};
+use clickup_cli::config::Config;
+use clickup_cli::journal::{Journal, JournalOperation};
+mod test_utils;
+use serde_json::{json, Value};
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+use tempfile::TempDir;
+
+/// Tests that recorded entries are persisted with sequential IDs
+///
+/// This test verifies that each recorded entry receives the next ID and
+/// that entries are read back in the order they were written.
+#[test]
+fn test_journal_records_sequential_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal = Journal::new(temp_dir.path().join("journal.jsonl"));
+
+    let previous = serde_json::json!({"id": "task_1", "name": "Before"});
+    let first = journal
+        .record(JournalOperation::TaskUpdate, "task_1", Some(previous), None)
+        .unwrap()
+        .unwrap();
+    let second = journal
+        .record(
+            JournalOperation::CommentDelete,
+            "comment_1",
+            None,
+            Some("task_1".to_string()),
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(first.id, 1);
+    assert_eq!(second.id, 2);
+
+    let entries = journal.entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].operation, JournalOperation::TaskUpdate);
+    assert_eq!(entries[0].previous.as_ref().unwrap()["name"], "Before");
+    assert_eq!(entries[1].parent_id.as_deref(), Some("task_1"));
+    assert!(!entries[1].undone);
+}
+
+/// Tests that a missing journal file reads as empty
+#[test]
+fn test_journal_missing_file_is_empty() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal = Journal::new(temp_dir.path().join("missing.jsonl"));
+
+    assert!(journal.entries().unwrap().is_empty());
+}
+
+/// Tests that malformed lines are skipped when reading the journal
+#[test]
+fn test_journal_skips_malformed_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("journal.jsonl");
+    let journal = Journal::new(path.clone());
+
+    journal
+        .record(JournalOperation::TaskCreate, "task_1", None, None)
+        .unwrap();
+    let mut content = std::fs::read_to_string(&path).unwrap();
+    content.push_str("not json\n");
+    std::fs::write(&path, content).unwrap();
+
+    let entries = journal.entries().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].entity_id, "task_1");
+}
+
+/// Tests that marking an entry as undone keeps malformed lines
+///
+/// This test verifies that IDs keep counting after a malformed line and
+/// that rewriting the journal does not drop lines it cannot parse.
+#[test]
+fn test_journal_mark_undone_keeps_malformed_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("journal.jsonl");
+    std::fs::write(&path, "not json\n").unwrap();
+    let journal = Journal::new(path.clone());
+
+    journal
+        .record(JournalOperation::TaskCreate, "task_1", None, None)
+        .unwrap();
+    let entry = journal
+        .record(JournalOperation::TaskCreate, "task_2", None, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(entry.id, 2);
+
+    journal.mark_undone(2).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.starts_with("not json\n"));
+    let entries = journal.entries().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries[1].undone);
+}
+
+/// Tests marking entries as undone
+///
+/// This test verifies that only the targeted entry is marked and that
+/// marking an unknown entry returns an error.
+#[test]
+fn test_journal_mark_undone() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal = Journal::new(temp_dir.path().join("journal.jsonl"));
+
+    journal
+        .record(JournalOperation::TaskDelete, "task_1", None, None)
+        .unwrap();
+    journal
+        .record(JournalOperation::TaskDelete, "task_2", None, None)
+        .unwrap();
+
+    journal.mark_undone(2).unwrap();
+
+    let entries = journal.entries().unwrap();
+    assert!(!entries[0].undone);
+    assert!(entries[1].undone);
+    assert!(journal.mark_undone(99).is_err());
+    // The journal is replaced through a temporary file that doesn't linger
+    assert!(!temp_dir.path().join("journal.jsonl.tmp").exists());
+}
+
+/// Tests that a disabled journal records nothing
+#[test]
+fn test_journal_disabled_records_nothing() {
+    let journal = Journal::disabled();
+
+    assert!(!journal.is_enabled());
+    let entry = journal
+        .record(JournalOperation::TaskUpdate, "task_1", None, None)
+        .unwrap();
+    assert!(entry.is_none());
+}
+
+/// Tests the undo command with an empty journal
+///
+/// This test verifies that undoing with nothing recorded succeeds without
+/// making any API requests.
+#[tokio::test]
+async fn test_undo_empty_journal() {
+    let _test_config = TestConfig::new();
+    let config = Config::default();
+
+    let args = UndoArgs {
+        last: Some(3),
+        id: None,
+        list: false,
+    };
+    let result = execute(args, &config).await;
+    assert!(result.is_ok());
+}
+
+/// Tests the undo command with an unknown entry ID
+#[tokio::test]
+async fn test_undo_unknown_entry() {
+    let _test_config = TestConfig::new();
+    let config = Config::default();
+
+    let args = UndoArgs {
+        last: None,
+        id: Some(42),
+        list: false,
+    };
+    let result = execute(args, &config).await;
+    assert!(result.is_err());
+}
+
+/// Returns task t1 with the given assignee, tag and custom field values
+fn tagged_task(assignee: i64, tag: &str, priority: &str, estimate: Option<&str>) -> Value {
+    let mut task = task_json("t1");
+    task["assignees"] = json!([{
+        "id": assignee, "username": format!("user{assignee}"),
+        "email": format!("user{assignee}@acme.test"), "color": "#000"
+    }]);
+    task["tags"] = json!([{"name": tag, "tag_fg": "#000", "tag_bg": "#fff", "creator": 1}]);
+    task["custom_fields"] = json!([
+        {"id": "cf_priority", "name": "Impact", "type": "short_text", "type_config": {}, "value": priority},
+        {"id": "cf_estimate", "name": "Size", "type": "short_text", "type_config": {}, "value": estimate}
+    ]);
+    task
+}
+
+/// Tests that undoing a task update restores assignees, tags and custom fields
+///
+/// The task update endpoint ignores these fields, so each difference must
+/// be reverted with its own request.
+#[tokio::test]
+async fn test_undo_restores_assignees_tags_and_fields() {
+    let _test_config = TestConfig::new();
+    let server = MockServer::start(|_| tagged_task(2, "feature", "Low", Some("XL"))).await;
+    let mut previous = tagged_task(1, "bug", "High", None);
+    previous["points"] = json!(3.0);
+    Journal::default_location()
+        .unwrap()
+        .record(JournalOperation::TaskUpdate, "t1", Some(previous), None)
+        .unwrap();
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    execute(args, &server_config(&server.url())).await.unwrap();
+
+    let changes = server.changes();
+    let summary: Vec<(&str, &str)> = changes
+        .iter()
+        .map(|(method, path, _)| (method.as_str(), path.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("PUT", "/task/t1"),
+            ("PUT", "/task/t1"),
+            ("POST", "/task/t1/tag/bug"),
+            ("DELETE", "/task/t1/tag/feature"),
+            ("POST", "/task/t1/field/cf_priority"),
+            ("DELETE", "/task/t1/field/cf_estimate"),
+        ]
+    );
+    let update: Value = serde_json::from_str(&changes[0].2).unwrap();
+    assert_eq!(update["points"], 3);
+    let assignees: Value = serde_json::from_str(&changes[1].2).unwrap();
+    assert_eq!(assignees, json!({"assignees": {"add": [1], "rem": [2]}}));
+    let field: Value = serde_json::from_str(&changes[4].2).unwrap();
+    assert_eq!(field, json!({"value": "High"}));
+    assert!(Journal::default_location().unwrap().entries().unwrap()[0].undone);
+}
+
+/// Tests that undoing a task update leaves fractional points alone
+///
+/// The API only accepts whole points, so 2.5 points cannot be sent back;
+/// the field is left out rather than sent empty, which would clear it.
+#[tokio::test]
+async fn test_undo_keeps_fractional_points() {
+    let _test_config = TestConfig::new();
+    let mut current = task_json("t1");
+    current["points"] = json!(2.5);
+    let server = MockServer::start(move |_| current.clone()).await;
+    let mut previous = task_json("t1");
+    previous["name"] = json!("Old name");
+    previous["points"] = json!(2.5);
+    Journal::default_location()
+        .unwrap()
+        .record(JournalOperation::TaskUpdate, "t1", Some(previous), None)
+        .unwrap();
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    execute(args, &server_config(&server.url())).await.unwrap();
+
+    let changes = server.changes();
+    assert_eq!(changes.len(), 1);
+    let update: Value = serde_json::from_str(&changes[0].2).unwrap();
+    assert_eq!(update["name"], "Old name");
+    assert!(update.get("points").is_none());
+}
+
+/// Tests that undoing a task update does not give date-only dates a time
+///
+/// The task's own date-only or timed setting is kept by leaving the time
+/// flags out of the restore request.
+#[tokio::test]
+async fn test_undo_keeps_date_only_dates() {
+    let _test_config = TestConfig::new();
+    let server = MockServer::start(|_| task_json("t1")).await;
+    let mut previous = task_json("t1");
+    previous["due_date"] = json!("1700006400000");
+    previous["start_date"] = json!("1699920000000");
+    Journal::default_location()
+        .unwrap()
+        .record(JournalOperation::TaskUpdate, "t1", Some(previous), None)
+        .unwrap();
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    execute(args, &server_config(&server.url())).await.unwrap();
+
+    let update: Value = serde_json::from_str(&server.changes()[0].2).unwrap();
+    assert_eq!(update["due_date"], 1700006400000_i64);
+    assert_eq!(update["start_date"], 1699920000000_i64);
+    assert!(update.get("due_date_time").is_none());
+    assert!(update.get("start_date_time").is_none());
+}
+
+/// Tests that undoing a task delete recreates date-only and timed dates
+///
+/// A date at midnight UTC is recreated as date-only and any other date
+/// keeps its time.
+#[tokio::test]
+async fn test_undo_delete_keeps_date_only_dates() {
+    let _test_config = TestConfig::new();
+    let server = MockServer::start(|_| task_json("t2")).await;
+    let mut previous = task_json("t1");
+    previous["due_date"] = json!("1700006400000");
+    previous["start_date"] = json!("1699957800000");
+    Journal::default_location()
+        .unwrap()
+        .record(JournalOperation::TaskDelete, "t1", Some(previous), None)
+        .unwrap();
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    execute(args, &server_config(&server.url())).await.unwrap();
+
+    let changes = server.changes();
+    assert_eq!((changes[0].0.as_str(), changes[0].1.as_str()), ("POST", "/list/901/task"));
+    let created: Value = serde_json::from_str(&changes[0].2).unwrap();
+    assert_eq!(created["due_date_time"], false);
+    assert_eq!(created["start_date_time"], true);
+}
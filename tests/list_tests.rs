@@ -28,6 +28,7 @@ use tempfile::TempDir;
 struct TestConfig {
     #[allow(dead_code)]
     temp_dir: TempDir,
+    #[allow(dead_code)]
     config_file: std::path::PathBuf,
     original_xdg: Option<String>,
     original_appdata: Option<String>,
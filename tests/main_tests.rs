@@ -10,7 +10,7 @@ fn test_cli_help_and_version() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Help command should succeed");
-    assert!(output.stdout.len() > 0, "Help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Help output should not be empty");
 
     // Test version command
     let output = Command::new("cargo")
@@ -19,7 +19,7 @@ fn test_cli_help_and_version() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Version command should succeed");
-    assert!(output.stdout.len() > 0, "Version output should not be empty");
+    assert!(!output.stdout.is_empty(), "Version output should not be empty");
 }
 
 /// Test that the CLI handles invalid commands gracefully
@@ -31,7 +31,7 @@ fn test_cli_invalid_command() {
         .expect("Failed to execute command");
 
     assert!(!output.status.success(), "Invalid command should fail");
-    assert!(output.stderr.len() > 0, "Error output should not be empty");
+    assert!(!output.stderr.is_empty(), "Error output should not be empty");
 }
 
 /// Test that subcommands show help when requested
@@ -44,7 +44,7 @@ fn test_cli_subcommand_help() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Auth help command should succeed");
-    assert!(output.stdout.len() > 0, "Auth help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Auth help output should not be empty");
 
     // Test workspaces subcommand help
     let output = Command::new("cargo")
@@ -53,7 +53,7 @@ fn test_cli_subcommand_help() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Workspaces help command should succeed");
-    assert!(output.stdout.len() > 0, "Workspaces help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Workspaces help output should not be empty");
 }
 
 /// Test that debug logging can be enabled
@@ -78,7 +78,7 @@ fn test_cli_auth_command_routing() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Auth set help command should succeed");
-    assert!(output.stdout.len() > 0, "Auth set help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Auth set help output should not be empty");
 }
 
 /// Test that command routing works for workspace commands
@@ -90,7 +90,7 @@ fn test_cli_workspace_command_routing() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Workspaces list help command should succeed");
-    assert!(output.stdout.len() > 0, "Workspaces list help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Workspaces list help output should not be empty");
 }
 
 /// Test that command routing works for task commands
@@ -102,7 +102,7 @@ fn test_cli_task_command_routing() {
         .expect("Failed to execute command");
 
     assert!(output.status.success(), "Tasks create help command should succeed");
-    assert!(output.stdout.len() > 0, "Tasks create help output should not be empty");
+    assert!(!output.stdout.is_empty(), "Tasks create help output should not be empty");
 }
 
 /// Test that the CLI handles configuration errors gracefully
@@ -118,5 +118,5 @@ fn test_cli_configuration_error_handling() {
 
     // The command should fail due to missing token, but not panic
     assert!(!output.status.success(), "Command should fail with missing token");
-    assert!(output.stderr.len() > 0, "Error output should not be empty");
+    assert!(!output.stderr.is_empty(), "Error output should not be empty");
 } 
\ No newline at end of file
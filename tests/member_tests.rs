@@ -0,0 +1,302 @@
+//! # Member Tests
+//!
+//! This module contains tests for the shared hierarchy and the `users` and
+//! `guests` commands.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for decoding shared items, members and roles
+//! - **Arguments**: Tests for choosing the shared item and permission level
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with a fixed workspace, member and guest.
+
+use clickup_cli::api::ClickUpApi;
+use clickup_cli::commands::guests::{
+    self, shared_item, validate_permission, GuestCommands, GuestPermission,
+};
+use clickup_cli::commands::users::{self, find_member_by_email, UserCommands};
+use clickup_cli::commands::workspaces::{self, WorkspaceCommands};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{MemberResponse, SharedHierarchyResponse, SharedItemKind, TeamResponse};
+mod test_utils;
+use test_utils::{server_config, MockServer, TestConfig};
+
+/// A workspace with an owner and a newly invited contractor
+const TEAM: &str = r##"{
+    "team": {
+        "id": 9, "name": "Acme", "color": "#000",
+        "members": [
+            {"user": {"id": 1, "username": "owner", "email": "owner@acme.test", "role": 1}},
+            {"user": {"id": 42, "username": null, "email": "Contractor@Example.com",
+                      "role": "4", "role_key": ""}, "invited_by": {"id": 1, "username": "owner"}}
+        ]
+    }
+}"##;
+
+/// A member with a list shared with them
+const MEMBER: &str = r#"{
+    "member": {
+        "user": {"id": 42, "username": "contractor", "email": "contractor@example.com",
+                 "role": 3, "date_invited": "1700000000000"},
+        "invited_by": {"id": 1, "username": "owner"},
+        "shared": {"tasks": [], "lists": [{"id": "901", "name": "Design", "task_count": "5"}],
+                   "folders": []}
+    }
+}"#;
+
+/// Tests decoding shared items given as objects or bare IDs
+#[test]
+fn test_decode_shared_hierarchy() {
+    let response: SharedHierarchyResponse = serde_json::from_str(
+        r#"{"shared": {
+            "tasks": ["9hx", 123],
+            "lists": [{"id": "1421", "name": "Shared List", "task_count": "5", "archived": false}],
+            "folders": [{"id": 1057, "name": "Shared Folder", "task_count": 2, "archived": true}]
+        }}"#,
+    )
+    .unwrap();
+    let shared = response.shared;
+
+    assert_eq!(shared.tasks.len(), 2);
+    assert_eq!(shared.tasks[0].id, "9hx");
+    assert_eq!(shared.tasks[1].id, "123");
+    assert_eq!(shared.tasks[0].name, None);
+    assert_eq!(shared.lists[0].name.as_deref(), Some("Shared List"));
+    assert_eq!(shared.lists[0].task_count, Some(5));
+    assert_eq!(shared.folders[0].id, "1057");
+    assert_eq!(shared.folders[0].archived, Some(true));
+    assert!(!shared.is_empty());
+
+    let empty: SharedHierarchyResponse = serde_json::from_str(r#"{"shared": {}}"#).unwrap();
+    assert!(empty.shared.is_empty());
+}
+
+/// Tests naming roles from their key or code, and finding members by email
+#[test]
+fn test_member_roles_and_lookup() {
+    let response: TeamResponse = serde_json::from_str(TEAM).unwrap();
+    let workspace = &response.team;
+
+    assert_eq!(workspace.members[0].user.role_name(), "owner");
+    let contractor = find_member_by_email(workspace, " contractor@example.com ").unwrap();
+    assert_eq!(contractor.id, Some(42));
+    assert_eq!(contractor.role_name(), "guest");
+    assert!(find_member_by_email(workspace, "nobody@example.com").is_none());
+
+    let member: MemberResponse = serde_json::from_str(MEMBER).unwrap();
+    assert_eq!(member.member.user.role_name(), "member");
+    assert_eq!(member.member.shared.unwrap().lists[0].id, "901");
+}
+
+/// Tests choosing the item a guest gets access to
+#[test]
+fn test_shared_item_selection() {
+    assert_eq!(
+        shared_item(Some("abc".to_string()), None, None).unwrap(),
+        (SharedItemKind::Task, "abc".to_string())
+    );
+    assert_eq!(
+        shared_item(None, Some("901".to_string()), None).unwrap(),
+        (SharedItemKind::List, "901".to_string())
+    );
+    assert_eq!(
+        shared_item(None, None, Some("77".to_string())).unwrap(),
+        (SharedItemKind::Folder, "77".to_string())
+    );
+    assert!(matches!(
+        shared_item(None, None, None),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert_eq!(SharedItemKind::Folder.endpoint_segment(), "folder");
+}
+
+/// Tests that `create` is only granted on lists and folders
+#[test]
+fn test_validate_permission() {
+    assert!(validate_permission(SharedItemKind::Task, GuestPermission::Edit).is_ok());
+    assert!(validate_permission(SharedItemKind::List, GuestPermission::Create).is_ok());
+    assert!(validate_permission(SharedItemKind::Folder, GuestPermission::Create).is_ok());
+    assert!(matches!(
+        validate_permission(SharedItemKind::Task, GuestPermission::Create),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert_eq!(GuestPermission::Comment.as_str(), "comment");
+}
+
+/// Starts a stand-in server for member and guest requests
+async fn spawn_member_server() -> MockServer {
+    MockServer::start(|request| {
+        let path = request.path.as_str();
+        if path.ends_with("/shared") {
+            r#"{"shared": {"tasks": ["9hx"], "lists": [], "folders": []}}"#.to_string()
+        } else if request.method == "GET" && path.starts_with("/team/9/user/") {
+            MEMBER.to_string()
+        } else if path.starts_with("/team/") {
+            TEAM.to_string()
+        } else {
+            MEMBER.replace("member", "guest")
+        }
+    })
+    .await
+}
+
+/// Returns a config for the stand-in server with workspace 9 and `--yes`
+fn confirmed_config(server: &MockServer) -> Config {
+    Config {
+        assume_yes: true,
+        ..server_config(&server.url())
+    }
+}
+
+/// Tests inviting a user and then removing them
+#[tokio::test]
+async fn test_invite_and_remove_user() {
+    let _test_config = TestConfig::new();
+    let server = spawn_member_server().await;
+    let config = confirmed_config(&server);
+
+    users::execute(
+        UserCommands::Invite {
+            workspace_id: None,
+            email: "contractor@example.com".to_string(),
+            admin: false,
+            custom_role_id: None,
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    users::execute(
+        UserCommands::Remove {
+            workspace_id: None,
+            id: 42,
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    let summary: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.path.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("POST", "/team/9/user"),
+            ("GET", "/team/9/user/42"),
+            ("DELETE", "/team/9/user/42"),
+        ]
+    );
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({"email": "contractor@example.com", "admin": false})
+    );
+}
+
+/// Tests inviting a guest and sharing a list with them
+#[tokio::test]
+async fn test_add_guest_and_grant_access() {
+    let _test_config = TestConfig::new();
+    let server = spawn_member_server().await;
+    let config = confirmed_config(&server);
+
+    guests::execute(
+        GuestCommands::Add {
+            workspace_id: Some("9".to_string()),
+            email: "contractor@example.com".to_string(),
+            can_edit_tags: false,
+            can_see_time_spent: true,
+            can_see_time_estimated: false,
+            can_create_views: false,
+            custom_role_id: None,
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    guests::execute(
+        GuestCommands::Grant {
+            id: 42,
+            task_id: None,
+            list_id: Some("901".to_string()),
+            folder_id: None,
+            permission: GuestPermission::Edit,
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    guests::execute(
+        GuestCommands::Revoke {
+            id: 42,
+            task_id: Some("abc".to_string()),
+            list_id: None,
+            folder_id: None,
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let requests = server.changes();
+    assert_eq!(requests.len(), 3);
+    assert_eq!((requests[0].0.as_str(), requests[0].1.as_str()), ("POST", "/team/9/guest"));
+    let invite: serde_json::Value = serde_json::from_str(&requests[0].2).unwrap();
+    assert_eq!(invite["can_see_time_spent"], true);
+    assert_eq!(invite["can_edit_tags"], false);
+
+    assert_eq!(
+        (requests[1].0.as_str(), requests[1].1.as_str()),
+        ("POST", "/list/901/guest/42")
+    );
+    assert_eq!(requests[1].2, r#"{"permission_level":"edit"}"#);
+    assert_eq!(
+        (requests[2].0.as_str(), requests[2].1.as_str()),
+        ("DELETE", "/task/abc/guest/42")
+    );
+}
+
+/// Tests that granting `create` on a task is refused before any request
+#[tokio::test]
+async fn test_grant_create_on_task_rejected() {
+    let _test_config = TestConfig::new();
+    let server = spawn_member_server().await;
+
+    let result = guests::execute(
+        GuestCommands::Grant {
+            id: 42,
+            task_id: Some("abc".to_string()),
+            list_id: None,
+            folder_id: None,
+            permission: GuestPermission::Create,
+        },
+        &confirmed_config(&server),
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+    assert!(server.requests().is_empty());
+}
+
+/// Tests fetching the shared hierarchy of the configured workspace
+#[tokio::test]
+async fn test_shared_hierarchy() {
+    let _test_config = TestConfig::new();
+    let server = spawn_member_server().await;
+    let config = confirmed_config(&server);
+
+    let api = ClickUpApi::new(config.clone()).unwrap();
+    let shared = api.get_shared_hierarchy("9").await.unwrap().shared;
+    assert_eq!(shared.tasks[0].id, "9hx");
+
+    workspaces::execute(WorkspaceCommands::Shared { id: None }, &config)
+        .await
+        .unwrap();
+    assert_eq!(server.paths(), vec!["/team/9/shared", "/team/9/shared"]);
+}
@@ -278,9 +278,11 @@ fn test_task_creation() {
         start_date: Some("2023-01-01T00:00:00Z".to_string()),
         time_estimate: Some(3600000), // 1 hour in milliseconds
         time_spent: Some(1800000),    // 30 minutes in milliseconds
+        points: None,
         custom_fields: vec![],
         dependencies: vec![],
         linked_tasks: vec![],
+        attachments: vec![],
         team_id: "team_123".to_string(),
         list: TaskList {
             id: "list_123".to_string(),
@@ -347,9 +349,11 @@ fn test_tasks_response_creation() {
         start_date: None,
         time_estimate: None,
         time_spent: None,
+        points: None,
         custom_fields: vec![],
         dependencies: vec![],
         linked_tasks: vec![],
+        attachments: vec![],
         team_id: "team_123".to_string(),
         list: TaskList {
             id: "list_123".to_string(),
@@ -474,6 +478,8 @@ fn test_comment_creation() {
         comment: vec![CommentText {
             text: "This is a comment".to_string(),
             type_: Some("text".to_string()),
+            attributes: None,
+            user: None,
         }],
         comment_text: "This is a comment".to_string(),
         user: CommentUser {
@@ -514,6 +520,8 @@ fn test_comments_response_creation() {
         comment: vec![CommentText {
             text: "This is a comment".to_string(),
             type_: Some("text".to_string()),
+            attributes: None,
+            user: None,
         }],
         comment_text: "This is a comment".to_string(),
         user: CommentUser {
@@ -552,8 +560,10 @@ fn test_comments_response_creation() {
 fn test_create_comment_request_creation() {
     let request = CreateCommentRequest {
         comment_text: "This is a new comment".to_string(),
+        comment: None,
         assignee: Some(123),
         notify_all: Some(true),
+        resolved: None,
     };
 
     assert_eq!(request.comment_text, "This is a new comment");
@@ -827,6 +837,8 @@ fn test_comment_text_creation() {
     let comment_text = CommentText {
         text: "This is comment text".to_string(),
         type_: Some("text".to_string()),
+        attributes: None,
+        user: None,
     };
 
     assert_eq!(comment_text.text, "This is comment text");
@@ -0,0 +1,290 @@
+//! # OAuth Login Tests
+//!
+//! This module contains tests for the OAuth2 authorization-code flow used by
+//! `auth login --oauth`.
+//!
+//! ## Test Categories
+//!
+//! - **Authorization URL**: Tests for building the authorization request
+//! - **Client Configuration**: Tests for resolving the OAuth app settings
+//! - **Login Flow**: End-to-end tests against a local stand-in authorization server
+//! - **Command**: Tests for the `auth login` command arguments
+//!
+//! ## Test Environment
+//!
+//! Tests run a small stand-in server on `127.0.0.1` that plays the part of
+//! ClickUp's authorization page, token endpoint and `/user` endpoint. The
+//! "browser" is a reqwest client that follows the authorization redirect.
+
+use clickup_cli::api::ClickUpApi;
+use clickup_cli::commands::auth::{execute, AuthCommands};
+use clickup_cli::config::{Config, OAuthConfig, TokenType};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::oauth::{generate_state, OAuthClient, RedirectListener};
+mod test_utils;
+use reqwest::Url;
+use std::time::Duration;
+use test_utils::{MockResponse, MockServer, TestConfig};
+
+const CLIENT_ID: &str = "test_client";
+const CLIENT_SECRET: &str = "test_secret";
+const AUTH_CODE: &str = "test_code";
+const ACCESS_TOKEN: &str = "oauth_access_token";
+
+/// How long the tests wait for a redirect before failing
+const WAIT: Duration = Duration::from_secs(10);
+
+/// Starts the stand-in authorization server and returns its base URL
+///
+/// - `GET /authorize` redirects to `redirect_uri` with a code and the given state
+/// - `POST /oauth/token` returns an access token for the expected app and code
+/// - `GET /user` returns a user when sent the access token with `Bearer`
+async fn spawn_auth_server() -> String {
+    let server = MockServer::start(|request| {
+        let url = Url::parse(&format!("http://localhost{}", request.path)).unwrap();
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        match (request.method.as_str(), url.path()) {
+            ("GET", "/authorize") => {
+                let mut location = Url::parse(&param("redirect_uri").unwrap()).unwrap();
+                location
+                    .query_pairs_mut()
+                    .append_pair("code", AUTH_CODE)
+                    .append_pair("state", &param("state").unwrap_or_default());
+                MockResponse::new("302 Found", "").with_header("Location", location)
+            }
+            ("POST", "/oauth/token")
+                if param("client_id").as_deref() == Some(CLIENT_ID)
+                    && param("client_secret").as_deref() == Some(CLIENT_SECRET)
+                    && param("code").as_deref() == Some(AUTH_CODE) =>
+            {
+                MockResponse::ok(format!(r#"{{"access_token":"{ACCESS_TOKEN}"}}"#))
+            }
+            ("GET", "/user")
+                if request.header("authorization") == Some(&format!("Bearer {ACCESS_TOKEN}")) =>
+            {
+                MockResponse::ok(
+                    r#"{"user":{"id":1,"username":"oauth_user","email":"oauth@example.com"}}"#,
+                )
+            }
+            _ => MockResponse::new(
+                "401 Unauthorized",
+                r#"{"err":"Unauthorized","ECODE":"OAUTH_019"}"#,
+            ),
+        }
+    })
+    .await;
+    server.url()
+}
+
+/// Creates a client for the stand-in authorization server
+fn stand_in_client(base: &str) -> OAuthClient {
+    OAuthClient::new(
+        CLIENT_ID,
+        CLIENT_SECRET,
+        format!("{base}/authorize"),
+        format!("{base}/oauth/token"),
+    )
+}
+
+/// Tests that the authorization URL carries the app, redirect and state
+#[test]
+fn test_authorization_url() {
+    let client = stand_in_client("https://auth.example.com");
+    let url = client.authorization_url("http://localhost:4320/callback", "abc123");
+    let url = Url::parse(&url).unwrap();
+
+    assert_eq!(url.path(), "/authorize");
+    let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    assert!(params.contains(&("client_id".to_string(), CLIENT_ID.to_string())));
+    assert!(params.contains(&(
+        "redirect_uri".to_string(),
+        "http://localhost:4320/callback".to_string()
+    )));
+    assert!(params.contains(&("state".to_string(), "abc123".to_string())));
+}
+
+/// Tests that generated states are random hex strings
+#[test]
+fn test_generate_state() {
+    let first = generate_state();
+    assert_eq!(first.len(), 32);
+    assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_ne!(first, generate_state());
+}
+
+/// Tests resolving the OAuth app from the configuration
+///
+/// This test verifies that options override the `[oauth]` table and that a
+/// missing client ID is reported as a configuration error.
+#[test]
+fn test_client_from_config() {
+    let config = Config {
+        oauth: OAuthConfig {
+            client_id: Some("configured_id".to_string()),
+            client_secret: Some("configured_secret".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let client = OAuthClient::from_config(&config, None, None).unwrap();
+    assert_eq!(client.client_id(), "configured_id");
+    let client = OAuthClient::from_config(&config, Some("flag_id".to_string()), None).unwrap();
+    assert_eq!(client.client_id(), "flag_id");
+    assert_eq!(client.client_secret(), "configured_secret");
+
+    let result = OAuthClient::from_config(&Config::default(), None, Some("s".to_string()));
+    assert!(matches!(result, Err(ClickUpError::ConfigError(_))));
+}
+
+/// Tests the full login flow against the stand-in server
+///
+/// This test verifies that the redirect delivers the code to the listener,
+/// that the code is exchanged for an access token, and that the token is
+/// sent with the `Bearer` scheme once recorded as an OAuth token.
+#[tokio::test]
+async fn test_oauth_login_flow() {
+    let base = spawn_auth_server().await;
+    let client = stand_in_client(&base);
+    let listener = RedirectListener::bind(0).await.unwrap();
+    let state = generate_state();
+    let url = client.authorization_url(&listener.redirect_uri(), &state);
+
+    let browser = tokio::spawn(async move { reqwest::get(url).await.unwrap() });
+    let code = listener.wait_for_code(&state, WAIT).await.unwrap();
+    assert_eq!(code, AUTH_CODE);
+    let page = browser.await.unwrap();
+    assert!(page.status().is_success());
+    assert!(page.text().await.unwrap().contains("login complete"));
+
+    let token = client.exchange_code(&code).await.unwrap();
+    assert_eq!(token, ACCESS_TOKEN);
+
+    let config = Config {
+        api_token: Some(token),
+        token_type: TokenType::OAuth,
+        api_base_url: base,
+        ..Default::default()
+    };
+    let user = ClickUpApi::new(config).unwrap().get_user().await.unwrap();
+    assert_eq!(user.user.username.as_deref(), Some("oauth_user"));
+}
+
+/// Tests that a redirect with the wrong state is rejected without ending the login
+///
+/// This test verifies that the forged redirect gets `400 Bad Request` and
+/// that the listener keeps waiting for the genuine one.
+#[tokio::test]
+async fn test_oauth_state_mismatch() {
+    let base = spawn_auth_server().await;
+    let client = stand_in_client(&base);
+    let listener = RedirectListener::bind(0).await.unwrap();
+    let forged = client.authorization_url(&listener.redirect_uri(), "forged_state");
+    let genuine = client.authorization_url(&listener.redirect_uri(), "expected_state");
+
+    let browser = tokio::spawn(async move {
+        let forged = reqwest::get(forged).await.unwrap();
+        let genuine = reqwest::get(genuine).await.unwrap();
+        (forged.status(), genuine.status())
+    });
+    let code = listener.wait_for_code("expected_state", WAIT).await.unwrap();
+    assert_eq!(code, AUTH_CODE);
+    let (forged, genuine) = browser.await.unwrap();
+    assert_eq!(forged, 400);
+    assert!(genuine.is_success());
+}
+
+/// Tests that the listener gives up when no redirect arrives
+#[tokio::test]
+async fn test_oauth_wait_timeout() {
+    let listener = RedirectListener::bind(0).await.unwrap();
+    let result = listener.wait_for_code("s", Duration::from_millis(50)).await;
+    match result {
+        Err(ClickUpError::AuthError(message)) => assert!(message.contains("Timed out")),
+        other => panic!("Expected AuthError, got {other:?}"),
+    }
+}
+
+/// Tests that a denied authorization is reported
+///
+/// This test also verifies that requests for other paths are ignored.
+#[tokio::test]
+async fn test_oauth_authorization_denied() {
+    let listener = RedirectListener::bind(0).await.unwrap();
+    let redirect_uri = listener.redirect_uri();
+
+    let browser = tokio::spawn(async move {
+        let origin = redirect_uri.trim_end_matches("/callback").to_string();
+        let favicon = reqwest::get(format!("{origin}/favicon.ico")).await.unwrap();
+        assert_eq!(favicon.status(), 404);
+        reqwest::get(format!("{redirect_uri}?error=access_denied&state=s"))
+            .await
+            .unwrap()
+    });
+    let result = listener.wait_for_code("s", WAIT).await;
+    match result {
+        Err(ClickUpError::AuthError(message)) => assert!(message.contains("access_denied")),
+        other => panic!("Expected AuthError, got {other:?}"),
+    }
+    assert_eq!(browser.await.unwrap().status(), 400);
+}
+
+/// Tests that the `error` parameter is echoed back as text, not markup
+#[tokio::test]
+async fn test_oauth_error_page_is_plain_text() {
+    let listener = RedirectListener::bind(0).await.unwrap();
+    let redirect_uri = listener.redirect_uri();
+
+    let browser = tokio::spawn(async move {
+        let mut url = Url::parse(&redirect_uri).unwrap();
+        url.query_pairs_mut()
+            .append_pair("error", "<script>alert(1)</script>")
+            .append_pair("state", "s");
+        reqwest::get(url).await.unwrap()
+    });
+    assert!(listener.wait_for_code("s", WAIT).await.is_err());
+
+    let page = browser.await.unwrap();
+    let content_type = page.headers()["content-type"].to_str().unwrap().to_string();
+    assert!(content_type.starts_with("text/plain"));
+    assert_eq!(
+        page.text().await.unwrap(),
+        "Authentication failed: Authorization denied: <script>alert(1)</script>"
+    );
+}
+
+/// Tests that a rejected code exchange returns an authentication error
+#[tokio::test]
+async fn test_oauth_exchange_rejected() {
+    let base = spawn_auth_server().await;
+    let client = stand_in_client(&base);
+
+    let result = client.exchange_code("wrong_code").await;
+    assert!(matches!(result, Err(ClickUpError::AuthError(_))));
+}
+
+/// Tests that `auth login` requires `--oauth`
+#[tokio::test]
+async fn test_auth_login_requires_oauth_flag() {
+    let _test_config = TestConfig::new();
+    let mut config = Config::default();
+
+    let result = execute(
+        AuthCommands::Login {
+            oauth: false,
+            client_id: None,
+            client_secret: None,
+            port: None,
+            no_browser: true,
+            storage: None,
+        },
+        &mut config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
@@ -0,0 +1,275 @@
+//! # Offline Tests
+//!
+//! This module contains tests for reading space backups in place of the
+//! ClickUp API with `--offline`.
+//!
+//! ## Test Categories
+//!
+//! - **Data Source**: Tests for the lists, tasks and comments a backup returns
+//! - **Commands**: Tests for task, list, comment and search commands run offline
+//!
+//! ## Test Environment
+//!
+//! Backups are written to a temporary directory. The configuration points at
+//! an address nothing listens on and has no token, so any request would fail.
+
+use clickup_cli::backup::SpaceBackup;
+use clickup_cli::commands::search::SearchArgs;
+use clickup_cli::commands::{comments, lists, search, tasks};
+use clickup_cli::config::Config;
+use clickup_cli::data_source::DataSource;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::CommentTarget;
+mod test_utils;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+use test_utils::TestConfig;
+
+/// Returns a minimal task in a list, optionally under a parent task
+fn task_json(id: &str, list_id: &str, parent: Option<&str>) -> serde_json::Value {
+    json!({
+        "id": id, "name": format!("Task {id}"), "text_content": "", "description": "",
+        "status": {"id": "s", "status": "open", "color": "#000", "orderindex": 0, "type": "open"},
+        "orderindex": "1", "date_created": "1", "date_updated": "1",
+        "creator": {"id": 1, "username": "owner", "color": "#000"},
+        "assignees": [], "watchers": [], "checklists": [], "tags": [],
+        "custom_fields": [], "team_id": "9", "parent": parent,
+        "list": {"id": list_id, "access": true}, "space": {"id": "77"},
+        "url": format!("https://app.clickup.com/t/{id}")
+    })
+}
+
+/// Returns a minimal list in space 77
+fn list_json(id: &str, name: &str) -> serde_json::Value {
+    json!({"id": id, "name": name, "orderindex": 0, "space": {"id": "77", "name": "Engineering"}})
+}
+
+/// Writes a backup of two lists, a task tree, a closed task and a comment,
+/// and returns its path
+fn write_backup(dir: &TempDir, include_comments: bool) -> PathBuf {
+    let mut closed = task_json("t5", "902", None);
+    closed["status"] = json!({"id": "c", "status": "complete", "color": "#000", "orderindex": 1, "type": "closed"});
+    let backup = json!({
+        "metadata": {"created_at": "2025-07-01T00:00:00Z", "space_id": "77", "include_comments": include_comments},
+        "space": null,
+        "folders": [],
+        "lists": [list_json("901", "Backlog"), list_json("902", "Sprint")],
+        "tasks": [
+            task_json("t1", "901", None),
+            task_json("t2", "901", Some("t1")),
+            task_json("t3", "901", Some("t2")),
+            task_json("t4", "902", None),
+            closed
+        ],
+        "comments": [{"task_id": "t1", "task_name": "Task t1", "comments": [{
+            "id": "c1", "comment": [{"text": "ship it"}], "comment_text": "ship it",
+            "user": {"id": 1, "username": "owner", "color": "#000"},
+            "reactions": [], "date": "1700000000000", "reply_count": 0
+        }]}]
+    });
+    let path = dir.path().join("backup.json");
+    fs::write(&path, serde_json::to_string_pretty(&backup).unwrap()).unwrap();
+    path
+}
+
+/// Returns a configuration that reads the backup and can't reach ClickUp
+fn offline_config(path: &Path) -> Config {
+    Config {
+        api_token: None,
+        api_base_url: "http://127.0.0.1:1/api/v2".to_string(),
+        offline: Some(path.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+/// Tests the lists and tasks a backup returns
+#[tokio::test]
+async fn test_backup_lists_and_tasks() {
+    let dir = TempDir::new().unwrap();
+    let backup = SpaceBackup::load(&write_backup(&dir, true)).unwrap();
+
+    let lists = backup.get_lists("77").await.unwrap();
+    assert_eq!(lists.lists.len(), 2);
+    assert!(matches!(
+        backup.get_lists("78").await,
+        Err(ClickUpError::NotFoundError(_))
+    ));
+
+    let tasks = DataSource::get_tasks(&backup, "901").await.unwrap();
+    let ids: Vec<&str> = tasks.tasks.iter().map(|task| task.id.as_str()).collect();
+    assert_eq!(ids, ["t1", "t2", "t3"]);
+    // Closed tasks are only returned when asked for, as from the API
+    assert_eq!(DataSource::get_tasks(&backup, "902").await.unwrap().tasks.len(), 1);
+    assert_eq!(backup.get_tasks_including_closed("902").await.unwrap().tasks.len(), 2);
+    assert!(matches!(
+        DataSource::get_tasks(&backup, "999").await,
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Tests that a task read from a backup has its subtasks at any depth
+#[tokio::test]
+async fn test_backup_task_with_subtasks() {
+    let dir = TempDir::new().unwrap();
+    let backup = SpaceBackup::load(&write_backup(&dir, true)).unwrap();
+
+    let task = backup.get_task_with_subtasks("t1").await.unwrap();
+    let mut subtasks: Vec<&str> = task
+        .subtasks
+        .as_deref()
+        .unwrap()
+        .iter()
+        .map(|subtask| subtask.id.as_str())
+        .collect();
+    subtasks.sort();
+    assert_eq!(subtasks, ["t2", "t3"]);
+
+    let leaf = backup.get_task_with_subtasks("t4").await.unwrap();
+    assert!(leaf.subtasks.unwrap().is_empty());
+    assert!(matches!(
+        DataSource::get_task(&backup, "missing").await,
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Tests the comments a backup returns, with and without comments saved
+#[tokio::test]
+async fn test_backup_comments() {
+    let dir = TempDir::new().unwrap();
+    let backup = SpaceBackup::load(&write_backup(&dir, true)).unwrap();
+
+    let comments = backup
+        .get_target_comments(CommentTarget::Task, "t1")
+        .await
+        .unwrap();
+    assert_eq!(comments.comments[0].comment_text, "ship it");
+    let none = backup
+        .get_target_comments(CommentTarget::Task, "t4")
+        .await
+        .unwrap();
+    assert!(none.comments.is_empty());
+    assert!(matches!(
+        backup.get_target_comments(CommentTarget::List, "901").await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        backup.get_target_comments(CommentTarget::Task, "missing").await,
+        Err(ClickUpError::NotFoundError(_))
+    ));
+
+    let without_comments = TempDir::new().unwrap();
+    let backup = SpaceBackup::load(&write_backup(&without_comments, false)).unwrap();
+    assert!(matches!(
+        backup.get_target_comments(CommentTarget::Task, "t1").await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests that task reads answer from the backup and changes are refused
+#[tokio::test]
+async fn test_offline_task_commands() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let config = offline_config(&write_backup(&dir, true));
+
+    for tree in [false, true] {
+        let command = tasks::TaskCommands::List {
+            list_id: "901".to_string(),
+            tree,
+        };
+        tasks::execute(command, &config).await.unwrap();
+    }
+    let show = tasks::TaskCommands::Show {
+        id: "t1".to_string(),
+    };
+    tasks::execute(show, &config).await.unwrap();
+    let subtasks = tasks::TaskCommands::Subtasks {
+        id: "t2".to_string(),
+    };
+    tasks::execute(subtasks, &config).await.unwrap();
+
+    let missing = tasks::TaskCommands::Show {
+        id: "missing".to_string(),
+    };
+    assert!(matches!(
+        tasks::execute(missing, &config).await,
+        Err(ClickUpError::NotFoundError(_))
+    ));
+    let delete = tasks::TaskCommands::Delete {
+        id: "t1".to_string(),
+    };
+    assert!(matches!(
+        tasks::execute(delete, &config).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests listing lists, comments and search results offline
+#[tokio::test]
+async fn test_offline_list_comment_and_search_commands() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let config = offline_config(&write_backup(&dir, true));
+
+    let list = lists::ListCommands::List {
+        space_id: "77".to_string(),
+    };
+    lists::execute(list, &config).await.unwrap();
+    let show = lists::ListCommands::Show {
+        id: "901".to_string(),
+    };
+    assert!(matches!(
+        lists::execute(show, &config).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+
+    let task_comments = comments::CommentCommands::List {
+        task_id: Some("t1".to_string()),
+        list_id: None,
+        view_id: None,
+    };
+    comments::execute(task_comments, &config).await.unwrap();
+    let delete = comments::CommentCommands::Delete {
+        id: "c1".to_string(),
+        task_id: None,
+        list_id: None,
+        view_id: None,
+    };
+    assert!(matches!(
+        comments::execute(delete, &config).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+
+    let args = SearchArgs {
+        text: "Task".to_string(),
+        workspace_id: None,
+        list_id: Some("901".to_string()),
+        space_id: None,
+        comments: true,
+        regex: false,
+        ignore_case: false,
+        include_closed: false,
+        limit: 20,
+        from_backup: None,
+    };
+    search::execute(args, &config).await.unwrap();
+}
+
+/// Tests that a missing backup file is reported before anything else
+#[tokio::test]
+async fn test_offline_missing_backup() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let config = offline_config(&dir.path().join("missing.json"));
+
+    let command = tasks::TaskCommands::List {
+        list_id: "901".to_string(),
+        tree: false,
+    };
+    assert!(matches!(
+        tasks::execute(command, &config).await,
+        Err(ClickUpError::IoError(_))
+    ));
+}
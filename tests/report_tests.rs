@@ -0,0 +1,290 @@
+//! # Report Tests
+//!
+//! This module contains tests for workload and burndown reports and the
+//! `reports` commands.
+//!
+//! ## Test Categories
+//!
+//! - **Workload**: Tests for grouping open tasks and summing their work
+//! - **Burndown**: Tests for reconstructing daily remaining work
+//! - **Charts**: Tests for the ASCII charts and number formatting
+//! - **Command**: Tests for the requests made against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requested paths.
+
+use chrono::NaiveDate;
+use clickup_cli::commands::reports::{execute, ReportCommands};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::Task;
+use clickup_cli::reports::{
+    burndown, burndown_chart, format_points, workload, workload_chart, workload_total, GroupBy,
+    ReportFormat,
+};
+mod test_utils;
+use serde_json::{json, Value};
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// 2025-07-01T00:00:00Z in milliseconds
+const JULY_1: i64 = 1_751_328_000_000;
+
+/// One day in milliseconds
+const DAY: i64 = 86_400_000;
+
+/// Returns a minimal open task in list 901 created on July 1
+fn report_task(id: &str) -> Value {
+    let mut task = task_json(id);
+    task["date_created"] = json!(JULY_1.to_string());
+    task["date_updated"] = json!(JULY_1.to_string());
+    task
+}
+
+/// Returns an assignee with a username
+fn assignee(id: i64, username: &str) -> Value {
+    json!({"id": id, "username": username, "color": "#000", "email": format!("{username}@acme.test")})
+}
+
+/// Returns a task decoded from JSON
+fn task(value: Value) -> Task {
+    serde_json::from_value(value).unwrap()
+}
+
+/// Returns tasks with different assignees, estimates, points and due dates
+fn workload_tasks() -> Vec<Task> {
+    let mut shared = report_task("t1");
+    shared["assignees"] = json!([assignee(1, "alice"), assignee(2, "bob")]);
+    shared["time_estimate"] = json!(3_600_000);
+    shared["points"] = json!(3);
+    shared["due_date"] = json!((JULY_1 - DAY).to_string());
+
+    let mut alice = report_task("t2");
+    alice["assignees"] = json!([assignee(1, "alice")]);
+    alice["time_estimate"] = json!(1_800_000);
+    alice["points"] = json!(1.5);
+    alice["status"]["status"] = json!("in progress");
+    alice["due_date"] = json!((JULY_1 + DAY).to_string());
+
+    let mut unassigned = report_task("t3");
+    unassigned["list"] = json!({"id": "902", "name": "Sprint 1", "access": true});
+
+    let mut done = report_task("t4");
+    done["assignees"] = json!([assignee(2, "bob")]);
+    done["status"] = json!({"id": "c", "status": "complete", "color": "#0f0", "orderindex": 2, "type": "closed"});
+
+    [shared, alice, unassigned, done].into_iter().map(task).collect()
+}
+
+/// Tests grouping open tasks by assignee, with shared tasks counted for each
+#[test]
+fn test_workload_by_assignee() {
+    let rows = workload(&workload_tasks(), GroupBy::Assignee, JULY_1);
+
+    let groups: Vec<(&str, usize)> = rows
+        .iter()
+        .map(|row| (row.group.as_str(), row.tasks))
+        .collect();
+    assert_eq!(groups, [("alice", 2), ("Unassigned", 1), ("bob", 1)]);
+
+    let alice = &rows[0];
+    assert_eq!(alice.time_estimate, 5_400_000);
+    assert_eq!(alice.points, 4.5);
+    assert_eq!(alice.overdue, 1);
+
+    // The done task doesn't count for bob
+    assert_eq!(rows[2].points, 3.0);
+
+    // The total counts each open task once
+    let total = workload_total(&workload_tasks(), JULY_1);
+    assert_eq!(total.group, "Total");
+    assert_eq!(total.tasks, 3);
+    assert_eq!(total.time_estimate, 5_400_000);
+    assert_eq!(total.overdue, 1);
+}
+
+/// Tests grouping open tasks by status and by list
+#[test]
+fn test_workload_by_status_and_list() {
+    let by_status = workload(&workload_tasks(), GroupBy::Status, JULY_1);
+    let statuses: Vec<(&str, usize)> = by_status
+        .iter()
+        .map(|row| (row.group.as_str(), row.tasks))
+        .collect();
+    assert_eq!(statuses, [("to do", 2), ("in progress", 1)]);
+
+    let by_list = workload(&workload_tasks(), GroupBy::List, JULY_1);
+    let lists: Vec<(&str, usize)> = by_list
+        .iter()
+        .map(|row| (row.group.as_str(), row.tasks))
+        .collect();
+    assert_eq!(lists, [("Backlog", 2), ("Sprint 1", 1)]);
+
+    assert!(workload(&[], GroupBy::List, JULY_1).is_empty());
+}
+
+/// Returns the day `offset` days after July 1, 2025
+fn july(offset: i64) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 7, 1).unwrap() + chrono::Duration::days(offset)
+}
+
+/// Tests reconstructing remaining tasks from creation and close dates
+#[test]
+fn test_burndown() {
+    let mut early = report_task("t1");
+    early["points"] = json!(2);
+    early["time_estimate"] = json!(3_600_000);
+    early["date_closed"] = json!((JULY_1 + DAY + 3_600_000).to_string());
+
+    let mut late = report_task("t2");
+    late["date_created"] = json!((JULY_1 + 2 * DAY + 60_000).to_string());
+
+    // Done without a close date: closed when last updated
+    let mut done = report_task("t3");
+    done["status"]["type"] = json!("done");
+    done["date_updated"] = json!((JULY_1 + 3 * DAY).to_string());
+
+    let mut open = report_task("t4");
+    open["time_estimate"] = json!(1_800_000);
+    let tasks: Vec<Task> = [early, late, done, open].into_iter().map(task).collect();
+
+    let days = burndown(&tasks, july(0), july(3));
+    let remaining: Vec<(NaiveDate, usize)> =
+        days.iter().map(|day| (day.date, day.remaining)).collect();
+    assert_eq!(
+        remaining,
+        [(july(0), 3), (july(1), 2), (july(2), 3), (july(3), 2)]
+    );
+    assert_eq!(days[0].remaining_points, 2.0);
+    assert_eq!(days[1].remaining_points, 0.0);
+    assert_eq!(days[0].remaining_estimate, 5_400_000);
+    assert_eq!(days[1].remaining_estimate, 1_800_000);
+    let json = serde_json::to_value(&days[0]).unwrap();
+    assert_eq!(json["remaining_estimate"], 5_400_000);
+
+    // The ideal line falls evenly from the first day's remaining work to zero
+    let ideal: Vec<f64> = days.iter().map(|day| day.ideal).collect();
+    assert_eq!(ideal, [3.0, 2.0, 1.0, 0.0]);
+
+    let single = burndown(&tasks, july(0), july(0));
+    assert_eq!(single.len(), 1);
+    assert_eq!(single[0].ideal, 3.0);
+}
+
+/// Tests the charts and how points are formatted
+#[test]
+fn test_charts_and_points() {
+    assert_eq!(format_points(3.0), "3");
+    assert_eq!(format_points(2.5), "2.5");
+    assert_eq!(format_points(0.0), "0");
+
+    let rows = workload(&workload_tasks(), GroupBy::Assignee, JULY_1);
+    let chart = workload_chart(&rows);
+    let lines: Vec<&str> = chart.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("alice      │"));
+    assert_eq!(lines[0].matches('█').count(), 40);
+    assert!(lines[0].ends_with(" 2 (1 overdue)"));
+    assert_eq!(lines[2].matches('█').count(), 20);
+
+    let tasks = vec![task(report_task("t1")), task(report_task("t2"))];
+    let chart = burndown_chart(&burndown(&tasks, july(0), july(1)));
+    let lines: Vec<&str> = chart.lines().collect();
+    assert_eq!(lines[0], format!("2025-07-01 │{} 2", "█".repeat(40)));
+    // Nothing burned, so the ideal line falls inside the full bar and isn't marked
+    assert_eq!(lines[1], format!("2025-07-02 │{} 2", "█".repeat(40)));
+}
+
+/// Starts a stand-in server with space 77 holding lists 901 and 902
+async fn spawn_report_server() -> MockServer {
+    MockServer::start(|request| {
+        let list = |id: &str, name: &str| {
+            json!({"id": id, "name": name, "orderindex": 0, "space": {"id": "77", "name": "Engineering"}})
+        };
+        let mut shared = report_task("t1");
+        shared["assignees"] = json!([assignee(1, "alice")]);
+        match request.route() {
+            "/api/v2/space/77/list" => json!({"lists": [list("901", "Backlog")]}),
+            "/api/v2/space/77/folder" => json!({"folders": [
+                {"id": "5", "name": "Sprints", "orderindex": 0, "space": {"id": "77", "name": "Engineering"}}
+            ]}),
+            "/api/v2/folder/5/list" => json!({"lists": [list("902", "Sprint 1")]}),
+            "/api/v2/list/901/task" => json!({"tasks": [shared.clone(), report_task("t2")]}),
+            "/api/v2/list/902/task" => json!({"tasks": [shared]}),
+            _ => json!({}),
+        }
+    })
+    .await
+}
+
+/// Tests that a workload report reads every list in the space
+#[tokio::test]
+async fn test_workload_command() {
+    let _test_config = TestConfig::new();
+    let server = spawn_report_server().await;
+    let config = server_config(&server.api_url());
+
+    for format in [ReportFormat::Table, ReportFormat::Json, ReportFormat::Chart] {
+        let command = ReportCommands::Workload {
+            space_id: "77".to_string(),
+            by: GroupBy::Assignee,
+            format,
+        };
+        execute(command, &config).await.unwrap();
+    }
+
+    let requests = server.paths();
+    for path in ["/api/v2/list/901/task", "/api/v2/list/902/task"] {
+        assert!(
+            requests.iter().any(|request| request.starts_with(path)),
+            "{path} was not requested"
+        );
+    }
+}
+
+/// Tests that a burndown report includes closed tasks and checks its dates
+///
+/// Reversed ranges and ranges over a year are rejected before any request.
+#[tokio::test]
+async fn test_burndown_command() {
+    let _test_config = TestConfig::new();
+    let server = spawn_report_server().await;
+    let config = server_config(&server.api_url());
+
+    let command = ReportCommands::Burndown {
+        list_id: "901".to_string(),
+        from: july(0),
+        to: july(6),
+        format: ReportFormat::Chart,
+    };
+    execute(command, &config).await.unwrap();
+    assert!(server
+        .paths()
+        .iter()
+        .any(|request| request.starts_with("/api/v2/list/901/task")
+            && request.contains("include_closed=true")));
+
+    let reversed = ReportCommands::Burndown {
+        list_id: "901".to_string(),
+        from: july(6),
+        to: july(0),
+        format: ReportFormat::Table,
+    };
+    assert!(matches!(
+        execute(reversed, &config).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+
+    let requests = server.paths().len();
+    let too_long = ReportCommands::Burndown {
+        list_id: "901".to_string(),
+        from: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
+        to: NaiveDate::from_ymd_opt(9999, 12, 31).unwrap(),
+        format: ReportFormat::Table,
+    };
+    assert!(matches!(
+        execute(too_long, &config).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert_eq!(server.paths().len(), requests);
+}
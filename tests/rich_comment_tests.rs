@@ -0,0 +1,362 @@
+//! # Rich Comment Tests
+//!
+//! This module contains tests for Markdown comments, mentions and assignees
+//! given by username.
+//!
+//! ## Test Categories
+//!
+//! - **Markdown**: Tests for converting Markdown into rich text segments
+//! - **Rendering**: Tests for laying rich text out for the terminal
+//! - **Members**: Tests for finding mentioned members and assignees
+//! - **Command**: End-to-end tests against a local stand-in server, including
+//!   comment threads on lists and views
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with a fixed workspace and comment.
+
+use clickup_cli::commands::comments::{execute, resolve_assignee, CommentCommands};
+use clickup_cli::commands::users::find_member_by_username;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{Comment, Workspace};
+use clickup_cli::rich_text::{
+    link_mentions, markdown_to_blocks, mention_count, mention_names, plain_text, text_blocks,
+};
+use serde_json::json;
+mod test_utils;
+use test_utils::{server_config, MockServer, TestConfig};
+
+/// A workspace with a two-word username and two members named `pat`
+const WORKSPACE: &str = r##"{
+    "id": 9, "name": "Acme", "color": "#000",
+    "members": [
+        {"user": {"id": 1, "username": "Jane Doe", "email": "jane@acme.test"}},
+        {"user": {"id": 2, "username": "sam", "email": "sam@acme.test"}},
+        {"user": {"id": 3, "username": "Pat", "email": "pat@acme.test"}},
+        {"user": {"id": 4, "username": "pat", "email": "pat.two@acme.test"}}
+    ]
+}"##;
+
+/// A list's comment thread with one rich standup comment
+const COMMENTS: &str = r#"{
+    "comments": [{
+        "id": "90",
+        "comment": [{"text": "Standup", "attributes": {"bold": true}}, {"text": "\n"}],
+        "comment_text": "Standup\n",
+        "user": {"id": 2, "username": "sam"},
+        "reactions": [],
+        "date": "1700000000000",
+        "reply_count": 0
+    }]
+}"#;
+
+/// Returns the test workspace
+fn workspace() -> Workspace {
+    serde_json::from_str(WORKSPACE).unwrap()
+}
+
+/// Tests converting inline Markdown into formatted segments
+#[test]
+fn test_markdown_inline_formatting() {
+    let blocks = markdown_to_blocks("Run **`cargo test`** and see [the docs](https://docs.rs), *please*");
+    let value = serde_json::to_value(&blocks).unwrap();
+
+    assert_eq!(
+        value,
+        json!([
+            {"text": "Run "},
+            {"text": "cargo test", "attributes": {"bold": true, "code": true}},
+            {"text": " and see "},
+            {"text": "the docs", "attributes": {"link": "https://docs.rs"}},
+            {"text": ", "},
+            {"text": "please", "attributes": {"italic": true}}
+        ])
+    );
+}
+
+/// Tests that unmatched markers and `snake_case` names stay as text
+#[test]
+fn test_markdown_leaves_plain_text_alone() {
+    let blocks = markdown_to_blocks("Rename my_var_name to 2 * 3 and [link] ** here");
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].text, "Rename my_var_name to 2 * 3 and [link] ** here");
+    assert_eq!(blocks[0].attributes, None);
+}
+
+/// Tests that list items, headings and code blocks format their line
+#[test]
+fn test_markdown_line_formatting() {
+    let markdown = "# Plan\n- first\n- **second**\n1. one\n2. two\n```\nlet x = 1;\n```\nDone";
+    let value = serde_json::to_value(markdown_to_blocks(markdown)).unwrap();
+
+    assert_eq!(
+        value,
+        json!([
+            {"text": "Plan"},
+            {"text": "\n", "attributes": {"header": 1}},
+            {"text": "first"},
+            {"text": "\n", "attributes": {"list": {"list": "bullet"}}},
+            {"text": "second", "attributes": {"bold": true}},
+            {"text": "\n", "attributes": {"list": {"list": "bullet"}}},
+            {"text": "one"},
+            {"text": "\n", "attributes": {"list": {"list": "ordered"}}},
+            {"text": "two"},
+            {"text": "\n", "attributes": {"list": {"list": "ordered"}}},
+            {"text": "let x = 1;"},
+            {"text": "\n", "attributes": {"code-block": {"code-block": "plain"}}},
+            {"text": "Done"}
+        ])
+    );
+}
+
+/// Tests turning resolved names into mentions, but not names in code
+#[test]
+fn test_link_mentions() {
+    let blocks = markdown_to_blocks("Thanks @sam and @nobody, see `@sam` or mail sam@acme.test.");
+    assert_eq!(mention_names(&blocks), vec!["sam", "nobody"]);
+
+    let blocks = link_mentions(blocks, |name| Ok((name == "sam").then_some(2))).unwrap();
+    assert_eq!(mention_count(&blocks), 1);
+    let value = serde_json::to_value(&blocks).unwrap();
+    assert_eq!(value[0], json!({"text": "Thanks "}));
+    assert_eq!(value[1], json!({"type": "tag", "user": {"id": 2}}));
+    assert_eq!(value[2], json!({"text": " and @nobody, see "}));
+    assert_eq!(value[3], json!({"text": "@sam", "attributes": {"code": true}}));
+
+    let result = link_mentions(text_blocks("hi @pat"), |_| {
+        Err(ClickUpError::ValidationError("ambiguous".to_string()))
+    });
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
+
+/// Tests laying out a comment from the API, including formatting the CLI
+/// does not know
+#[test]
+fn test_render_api_comment() {
+    let comment: Comment = serde_json::from_value(json!({
+        "id": "90",
+        "comment": [
+            {"text": "Steps", "attributes": {"bold": true}},
+            {"text": "\n"},
+            {"text": "Build"},
+            {"text": "\n", "attributes": {"list": {"list": "ordered"}}},
+            {"text": "Ship to "},
+            {"text": "prod", "attributes": {"link": "https://acme.test", "color": 3}},
+            {"text": "\n", "attributes": {"list": {"list": "ordered"}}},
+            {"text": "Ask "},
+            {"type": "tag", "text": "@Jane Doe", "user": {"id": 1, "username": "Jane Doe"}},
+            {"text": " ", "attributes": {"bold": "yes"}},
+            {"type": "emoticon", "text": "🎉", "emoticon": {"code": "1f389"}},
+            {"text": "\n"}
+        ],
+        "comment_text": "Steps\nBuild\nShip to prod\nAsk @Jane Doe 🎉\n",
+        "user": {"id": 1, "username": "Jane Doe"},
+        "reactions": [],
+        "date": "1700000000000",
+        "reply_count": 0
+    }))
+    .unwrap();
+
+    assert_eq!(
+        plain_text(&comment.comment),
+        "Steps\n  1. Build\n  2. Ship to prod (https://acme.test)\nAsk @Jane Doe 🎉"
+    );
+    assert_eq!(comment.comment[9].attributes, None);
+}
+
+/// Tests finding members by username and resolving assignees
+#[test]
+fn test_resolve_members() {
+    let workspace = workspace();
+
+    assert_eq!(find_member_by_username(&workspace, "janedoe").unwrap().unwrap().id, Some(1));
+    assert!(find_member_by_username(&workspace, "nobody").unwrap().is_none());
+    assert!(matches!(
+        find_member_by_username(&workspace, "PAT"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+
+    assert_eq!(resolve_assignee("42", None).unwrap(), 42);
+    assert_eq!(resolve_assignee("@Jane Doe", Some(&workspace)).unwrap(), 1);
+    assert_eq!(resolve_assignee("SAM@acme.test", Some(&workspace)).unwrap(), 2);
+    assert!(matches!(
+        resolve_assignee("sam", None),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        resolve_assignee("nobody", Some(&workspace)),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Starts a stand-in server that answers workspace and comment requests
+async fn spawn_comment_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.path.starts_with("/team/") {
+            WORKSPACE
+        } else if request.method == "GET" && request.path.ends_with("/comment") {
+            COMMENTS
+        } else {
+            r#"{"id": "90"}"#
+        }
+    })
+    .await
+}
+
+/// Tests creating a Markdown comment with a mention and an assignee by name
+#[tokio::test]
+async fn test_create_markdown_comment() {
+    let _test_config = TestConfig::new();
+    let server = spawn_comment_server().await;
+
+    execute(
+        CommentCommands::Create {
+            task_id: Some("abc".to_string()),
+            list_id: None,
+            view_id: None,
+            text: "**Review** by @janedoe".to_string(),
+            assignee: Some("sam".to_string()),
+            notify_all: None,
+            markdown: true,
+            workspace_id: None,
+        },
+        &server_config(&server.url()),
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!((requests[0].method.as_str(), requests[0].path.as_str()), ("GET", "/team/9"));
+    assert_eq!(
+        (requests[1].method.as_str(), requests[1].path.as_str()),
+        ("POST", "/task/abc/comment")
+    );
+    let body = requests[1].json();
+    assert_eq!(
+        body,
+        json!({
+            "comment": [
+                {"text": "Review", "attributes": {"bold": true}},
+                {"text": " by "},
+                {"type": "tag", "user": {"id": 1}}
+            ],
+            "assignee": 2,
+            "notify_all": null
+        })
+    );
+}
+
+/// Tests that plain text without mentions is sent as before, without
+/// looking up members
+#[tokio::test]
+async fn test_create_plain_comment() {
+    let _test_config = TestConfig::new();
+    let server = spawn_comment_server().await;
+
+    execute(
+        CommentCommands::Create {
+            task_id: Some("abc".to_string()),
+            list_id: None,
+            view_id: None,
+            text: "Looks good, mail me at sam@acme.test".to_string(),
+            assignee: Some("7".to_string()),
+            notify_all: Some(true),
+            markdown: false,
+            workspace_id: None,
+        },
+        &server_config(&server.url()),
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let body = requests[0].json();
+    assert_eq!(
+        body,
+        json!({
+            "comment_text": "Looks good, mail me at sam@acme.test",
+            "assignee": 7,
+            "notify_all": true
+        })
+    );
+}
+
+/// Tests posting to and listing a list's comment thread, and listing a view's
+#[tokio::test]
+async fn test_list_and_view_comments() {
+    let _test_config = TestConfig::new();
+    let server = spawn_comment_server().await;
+    let config = server_config(&server.url());
+
+    execute(
+        CommentCommands::Create {
+            task_id: None,
+            list_id: Some("901".to_string()),
+            view_id: None,
+            text: "- shipped search\n- next: **imports**".to_string(),
+            assignee: None,
+            notify_all: None,
+            markdown: true,
+            workspace_id: None,
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    for (list_id, view_id) in [(Some("901"), None), (None, Some("3v-1"))] {
+        execute(
+            CommentCommands::List {
+                task_id: None,
+                list_id: list_id.map(str::to_string),
+                view_id: view_id.map(str::to_string),
+            },
+            &config,
+        )
+        .await
+        .unwrap();
+    }
+
+    let requests = server.requests();
+    let summary: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.path.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("POST", "/list/901/comment"),
+            ("GET", "/list/901/comment"),
+            ("GET", "/view/3v-1/comment"),
+        ]
+    );
+    let body = requests[0].json();
+    assert_eq!(body["comment"][0], json!({"text": "shipped search"}));
+    assert_eq!(body["comment"][3], json!({"text": "imports", "attributes": {"bold": true}}));
+}
+
+/// Tests that a comment without a task, list or view is refused
+#[tokio::test]
+async fn test_create_comment_without_target() {
+    let _test_config = TestConfig::new();
+    let server = spawn_comment_server().await;
+
+    let result = execute(
+        CommentCommands::Create {
+            task_id: None,
+            list_id: None,
+            view_id: None,
+            text: "Standup".to_string(),
+            assignee: None,
+            notify_all: None,
+            markdown: false,
+            workspace_id: None,
+        },
+        &server_config(&server.url()),
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+    assert!(server.requests().is_empty());
+}
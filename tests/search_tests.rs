@@ -0,0 +1,351 @@
+//! # Search Tests
+//!
+//! This module contains tests for full-text search and the `search` command.
+//!
+//! ## Test Categories
+//!
+//! - **Matching**: Tests for literal, regex and case-insensitive matching
+//! - **Snippets**: Tests for the context shown around a match
+//! - **Ranking**: Tests for scoring tasks and ordering results
+//! - **Backups**: Tests for reading `spaces backup` files and searching them
+//! - **Command**: Tests for the requests made against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Backup tests write files to a temporary directory. Command tests run a
+//! small stand-in server on `127.0.0.1` that records the requested paths.
+
+use clickup_cli::backup::SpaceBackup;
+use clickup_cli::commands::search::{execute, SearchArgs};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{Comment, Task, TeamTaskFilter};
+use clickup_cli::search::{rank, MatchField, Matcher, SearchOptions};
+mod test_utils;
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns an open task with the given name and description
+fn search_task_json(id: &str, name: &str, text: &str) -> serde_json::Value {
+    let mut task = task_json(id);
+    task["name"] = json!(name);
+    task["text_content"] = json!(text);
+    task["description"] = json!(text);
+    task["status"]["status"] = json!("open");
+    task
+}
+
+/// Returns a task decoded from `search_task_json`
+fn task(id: &str, name: &str, text: &str) -> Task {
+    serde_json::from_value(search_task_json(id, name, text)).unwrap()
+}
+
+/// Returns a comment with the given text
+fn comment_json(id: &str, text: &str) -> serde_json::Value {
+    json!({
+        "id": id, "comment": [{"text": text}], "comment_text": text,
+        "user": {"id": 1, "username": "owner", "email": "owner@acme.test", "color": "#000"},
+        "reactions": [], "date": "1700000000000", "reply_count": 0
+    })
+}
+
+/// Returns a comment decoded from `comment_json`
+fn comment(id: &str, text: &str) -> Comment {
+    serde_json::from_value(comment_json(id, text)).unwrap()
+}
+
+/// Returns search arguments for the text with every option off
+fn args(text: &str) -> SearchArgs {
+    SearchArgs {
+        text: text.to_string(),
+        workspace_id: None,
+        list_id: None,
+        space_id: None,
+        comments: false,
+        regex: false,
+        ignore_case: false,
+        include_closed: false,
+        limit: 20,
+        from_backup: None,
+    }
+}
+
+/// Tests literal, regex and case-insensitive matching
+#[test]
+fn test_matching_modes() {
+    let literal = Matcher::new("a.c", &SearchOptions::default()).unwrap();
+    assert_eq!(literal.count("abc a.c A.C"), 1);
+
+    let ignore_case = Matcher::new(
+        "a.c",
+        &SearchOptions {
+            regex: false,
+            ignore_case: true,
+        },
+    )
+    .unwrap();
+    assert_eq!(ignore_case.count("abc a.c A.C"), 2);
+
+    let regex = Matcher::new(
+        r"log(in|out)\b",
+        &SearchOptions {
+            regex: true,
+            ignore_case: false,
+        },
+    )
+    .unwrap();
+    assert_eq!(regex.count("login, logout, logging, Login"), 2);
+
+    // Patterns that can match nothing never count as a match
+    let empty_match = Matcher::new(
+        "x*",
+        &SearchOptions {
+            regex: true,
+            ignore_case: false,
+        },
+    )
+    .unwrap();
+    assert_eq!(empty_match.count("abc"), 0);
+
+    assert!(matches!(
+        Matcher::new("  ", &SearchOptions::default()),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        Matcher::new(
+            "(unclosed",
+            &SearchOptions {
+                regex: true,
+                ignore_case: false
+            }
+        ),
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests trimming and collapsing the context around a match
+#[test]
+fn test_snippets() {
+    let matcher = Matcher::new("needle", &SearchOptions::default()).unwrap();
+
+    let short = matcher.snippet("Find the needle\n\nquickly").unwrap();
+    assert_eq!(short.before, "Find the ");
+    assert_eq!(short.matched, "needle");
+    assert_eq!(short.after, " quickly");
+
+    let text = format!("{} needle {}", "é".repeat(60), "ü".repeat(60));
+    let long = matcher.snippet(&text).unwrap();
+    assert!(long.before.starts_with('…'));
+    assert!(long.after.ends_with('…'));
+    assert_eq!(long.before.chars().count(), 41);
+    assert_eq!(long.after.chars().count(), 41);
+
+    assert_eq!(matcher.snippet("nothing here"), None);
+}
+
+/// Tests scoring the fields of a task
+#[test]
+fn test_search_task_scores_fields() {
+    let matcher = Matcher::new(
+        "login",
+        &SearchOptions {
+            regex: false,
+            ignore_case: true,
+        },
+    )
+    .unwrap();
+
+    let in_text = task("t1", "Fix header", "The login page breaks. Login fails twice.");
+    let result = matcher.search_task(&in_text, &[]).unwrap();
+    assert_eq!(result.field, MatchField::Text);
+    assert_eq!(result.matches, 2);
+    assert_eq!(result.score, 6);
+    assert_eq!(result.url, "https://app.clickup.com/t/t1");
+
+    let in_comment = task("t2", "Fix header", "");
+    let comments = [comment("c1", "Unrelated"), comment("c2", "Seen on LOGIN too")];
+    let result = matcher.search_task(&in_comment, &comments).unwrap();
+    assert_eq!(result.field, MatchField::Comment);
+    assert_eq!(result.snippet.matched, "LOGIN");
+    assert_eq!(result.score, 1);
+    assert!(matcher.search_task(&in_comment, &[]).is_none());
+
+    // A name that matches in full gets a bonus on top of the name weight
+    let exact = task("t3", "Login", "");
+    assert_eq!(matcher.search_task(&exact, &[]).unwrap().score, 20);
+}
+
+/// Tests ordering results by score, then by name
+#[test]
+fn test_rank_results() {
+    let matcher = Matcher::new("bug", &SearchOptions::default()).unwrap();
+    let tasks = [
+        task("t1", "zeta", "bug"),
+        task("t2", "bug in parser", ""),
+        task("t3", "alpha", "bug"),
+        task("t4", "bug", ""),
+    ];
+    let mut results: Vec<_> = tasks
+        .iter()
+        .filter_map(|task| matcher.search_task(task, &[]))
+        .collect();
+    rank(&mut results);
+
+    let ids: Vec<&str> = results.iter().map(|result| result.task_id.as_str()).collect();
+    assert_eq!(ids, vec!["t4", "t2", "t3", "t1"]);
+}
+
+/// Tests the query of the filtered team task endpoint
+#[test]
+fn test_team_task_filter_query() {
+    let filter = TeamTaskFilter {
+        list_ids: vec!["901".to_string()],
+        space_ids: vec!["77".to_string(), "78".to_string()],
+        include_closed: true,
+    };
+    let query: Vec<String> = filter
+        .query_params(2)
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    assert_eq!(
+        query,
+        vec![
+            "page=2",
+            "subtasks=true",
+            "include_closed=true",
+            "list_ids[]=901",
+            "space_ids[]=77",
+            "space_ids[]=78",
+        ]
+    );
+}
+
+/// Writes a backup with two tasks and a comment, and returns its path
+fn write_backup(dir: &TempDir) -> PathBuf {
+    let backup = json!({
+        "metadata": {"created_at": "2025-07-01T00:00:00Z", "space_id": "77", "include_comments": true},
+        "space": null,
+        "folders": [],
+        "lists": [],
+        "tasks": [search_task_json("t1", "Fix login", ""), search_task_json("t2", "Docs", "")],
+        "comments": [{"task_id": "t2", "task_name": "Docs", "comments": [comment_json("c1", "login docs")]}]
+    });
+    let path = dir.path().join("backup.json");
+    fs::write(&path, serde_json::to_string_pretty(&backup).unwrap()).unwrap();
+    path
+}
+
+/// Tests reading a backup, including one written before newer metadata fields
+#[test]
+fn test_load_backup() {
+    let dir = TempDir::new().unwrap();
+    let path = write_backup(&dir);
+
+    let backup = SpaceBackup::load(&path).unwrap();
+    assert_eq!(backup.tasks.len(), 2);
+    assert_eq!(backup.comments[0].comments[0].comment_text, "login docs");
+    assert!(backup.metadata.include_comments);
+    assert!(!backup.metadata.include_attachments);
+
+    // A backup read back and written again is unchanged
+    let round_trip = serde_json::to_string(&backup).unwrap();
+    let reloaded: SpaceBackup = serde_json::from_str(&round_trip).unwrap();
+    assert_eq!(reloaded.tasks[1].name.as_deref(), Some("Docs"));
+
+    fs::write(&path, "{\"tasks\": []}").unwrap();
+    assert!(matches!(
+        SpaceBackup::load(&path),
+        Err(ClickUpError::DeserializationError(_))
+    ));
+}
+
+/// Tests searching a backup without a token or network access
+#[tokio::test]
+async fn test_search_from_backup() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let path = write_backup(&dir);
+
+    let search = SearchArgs {
+        comments: true,
+        from_backup: Some(path.clone()),
+        ..args("login")
+    };
+    execute(search, &Config::default()).await.unwrap();
+
+    let invalid = SearchArgs {
+        regex: true,
+        from_backup: Some(path),
+        ..args("[")
+    };
+    assert!(matches!(
+        execute(invalid, &Config::default()).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Starts a stand-in server that answers every request with one task
+async fn spawn_search_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.path.contains("/comment") {
+            json!({"comments": [comment_json("c1", "login")]})
+        } else {
+            json!({"tasks": [search_task_json("t1", "Fix login", "")]})
+        }
+    })
+    .await
+}
+
+/// Tests that a workspace search uses the filtered team task endpoint
+#[tokio::test]
+async fn test_search_uses_team_endpoint() {
+    let _test_config = TestConfig::new();
+    let server = spawn_search_server().await;
+    let config = server_config(&server.url());
+
+    let search = SearchArgs {
+        list_id: Some("901".to_string()),
+        comments: true,
+        ..args("login")
+    };
+    execute(search, &config).await.unwrap();
+
+    assert_eq!(
+        server.paths(),
+        vec![
+            "/team/9/task?page=0&subtasks=true&include_closed=false&list_ids[]=901",
+            "/task/t1/comment",
+        ]
+    );
+}
+
+/// Tests that a list is fetched directly when no workspace is known
+#[tokio::test]
+async fn test_search_list_without_workspace() {
+    let _test_config = TestConfig::new();
+    let server = spawn_search_server().await;
+    let config = Config {
+        workspace_id: None,
+        ..server_config(&server.url())
+    };
+
+    let search = SearchArgs {
+        list_id: Some("901".to_string()),
+        ..args("login")
+    };
+    execute(search, &config).await.unwrap();
+    assert!(server.paths()[0].starts_with("/list/901/task?"));
+
+    let space_search = SearchArgs {
+        space_id: Some("77".to_string()),
+        ..args("login")
+    };
+    assert!(matches!(
+        execute(space_search, &config).await,
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
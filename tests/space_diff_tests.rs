@@ -0,0 +1,345 @@
+//! # Space Diff Tests
+//!
+//! This module contains tests for comparing space backups and the
+//! `spaces diff` command.
+//!
+//! ## Test Categories
+//!
+//! - **Diffing**: Tests for added, removed and changed folders, lists and tasks
+//! - **Formatting**: Tests for the JSON output and text values of changes
+//! - **Command**: Tests for diffing backup files and a backup against a local
+//!   stand-in server
+//!
+//! ## Test Environment
+//!
+//! Backups are written to a temporary directory. The live test runs a small
+//! stand-in server on `127.0.0.1` that records the requested paths.
+
+use clickup_cli::backup::SpaceBackup;
+use clickup_cli::commands::spaces::{execute, SpaceCommands};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::space_diff::{diff, ChangeKind, ItemDiff};
+use clickup_cli::watch::format_change_value;
+mod test_utils;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns a minimal open task in a list
+fn list_task(id: &str, name: &str, list_id: &str) -> Value {
+    let mut task = task_json(id);
+    task["name"] = json!(name);
+    task["list"] = json!({"id": list_id, "access": true});
+    task
+}
+
+/// Returns an assignee with a username
+fn assignee(id: i64, username: &str) -> Value {
+    json!({"id": id, "username": username, "color": "#000", "email": format!("{username}@acme.test")})
+}
+
+/// Returns a tag with a name
+fn tag(name: &str) -> Value {
+    json!({"name": name, "tag_fg": "#000", "tag_bg": "#fff", "creator": 1})
+}
+
+/// Returns a minimal list in space 77
+fn list_json(id: &str, name: &str) -> Value {
+    json!({"id": id, "name": name, "orderindex": 0, "space": {"id": "77", "name": "Engineering"}})
+}
+
+/// Returns a minimal folder in space 77
+fn folder_json(id: &str, name: &str) -> Value {
+    json!({"id": id, "name": name, "orderindex": 0, "space": {"id": "77", "name": "Engineering"}})
+}
+
+/// Returns backup JSON made at a time with the given folders, lists and tasks
+fn backup_json(created_at: &str, folders: Vec<Value>, lists: Vec<Value>, tasks: Vec<Value>) -> Value {
+    json!({
+        "metadata": {"created_at": created_at, "space_id": "77"},
+        "space": null,
+        "folders": folders,
+        "lists": lists,
+        "tasks": tasks
+    })
+}
+
+/// Returns a backup decoded from `backup_json`
+fn backup(value: Value) -> SpaceBackup {
+    serde_json::from_value(value).unwrap()
+}
+
+/// Returns the earlier of two backups used by several tests
+fn monday() -> Value {
+    let mut fix_login = list_task("t1", "Fix login", "901");
+    fix_login["assignees"] = json!([assignee(1, "alice")]);
+    fix_login["tags"] = json!([tag("bug"), tag("auth")]);
+    fix_login["due_date"] = json!("1751328000000");
+    backup_json(
+        "2025-06-30T09:00:00Z",
+        vec![folder_json("5", "Sprints")],
+        vec![list_json("901", "Backlog"), list_json("902", "Sprint 1")],
+        vec![fix_login, list_task("t2", "Old docs", "901")],
+    )
+}
+
+/// Returns the later of two backups used by several tests
+fn friday() -> Value {
+    let mut fix_login = list_task("t1", "Fix login", "902");
+    fix_login["status"]["status"] = json!("in progress");
+    fix_login["assignees"] = json!([assignee(2, "bob"), assignee(1, "alice")]);
+    fix_login["tags"] = json!([tag("auth"), tag("bug")]);
+    fix_login["due_date"] = json!("1751587200000");
+    fix_login["description"] = json!("Sessions expire too early");
+    fix_login["date_updated"] = json!("2");
+    backup_json(
+        "2025-07-04T09:00:00Z",
+        vec![folder_json("5", "Sprints")],
+        vec![list_json("901", "Icebox"), list_json("902", "Sprint 1")],
+        vec![fix_login, list_task("t3", "New onboarding", "902")],
+    )
+}
+
+/// Returns the names of the changed fields of an item
+fn fields(item: &ItemDiff) -> Vec<&str> {
+    item.changes.iter().map(|change| change.field.as_str()).collect()
+}
+
+/// Tests added, removed and changed tasks with field-by-field changes
+#[test]
+fn test_diff_tasks() {
+    let result = diff(&backup(monday()), &backup(friday()));
+
+    assert_eq!(result.space_id, "77");
+    assert_eq!(result.from, "2025-06-30T09:00:00Z");
+    assert_eq!(result.to, "2025-07-04T09:00:00Z");
+
+    let tasks: Vec<(ChangeKind, &str)> = result
+        .tasks
+        .iter()
+        .map(|task| (task.change, task.id.as_str()))
+        .collect();
+    assert_eq!(
+        tasks,
+        [
+            (ChangeKind::Changed, "t1"),
+            (ChangeKind::Added, "t3"),
+            (ChangeKind::Removed, "t2")
+        ]
+    );
+
+    // Tag order doesn't count as a change, and neither does date_updated
+    let changed = &result.tasks[0];
+    assert_eq!(
+        fields(changed),
+        ["list", "status", "assignees", "due_date", "description"]
+    );
+    assert_eq!(changed.changes[1].before, json!("to do"));
+    assert_eq!(changed.changes[1].after, json!("in progress"));
+    assert_eq!(changed.changes[2].after, json!(["alice", "bob"]));
+    assert!(result.tasks[1].changes.is_empty());
+    assert_eq!(result.tasks[2].name, "Old docs");
+}
+
+/// Tests folders and lists, and counting differences
+#[test]
+fn test_diff_folders_and_lists() {
+    let mut before = monday();
+    before["folders"] = json!([folder_json("5", "Sprints"), folder_json("6", "Archive")]);
+    let mut after = friday();
+    after["folders"][0]["archived"] = json!(true);
+
+    let result = diff(&backup(before), &backup(after));
+    assert_eq!(result.folders.len(), 2);
+    assert_eq!(result.folders[0].change, ChangeKind::Changed);
+    assert_eq!(fields(&result.folders[0]), ["archived"]);
+    assert_eq!(result.folders[1].change, ChangeKind::Removed);
+
+    assert_eq!(result.lists.len(), 1);
+    assert_eq!(fields(&result.lists[0]), ["name"]);
+    assert_eq!(result.lists[0].name, "Icebox");
+
+    assert_eq!(result.count(ChangeKind::Added), 1);
+    assert_eq!(result.count(ChangeKind::Removed), 2);
+    assert_eq!(result.count(ChangeKind::Changed), 3);
+    assert!(!result.is_empty());
+}
+
+/// Tests that a backup compared with itself has no differences
+#[test]
+fn test_diff_identical() {
+    let result = diff(&backup(monday()), &backup(monday()));
+    assert!(result.is_empty());
+    assert_eq!(result.count(ChangeKind::Changed), 0);
+}
+
+/// Returns a copy of a task with a closed status
+fn closed(mut task: Value) -> Value {
+    task["status"] = json!({"id": "c", "status": "complete", "color": "#000", "orderindex": 3, "type": "closed"});
+    task["date_closed"] = json!("1751587200000");
+    task
+}
+
+/// Tests that a task closed between two backups is a status change
+#[test]
+fn test_diff_closed_task() {
+    let mut before = monday();
+    before["metadata"]["include_closed"] = json!(true);
+    let mut after = monday();
+    after["metadata"]["include_closed"] = json!(true);
+    after["tasks"][1] = closed(after["tasks"][1].clone());
+
+    let result = diff(&backup(before), &backup(after));
+    assert_eq!(result.tasks.len(), 1);
+    assert_eq!(result.tasks[0].change, ChangeKind::Changed);
+    assert_eq!(result.tasks[0].id, "t2");
+    assert_eq!(fields(&result.tasks[0]), ["status"]);
+    assert_eq!(result.tasks[0].changes[0].after, json!("complete"));
+}
+
+/// Tests comparing with a backup made without closed tasks
+///
+/// A closed task only the newer backup holds may have been closed before
+/// the older backup was made, so it is not reported as added.
+#[test]
+fn test_diff_backup_without_closed_tasks() {
+    let before = monday();
+    let mut after = monday();
+    after["metadata"]["include_closed"] = json!(true);
+    after["tasks"][1] = closed(after["tasks"][1].clone());
+    after["tasks"]
+        .as_array_mut()
+        .unwrap()
+        .push(closed(list_task("t0", "Ancient", "901")));
+
+    let result = diff(&backup(before), &backup(after));
+    let tasks: Vec<(ChangeKind, &str)> = result
+        .tasks
+        .iter()
+        .map(|task| (task.change, task.id.as_str()))
+        .collect();
+    assert_eq!(tasks, [(ChangeKind::Changed, "t2")]);
+}
+
+/// Tests the JSON output and the text shown for changed values
+#[test]
+fn test_diff_json_and_values() {
+    let value = serde_json::to_value(diff(&backup(monday()), &backup(friday()))).unwrap();
+    assert_eq!(value["tasks"][0]["change"], "changed");
+    assert_eq!(value["tasks"][0]["changes"][0]["field"], "list");
+    assert_eq!(value["tasks"][1]["change"], "added");
+    assert!(value["tasks"][1].get("changes").is_none());
+    assert_eq!(value["lists"][0]["changes"][0]["before"], "Backlog");
+
+    assert_eq!(format_change_value("due_date", &json!("1751587200000")), "2025-07-04");
+    assert_eq!(format_change_value("start_date", &Value::Null), "none");
+    assert_eq!(format_change_value("tags", &json!(["auth", "bug"])), "auth, bug");
+    assert_eq!(format_change_value("tags", &json!([])), "none");
+    assert_eq!(format_change_value("archived", &json!(true)), "true");
+}
+
+/// Writes a backup to the directory and returns its path
+fn write_backup(dir: &TempDir, name: &str, value: Value) -> PathBuf {
+    let path = dir.path().join(name);
+    fs::write(&path, serde_json::to_string_pretty(&value).unwrap()).unwrap();
+    path
+}
+
+/// Tests diffing two backup files without a token or network access
+#[tokio::test]
+async fn test_diff_command_files() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let from = write_backup(&dir, "monday.json", monday());
+    let to = write_backup(&dir, "friday.json", friday());
+
+    for json in [false, true] {
+        let command = SpaceCommands::Diff {
+            from: from.clone(),
+            to: to.display().to_string(),
+            json,
+        };
+        execute(command, &Config::default()).await.unwrap();
+    }
+
+    let missing = SpaceCommands::Diff {
+        from,
+        to: dir.path().join("missing.json").display().to_string(),
+        json: false,
+    };
+    assert!(matches!(
+        execute(missing, &Config::default()).await,
+        Err(ClickUpError::IoError(_))
+    ));
+}
+
+/// Starts a stand-in server with space 77 as it is in `friday`
+async fn spawn_space_server() -> MockServer {
+    let space = friday();
+    MockServer::start(move |request| {
+        let tasks_in = |list_id: &str| {
+            let tasks: Vec<&Value> = space["tasks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter(|task| task["list"]["id"] == list_id)
+                .collect();
+            json!({ "tasks": tasks })
+        };
+        match request.route() {
+            "/api/v2/team" => json!({"teams": [{"id": "9", "name": "Acme", "members": []}]}),
+            "/api/v2/team/9/space" => json!({"spaces": [{
+                "id": "77", "name": "Engineering", "private": false,
+                "multiple_assignees": true, "archived": false, "statuses": []
+            }]}),
+            "/api/v2/space/77/folder" => json!({ "folders": space["folders"] }),
+            "/api/v2/space/77/list" => json!({"lists": [space["lists"][0]]}),
+            "/api/v2/folder/5/list" => json!({"lists": [space["lists"][1]]}),
+            "/api/v2/list/901/task" => tasks_in("901"),
+            "/api/v2/list/902/task" => tasks_in("902"),
+            _ => json!({}),
+        }
+    })
+    .await
+}
+
+/// Tests diffing a backup against the space fetched from the stand-in server
+#[tokio::test]
+async fn test_diff_command_live() {
+    let _test_config = TestConfig::new();
+    let dir = TempDir::new().unwrap();
+    let from = write_backup(&dir, "monday.json", monday());
+    let server = spawn_space_server().await;
+    let config = server_config(&server.api_url());
+
+    let command = SpaceCommands::Diff {
+        from,
+        to: "live".to_string(),
+        json: true,
+    };
+    execute(command, &config).await.unwrap();
+
+    let requests = server.paths();
+    for path in [
+        "/api/v2/space/77/folder",
+        "/api/v2/space/77/list",
+        "/api/v2/folder/5/list",
+        "/api/v2/list/901/task",
+        "/api/v2/list/902/task",
+    ] {
+        assert!(
+            requests.iter().any(|request| request.starts_with(path)),
+            "{path} was not requested"
+        );
+    }
+    // Closed tasks are fetched so they aren't mistaken for removed ones
+    assert!(requests
+        .iter()
+        .filter(|request| request.contains("/task"))
+        .all(|request| request.contains("include_closed=true")));
+    // Live diffs never fetch comments
+    assert!(!requests.iter().any(|request| request.contains("/comment")));
+}
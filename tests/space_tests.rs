@@ -206,6 +206,7 @@ async fn test_space_backup_no_auth() {
         space_id: Some("space_123".to_string()),
         output_dir: ".".to_string(),
         include_comments: false,
+        include_attachments: false,
     }, &config).await;
     assert!(result.is_err());
 }
@@ -230,6 +231,7 @@ async fn test_space_backup_with_auth() {
         space_id: Some("space_123".to_string()),
         output_dir: ".".to_string(),
         include_comments: false,
+        include_attachments: false,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
@@ -252,6 +254,7 @@ async fn test_space_backup_empty_id() {
         space_id: Some("".to_string()),
         output_dir: ".".to_string(),
         include_comments: false,
+        include_attachments: false,
     }, &config).await;
     // This should fail due to invalid space ID
     assert!(result.is_err());
@@ -276,6 +279,7 @@ async fn test_space_backup_with_comments() {
         space_id: Some("space_123".to_string()),
         output_dir: ".".to_string(),
         include_comments: true,
+        include_attachments: false,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 } 
\ No newline at end of file
@@ -0,0 +1,444 @@
+//! # Task Move Tests
+//!
+//! This module contains tests for moving and duplicating tasks and for
+//! adding tasks to additional lists.
+//!
+//! ## Test Categories
+//!
+//! - **Status Mapping**: Tests for choosing a status in a list with other statuses
+//! - **Move**: Tests for moving a task, mapping its status and undoing the move
+//! - **Duplicate**: Tests for copying a task with its subtasks, checklists and comments
+//! - **Multiple Lists**: Tests for adding a task to and removing it from other lists
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with a task in list 901, a second list
+//! 902 with its own statuses, and the task's checklists and comments.
+
+use clickup_cli::commands::task_move::map_status;
+use clickup_cli::commands::tasks::{execute, TaskCommands};
+use clickup_cli::commands::undo::{self, UndoArgs};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::journal::{Journal, JournalOperation};
+use clickup_cli::models::{SpaceStatus, TaskStatus};
+use serde_json::json;
+mod test_utils;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns a status with the given name, type and position
+fn status(name: &str, type_: &str, orderindex: i64) -> SpaceStatus {
+    SpaceStatus {
+        id: name.to_string(),
+        status: name.to_string(),
+        color: "#000".to_string(),
+        orderindex,
+        type_: type_.to_string(),
+    }
+}
+
+/// Returns a task status with the given name and type
+fn task_status(name: &str, type_: &str) -> TaskStatus {
+    TaskStatus {
+        id: "s".to_string(),
+        status: name.to_string(),
+        color: "#000".to_string(),
+        orderindex: 1,
+        type_: type_.to_string(),
+    }
+}
+
+/// Returns the statuses of the target list
+fn target_statuses() -> Vec<SpaceStatus> {
+    vec![
+        status("backlog", "open", 0),
+        status("doing", "custom", 1),
+        status("QA", "custom", 2),
+        status("shipped", "closed", 3),
+    ]
+}
+
+/// Tests that a status with the same name is kept, ignoring case
+#[test]
+fn test_map_status_by_name() {
+    let statuses = target_statuses();
+    let mapped = map_status(&task_status("qa", "custom"), &statuses).unwrap();
+    assert_eq!(mapped.status, "QA");
+}
+
+/// Tests falling back to the first status of the same type
+#[test]
+fn test_map_status_by_type() {
+    let statuses = target_statuses();
+    assert_eq!(
+        map_status(&task_status("in review", "custom"), &statuses).unwrap().status,
+        "doing"
+    );
+    assert_eq!(
+        map_status(&task_status("complete", "closed"), &statuses).unwrap().status,
+        "shipped"
+    );
+}
+
+/// Tests falling back to the list's first status, or none without statuses
+#[test]
+fn test_map_status_fallback() {
+    let statuses = target_statuses();
+    assert_eq!(
+        map_status(&task_status("approved", "done"), &statuses).unwrap().status,
+        "backlog"
+    );
+    assert!(map_status(&task_status("open", "open"), &[]).is_none());
+}
+
+/// Returns a task with the given ID, parent, list and status
+fn placed_task(id: &str, parent: Option<&str>, list_id: &str, status: &str) -> serde_json::Value {
+    let mut task = task_json(id);
+    task["description"] = json!("Notes");
+    task["status"] = json!({"id": "s", "status": status, "color": "#000", "orderindex": 1, "type": "custom"});
+    task["parent"] = json!(parent);
+    task["list"] = json!({"id": list_id, "access": true});
+    task
+}
+
+/// Returns task t1 with a checklist and, when asked for, two levels of subtasks
+fn source_task(with_subtasks: bool) -> serde_json::Value {
+    let mut task = placed_task("t1", None, "901", "in review");
+    task["checklists"] = json!([{
+        "id": "cl_src", "name": "Release", "orderindex": 0, "resolved": 0,
+        "date_created": "1", "items": [
+            {"id": "i2", "name": "Tag", "orderindex": 1, "resolved": false, "date_created": "1"},
+            {"id": "i1", "name": "Build", "orderindex": 0, "resolved": true, "date_created": "1"}
+        ]
+    }]);
+    if with_subtasks {
+        // The nested subtask comes first to check that parents are created first
+        task["subtasks"] = json!([
+            placed_task("s2", Some("s1"), "901", "in review"),
+            placed_task("s1", Some("t1"), "901", "in review")
+        ]);
+    }
+    task
+}
+
+/// Returns a checklist with the given number of items
+fn checklist_json(items: usize) -> serde_json::Value {
+    let items: Vec<serde_json::Value> = (1..=items)
+        .map(|n| json!({"id": format!("it_{n}"), "name": "item", "orderindex": n, "resolved": false, "date_created": "1"}))
+        .collect();
+    json!({"checklist": {"id": "cl_new", "name": "Release", "orderindex": 0, "resolved": 0, "date_created": "1", "items": items}})
+}
+
+/// Starts a stand-in server for move, duplicate and list membership requests
+async fn spawn_task_server() -> MockServer {
+    let mut created = 0;
+    let mut items = 0;
+    MockServer::start(move |request| match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/api/v2/task/t1") => source_task(false),
+        ("GET", "/api/v2/task/t1?include_subtasks=true") => source_task(true),
+        ("GET", "/api/v2/list/902") => json!({
+            "id": "902", "name": "Sprint", "orderindex": 0,
+            "space": {"id": "77", "name": "Eng"},
+            "statuses": [
+                {"id": "a", "status": "backlog", "color": "#000", "orderindex": 0, "type": "open"},
+                {"id": "b", "status": "doing", "color": "#000", "orderindex": 1, "type": "custom"},
+                {"id": "c", "status": "shipped", "color": "#000", "orderindex": 2, "type": "closed"}
+            ]
+        }),
+        ("GET", "/api/v2/task/t1/comment") => json!({"comments": [
+            {"id": "c2", "comment": [{"text": "Second"}], "comment_text": "Second",
+             "user": {"id": 1, "username": "owner"}, "resolved": false,
+             "reactions": [], "date": "1700000000200", "reply_count": 0},
+            {"id": "c1", "comment": [{"text": "First"}], "comment_text": "First",
+             "user": {"id": 1, "username": "owner"}, "resolved": false,
+             "reactions": [], "date": "1700000000100", "reply_count": 0}
+        ]}),
+        ("GET", path) if path.ends_with("/comment") => json!({"comments": []}),
+        ("GET", path) if path.starts_with("/api/v2/task/") => {
+            let id = path.trim_start_matches("/api/v2/task/");
+            placed_task(id, None, "901", "in review")
+        }
+        ("POST", "/api/v2/list/901/task" | "/api/v2/list/902/task") => {
+            created += 1;
+            placed_task(&format!("n{created}"), None, "902", "doing")
+        }
+        ("POST", path) if path.ends_with("/checklist") => {
+            items = 0;
+            checklist_json(0)
+        }
+        ("POST", path) if path.ends_with("/checklist_item") => {
+            items += 1;
+            checklist_json(items)
+        }
+        ("PUT", path) if path.contains("/checklist_item/") => checklist_json(items),
+        ("POST", path) if path.ends_with("/comment") => json!({"id": 90, "date": 1700000000300_i64}),
+        ("PUT", "/api/v2/task/t1") => placed_task("t1", None, "902", "doing"),
+        _ => json!({}),
+    })
+    .await
+}
+
+/// Tests that a move uses the v3 home list endpoint and maps the status
+#[tokio::test]
+async fn test_move_task_maps_status() {
+    let _test_config = TestConfig::new();
+    let server = spawn_task_server().await;
+
+    execute(
+        TaskCommands::Move {
+            id: "t1".to_string(),
+            to_list: "902".to_string(),
+        },
+        &server_config(&server.api_url()),
+    )
+    .await
+    .unwrap();
+
+    let changes = server.changes();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        (changes[0].0.as_str(), changes[0].1.as_str()),
+        ("PUT", "/api/v3/workspaces/9/tasks/t1/home_list/902")
+    );
+    assert_eq!(
+        (changes[1].0.as_str(), changes[1].1.as_str()),
+        ("PUT", "/api/v2/task/t1")
+    );
+    let update: serde_json::Value = serde_json::from_str(&changes[1].2).unwrap();
+    assert_eq!(update["status"], "doing");
+}
+
+/// Tests that moving a task to its own list sends nothing
+#[tokio::test]
+async fn test_move_task_to_same_list() {
+    let _test_config = TestConfig::new();
+    let server = spawn_task_server().await;
+
+    execute(
+        TaskCommands::Move {
+            id: "t1".to_string(),
+            to_list: "901".to_string(),
+        },
+        &server_config(&server.api_url()),
+    )
+    .await
+    .unwrap();
+
+    assert!(server.changes().is_empty());
+}
+
+/// Tests that a move is journaled once and that undo moves the task back
+///
+/// The status change that follows the move is part of the same entry, so
+/// undo restores the task's list and status together.
+#[tokio::test]
+async fn test_undo_move_task() {
+    let _test_config = TestConfig::new();
+    let mut home = "901".to_string();
+    let mut status = "in review".to_string();
+    let server = MockServer::start(move |request| match (request.method.as_str(), request.route()) {
+        ("GET", "/api/v2/task/t1") => placed_task("t1", None, &home, &status),
+        ("GET", "/api/v2/list/902") => json!({
+            "id": "902", "name": "Sprint", "orderindex": 0,
+            "space": {"id": "77", "name": "Eng"},
+            "statuses": [
+                {"id": "b", "status": "doing", "color": "#000", "orderindex": 0, "type": "custom"}
+            ]
+        }),
+        ("PUT", path) if path.contains("/home_list/") => {
+            home = path.rsplit('/').next().unwrap().to_string();
+            json!({})
+        }
+        ("PUT", "/api/v2/task/t1") => {
+            let update: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+            status = update["status"].as_str().unwrap().to_string();
+            placed_task("t1", None, &home, &status)
+        }
+        _ => json!({}),
+    })
+    .await;
+    let config = server_config(&server.api_url());
+
+    execute(
+        TaskCommands::Move {
+            id: "t1".to_string(),
+            to_list: "902".to_string(),
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let entries = Journal::default_location().unwrap().entries().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].operation, JournalOperation::TaskMove);
+    assert_eq!(entries[0].source_list_id.as_deref(), Some("901"));
+    assert_eq!(entries[0].parent_kind, None);
+
+    let args = UndoArgs {
+        last: None,
+        id: None,
+        list: false,
+    };
+    undo::execute(args, &config).await.unwrap();
+
+    let changes = server.changes();
+    assert_eq!(changes.len(), 4);
+    assert_eq!(
+        (changes[2].0.as_str(), changes[2].1.as_str()),
+        ("PUT", "/api/v3/workspaces/9/tasks/t1/home_list/901")
+    );
+    assert_eq!(
+        (changes[3].0.as_str(), changes[3].1.as_str()),
+        ("PUT", "/api/v2/task/t1")
+    );
+    let update: serde_json::Value = serde_json::from_str(&changes[3].2).unwrap();
+    assert_eq!(update["status"], "in review");
+    assert!(Journal::default_location().unwrap().entries().unwrap()[0].undone);
+}
+
+/// Tests duplicating a task with its subtasks, checklists and comments
+#[tokio::test]
+async fn test_duplicate_task_with_everything() {
+    let _test_config = TestConfig::new();
+    let server = spawn_task_server().await;
+
+    execute(
+        TaskCommands::Duplicate {
+            id: "t1".to_string(),
+            to_list: Some("902".to_string()),
+            with_subtasks: true,
+            with_checklists: true,
+            with_comments: true,
+        },
+        &server_config(&server.api_url()),
+    )
+    .await
+    .unwrap();
+
+    let changes = server.changes();
+    let creates: Vec<serde_json::Value> = changes
+        .iter()
+        .filter(|(_, path, _)| path == "/api/v2/list/902/task")
+        .map(|(_, _, body)| serde_json::from_str(body).unwrap())
+        .collect();
+    assert_eq!(creates.len(), 3);
+    assert_eq!(creates[0]["name"], "Task t1");
+    assert_eq!(creates[0]["status"], "doing");
+    assert_eq!(creates[0]["parent"], serde_json::Value::Null);
+    // s1 hangs off the copy of t1, and s2 off the copy of s1
+    assert_eq!(creates[1]["name"], "Task s1");
+    assert_eq!(creates[1]["parent"], "n1");
+    assert_eq!(creates[2]["name"], "Task s2");
+    assert_eq!(creates[2]["parent"], "n2");
+
+    let paths: Vec<(&str, &str)> = changes
+        .iter()
+        .filter(|(_, path, _)| !path.starts_with("/api/v2/list/"))
+        .map(|(method, path, _)| (method.as_str(), path.as_str()))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            ("POST", "/api/v2/task/n1/checklist"),
+            ("POST", "/api/v2/checklist/cl_new/checklist_item"),
+            ("PUT", "/api/v2/checklist/cl_new/checklist_item/it_1"),
+            ("POST", "/api/v2/checklist/cl_new/checklist_item"),
+            ("POST", "/api/v2/task/n1/comment"),
+            ("POST", "/api/v2/task/n1/comment"),
+        ]
+    );
+    let item: serde_json::Value = serde_json::from_str(&changes[4].2).unwrap();
+    assert_eq!(item["name"], "Build");
+    let comments: Vec<&str> = changes
+        .iter()
+        .filter(|(_, path, _)| path.ends_with("/comment"))
+        .map(|(_, _, body)| body.as_str())
+        .collect();
+    assert!(comments[0].contains("First"));
+    assert!(comments[1].contains("Second"));
+}
+
+/// Tests that a plain duplicate stays in the task's list with its status
+#[tokio::test]
+async fn test_duplicate_task_in_same_list() {
+    let _test_config = TestConfig::new();
+    let server = spawn_task_server().await;
+
+    execute(
+        TaskCommands::Duplicate {
+            id: "t1".to_string(),
+            to_list: None,
+            with_subtasks: false,
+            with_checklists: false,
+            with_comments: false,
+        },
+        &server_config(&server.api_url()),
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    assert!(!requests.iter().any(|request| request.path == "/api/v2/list/901"));
+    let create = requests.iter().find(|request| request.method == "POST").unwrap();
+    assert_eq!(create.path, "/api/v2/list/901/task");
+    assert_eq!(create.json()["status"], "in review");
+}
+
+/// Tests adding a task to another list and removing it again
+#[tokio::test]
+async fn test_add_and_remove_task_from_list() {
+    let _test_config = TestConfig::new();
+    let server = spawn_task_server().await;
+    let config = server_config(&server.api_url());
+
+    execute(
+        TaskCommands::AddToList {
+            id: "t1".to_string(),
+            list_id: "902".to_string(),
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    execute(
+        TaskCommands::RemoveFromList {
+            id: "t1".to_string(),
+            list_id: "902".to_string(),
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let changes: Vec<(String, String)> = server.changes()
+        .into_iter()
+        .map(|(method, path, _)| (method, path))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            ("POST".to_string(), "/api/v2/list/902/task/t1".to_string()),
+            ("DELETE".to_string(), "/api/v2/list/902/task/t1".to_string()),
+        ]
+    );
+}
+
+/// Tests that the home list can't be added or removed as an extra list
+#[tokio::test]
+async fn test_home_list_membership_rejected() {
+    let _test_config = TestConfig::new();
+    let server = spawn_task_server().await;
+
+    let result = execute(
+        TaskCommands::RemoveFromList {
+            id: "t1".to_string(),
+            list_id: "901".to_string(),
+        },
+        &server_config(&server.api_url()),
+    )
+    .await;
+
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+    assert!(server.changes().is_empty());
+}
@@ -22,10 +22,8 @@
 use clickup_cli::commands::tasks::{execute, TaskCommands};
 use clickup_cli::config::Config;
 mod test_utils;
-use test_utils::{TestConfig, TestApiUtils};
-use std::env;
-use std::fs;
-use tempfile::TempDir;
+use serde_json::json;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
 
 
 
@@ -42,7 +40,7 @@ async fn test_task_list_no_auth() {
     assert!(!config.is_authenticated());
     
     // Execute the List command
-    let result = execute(TaskCommands::List { list_id: "list_123".to_string() }, &config).await;
+    let result = execute(TaskCommands::List { list_id: "list_123".to_string(), tree: false }, &config).await;
     assert!(result.is_err());
 }
 
@@ -62,7 +60,7 @@ async fn test_task_list_with_auth() {
     
     // Execute the List command
     // Note: This will likely fail due to invalid token, but the command structure is valid
-    let _result = execute(TaskCommands::List { list_id: "list_123".to_string() }, &config).await;
+    let _result = execute(TaskCommands::List { list_id: "list_123".to_string(), tree: false }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
 
@@ -80,7 +78,7 @@ async fn test_task_list_empty_list_id() {
     assert!(config.is_authenticated());
     
     // Execute the List command with empty list ID
-    let result = execute(TaskCommands::List { list_id: "".to_string() }, &config).await;
+    let result = execute(TaskCommands::List { list_id: "".to_string(), tree: false }, &config).await;
     // This should fail due to invalid list ID
     assert!(result.is_err());
 }
@@ -217,6 +215,44 @@ async fn test_task_update_overdue_by_tag_with_auth() {
     // We don't assert on result since it depends on API connectivity
 }
 
+/// Tests UpdateOverdueByTag with the global dry-run flag
+///
+/// The global flag is not the command's own `-d`: the overdue task is
+/// updated through the API client, which prints the request instead of
+/// sending it.
+#[tokio::test]
+async fn test_task_update_overdue_by_tag_global_dry_run() {
+    let _test_config = TestConfig::new();
+    let server = MockServer::start(|request| match request.route() {
+        "/space/77/list" => json!({"lists": [
+            {"id": "901", "name": "Backlog", "orderindex": 0, "space": {"id": "77", "name": "Product"}}
+        ]}),
+        "/space/77/folder" => json!({"folders": []}),
+        "/list/901/task" => {
+            let mut task = task_json("t1");
+            task["tags"] = json!([{"name": "urgent", "tag_fg": "#000", "tag_bg": "#fff", "creator": 1}]);
+            task["due_date"] = json!("1600000000000");
+            json!({"tasks": [task]})
+        }
+        _ => json!({}),
+    })
+    .await;
+    let config = Config {
+        dry_run: true,
+        ..server_config(&server.url())
+    };
+
+    execute(TaskCommands::UpdateOverdueByTag {
+        tag: "urgent".to_string(),
+        workspace_id: Some("9".to_string()),
+        space_id: Some("77".to_string()),
+        dry_run: false,
+    }, &config).await.unwrap();
+
+    assert!(server.paths().iter().any(|path| path.starts_with("/list/901/task")));
+    assert!(server.changes().is_empty());
+}
+
 /// Tests the Show command with no authentication
 ///
 /// This test verifies that the Show command correctly handles
@@ -268,12 +304,15 @@ async fn test_task_create_no_auth() {
     // Execute the Create command
     let result = execute(TaskCommands::Create { 
         list_id: "list_123".to_string(),
-        name: "Test Task".to_string(),
+        name: Some("Test Task".to_string()),
         description: Some("Test description".to_string()),
         status: Some("in progress".to_string()),
         priority: Some(2),
         due_date: Some(1640995200), // 2022-01-01
         time_estimate: Some(3600000), // 1 hour in milliseconds
+        assignees: vec![],
+        from_template: None,
+        workspace_id: None,
     }, &config).await;
     assert!(result.is_err());
 }
@@ -295,12 +334,15 @@ async fn test_task_create_with_auth() {
     // Note: This will likely fail due to invalid token, but the command structure is valid
     let _result = execute(TaskCommands::Create { 
         list_id: "list_123".to_string(),
-        name: "Test Task".to_string(),
+        name: Some("Test Task".to_string()),
         description: Some("Test description".to_string()),
         status: Some("in progress".to_string()),
         priority: Some(2),
         due_date: Some(1640995200), // 2022-01-01
         time_estimate: Some(3600000), // 1 hour in milliseconds
+        assignees: vec![],
+        from_template: None,
+        workspace_id: None,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
@@ -322,12 +364,15 @@ async fn test_task_create_minimal_params() {
     // Note: This will likely fail due to invalid token, but the command structure is valid
     let _result = execute(TaskCommands::Create { 
         list_id: "list_123".to_string(),
-        name: "Test Task".to_string(),
+        name: Some("Test Task".to_string()),
         description: None,
         status: None,
         priority: None,
         due_date: None,
         time_estimate: None,
+        assignees: vec![],
+        from_template: None,
+        workspace_id: None,
     }, &config).await;
     // We don't assert on result since it depends on API connectivity
 }
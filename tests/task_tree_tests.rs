@@ -0,0 +1,196 @@
+//! # Task Tree Tests
+//!
+//! This module contains tests for nesting subtasks under their parents and
+//! rolling up their progress and time estimates.
+//!
+//! ## Test Categories
+//!
+//! - **Tree Building**: Tests for nesting tasks by parent and handling orphans
+//! - **Rollups**: Tests for done/total counts and summed time estimates
+//! - **Formatting**: Tests for depth-first flattening and estimate formatting
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that records the
+//! requests it receives and answers with a list holding a two-level tree.
+
+use clickup_cli::commands::tasks::{execute, TaskCommands};
+use clickup_cli::models::Task;
+use clickup_cli::task_tree::{build_tree, flatten, format_estimate, is_done};
+use serde_json::json;
+mod test_utils;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns a task with the given ID, parent, status type and estimate
+fn tree_task(id: &str, parent: Option<&str>, status_type: &str, estimate: i64) -> serde_json::Value {
+    let mut task = task_json(id);
+    task["status"] = json!({"id": "s", "status": status_type, "color": "#000", "orderindex": 0, "type": status_type});
+    task["parent"] = json!(parent);
+    task["time_estimate"] = json!(estimate);
+    task
+}
+
+/// Returns an epic with two stories, one of which has a done subtask, and a chore
+fn tree_json() -> Vec<serde_json::Value> {
+    vec![
+        tree_task("epic", None, "open", 3_600_000),
+        tree_task("sub", Some("story1"), "closed", 900_000),
+        tree_task("story1", Some("epic"), "custom", 1_800_000),
+        tree_task("story2", Some("epic"), "done", 0),
+        tree_task("chore", None, "open", 0),
+    ]
+}
+
+/// Returns the tasks from `tree_json`
+fn tasks() -> Vec<Task> {
+    tree_json()
+        .into_iter()
+        .map(|task| serde_json::from_value(task).unwrap())
+        .collect()
+}
+
+/// Tests nesting subtasks under their parents, keeping the given order
+#[test]
+fn test_build_tree() {
+    let tasks = tasks();
+    let tree = build_tree(&tasks);
+    let roots: Vec<&str> = tree.iter().map(|node| node.task.id.as_str()).collect();
+    assert_eq!(roots, vec!["epic", "chore"]);
+    let stories: Vec<&str> = tree[0]
+        .children
+        .iter()
+        .map(|node| node.task.id.as_str())
+        .collect();
+    assert_eq!(stories, vec!["story1", "story2"]);
+    assert_eq!(tree[0].children[0].children[0].task.id, "sub");
+}
+
+/// Tests that subtasks whose parent is missing become roots
+#[test]
+fn test_build_tree_orphans() {
+    let tasks: Vec<Task> = vec![
+        serde_json::from_value(tree_task("a", Some("elsewhere"), "open", 0)).unwrap(),
+        serde_json::from_value(tree_task("b", Some("a"), "open", 0)).unwrap(),
+    ];
+    let tree = build_tree(&tasks);
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].task.id, "a");
+    assert_eq!(tree[0].children[0].task.id, "b");
+}
+
+/// Tests done/total counts and summed estimates at any depth
+#[test]
+fn test_rollup() {
+    let tasks = tasks();
+    let tree = build_tree(&tasks);
+    let epic = tree[0].rollup();
+    assert_eq!((epic.done, epic.total), (2, 3));
+    assert_eq!(epic.time_estimate, 6_300_000);
+    let story = tree[0].find("story1").unwrap().rollup();
+    assert_eq!((story.done, story.total), (1, 1));
+    assert_eq!(story.time_estimate, 2_700_000);
+    assert_eq!(tree[1].rollup().total, 0);
+    assert!(is_done(tree[0].find("story2").unwrap().task));
+}
+
+/// Tests depth-first flattening with depths
+#[test]
+fn test_flatten() {
+    let tasks = tasks();
+    let tree = build_tree(&tasks);
+    let flat: Vec<(usize, &str)> = flatten(&tree)
+        .into_iter()
+        .map(|(depth, node)| (depth, node.task.id.as_str()))
+        .collect();
+    assert_eq!(
+        flat,
+        vec![(0, "epic"), (1, "story1"), (2, "sub"), (1, "story2"), (0, "chore")]
+    );
+}
+
+/// Tests formatting estimates as hours and minutes
+#[test]
+fn test_format_estimate() {
+    assert_eq!(format_estimate(0), "0m");
+    assert_eq!(format_estimate(2_700_000), "45m");
+    assert_eq!(format_estimate(7_200_000), "2h");
+    assert_eq!(format_estimate(9_000_000), "2h 30m");
+}
+
+/// Starts a stand-in server that answers with the tree's list
+async fn spawn_tree_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.path.starts_with("/list/901/task") {
+            json!({"tasks": tree_json()})
+        } else if let Some(id) = request.route().strip_prefix("/task/") {
+            tree_json()
+                .into_iter()
+                .find(|task| task["id"] == id)
+                .unwrap_or_else(|| json!({}))
+        } else {
+            json!({})
+        }
+    })
+    .await
+}
+
+/// Tests that the tree view fetches the list with subtasks and closed tasks
+#[tokio::test]
+async fn test_list_tree_includes_closed() {
+    let _test_config = TestConfig::new();
+    let server = spawn_tree_server().await;
+
+    execute(
+        TaskCommands::List {
+            list_id: "901".to_string(),
+            tree: true,
+        },
+        &server_config(&server.url()),
+    )
+    .await
+    .unwrap();
+
+    let paths = server.paths();
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].contains("subtasks=true"));
+    assert!(paths[0].contains("include_closed=true"));
+}
+
+/// Tests that subtasks are looked up in the task's list
+#[tokio::test]
+async fn test_subtasks_walks_task_list() {
+    let _test_config = TestConfig::new();
+    let server = spawn_tree_server().await;
+
+    execute(
+        TaskCommands::Subtasks {
+            id: "story1".to_string(),
+        },
+        &server_config(&server.url()),
+    )
+    .await
+    .unwrap();
+
+    let paths = server.paths();
+    assert_eq!(paths[0], "/task/story1");
+    assert!(paths[1].starts_with("/list/901/task?"));
+}
+
+/// Tests that showing a task asks for its subtasks
+#[tokio::test]
+async fn test_show_requests_subtasks() {
+    let _test_config = TestConfig::new();
+    let server = spawn_tree_server().await;
+
+    execute(
+        TaskCommands::Show {
+            id: "epic".to_string(),
+        },
+        &server_config(&server.url()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(server.paths()[0], "/task/epic?include_subtasks=true");
+}
@@ -0,0 +1,387 @@
+//! # Template Tests
+//!
+//! This module contains tests for local YAML templates, ClickUp task
+//! templates, and `tasks create --from-template`.
+//!
+//! ## Test Categories
+//!
+//! - **Parsing**: Tests for reading local templates and rejecting invalid ones
+//! - **Lookup**: Tests for finding local and ClickUp templates by name or ID
+//! - **Overrides**: Tests for applying command-line values to a template
+//! - **Command**: End-to-end tests against a local stand-in server
+//!
+//! ## Test Environment
+//!
+//! Command tests point the config directory at a temporary directory, so they
+//! run serially. A small stand-in server on `127.0.0.1` records the requests
+//! it receives and answers with fixed tasks, checklists and templates.
+
+use clickup_cli::commands::tasks::{execute, TaskCommands};
+use clickup_cli::commands::templates::{
+    find_clickup_template, local_task_request, TemplateOverrides,
+};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{TaskTemplate, TemplateOverridesRequest};
+use clickup_cli::template::{
+    find_template, load_templates, templates_dir, LocalTemplate, TemplateChecklist,
+};
+mod test_utils;
+use serial_test::serial;
+use std::fs;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// A local template using every field
+const BUG_REPORT: &str = "\
+name: Bug report
+task_name: 'Bug: '
+description: |
+  Steps to reproduce:
+priority: 2
+tags: [bug]
+checklists:
+  - name: Triage
+    items: [Reproduce, Assign severity]
+subtasks:
+  - name: Write a failing test
+    tags: [testing]
+  - name: Fix
+    checklists:
+      - name: Review
+        items: [Code review]
+";
+
+/// Tests parsing a template with every field
+#[test]
+fn test_parse_local_template() {
+    let template = LocalTemplate::from_yaml(BUG_REPORT, "bug").unwrap();
+
+    assert_eq!(template.name, "Bug report");
+    assert_eq!(template.task_name(), "Bug: ");
+    assert_eq!(template.description.as_deref(), Some("Steps to reproduce:\n"));
+    assert_eq!(template.priority, Some(2));
+    assert_eq!(
+        template.checklists,
+        vec![TemplateChecklist {
+            name: "Triage".to_string(),
+            items: vec!["Reproduce".to_string(), "Assign severity".to_string()],
+        }]
+    );
+    assert_eq!(template.subtasks.len(), 2);
+    assert_eq!(template.subtasks[0].tags, vec!["testing".to_string()]);
+    assert_eq!(template.checklist_count(), 2);
+    assert_eq!(template.summary(), "2 subtasks, 2 checklists, 1 tag");
+}
+
+/// Tests the defaults for minimal templates
+#[test]
+fn test_parse_minimal_template() {
+    let empty = LocalTemplate::from_yaml("", "weekly-review").unwrap();
+    assert_eq!(empty.name, "weekly-review");
+    assert_eq!(empty.task_name(), "weekly-review");
+    assert_eq!(empty.summary(), "");
+
+    let named = LocalTemplate::from_yaml("description: Notes", "standup").unwrap();
+    assert_eq!(named.name, "standup");
+}
+
+/// Tests that typos and invalid values are rejected
+#[test]
+fn test_reject_invalid_templates() {
+    assert!(matches!(
+        LocalTemplate::from_yaml("name: Bug\nchecklist: []", "bug"),
+        Err(ClickUpError::DeserializationError(_))
+    ));
+    assert!(matches!(
+        LocalTemplate::from_yaml("priority: 7", "bug"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        LocalTemplate::from_yaml("subtasks:\n  - name: Fix\n    priority: 0", "bug"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests loading templates from a directory
+///
+/// Only `.yaml` and `.yml` files are read, templates are sorted by name, and
+/// an invalid template is reported with its file name.
+#[test]
+fn test_load_templates() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(load_templates(&dir.path().join("missing")).unwrap().is_empty());
+
+    fs::write(dir.path().join("bug.yaml"), BUG_REPORT).unwrap();
+    fs::write(dir.path().join("release.YML"), "name: Release\n").unwrap();
+    fs::write(dir.path().join("notes.txt"), "not a template").unwrap();
+
+    let templates = load_templates(dir.path()).unwrap();
+    let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["Bug report", "Release"]);
+    assert_eq!(templates[1].path, dir.path().join("release.YML"));
+
+    fs::write(dir.path().join("broken.yaml"), "tags: {").unwrap();
+    match load_templates(dir.path()) {
+        Err(ClickUpError::DeserializationError(message)) => {
+            assert!(message.contains("broken.yaml"), "{message}")
+        }
+        other => panic!("expected a deserialization error, got {other:?}"),
+    }
+}
+
+/// Tests finding local templates by name and file name
+#[test]
+fn test_find_local_template() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("bug.yaml"), BUG_REPORT).unwrap();
+    fs::write(dir.path().join("bug-copy.yaml"), BUG_REPORT).unwrap();
+    fs::write(dir.path().join("release.yaml"), "").unwrap();
+    let templates = load_templates(dir.path()).unwrap();
+
+    assert_eq!(find_template(&templates, "RELEASE").unwrap().unwrap().name, "release");
+    assert_eq!(
+        find_template(&templates, "bug-copy").unwrap().unwrap().path,
+        dir.path().join("bug-copy.yaml")
+    );
+    assert!(find_template(&templates, "launch").unwrap().is_none());
+    assert!(matches!(
+        find_template(&templates, "bug report"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+}
+
+/// Tests finding ClickUp templates by ID and name
+#[test]
+fn test_find_clickup_template() {
+    let templates: Vec<TaskTemplate> = serde_json::from_str(
+        r#"[{"id": "t-1", "name": "Onboarding"}, {"id": "t-2", "name": "Offboarding"},
+            {"id": "t-3", "name": "offboarding"}]"#,
+    )
+    .unwrap();
+
+    assert_eq!(find_clickup_template(&templates, "t-2").unwrap().id, "t-2");
+    assert_eq!(find_clickup_template(&templates, "onboarding").unwrap().id, "t-1");
+    assert!(matches!(
+        find_clickup_template(&templates, "Offboarding"),
+        Err(ClickUpError::ValidationError(_))
+    ));
+    assert!(matches!(
+        find_clickup_template(&templates, "t-9"),
+        Err(ClickUpError::NotFoundError(_))
+    ));
+}
+
+/// Tests that command-line values override a local template
+#[test]
+fn test_local_task_request_overrides() {
+    let template = LocalTemplate::from_yaml(BUG_REPORT, "bug").unwrap();
+
+    let defaults = local_task_request(&template, &TemplateOverrides::default());
+    assert_eq!(defaults.name, "Bug: ");
+    assert_eq!(defaults.priority, Some(2));
+    assert_eq!(defaults.tags, Some(vec!["bug".to_string()]));
+    assert_eq!(defaults.assignees, None);
+    assert_eq!(defaults.due_date, None);
+
+    let overrides = TemplateOverrides {
+        name: Some("Bug: login loop".to_string()),
+        priority: Some(1),
+        due_date: Some(1711929600000),
+        assignees: vec![183],
+        ..Default::default()
+    };
+    let request = local_task_request(&template, &overrides);
+    assert_eq!(request.name, "Bug: login loop");
+    assert_eq!(request.priority, Some(1));
+    assert_eq!(request.due_date, Some(1711929600000));
+    assert_eq!(request.due_date_time, Some(true));
+    assert_eq!(request.assignees, Some(vec![183]));
+    assert_eq!(request.description, template.description);
+}
+
+/// Tests that template overrides only send the fields being set
+#[test]
+fn test_template_overrides_request() {
+    assert!(TemplateOverridesRequest::default().is_empty());
+    let request = TemplateOverridesRequest {
+        due_date: Some(1711929600000),
+        due_date_time: Some(true),
+        ..Default::default()
+    };
+    assert!(!request.is_empty());
+    assert_eq!(
+        serde_json::to_value(request).unwrap(),
+        serde_json::json!({"due_date": 1711929600000_i64, "due_date_time": true})
+    );
+}
+
+/// Returns a minimal task in list `list_1`
+fn template_task(id: &str) -> serde_json::Value {
+    let mut task = task_json(id);
+    task["list"] = serde_json::json!({"id": "list_1", "access": true});
+    task
+}
+
+/// Starts a stand-in server for creating tasks from templates
+///
+/// Created tasks get the IDs `task_1`, `task_2`, ... in order, and checklists
+/// get `cl_1`, `cl_2`, .... The workspace has one ClickUp template, `t-1`.
+async fn spawn_template_server() -> MockServer {
+    let (mut tasks, mut checklists) = (0, 0);
+    MockServer::start(move |request| {
+        let path = request.path.as_str();
+        if path.ends_with("/taskTemplate?page=0") {
+            r#"{"templates": [{"id": "t-1", "name": "Onboarding"}]}"#.to_string()
+        } else if path.contains("/taskTemplate?page=") {
+            r#"{"templates": []}"#.to_string()
+        } else if path.contains("/taskTemplate/") {
+            r#"{"id": "task_from_template"}"#.to_string()
+        } else if request.method == "POST" && path.ends_with("/task") {
+            tasks += 1;
+            template_task(&format!("task_{tasks}")).to_string()
+        } else if request.method == "POST" && path.ends_with("/checklist") {
+            checklists += 1;
+            format!(
+                r#"{{"checklist": {{"id": "cl_{checklists}", "name": "c", "orderindex": 0, "resolved": 0, "date_created": "1", "items": []}}}}"#
+            )
+        } else if path.contains("/checklist_item") {
+            r#"{"checklist": {"id": "cl", "name": "c", "orderindex": 0, "resolved": 0, "date_created": "1", "items": []}}"#
+                .to_string()
+        } else {
+            template_task("task_from_template").to_string()
+        }
+    })
+    .await
+}
+
+/// Returns a create command for `--from-template`
+fn create_from_template(reference: &str, overrides: TemplateOverrides) -> TaskCommands {
+    TaskCommands::Create {
+        list_id: "list_1".to_string(),
+        name: overrides.name,
+        description: overrides.description,
+        status: overrides.status,
+        priority: overrides.priority,
+        due_date: overrides.due_date,
+        time_estimate: overrides.time_estimate,
+        assignees: overrides.assignees,
+        from_template: Some(reference.to_string()),
+        workspace_id: Some("ws_1".to_string()),
+    }
+}
+
+/// Tests creating a task with checklists and subtasks from a local template
+#[tokio::test]
+#[serial]
+async fn test_create_from_local_template() {
+    let _test_config = TestConfig::new();
+    let dir = templates_dir().unwrap();
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("bug.yaml"), BUG_REPORT).unwrap();
+    let server = spawn_template_server().await;
+    let config = server_config(&server.url());
+
+    execute(
+        create_from_template(
+            "bug",
+            TemplateOverrides {
+                name: Some("Bug: login loop".to_string()),
+                ..Default::default()
+            },
+        ),
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    let summary: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.path.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("POST", "/list/list_1/task"),
+            ("POST", "/task/task_1/checklist"),
+            ("POST", "/checklist/cl_1/checklist_item"),
+            ("POST", "/checklist/cl_1/checklist_item"),
+            ("POST", "/list/list_1/task"),
+            ("POST", "/list/list_1/task"),
+            ("POST", "/task/task_3/checklist"),
+            ("POST", "/checklist/cl_2/checklist_item"),
+        ]
+    );
+
+    let task = requests[0].json();
+    assert_eq!(task["name"], "Bug: login loop");
+    assert_eq!(task["tags"], serde_json::json!(["bug"]));
+    let subtask = requests[4].json();
+    assert_eq!(subtask["name"], "Write a failing test");
+    assert_eq!(subtask["parent"], "task_1");
+}
+
+/// Tests creating a task from a ClickUp template with overrides
+#[tokio::test]
+#[serial]
+async fn test_create_from_clickup_template() {
+    let _test_config = TestConfig::new();
+    let server = spawn_template_server().await;
+    let config = server_config(&server.url());
+
+    execute(
+        create_from_template(
+            "onboarding",
+            TemplateOverrides {
+                name: Some("Onboard Sam".to_string()),
+                due_date: Some(1711929600000),
+                assignees: vec![183],
+                ..Default::default()
+            },
+        ),
+        &config,
+    )
+    .await
+    .unwrap();
+
+    let requests = server.requests();
+    let summary: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.path.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("GET", "/team/ws_1/taskTemplate?page=0"),
+            ("GET", "/team/ws_1/taskTemplate?page=1"),
+            ("POST", "/list/list_1/taskTemplate/t-1"),
+            ("PUT", "/task/task_from_template"),
+        ]
+    );
+    assert_eq!(requests[2].body, r#"{"name":"Onboard Sam"}"#);
+    let overrides = requests[3].json();
+    assert_eq!(
+        overrides,
+        serde_json::json!({
+            "due_date": 1711929600000_i64,
+            "due_date_time": true,
+            "assignees": {"add": [183], "rem": []}
+        })
+    );
+}
+
+/// Tests that an unknown template is reported before anything is created
+#[tokio::test]
+#[serial]
+async fn test_create_from_unknown_template() {
+    let _test_config = TestConfig::new();
+    let server = spawn_template_server().await;
+    let config = server_config(&server.url());
+
+    let result = execute(
+        create_from_template("launch", TemplateOverrides::default()),
+        &config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::NotFoundError(_))));
+    assert!(server.changes().is_empty());
+}
@@ -10,10 +10,12 @@
 //! - API client creation for tests (`TestApiUtils`)
 //! - Common test assertions and helpers
 //! - Standardized error handling patterns
+//! - A stand-in ClickUp server that records requests (`MockServer`)
+//! - Task and configuration fixtures for the stand-in server
 //!
 //! ## Usage
 //!
-//! ```rust
+//! ```ignore
 //! use clickup_cli::tests::test_utils::{TestConfig, TestApiUtils};
 //! use clickup_cli::config::Config;
 //!
@@ -30,9 +32,13 @@ use clickup_cli::api::ClickUpApi;
 use clickup_cli::commands::utils::ApiUtils;
 use clickup_cli::config::Config;
 use clickup_cli::error::ClickUpError;
+use serde_json::{json, Value};
 use std::env;
 use std::fs;
+use std::sync::{Arc, Mutex};
 use tempfile::TempDir;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
 
 /// Test configuration for isolated test environments
 ///
@@ -48,7 +54,7 @@ use tempfile::TempDir;
 ///
 /// ## Usage Pattern
 ///
-/// ```rust
+/// ```ignore
 /// #[test]
 /// fn test_example() {
 ///     let _test_config = TestConfig::new();
@@ -56,6 +62,7 @@ use tempfile::TempDir;
 /// }
 /// ```
 pub struct TestConfig {
+    #[allow(dead_code)]
     temp_dir: TempDir,
     pub config_file: std::path::PathBuf,
     original_xdg: Option<String>,
@@ -110,6 +117,12 @@ impl TestConfig {
     }
 }
 
+impl Default for TestConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Drop for TestConfig {
     fn drop(&mut self) {
         // Restore original environment variables
@@ -159,7 +172,7 @@ impl TestApiUtils {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```ignore
     /// use clickup_cli::tests::test_utils::{TestConfig, TestApiUtils};
     /// use clickup_cli::config::Config;
     ///
@@ -190,7 +203,7 @@ impl TestApiUtils {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```ignore
     /// use clickup_cli::tests::test_utils::{TestConfig, TestApiUtils};
     ///
     /// #[test]
@@ -217,7 +230,7 @@ impl TestApiUtils {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```ignore
     /// use clickup_cli::tests::test_utils::{TestConfig, TestApiUtils};
     ///
     /// #[test]
@@ -251,7 +264,7 @@ impl TestAssertions {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```ignore
     /// use clickup_cli::tests::test_utils::TestAssertions;
     ///
     /// #[test]
@@ -278,7 +291,7 @@ impl TestAssertions {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```ignore
     /// use clickup_cli::tests::test_utils::TestAssertions;
     ///
     /// #[test]
@@ -305,7 +318,7 @@ impl TestAssertions {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```ignore
     /// use clickup_cli::tests::test_utils::TestAssertions;
     ///
     /// #[test]
@@ -322,6 +335,240 @@ impl TestAssertions {
     }
 }
 
+/// A request received by a `MockServer`
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// HTTP method, e.g. `GET`
+    pub method: String,
+    /// Request path including the query string
+    pub path: String,
+    /// Headers as (lowercase name, value)
+    pub headers: Vec<(String, String)>,
+    /// Request body (lossily decoded as UTF-8)
+    pub body: String,
+}
+
+impl MockRequest {
+    /// Returns the path without its query string
+    pub fn route(&self) -> &str {
+        self.path.split('?').next().unwrap_or_default()
+    }
+
+    /// Returns the value of a header, looked up case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Parses the body as JSON
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+/// A response sent by a `MockServer`
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    /// Status line after the HTTP version, e.g. `200 OK`
+    pub status: &'static str,
+    /// Extra headers as (name, value)
+    pub headers: Vec<(String, String)>,
+    /// Response body
+    pub body: String,
+}
+
+impl MockResponse {
+    /// Returns a response with the given status and body
+    pub fn new(status: &'static str, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Returns a `200 OK` response with the given body
+    pub fn ok(body: impl Into<String>) -> Self {
+        Self::new("200 OK", body)
+    }
+
+    /// Adds a header to the response
+    pub fn with_header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+}
+
+impl From<Value> for MockResponse {
+    fn from(body: Value) -> Self {
+        Self::ok(body.to_string())
+    }
+}
+
+impl From<String> for MockResponse {
+    fn from(body: String) -> Self {
+        Self::ok(body)
+    }
+}
+
+impl From<&str> for MockResponse {
+    fn from(body: &str) -> Self {
+        Self::ok(body)
+    }
+}
+
+/// Stand-in ClickUp server on `127.0.0.1` for command tests
+///
+/// Each request is recorded and answered by a handler, one connection at a
+/// time, so the recorded order is the order in which requests were sent.
+///
+/// ## Usage Pattern
+///
+/// ```ignore
+/// let server = MockServer::start(|request| match request.route() {
+///     "/api/v2/task/t1" => task_json("t1").into(),
+///     _ => MockResponse::from(json!({})),
+/// })
+/// .await;
+/// let config = server_config(&server.api_url());
+/// // ... run a command, then inspect server.requests()
+/// ```
+pub struct MockServer {
+    /// Base URL of the server, e.g. `http://127.0.0.1:1234`
+    url: String,
+    /// Requests received so far
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+    /// Starts a server that answers every request with `handler`
+    pub async fn start<F, R>(mut handler: F) -> Self
+    where
+        F: FnMut(&MockRequest) -> R + Send + 'static,
+        R: Into<MockResponse>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Arc<Mutex<Vec<MockRequest>>> = Arc::default();
+        let recorded = requests.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).await.unwrap();
+                let mut headers = Vec::new();
+                let mut content_length = 0;
+                let mut header = String::new();
+                while reader.read_line(&mut header).await.unwrap() > 2 {
+                    if let Some((name, value)) = header.split_once(':') {
+                        let name = name.trim().to_lowercase();
+                        let value = value.trim().to_string();
+                        if name == "content-length" {
+                            content_length = value.parse().unwrap();
+                        }
+                        headers.push((name, value));
+                    }
+                    header.clear();
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).await.unwrap();
+
+                let mut parts = request_line.split_whitespace();
+                let request = MockRequest {
+                    method: parts.next().unwrap_or_default().to_string(),
+                    path: parts.next().unwrap_or("/").to_string(),
+                    headers,
+                    body: String::from_utf8_lossy(&body).into_owned(),
+                };
+                recorded.lock().unwrap().push(request.clone());
+
+                let response: MockResponse = handler(&request).into();
+                let headers: String = response
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .collect();
+                let response = format!(
+                    "HTTP/1.1 {}\r\n{headers}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.status,
+                    response.body.len(),
+                    response.body
+                );
+                let mut stream = reader.into_inner();
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.ok();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// Returns the base URL of the server, e.g. `http://127.0.0.1:1234`
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// Returns the API v2 base URL of the server
+    pub fn api_url(&self) -> String {
+        format!("{}/api/v2", self.url)
+    }
+
+    /// Returns the requests received so far, oldest first
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns the paths of the requests received so far
+    pub fn paths(&self) -> Vec<String> {
+        self.requests()
+            .into_iter()
+            .map(|request| request.path)
+            .collect()
+    }
+
+    /// Returns the requests other than GETs as (method, path, body)
+    pub fn changes(&self) -> Vec<(String, String, String)> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.method != "GET")
+            .map(|request| (request.method, request.path, request.body))
+            .collect()
+    }
+}
+
+/// Returns a configuration for a `MockServer` with a token and workspace 9
+pub fn server_config(base: &str) -> Config {
+    Config {
+        api_token: Some("pk_test".to_string()),
+        api_base_url: base.to_string(),
+        workspace_id: Some("9".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Returns a minimal open task in list 901 of space 77 and workspace 9
+///
+/// Tests change the fields they care about, e.g.
+/// `task["status"]["status"] = json!("done")`.
+pub fn task_json(id: &str) -> Value {
+    json!({
+        "id": id, "name": format!("Task {id}"), "text_content": "", "description": "",
+        "status": {"id": "s", "status": "to do", "color": "#000", "orderindex": 0, "type": "open"},
+        "orderindex": "1", "date_created": "1", "date_updated": "1",
+        "creator": {"id": 1, "username": "owner", "color": "#000"},
+        "assignees": [], "watchers": [], "checklists": [], "tags": [],
+        "custom_fields": [], "team_id": "9",
+        "list": {"id": "901", "name": "Backlog", "access": true}, "space": {"id": "77"},
+        "url": format!("https://app.clickup.com/t/{id}")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,4 +618,48 @@ mod tests {
         let result: Result<(), ClickUpError> = Err(ClickUpError::NotFoundError("Item not found".to_string()));
         TestAssertions::assert_not_found_error(&result);
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_mock_server_records_requests() {
+        let server = MockServer::start(|request| match (request.method.as_str(), request.route()) {
+            ("GET", "/api/v2/task/t1") => task_json("t1").into(),
+            ("POST", _) => MockResponse::new("201 Created", request.json().to_string())
+                .with_header("X-Route", request.route()),
+            _ => MockResponse::ok("{}"),
+        })
+        .await;
+        let config = server_config(&server.api_url());
+        assert!(config.api_base_url.starts_with(&server.url()));
+
+        let client = reqwest::Client::new();
+        let task: Value = client
+            .get(format!("{}/task/t1?include_subtasks=true", server.api_url()))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(task["list"]["id"], "901");
+
+        let response = client
+            .post(format!("{}/list/901/task", server.api_url()))
+            .header("Authorization", "pk_test")
+            .json(&json!({"name": "New"}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        assert_eq!(response.headers()["x-route"], "/api/v2/list/901/task");
+
+        assert_eq!(
+            server.paths(),
+            ["/api/v2/task/t1?include_subtasks=true", "/api/v2/list/901/task"]
+        );
+        let requests = server.requests();
+        assert_eq!(requests[1].header("authorization"), Some("pk_test"));
+        let changes = server.changes();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].2, r#"{"name":"New"}"#);
+    }
+}
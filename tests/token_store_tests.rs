@@ -0,0 +1,172 @@
+//! # Token Storage Tests
+//!
+//! This module contains tests for API token storage outside `config.toml`.
+//!
+//! ## Test Categories
+//!
+//! - **Encryption**: Tests for encrypting and decrypting the token file
+//! - **Encrypted File Storage**: Tests for storing tokens in the encrypted file
+//! - **Configuration**: Tests for recording the token location in the config
+//! - **OAuth Client Secret**: Tests for keeping the OAuth app secret out of the config
+//!
+//! ## Test Environment
+//!
+//! Keyring storage is not exercised here, since it depends on a running
+//! Secret Service. Tests use temporary directories for all files.
+
+use clickup_cli::config::{Config, OAuthConfig};
+use clickup_cli::error::ClickUpError;
+use clickup_cli::oauth::OAuthClient;
+use clickup_cli::token_store::{
+    decrypt_token, encrypt_token, TokenStorage, TokenStore, PASSPHRASE_ENV,
+};
+mod test_utils;
+use tempfile::TempDir;
+use test_utils::TestConfig;
+
+/// Tests that an encrypted token decrypts with the same passphrase
+///
+/// This test also verifies that the token does not appear in the
+/// encrypted output.
+#[test]
+fn test_encrypt_decrypt_roundtrip() {
+    let data = encrypt_token("pk_123_secret", "passphrase").unwrap();
+    assert!(!data.contains("pk_123_secret"));
+
+    let token = decrypt_token(&data, "passphrase").unwrap();
+    assert_eq!(token, "pk_123_secret");
+}
+
+/// Tests that decrypting with the wrong passphrase fails
+#[test]
+fn test_decrypt_wrong_passphrase() {
+    let data = encrypt_token("pk_123_secret", "passphrase").unwrap();
+
+    match decrypt_token(&data, "wrong") {
+        Err(ClickUpError::AuthError(_)) => {}
+        other => panic!("Expected AuthError for wrong passphrase, got {other:?}"),
+    }
+}
+
+/// Tests that malformed token files are rejected
+#[test]
+fn test_decrypt_malformed_data() {
+    assert!(matches!(
+        decrypt_token("not json", "passphrase"),
+        Err(ClickUpError::DeserializationError(_))
+    ));
+    assert!(matches!(
+        decrypt_token(r#"{"salt":"00","nonce":"zz","ciphertext":"00"}"#, "passphrase"),
+        Err(ClickUpError::DeserializationError(_))
+    ));
+}
+
+/// Tests storing, loading and deleting a token in the encrypted file
+#[test]
+fn test_encrypted_file_storage() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("token-default.json");
+    let store = TokenStore::with_path("default", path.clone());
+    std::env::set_var(PASSPHRASE_ENV, "test passphrase");
+
+    assert!(store.load(TokenStorage::EncryptedFile).unwrap().is_none());
+
+    store
+        .save(TokenStorage::EncryptedFile, "pk_file_token")
+        .unwrap();
+    assert!(path.exists());
+    assert!(!std::fs::read_to_string(&path).unwrap().contains("pk_file_token"));
+    assert_eq!(
+        store.load(TokenStorage::EncryptedFile).unwrap().as_deref(),
+        Some("pk_file_token")
+    );
+
+    store.delete(TokenStorage::EncryptedFile).unwrap();
+    assert!(!path.exists());
+    // Deleting a token that isn't stored is not an error
+    store.delete(TokenStorage::EncryptedFile).unwrap();
+}
+
+/// Tests that config storage never touches the token store
+#[test]
+fn test_config_storage_is_noop() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("token-default.json");
+    let store = TokenStore::with_path("default", path.clone());
+
+    store.save(TokenStorage::Config, "pk_token").unwrap();
+    assert!(!path.exists());
+    assert!(store.load(TokenStorage::Config).unwrap().is_none());
+}
+
+/// Tests that profile names which aren't safe file names are rejected
+#[test]
+fn test_token_store_invalid_profile_names() {
+    let _test_config = TestConfig::new();
+    assert!(TokenStore::for_profile("").is_err());
+    assert!(TokenStore::for_profile("../evil").is_err());
+    assert!(TokenStore::for_profile("a/b").is_err());
+    assert!(TokenStore::for_profile("client").is_ok());
+}
+
+/// Tests that tokens kept outside the config file are never saved to it
+///
+/// This test verifies that `token_storage` is written to `config.toml`
+/// while `api_token` is left out.
+#[test]
+fn test_config_does_not_save_secure_token() {
+    let test_config = TestConfig::new();
+    let config = Config {
+        api_token: Some("pk_secret_token".to_string()),
+        token_storage: TokenStorage::EncryptedFile,
+        ..Default::default()
+    };
+    config.save_with_path(Some(&test_config.config_file)).unwrap();
+
+    let content = std::fs::read_to_string(&test_config.config_file).unwrap();
+    assert!(!content.contains("pk_secret_token"));
+    assert!(content.contains("token_storage = \"encrypted-file\""));
+}
+
+/// Tests that older configurations default to plaintext storage
+#[test]
+fn test_config_token_storage_defaults_to_config() {
+    let test_config = TestConfig::new();
+    std::fs::write(&test_config.config_file, "api_token = \"pk_plain_token\"\n").unwrap();
+
+    let config = Config::load_with_path(Some(&test_config.config_file)).unwrap();
+    assert_eq!(config.token_storage, TokenStorage::Config);
+    assert_eq!(config.api_token.as_deref(), Some("pk_plain_token"));
+}
+
+/// Tests that a plaintext OAuth client secret is moved out of the config file
+///
+/// This test verifies that only the secret's location is written to
+/// `config.toml`, and that the OAuth client reads the secret back from it.
+#[test]
+fn test_oauth_client_secret_moved_out_of_config() {
+    let test_config = TestConfig::new();
+    std::env::set_var(PASSPHRASE_ENV, "test passphrase");
+    let mut config = Config {
+        oauth: OAuthConfig {
+            client_id: Some("app_id".to_string()),
+            client_secret: Some("app_secret".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let storage = config
+        .store_oauth_client_secret("app_secret".to_string(), Some(TokenStorage::EncryptedFile))
+        .unwrap();
+    assert_eq!(storage, TokenStorage::EncryptedFile);
+
+    let content = std::fs::read_to_string(&test_config.config_file).unwrap();
+    assert!(!content.contains("app_secret"));
+    assert!(content.contains("client_secret_storage = \"encrypted-file\""));
+
+    let loaded = Config::load_with_path(Some(&test_config.config_file)).unwrap();
+    assert!(loaded.oauth.client_secret.is_none());
+    let client = OAuthClient::from_config(&loaded, None, None).unwrap();
+    assert_eq!(client.client_secret(), "app_secret");
+}
@@ -34,7 +34,7 @@ mod table_builder_tests {
         
         // Test that add_header doesn't panic and returns self for chaining
         let result = builder.add_header(headers);
-        assert!(std::ptr::eq(result, &mut builder));
+        assert!(std::ptr::eq(result, &builder));
     }
 
     #[test]
@@ -44,7 +44,7 @@ mod table_builder_tests {
         
         // Test that add_row doesn't panic and returns self for chaining
         let result = builder.add_row(row_data);
-        assert!(std::ptr::eq(result, &mut builder));
+        assert!(std::ptr::eq(result, &builder));
     }
 
     #[test]
@@ -342,7 +342,6 @@ mod command_executor_trait_tests {
         // and can be imported. The actual implementation testing is done
         // in the individual command module tests.
         // We can't easily test the trait without complex mocking, so we just verify it exists
-        assert!(true); // Placeholder test
     }
 }
 
@@ -0,0 +1,199 @@
+//! # View Tests
+//!
+//! This module contains tests for view models and the `views` commands.
+//!
+//! ## Test Categories
+//!
+//! - **Models**: Tests for decoding views and their locations
+//! - **Descriptions**: Tests for describing filters, sorting and grouping
+//! - **Pagination**: Tests for fetching the tasks of a view page by page
+//!
+//! ## Test Environment
+//!
+//! Pagination tests run a small stand-in server on `127.0.0.1` that serves
+//! three pages of view tasks and records the requested paths.
+
+use clickup_cli::api::ClickUpApi;
+use clickup_cli::commands::views::{
+    describe_filters, describe_grouping, describe_sorting, execute, ViewCommands,
+};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{ViewParentKind, ViewResponse, ViewTasksResponse, ViewsResponse};
+mod test_utils;
+use serde_json::json;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Views of a list as returned by the API, including the built-in views
+const LIST_VIEWS: &str = r#"{
+    "views": [
+        {"id": "3c-105", "name": "Sprint board", "type": "board",
+         "parent": {"id": "901", "type": 6}, "visibility": "public", "protected": false},
+        {"id": "3c-100", "name": "List", "type": "list", "parent": {"id": 901, "type": "6"}}
+    ],
+    "required_views": {
+        "list": {"id": "3c-100", "name": "List", "type": "list", "parent": {"id": "901", "type": 6}},
+        "board": null
+    }
+}"#;
+
+/// Tests decoding views and merging the built-in views
+#[test]
+fn test_decode_views() {
+    let response: ViewsResponse = serde_json::from_str(LIST_VIEWS).unwrap();
+    let views = response.all_views();
+
+    assert_eq!(views.len(), 2);
+    assert_eq!(views[0].id, "3c-100");
+    assert_eq!(views[1].name, "Sprint board");
+    assert_eq!(views[1].protected, Some(false));
+    assert_eq!(
+        views[1].parent.as_ref().unwrap().kind(),
+        Some(ViewParentKind::List)
+    );
+
+    let workspace: ViewsResponse = serde_json::from_str(r#"{"views": []}"#).unwrap();
+    assert!(workspace.all_views().is_empty());
+}
+
+/// Tests mapping location type codes and endpoint segments
+#[test]
+fn test_view_parent_kinds() {
+    assert_eq!(ViewParentKind::from_code(7), Some(ViewParentKind::Workspace));
+    assert_eq!(ViewParentKind::from_code(4), Some(ViewParentKind::Space));
+    assert_eq!(ViewParentKind::from_code(5), Some(ViewParentKind::Folder));
+    assert_eq!(ViewParentKind::from_code(6), Some(ViewParentKind::List));
+    assert_eq!(ViewParentKind::from_code(1), None);
+    assert_eq!(ViewParentKind::Workspace.endpoint_segment(), "team");
+    assert_eq!(ViewParentKind::Folder.endpoint_segment(), "folder");
+}
+
+/// Tests describing a view's filters, sorting and grouping
+#[test]
+fn test_describe_view_settings() {
+    let view: ViewResponse = serde_json::from_value(json!({
+        "view": {
+            "id": "3c-105", "name": "My open bugs", "type": "list",
+            "filters": {
+                "op": "and",
+                "fields": [
+                    {"field": "assignee", "op": "EQ", "values": ["me"]},
+                    {"field": "tag", "op": "ANY", "values": ["bug", "regression"]},
+                    {"field": "dueDate", "op": "IS SET", "values": null}
+                ],
+                "search": "login",
+                "show_closed": false
+            },
+            "sorting": {"fields": [{"field": "priority", "dir": -1}, {"field": "dueDate", "dir": 1}]},
+            "grouping": {"field": "status", "dir": 1}
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        describe_filters(view.view.filters.as_ref().unwrap()),
+        vec![
+            "assignee EQ me",
+            "AND tag ANY bug, regression",
+            "AND dueDate IS SET",
+            "search \"login\"",
+            "closed tasks hidden",
+        ]
+    );
+    assert_eq!(
+        describe_sorting(view.view.sorting.as_ref().unwrap()).as_deref(),
+        Some("priority descending, dueDate ascending")
+    );
+    assert_eq!(
+        describe_grouping(view.view.grouping.as_ref().unwrap()).as_deref(),
+        Some("status")
+    );
+
+    assert!(describe_filters(&json!({})).is_empty());
+    assert_eq!(describe_sorting(&json!({"fields": []})), None);
+    assert_eq!(describe_grouping(&json!({"field": "none"})), None);
+}
+
+/// Tests that a page without `last_page` is treated as the last one
+#[test]
+fn test_view_tasks_last_page_default() {
+    let page: ViewTasksResponse = serde_json::from_str(r#"{"tasks": []}"#).unwrap();
+    assert!(page.last_page);
+}
+
+/// Starts a stand-in server that serves three pages of view tasks
+///
+/// Each page holds one task. The requested paths are recorded.
+async fn spawn_view_server() -> MockServer {
+    MockServer::start(|request| {
+        let page: usize = request
+            .path
+            .rsplit("page=")
+            .next()
+            .and_then(|page| page.parse().ok())
+            .unwrap_or(0);
+        json!({"tasks": [task_json(&format!("task_{page}"))], "last_page": page == 2})
+    })
+    .await
+}
+
+/// Tests fetching every page of a view's tasks
+#[tokio::test]
+async fn test_get_all_view_tasks() {
+    let _test_config = TestConfig::new();
+    let server = spawn_view_server().await;
+    let api = ClickUpApi::new(server_config(&server.url())).unwrap();
+
+    let tasks = api.get_all_view_tasks("3c-105").await.unwrap().tasks;
+    let ids: Vec<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    assert_eq!(ids, vec!["task_0", "task_1", "task_2"]);
+    assert_eq!(
+        server.paths(),
+        vec![
+            "/view/3c-105/task?page=0",
+            "/view/3c-105/task?page=1",
+            "/view/3c-105/task?page=2",
+        ]
+    );
+}
+
+/// Tests fetching a single page through `views tasks --page`
+#[tokio::test]
+async fn test_view_tasks_single_page() {
+    let _test_config = TestConfig::new();
+    let server = spawn_view_server().await;
+    let config = server_config(&server.url());
+
+    execute(
+        ViewCommands::Tasks {
+            id: "3c-105".to_string(),
+            page: Some(1),
+        },
+        &config,
+    )
+    .await
+    .unwrap();
+    assert_eq!(server.paths(), vec!["/view/3c-105/task?page=1"]);
+}
+
+/// Tests that listing workspace views needs a workspace ID
+#[tokio::test]
+async fn test_list_views_requires_workspace() {
+    let _test_config = TestConfig::new();
+    let config = Config {
+        api_token: Some("pk_test".to_string()),
+        ..Default::default()
+    };
+
+    let result = execute(
+        ViewCommands::List {
+            workspace_id: None,
+            space_id: None,
+            folder_id: None,
+            list_id: None,
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
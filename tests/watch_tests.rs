@@ -0,0 +1,264 @@
+//! # Watch Tests
+//!
+//! This module contains tests for the snapshot diffing behind `tasks watch`
+//! and for the command itself.
+//!
+//! ## Test Categories
+//!
+//! - **Intervals**: Tests for parsing poll intervals
+//! - **Diffing**: Tests for detecting created, updated, closed and deleted tasks
+//! - **State**: Tests for saving and loading snapshots
+//! - **Command**: Tests for polling a list through the `tasks watch` command
+//!
+//! ## Test Environment
+//!
+//! Command tests run a small stand-in server on `127.0.0.1` that returns a
+//! different set of tasks for each poll. No requests are sent to the ClickUp API.
+
+use clickup_cli::commands::tasks::{execute, TaskCommands};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::Task;
+use clickup_cli::watch::{diff, parse_interval, TaskSnapshot, WatchEventKind, WatchState};
+mod test_utils;
+use serde_json::{json, Value};
+use std::time::Duration;
+use test_utils::{server_config, task_json, MockServer, TestConfig};
+
+/// Returns a minimal task with the given status and update time
+fn watched_task(id: &str, status: &str, status_type: &str, date_updated: &str) -> Value {
+    let mut task = task_json(id);
+    task["status"] = json!({"id": "s", "status": status, "color": "#000", "orderindex": 0, "type": status_type});
+    task["date_created"] = json!("1700000000000");
+    task["date_updated"] = json!(date_updated);
+    task["dependencies"] = json!([]);
+    task["linked_tasks"] = json!([]);
+    task
+}
+
+/// Returns a snapshot with the given status and update time
+fn snapshot(status: &str, date_updated: &str) -> TaskSnapshot {
+    TaskSnapshot {
+        name: "Task".to_string(),
+        status: status.to_string(),
+        date_updated: date_updated.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Returns a list state containing the given tasks
+fn state(tasks: Vec<(&str, TaskSnapshot)>) -> WatchState {
+    let mut state = WatchState::new("901");
+    for (id, task) in tasks {
+        state.tasks.insert(id.to_string(), task);
+    }
+    state
+}
+
+/// Tests parsing intervals with and without units
+#[test]
+fn test_parse_interval() {
+    assert_eq!(parse_interval("60s").unwrap(), Duration::from_secs(60));
+    assert_eq!(parse_interval("90").unwrap(), Duration::from_secs(90));
+    assert_eq!(parse_interval("5m").unwrap(), Duration::from_secs(300));
+    assert_eq!(parse_interval("1h").unwrap(), Duration::from_secs(3600));
+
+    for invalid in ["", "s", "10d", "1.5m", "-5s", "1s"] {
+        assert!(
+            matches!(parse_interval(invalid), Err(ClickUpError::ValidationError(_))),
+            "expected '{invalid}' to be rejected"
+        );
+    }
+}
+
+/// Tests that new and missing tasks are reported as created and deleted
+#[test]
+fn test_diff_created_and_deleted() {
+    let previous = state(vec![("a", snapshot("to do", "1")), ("b", snapshot("to do", "1"))]);
+    let current = state(vec![("b", snapshot("to do", "1")), ("c", snapshot("to do", "1"))]);
+
+    let events = diff(&previous, &current);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].event, WatchEventKind::Created);
+    assert_eq!(events[0].task_id, "c");
+    assert_eq!(events[1].event, WatchEventKind::Deleted);
+    assert_eq!(events[1].task_id, "a");
+}
+
+/// Tests that changes to tracked fields are listed on update events
+#[test]
+fn test_diff_field_changes() {
+    let before = snapshot("to do", "1");
+    let after = TaskSnapshot {
+        status: "in progress".to_string(),
+        assignees: vec!["alice".to_string()],
+        due_date: Some("1700000000000".to_string()),
+        date_updated: "2".to_string(),
+        ..before.clone()
+    };
+
+    let events = diff(&state(vec![("a", before)]), &state(vec![("a", after)]));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, WatchEventKind::Updated);
+    let fields: Vec<&str> = events[0].changes.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(fields, vec!["status", "assignees", "due_date"]);
+    assert_eq!(events[0].changes[0].before, "to do");
+    assert_eq!(events[0].changes[0].after, "in progress");
+    assert_eq!(events[0].changes[2].before, serde_json::Value::Null);
+}
+
+/// Tests that a task moving to a closed status is reported as closed
+///
+/// This test also verifies that reopening a task is reported as an update.
+#[test]
+fn test_diff_closed_and_reopened() {
+    let open = snapshot("in progress", "1");
+    let closed = TaskSnapshot {
+        status: "complete".to_string(),
+        closed: true,
+        date_updated: "2".to_string(),
+        ..open.clone()
+    };
+
+    let events = diff(&state(vec![("a", open.clone())]), &state(vec![("a", closed.clone())]));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, WatchEventKind::Closed);
+    assert_eq!(events[0].changes[0].field, "status");
+
+    let reopened = TaskSnapshot {
+        date_updated: "3".to_string(),
+        ..open
+    };
+    let events = diff(&state(vec![("a", closed)]), &state(vec![("a", reopened)]));
+    assert_eq!(events[0].event, WatchEventKind::Updated);
+}
+
+/// Tests that an update to untracked fields is still reported
+#[test]
+fn test_diff_date_updated_only() {
+    let events = diff(
+        &state(vec![("a", snapshot("to do", "1"))]),
+        &state(vec![("a", snapshot("to do", "2"))]),
+    );
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, WatchEventKind::Updated);
+    assert!(events[0].changes.is_empty());
+
+    let unchanged = diff(
+        &state(vec![("a", snapshot("to do", "1"))]),
+        &state(vec![("a", snapshot("to do", "1"))]),
+    );
+    assert!(unchanged.is_empty());
+}
+
+/// Tests building a snapshot from an API task
+#[test]
+fn test_snapshot_from_task() {
+    let task: Task = serde_json::from_value(watched_task("a", "complete", "closed", "5")).unwrap();
+    let snapshot = TaskSnapshot::from(&task);
+
+    assert_eq!(snapshot.name, "Task a");
+    assert_eq!(snapshot.status, "complete");
+    assert!(snapshot.closed);
+    assert_eq!(snapshot.date_updated, "5");
+    assert_eq!(snapshot.url, "https://app.clickup.com/t/a");
+}
+
+/// Tests saving and loading a snapshot
+#[test]
+fn test_state_save_and_load() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("watch").join("901.json");
+
+    assert!(WatchState::load(&path).unwrap().is_none());
+
+    let saved = state(vec![("a", snapshot("to do", "1"))]);
+    saved.save(&path).unwrap();
+    let loaded = WatchState::load(&path).unwrap().unwrap();
+    assert_eq!(loaded.list_id, "901");
+    assert_eq!(loaded.tasks, saved.tasks);
+}
+
+/// Starts a stand-in server that serves a different task list on each poll
+async fn spawn_list_server(polls: Vec<Vec<Value>>) -> MockServer {
+    let mut served = 0;
+    MockServer::start(move |request| {
+        assert!(request.path.contains("include_closed=true"));
+        let index = served.min(polls.len() - 1);
+        served += 1;
+        json!({ "tasks": polls[index] })
+    })
+    .await
+}
+
+/// Tests that the watch state persists between runs
+///
+/// This test verifies that the first run records a baseline, that a later
+/// run picks up the saved snapshot, and that `--reset` discards it.
+#[tokio::test]
+async fn test_watch_once_persists_state() {
+    let _test_config = TestConfig::new();
+    let server = spawn_list_server(vec![
+        vec![watched_task("a", "to do", "open", "1")],
+        vec![
+            watched_task("a", "complete", "closed", "2"),
+            watched_task("b", "to do", "open", "2"),
+        ],
+    ])
+    .await;
+    let config = server_config(&server.url());
+    let watch = |reset| TaskCommands::Watch {
+        list_id: "901".to_string(),
+        interval: "60s".to_string(),
+        json: true,
+        once: true,
+        reset,
+    };
+    let path = WatchState::default_path("901").unwrap();
+
+    execute(watch(false), &config).await.unwrap();
+    let baseline = WatchState::load(&path).unwrap().unwrap();
+    assert_eq!(baseline.tasks.len(), 1);
+    assert!(!baseline.tasks["a"].closed);
+
+    execute(watch(false), &config).await.unwrap();
+    let latest = WatchState::load(&path).unwrap().unwrap();
+    assert_eq!(latest.tasks.len(), 2);
+    assert!(latest.tasks["a"].closed);
+    assert_eq!(server.requests().len(), 2);
+
+    execute(watch(true), &config).await.unwrap();
+    assert_eq!(WatchState::load(&path).unwrap().unwrap().tasks.len(), 2);
+}
+
+/// Tests that list IDs which could escape the state directory are rejected
+#[test]
+fn test_watch_state_path_rejects_unsafe_list_id() {
+    for list_id in ["../901", "901/..", "..", "", "9 01"] {
+        let result = WatchState::default_path(list_id);
+        assert!(
+            matches!(result, Err(ClickUpError::ValidationError(_))),
+            "{list_id:?} was accepted"
+        );
+    }
+}
+
+/// Tests that an invalid interval is rejected before any request
+#[tokio::test]
+async fn test_watch_invalid_interval() {
+    let result = execute(
+        TaskCommands::Watch {
+            list_id: "901".to_string(),
+            interval: "soon".to_string(),
+            json: false,
+            once: true,
+            reset: false,
+        },
+        &Config {
+            api_token: Some("pk_test".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
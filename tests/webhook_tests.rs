@@ -0,0 +1,349 @@
+//! # Webhook Tests
+//!
+//! This module contains tests for webhook models, signature verification,
+//! the local webhook receiver, and the `webhooks` command.
+//!
+//! ## Test Categories
+//!
+//! - **Signatures**: Tests for signing and verifying deliveries
+//! - **Models**: Tests for decoding webhooks and webhook events
+//! - **Receiver**: Tests for accepting and rejecting deliveries over HTTP
+//! - **Forwarding**: Tests for passing events to a shell command
+//! - **Command**: Tests for argument validation in the `webhooks` command
+//!
+//! ## Test Environment
+//!
+//! Receiver tests bind to a free port on `127.0.0.1` and send requests with
+//! reqwest. No requests are sent to the ClickUp API.
+
+use clickup_cli::commands::webhooks::{execute, WebhookCommands};
+use clickup_cli::config::Config;
+use clickup_cli::error::ClickUpError;
+use clickup_cli::models::{WebhookEvent, WebhookEventType, WebhooksResponse};
+use clickup_cli::webhook::{verify_signature, Delivery, WebhookReceiver};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+mod test_utils;
+use test_utils::TestConfig;
+
+const SECRET: &str = "webhook_secret";
+
+const STATUS_EVENT: &str = r##"{
+    "event": "taskStatusUpdated",
+    "history_items": [{
+        "id": "2800787904723074029",
+        "type": 1,
+        "date": "1642740510345",
+        "field": "status",
+        "parent_id": "162641285",
+        "data": {"status_type": "custom"},
+        "source": null,
+        "user": {"id": 183, "username": "John", "email": "john@company.com", "color": "#7b68ee"},
+        "before": {"status": "to do", "color": "#f9d900", "orderindex": 0, "type": "open"},
+        "after": {"status": "in progress", "color": "#7C4DFF", "orderindex": 1, "type": "custom"}
+    }],
+    "task_id": "1vj37mc",
+    "webhook_id": "7fa3ec74-69a8-4530-a251-8a13730bd204"
+}"##;
+
+/// Starts a receiver on a free port and returns it with its base URL
+async fn start_receiver(secret: Option<&str>) -> (WebhookReceiver, String) {
+    let receiver = WebhookReceiver::bind("127.0.0.1", 0, secret.map(str::to_string))
+        .await
+        .unwrap();
+    let url = format!("http://{}", receiver.local_addr().unwrap());
+    (receiver, url)
+}
+
+/// Sends a delivery to the receiver in the background
+fn post(
+    url: &str,
+    body: &'static str,
+    signature: Option<String>,
+) -> tokio::task::JoinHandle<reqwest::StatusCode> {
+    let url = url.to_string();
+    tokio::spawn(async move {
+        let mut request = reqwest::Client::new().post(url).body(body);
+        if let Some(signature) = signature {
+            request = request.header("X-Signature", signature);
+        }
+        request.send().await.unwrap().status()
+    })
+}
+
+/// Computes the signature ClickUp sends for a payload
+///
+/// Returns the hex-encoded HMAC-SHA256 of the body, as sent in `X-Signature`.
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Tests signing against a known HMAC-SHA256 test vector
+#[test]
+fn test_sign_known_vector() {
+    let signature = sign("key", b"The quick brown fox jumps over the lazy dog");
+    assert_eq!(
+        signature,
+        "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    );
+}
+
+/// Tests that signatures only verify for the same secret and body
+#[test]
+fn test_verify_signature() {
+    let body = STATUS_EVENT.as_bytes();
+    let signature = sign(SECRET, body);
+
+    assert!(verify_signature(SECRET, body, &signature));
+    assert!(verify_signature(SECRET, body, &signature.to_uppercase()));
+    assert!(!verify_signature("wrong_secret", body, &signature));
+    assert!(!verify_signature(SECRET, b"tampered", &signature));
+    assert!(!verify_signature(SECRET, body, "not hex"));
+    assert!(!verify_signature(SECRET, body, ""));
+}
+
+/// Tests decoding a task status event into typed structs
+#[test]
+fn test_decode_status_event() {
+    let event: WebhookEvent = serde_json::from_str(STATUS_EVENT).unwrap();
+
+    assert_eq!(event.event, WebhookEventType::TaskStatusUpdated);
+    assert_eq!(event.task_id.as_deref(), Some("1vj37mc"));
+    assert_eq!(event.history_items.len(), 1);
+
+    let item = &event.history_items[0];
+    assert_eq!(item.field.as_deref(), Some("status"));
+    assert_eq!(item.date.as_deref(), Some("1642740510345"));
+    assert_eq!(item.user.as_ref().unwrap().username.as_deref(), Some("John"));
+    assert_eq!(item.after.as_ref().unwrap()["status"], "in progress");
+}
+
+/// Tests decoding events with unknown types and numeric IDs
+#[test]
+fn test_decode_unknown_event() {
+    let event: WebhookEvent =
+        serde_json::from_str(r#"{"event":"docCreated","list_id":901234,"webhook_id":"w1"}"#)
+            .unwrap();
+
+    assert_eq!(event.event, WebhookEventType::Unknown);
+    assert_eq!(event.list_id.as_deref(), Some("901234"));
+    assert!(event.history_items.is_empty());
+}
+
+/// Tests event name lookups
+#[test]
+fn test_event_type_names() {
+    assert_eq!(
+        WebhookEventType::from_name("taskCommentPosted"),
+        Some(WebhookEventType::TaskCommentPosted)
+    );
+    assert_eq!(WebhookEventType::from_name("notAnEvent"), None);
+    for event in WebhookEventType::ALL {
+        assert_eq!(WebhookEventType::from_name(event.as_str()), Some(event));
+    }
+}
+
+/// Tests decoding a webhook list response
+#[test]
+fn test_decode_webhooks_response() {
+    let response: WebhooksResponse = serde_json::from_str(
+        r#"{"webhooks":[{
+            "id": "4b67ac88-e506-4a29-9d42-26e504e3435e",
+            "userid": 183,
+            "team_id": 108,
+            "endpoint": "https://example.com/hook",
+            "client_id": "QVOQP06ZXC6CMGVFKB0ZT7J9Y7APOYGO",
+            "events": ["taskCreated", "taskStatusUpdated"],
+            "task_id": null,
+            "list_id": 901234,
+            "folder_id": null,
+            "space_id": null,
+            "health": {"status": "active", "fail_count": 0},
+            "secret": "O94IM25S7PXBPYTMNXLLET230SRP0S89COR7B1YOJ2ZIE8WQNK5UUKEF26W0Z5GA"
+        }]}"#,
+    )
+    .unwrap();
+
+    let webhook = &response.webhooks[0];
+    assert_eq!(webhook.team_id.as_deref(), Some("108"));
+    assert_eq!(webhook.list_id.as_deref(), Some("901234"));
+    assert_eq!(webhook.events.len(), 2);
+    assert_eq!(webhook.health.as_ref().unwrap().fail_count, Some(0));
+    assert!(webhook.secret.is_some());
+}
+
+/// Tests that a correctly signed delivery is accepted and decoded
+///
+/// This test also verifies that health checks are answered and skipped.
+#[tokio::test]
+async fn test_receiver_accepts_signed_delivery() {
+    let (receiver, url) = start_receiver(Some(SECRET)).await;
+
+    // The health check must arrive first, so send both from one task
+    let sender = tokio::spawn(async move {
+        let health = reqwest::get(&url).await.unwrap().status();
+        let delivery = reqwest::Client::new()
+            .post(&url)
+            .header("X-Signature", sign(SECRET, STATUS_EVENT.as_bytes()))
+            .body(STATUS_EVENT)
+            .send()
+            .await
+            .unwrap()
+            .status();
+        (health, delivery)
+    });
+
+    let delivery = receiver.next().await.unwrap();
+    match delivery {
+        Delivery::Event { event, payload } => {
+            assert_eq!(event.event, WebhookEventType::TaskStatusUpdated);
+            assert_eq!(payload, STATUS_EVENT);
+        }
+        other => panic!("Expected an event, got {other:?}"),
+    }
+    let (health, delivery) = sender.await.unwrap();
+    assert_eq!(health, 200);
+    assert_eq!(delivery, 200);
+}
+
+/// Tests that deliveries with a wrong or missing signature are rejected
+#[tokio::test]
+async fn test_receiver_rejects_bad_signatures() {
+    let (receiver, url) = start_receiver(Some(SECRET)).await;
+
+    let sender = post(&url, STATUS_EVENT, Some(sign("other_secret", STATUS_EVENT.as_bytes())));
+    match receiver.next().await.unwrap() {
+        Delivery::Rejected { reason } => assert_eq!(reason, "invalid signature"),
+        other => panic!("Expected a rejection, got {other:?}"),
+    }
+    assert_eq!(sender.await.unwrap(), 401);
+
+    let sender = post(&url, STATUS_EVENT, None);
+    match receiver.next().await.unwrap() {
+        Delivery::Rejected { reason } => assert!(reason.contains("X-Signature")),
+        other => panic!("Expected a rejection, got {other:?}"),
+    }
+    assert_eq!(sender.await.unwrap(), 401);
+}
+
+/// Tests that malformed payloads are rejected after verification
+#[tokio::test]
+async fn test_receiver_rejects_invalid_payload() {
+    let (receiver, url) = start_receiver(Some(SECRET)).await;
+
+    let sender = post(&url, "not json", Some(sign(SECRET, b"not json")));
+    assert!(matches!(
+        receiver.next().await.unwrap(),
+        Delivery::Rejected { .. }
+    ));
+    assert_eq!(sender.await.unwrap(), 400);
+}
+
+/// Tests that a receiver without a secret accepts unsigned deliveries
+#[tokio::test]
+async fn test_receiver_without_verification() {
+    let (receiver, url) = start_receiver(None).await;
+
+    let sender = post(&url, STATUS_EVENT, None);
+    assert!(matches!(
+        receiver.next().await.unwrap(),
+        Delivery::Event { .. }
+    ));
+    assert_eq!(sender.await.unwrap(), 200);
+}
+
+/// Tests forwarding an event to a shell command
+///
+/// This test verifies that the payload is passed on stdin and the event
+/// details in environment variables.
+#[cfg(unix)]
+#[tokio::test]
+async fn test_forward_to_command() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output = temp_dir.path().join("out.txt");
+    let command = format!(
+        "printf '%s %s\\n' \"$CLICKUP_WEBHOOK_EVENT\" \"$CLICKUP_TASK_ID\" > '{0}' && cat >> '{0}'",
+        output.display()
+    );
+    let event: WebhookEvent = serde_json::from_str(STATUS_EVENT).unwrap();
+
+    let status = clickup_cli::webhook::forward_to_command(&command, &event, STATUS_EVENT)
+        .await
+        .unwrap();
+    assert!(status.success());
+
+    let written = std::fs::read_to_string(&output).unwrap();
+    assert!(written.starts_with("taskStatusUpdated 1vj37mc\n"));
+    assert!(written.ends_with(STATUS_EVENT));
+}
+
+/// Tests that creating a webhook with an unknown event fails before any request
+#[tokio::test]
+async fn test_create_webhook_unknown_event() {
+    let _test_config = TestConfig::new();
+    let config = Config {
+        workspace_id: Some("123".to_string()),
+        ..Default::default()
+    };
+
+    let result = execute(
+        WebhookCommands::Create {
+            workspace_id: None,
+            endpoint: "https://example.com/hook".to_string(),
+            events: vec!["taskCreated".to_string(), "taskExploded".to_string()],
+            space_id: None,
+            folder_id: None,
+            list_id: None,
+            task_id: None,
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
+
+/// Tests that updating a webhook requires at least one change
+#[tokio::test]
+async fn test_update_webhook_requires_changes() {
+    let _test_config = TestConfig::new();
+    let config = Config::default();
+
+    let result = execute(
+        WebhookCommands::Update {
+            id: "webhook_1".to_string(),
+            endpoint: None,
+            events: None,
+            activate: false,
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}
+
+/// Tests that listening requires a secret unless verification is disabled
+#[tokio::test]
+async fn test_listen_requires_secret() {
+    let _test_config = TestConfig::new();
+    let config = Config::default();
+
+    let result = execute(
+        WebhookCommands::Listen {
+            port: 0,
+            host: "127.0.0.1".to_string(),
+            secret: None,
+            webhook_id: None,
+            workspace_id: None,
+            no_verify: false,
+            events: Vec::new(),
+            exec: None,
+            json: false,
+            max_events: Some(1),
+        },
+        &config,
+    )
+    .await;
+    assert!(matches!(result, Err(ClickUpError::ValidationError(_))));
+}